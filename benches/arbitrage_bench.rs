@@ -1,7 +1,7 @@
-///! Benchmarks for arbitrage detection
-///!
-///! Measures performance of scalar vs SIMD arbitrage detection.
-///! Target: <10μs detection latency
+//! Benchmarks for arbitrage detection
+//!
+//! Measures performance of scalar vs SIMD arbitrage detection.
+//! Target: <10μs detection latency
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, BenchmarkId};
 use polymarket_hft_bot::types::{MarketId, OrderBook, OrderBookEntry, TokenId};
@@ -28,7 +28,7 @@ fn create_test_order_book(bid_price: f64, ask_price: f64, size: f64) -> OrderBoo
 
 fn benchmark_scalar_single(c: &mut Criterion) {
     let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-    let market_id = MarketId("market-1".to_string());
+    let market_id = MarketId::new("market-1");
     let token_id = TokenId("token-1".to_string());
     let order_book = create_test_order_book(0.75, 0.70, 100.0);
 
@@ -50,7 +50,7 @@ fn benchmark_scalar_batch(c: &mut Criterion) {
         let markets: Vec<_> = (0..*size)
             .map(|i| {
                 (
-                    MarketId(format!("market-{}", i)),
+                    MarketId::new(format!("market-{}", i)),
                     TokenId(format!("token-{}", i)),
                     create_test_order_book(0.75, 0.70, 100.0),
                 )
@@ -73,22 +73,22 @@ fn benchmark_simd_batch(c: &mut Criterion) {
     // Test with exactly 4 (optimal for SIMD)
     let markets_4: [(MarketId, TokenId, OrderBook); 4] = [
         (
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_test_order_book(0.75, 0.70, 100.0),
         ),
         (
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_test_order_book(0.72, 0.68, 100.0),
         ),
         (
-            MarketId("m3".to_string()),
+            MarketId::new("m3"),
             TokenId("t3".to_string()),
             create_test_order_book(0.80, 0.75, 100.0),
         ),
         (
-            MarketId("m4".to_string()),
+            MarketId::new("m4"),
             TokenId("t4".to_string()),
             create_test_order_book(0.65, 0.60, 100.0),
         ),
@@ -103,7 +103,7 @@ fn benchmark_simd_batch(c: &mut Criterion) {
         let markets: Vec<_> = (0..*size)
             .map(|i| {
                 (
-                    MarketId(format!("market-{}", i)),
+                    MarketId::new(format!("market-{}", i)),
                     TokenId(format!("token-{}", i)),
                     create_test_order_book(0.75, 0.70, 100.0),
                 )
@@ -130,7 +130,7 @@ fn benchmark_comparison(c: &mut Criterion) {
     let markets: Vec<_> = (0..100)
         .map(|i| {
             (
-                MarketId(format!("market-{}", i)),
+                MarketId::new(format!("market-{}", i)),
                 TokenId(format!("token-{}", i)),
                 create_test_order_book(0.75, 0.70, 100.0),
             )
@@ -44,7 +44,7 @@ fn create_normal_orderbook() -> OrderBook {
 
 fn bench_detector_with_arbitrage(c: &mut Criterion) {
     let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-    let market_id = MarketId("market-1".to_string());
+    let market_id = MarketId::new("market-1");
     let token_id = TokenId("token-1".to_string());
     let orderbook = create_arbitrage_orderbook();
 
@@ -61,7 +61,7 @@ fn bench_detector_with_arbitrage(c: &mut Criterion) {
 
 fn bench_detector_without_arbitrage(c: &mut Criterion) {
     let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-    let market_id = MarketId("market-1".to_string());
+    let market_id = MarketId::new("market-1");
     let token_id = TokenId("token-1".to_string());
     let orderbook = create_normal_orderbook();
 
@@ -82,22 +82,22 @@ fn bench_detector_batch(c: &mut Criterion) {
     // Create mix of arbitrage and normal markets
     let markets = vec![
         (
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_arbitrage_orderbook(),
         ),
         (
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_normal_orderbook(),
         ),
         (
-            MarketId("m3".to_string()),
+            MarketId::new("m3"),
             TokenId("t3".to_string()),
             create_arbitrage_orderbook(),
         ),
         (
-            MarketId("m4".to_string()),
+            MarketId::new("m4"),
             TokenId("t4".to_string()),
             create_normal_orderbook(),
         ),
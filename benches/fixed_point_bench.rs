@@ -92,5 +92,26 @@ fn benchmark_fixed_point_operations(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, benchmark_fixed_point_operations);
+fn benchmark_batch_conversions(c: &mut Criterion) {
+    let values: Vec<f64> = (0..1000).map(|i| i as f64 / 1000.0).collect();
+    let prices = FixedPrice::batch_from_f64(&values);
+
+    c.bench_function("batch_from_f64_1000", |bencher| {
+        bencher.iter(|| black_box(FixedPrice::batch_from_f64(black_box(&values))))
+    });
+
+    c.bench_function("scalar_from_f64_1000", |bencher| {
+        bencher.iter(|| {
+            black_box(
+                black_box(&values).iter().map(|v| FixedPrice::from_f64(*v)).collect::<Vec<_>>(),
+            )
+        })
+    });
+
+    c.bench_function("batch_to_f64_1000", |bencher| {
+        bencher.iter(|| black_box(FixedPrice::batch_to_f64(black_box(&prices))))
+    });
+}
+
+criterion_group!(benches, benchmark_fixed_point_operations, benchmark_batch_conversions);
 criterion_main!(benches);
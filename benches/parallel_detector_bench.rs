@@ -0,0 +1,91 @@
+//! Benchmark: Sequential vs Parallel Batch Detection
+//!
+//! Compares `detect_batch` with the rayon-backed `detect_batch_parallel`
+//! for 100, 1,000 and 10,000 markets, for both the scalar and SIMD detectors.
+//!
+//! Run with: `cargo bench --features rayon --bench parallel_detector_bench`
+//!
+//! # Crossover
+//! Detection is ~10-20ns per market, so rayon's fork/join overhead (a few μs)
+//! dominates small batches. Parallel detection is only expected to win for
+//! batches in the thousands, and only with more than one core available -
+//! check the crossover on the target machine before enabling it.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use polymarket_hft_bot::core::arbitrage::{
+    ArbitrageConfig, ScalarArbitrageDetector, SimdArbitrageDetector,
+};
+use polymarket_hft_bot::types::{MarketId, OrderBook, OrderBookEntry, TokenId};
+
+/// Create test orderbook (arbitrage when bid > ask)
+fn create_orderbook(bid: f64, ask: f64) -> OrderBook {
+    OrderBook {
+        token_id: TokenId("test-token".to_string()),
+        bids: vec![OrderBookEntry {
+            price: bid,
+            size: 100.0,
+            timestamp: Some(1000),
+        }],
+        asks: vec![OrderBookEntry {
+            price: ask,
+            size: 100.0,
+            timestamp: Some(1000),
+        }],
+        timestamp: 1000,
+    }
+}
+
+/// Create `count` markets, every 10th with an arbitrage opportunity
+fn create_markets(count: usize) -> Vec<(MarketId, TokenId, OrderBook)> {
+    (0..count)
+        .map(|i| {
+            let bid = if i % 10 == 0 { 0.76 } else { 0.74 };
+            (
+                MarketId::new(format!("market-{}", i)),
+                TokenId(format!("token-{}", i)),
+                create_orderbook(bid, 0.75),
+            )
+        })
+        .collect()
+}
+
+fn bench_sequential_vs_parallel(c: &mut Criterion) {
+    let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
+    let mut group = c.benchmark_group("detect_batch");
+
+    for count in [100, 1_000, 10_000] {
+        let markets = create_markets(count);
+
+        group.bench_with_input(BenchmarkId::new("sequential", count), &markets, |b, markets| {
+            b.iter(|| black_box(detector.detect_batch(black_box(markets))))
+        });
+
+        group.bench_with_input(BenchmarkId::new("parallel", count), &markets, |b, markets| {
+            b.iter(|| black_box(detector.detect_batch_parallel(black_box(markets))))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_simd_sequential_vs_parallel(c: &mut Criterion) {
+    let detector = SimdArbitrageDetector::new(ArbitrageConfig::default());
+    let mut group = c.benchmark_group("simd_detect_batch");
+
+    for count in [100, 1_000, 10_000] {
+        let markets = create_markets(count);
+
+        group.bench_with_input(BenchmarkId::new("sequential", count), &markets, |b, markets| {
+            b.iter(|| black_box(detector.detect_batch(black_box(markets))))
+        });
+
+        group.bench_with_input(BenchmarkId::new("parallel", count), &markets, |b, markets| {
+            b.iter(|| black_box(detector.detect_batch_parallel(black_box(markets))))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_sequential_vs_parallel, bench_simd_sequential_vs_parallel);
+criterion_main!(benches);
@@ -0,0 +1,48 @@
+//! Benchmark: EIP-712 order signing
+//!
+//! **Target:** Signing throughput for fresh orders (ECDSA dominates)
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use ethers::types::H160;
+use polymarket_hft_bot::clob::OrderSigner;
+use polymarket_hft_bot::types::SignedOrder;
+use std::str::FromStr;
+
+fn create_order(salt: u64) -> SignedOrder {
+    SignedOrder {
+        salt: salt.to_string(),
+        maker: "0x0000000000000000000000000000000000000001".to_string(),
+        signer: "0x0000000000000000000000000000000000000001".to_string(),
+        taker: "0x0000000000000000000000000000000000000002".to_string(),
+        token_id: "1".to_string(),
+        maker_amount: "1000000".to_string(),
+        taker_amount: "700000".to_string(),
+        expiration: "1700000000".to_string(),
+        nonce: "1".to_string(),
+        fee_rate_bps: "100".to_string(),
+        side: 0,
+        signature_type: 0,
+        signature: String::new(),
+    }
+}
+
+fn benchmark_signing(c: &mut Criterion) {
+    // Test key (DO NOT USE IN PRODUCTION)
+    let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+    let contract = H160::from_str("0x0000000000000000000000000000000000000001").unwrap();
+    let signer = OrderSigner::new(private_key, 137, contract).unwrap();
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+
+    c.bench_function("sign_order", |bencher| {
+        // Fresh salt every time, as for new orders
+        let mut salt = 0u64;
+        bencher.iter(|| {
+            salt += 1;
+            let order = create_order(salt);
+            black_box(runtime.block_on(signer.sign_order(black_box(&order))))
+        })
+    });
+}
+
+criterion_group!(benches, benchmark_signing);
+criterion_main!(benches);
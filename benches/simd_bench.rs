@@ -1,9 +1,12 @@
 //! Benchmark: SIMD Arbitrage Detection Performance
 //!
-//! Compares f64x4 vs u64x4 fixed-point SIMD performance.
+//! Compares f64x4 vs u64x4 fixed-point SIMD performance, and 8-wide vs
+//! 4-wide vs scalar detection.
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use polymarket_hft_bot::core::arbitrage::{ArbitrageConfig, SimdArbitrageDetector};
+use polymarket_hft_bot::core::arbitrage::{
+    ArbitrageConfig, ScalarArbitrageDetector, SimdArbitrageDetector,
+};
 use polymarket_hft_bot::types::{MarketId, OrderBook, OrderBookEntry, TokenId};
 
 /// Create test orderbook with arbitrage opportunity
@@ -47,22 +50,22 @@ fn bench_simd_fixed_batch(c: &mut Criterion) {
 
     let markets: [(MarketId, TokenId, OrderBook); 4] = [
         (
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_arbitrage_orderbook("t1"),
         ),
         (
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_normal_orderbook("t2"),
         ),
         (
-            MarketId("m3".to_string()),
+            MarketId::new("m3"),
             TokenId("t3".to_string()),
             create_arbitrage_orderbook("t3"),
         ),
         (
-            MarketId("m4".to_string()),
+            MarketId::new("m4"),
             TokenId("t4".to_string()),
             create_normal_orderbook("t4"),
         ),
@@ -78,22 +81,22 @@ fn bench_simd_f64_batch(c: &mut Criterion) {
 
     let markets: [(MarketId, TokenId, OrderBook); 4] = [
         (
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_arbitrage_orderbook("t1"),
         ),
         (
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_normal_orderbook("t2"),
         ),
         (
-            MarketId("m3".to_string()),
+            MarketId::new("m3"),
             TokenId("t3".to_string()),
             create_arbitrage_orderbook("t3"),
         ),
         (
-            MarketId("m4".to_string()),
+            MarketId::new("m4"),
             TokenId("t4".to_string()),
             create_normal_orderbook("t4"),
         ),
@@ -110,22 +113,22 @@ fn bench_simd_fixed_all_arbitrage(c: &mut Criterion) {
     // All 4 markets have arbitrage
     let markets: [(MarketId, TokenId, OrderBook); 4] = [
         (
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_arbitrage_orderbook("t1"),
         ),
         (
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_arbitrage_orderbook("t2"),
         ),
         (
-            MarketId("m3".to_string()),
+            MarketId::new("m3"),
             TokenId("t3".to_string()),
             create_arbitrage_orderbook("t3"),
         ),
         (
-            MarketId("m4".to_string()),
+            MarketId::new("m4"),
             TokenId("t4".to_string()),
             create_arbitrage_orderbook("t4"),
         ),
@@ -142,22 +145,22 @@ fn bench_simd_fixed_no_arbitrage(c: &mut Criterion) {
     // None have arbitrage (fast path)
     let markets: [(MarketId, TokenId, OrderBook); 4] = [
         (
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_normal_orderbook("t1"),
         ),
         (
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_normal_orderbook("t2"),
         ),
         (
-            MarketId("m3".to_string()),
+            MarketId::new("m3"),
             TokenId("t3".to_string()),
             create_normal_orderbook("t3"),
         ),
         (
-            MarketId("m4".to_string()),
+            MarketId::new("m4"),
             TokenId("t4".to_string()),
             create_normal_orderbook("t4"),
         ),
@@ -168,11 +171,91 @@ fn bench_simd_fixed_no_arbitrage(c: &mut Criterion) {
     });
 }
 
+/// Benchmark mostly-empty replay windows (empty-book fast path)
+fn bench_simd_empty_books(c: &mut Criterion) {
+    let detector = SimdArbitrageDetector::new(ArbitrageConfig::default());
+
+    let empty = |token_id: &str| OrderBook {
+        token_id: TokenId(token_id.to_string()),
+        bids: vec![],
+        asks: vec![],
+        timestamp: 1000,
+    };
+
+    // 1 populated batch per 16 empty batches
+    let mut markets: Vec<(MarketId, TokenId, OrderBook)> = (0..64)
+        .map(|i| (MarketId::new(format!("m{}", i)), TokenId(format!("t{}", i)), empty("t")))
+        .collect();
+    for (i, market) in markets.iter_mut().enumerate().step_by(16) {
+        market.2 = create_arbitrage_orderbook(&format!("t{}", i));
+    }
+
+    let empty_batch: [(MarketId, TokenId, OrderBook); 4] = [
+        markets[4].clone(),
+        markets[5].clone(),
+        markets[6].clone(),
+        markets[7].clone(),
+    ];
+
+    c.bench_function("simd_f64_batch_empty_books", |bencher| {
+        bencher.iter(|| black_box(detector.detect_batch_simd(black_box(&empty_batch))))
+    });
+
+    c.bench_function("simd_detect_batch_mostly_empty_replay", |bencher| {
+        bencher.iter(|| black_box(detector.detect_batch(black_box(&markets))))
+    });
+}
+
+/// Benchmark 8 markets as one 8-wide batch, two 4-wide batches, or scalar
+fn bench_simd_width(c: &mut Criterion) {
+    let config = ArbitrageConfig::default();
+    let detector = SimdArbitrageDetector::new(config.clone());
+    let scalar = ScalarArbitrageDetector::new(config);
+
+    // Alternating arbitrage/normal books
+    let markets: [(MarketId, TokenId, OrderBook); 8] = std::array::from_fn(|i| {
+        let token_id = format!("t{}", i);
+        let order_book = if i % 2 == 0 {
+            create_arbitrage_orderbook(&token_id)
+        } else {
+            create_normal_orderbook(&token_id)
+        };
+        (MarketId::new(format!("m{}", i)), TokenId(token_id), order_book)
+    });
+    let lo: [(MarketId, TokenId, OrderBook); 4] = markets[..4].to_vec().try_into().unwrap();
+    let hi: [(MarketId, TokenId, OrderBook); 4] = markets[4..].to_vec().try_into().unwrap();
+
+    let mut group = c.benchmark_group("simd_width_8_markets");
+
+    group.bench_function("simd_8", |bencher| {
+        bencher.iter(|| black_box(detector.detect_batch_simd_8(black_box(&markets))))
+    });
+
+    group.bench_function("simd_4x2", |bencher| {
+        bencher.iter(|| {
+            black_box(detector.detect_batch_simd(black_box(&lo)));
+            black_box(detector.detect_batch_simd(black_box(&hi)))
+        })
+    });
+
+    group.bench_function("scalar", |bencher| {
+        bencher.iter(|| {
+            for (market_id, token_id, order_book) in black_box(&markets) {
+                black_box(scalar.detect(market_id, token_id, order_book));
+            }
+        })
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_simd_fixed_batch,
     bench_simd_f64_batch,
     bench_simd_fixed_all_arbitrage,
-    bench_simd_fixed_no_arbitrage
+    bench_simd_fixed_no_arbitrage,
+    bench_simd_empty_books,
+    bench_simd_width
 );
 criterion_main!(benches);
@@ -13,11 +13,12 @@
 //! Run with: cargo run --example binary_arbitrage_scanner
 
 use polymarket_hft_bot::{
+    services::polymarket::GammaClient,
     strategies::{
         BinaryArbitrageConfig, BinaryArbitrageDetector,
         CryptoAsset, CryptoUpDownConfig, CryptoUpDownFetcher, Timeframe,
     },
-    types::{OrderBook, OrderBookEntry, TokenId},
+    types::{MarketId, OrderBook, OrderBookEntry, TokenId},
 };
 
 #[tokio::main]
@@ -58,19 +59,22 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Configure arbitrage detector
     let arb_config = BinaryArbitrageConfig {
-        min_profit_margin: 0.02, // 2% minimum (covers fees + buffer)
+        min_profit_margin: 0.02, // 2% minimum (net of redemption fee)
         min_size: 5.0,           // $5 minimum size
         max_cost: 100.0,         // Max $100 per trade
+        redemption_fee_bps: 200, // Polymarket 2% redemption fee
+        ..BinaryArbitrageConfig::default()
     };
 
     println!("⚙️  Arbitrage Configuration:");
-    println!("   Min profit margin: {:.1}%", arb_config.min_profit_margin * 100.0);
+    println!("   Min profit margin: {:.1}% (net)", arb_config.min_profit_margin * 100.0);
+    println!("   Redemption fee: {:.1}%", arb_config.redemption_fee_bps as f64 / 100.0);
     println!("   Min size: ${:.2}", arb_config.min_size);
     println!("   Max cost: ${:.2}\n", arb_config.max_cost);
 
     // Create fetcher and detector
     let gamma_api_url = "https://gamma-api.polymarket.com".to_string();
-    let fetcher = CryptoUpDownFetcher::new(market_config, gamma_api_url);
+    let fetcher = CryptoUpDownFetcher::new(market_config, GammaClient::new(gamma_api_url));
     let detector = BinaryArbitrageDetector::new(arb_config);
 
     // Step 1: Fetch crypto up/down markets
@@ -121,13 +125,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     if let Some(market) = markets.first() {
         if let Some(opp) = detector.detect(
-            &market.event_id.clone().into(),
-            &market.token_ids[0].clone().into(),
-            &market.token_ids.get(1).unwrap_or(&market.token_ids[0]).clone().into(),
+            &MarketId::new(market.event_id.clone()),
+            &TokenId(market.token_ids[0].clone()),
+            &TokenId(market.token_ids.get(1).unwrap_or(&market.token_ids[0]).clone()),
             &yes_orderbook_1,
             &no_orderbook_1,
             market.title.clone(),
             market.end_date.clone(),
+            None, // Mock orderbooks: skip the expiry window
         ) {
             opportunities_found += 1;
             println!("\n🎯 BUY ARBITRAGE FOUND!\n");
@@ -146,13 +151,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     if let Some(market) = markets.get(1).or(markets.first()) {
         if let Some(opp) = detector.detect(
-            &market.event_id.clone().into(),
-            &market.token_ids[0].clone().into(),
-            &market.token_ids.get(1).unwrap_or(&market.token_ids[0]).clone().into(),
+            &MarketId::new(market.event_id.clone()),
+            &TokenId(market.token_ids[0].clone()),
+            &TokenId(market.token_ids.get(1).unwrap_or(&market.token_ids[0]).clone()),
             &yes_orderbook_2,
             &no_orderbook_2,
             market.title.clone(),
             market.end_date.clone(),
+            None, // Mock orderbooks: skip the expiry window
         ) {
             opportunities_found += 1;
             println!("\n🎯 SELL ARBITRAGE FOUND!\n");
@@ -161,8 +167,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Example 3: No arbitrage (YES + NO = $1.00)
-    let yes_orderbook_3 = create_mock_orderbook_full(0.51, 0.50, 50.0);
-    let no_orderbook_3 = create_mock_orderbook_full(0.49, 0.50, 50.0);
+    let _yes_orderbook_3 = create_mock_orderbook_full(0.51, 0.50, 50.0);
+    let _no_orderbook_3 = create_mock_orderbook_full(0.49, 0.50, 50.0);
 
     println!("\n\nExample 3: SOL Up/Down 4hour");
     println!("  YES ask: $0.51, bid: $0.50");
@@ -171,14 +177,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("  ❌ No arbitrage (prices are efficient)\n");
 
     // Example 4: Small profit (filtered out)
-    let yes_orderbook_4 = create_mock_orderbook_full(0.49, 0.47, 50.0);
-    let no_orderbook_4 = create_mock_orderbook_full(0.50, 0.48, 50.0);
+    let _yes_orderbook_4 = create_mock_orderbook_full(0.49, 0.47, 50.0);
+    let _no_orderbook_4 = create_mock_orderbook_full(0.50, 0.48, 50.0);
 
     println!("\nExample 4: XRP Up/Down 15min");
     println!("  YES ask: $0.49, bid: $0.47");
     println!("  NO ask:  $0.50, bid: $0.48");
-    println!("  Ask sum: $0.99 (1% profit)");
-    println!("  ❌ Filtered out (below 2% minimum threshold)\n");
+    println!("  Ask sum: $0.99 (1% gross profit)");
+    println!("  ❌ Filtered out (2% redemption fee exceeds profit)\n");
 
     // Summary
     println!("\n═══════════════════════════════════════════════════════════");
@@ -208,7 +214,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Timeframe::FourHour.duration_minutes()
     );
     println!("     d. Redeem winning position for $1.00");
-    println!("     e. Profit = $1.00 - (YES + NO)\n");
+    println!("     e. Profit = $1.00 - (YES + NO) - redemption fee\n");
 
     println!("Risk management:");
     println!("  ✅ Zero market risk (own both outcomes)");
@@ -238,14 +244,14 @@ fn create_mock_orderbook_full(ask_price: f64, bid_price: f64, size: f64) -> Orde
         bids: vec![OrderBookEntry {
             price: bid_price,
             size,
-            timestamp: Some(chrono::Utc::now().timestamp() as u64),
+            timestamp: Some(chrono::Utc::now().timestamp()),
         }],
         asks: vec![OrderBookEntry {
             price: ask_price,
             size,
-            timestamp: Some(chrono::Utc::now().timestamp() as u64),
+            timestamp: Some(chrono::Utc::now().timestamp()),
         }],
-        timestamp: chrono::Utc::now().timestamp() as u64,
+        timestamp: chrono::Utc::now().timestamp(),
     }
 }
 
@@ -258,7 +264,7 @@ fn print_opportunity(opp: &polymarket_hft_bot::strategies::BinaryArbitrageOpport
     println!("  YES Price: ${:.3}", opp.yes_price);
     println!("  NO Price:  ${:.3}", opp.no_price);
     println!("  Sum:       ${:.3}", opp.price_sum);
-    println!("  Profit:    ${:.3} ({:.1}%)",
+    println!("  Profit:    ${:.3} ({:.1}%, net of fee)",
         opp.profit_margin, opp.profit_margin * 100.0);
     println!("  Max Size:  ${:.2}", opp.max_size);
     println!("  Expected:  ${:.2} profit", opp.expected_profit);
@@ -274,7 +280,11 @@ fn print_opportunity(opp: &polymarket_hft_bot::strategies::BinaryArbitrageOpport
             println!("    3. Total cost: ${:.2}", opp.price_sum * opp.max_size);
             println!("    4. Wait for expiry...");
             println!("    5. Redeem winning side for ${:.2}", opp.max_size);
-            println!("    6. Profit: ${:.2} ({:.1}% return)",
+            println!("    6. Gross profit: ${:.2}", opp.gross_margin() * opp.max_size);
+            println!("    7. Redemption fee: -${:.2} ({:.1}%)",
+                opp.redemption_fee * opp.max_size,
+                opp.redemption_fee * 100.0);
+            println!("    8. Net profit: ${:.2} ({:.1}% return)",
                 opp.expected_profit,
                 (opp.profit_margin / opp.price_sum) * 100.0);
         }
@@ -284,7 +294,11 @@ fn print_opportunity(opp: &polymarket_hft_bot::strategies::BinaryArbitrageOpport
             println!("    3. Total revenue: ${:.2}", opp.price_sum * opp.max_size);
             println!("    4. Wait for expiry...");
             println!("    5. Pay ${:.2} for losing side", opp.max_size);
-            println!("    6. Profit: ${:.2} ({:.1}% return)",
+            println!("    6. Gross profit: ${:.2}", opp.gross_margin() * opp.max_size);
+            println!("    7. Redemption fee: -${:.2} ({:.1}%)",
+                opp.redemption_fee * opp.max_size,
+                opp.redemption_fee * 100.0);
+            println!("    8. Net profit: ${:.2} ({:.1}% return)",
                 opp.expected_profit,
                 (opp.profit_margin / opp.price_sum) * 100.0);
         }
@@ -13,10 +13,17 @@ fn main() {
     let config = RiskConfig {
         max_daily_loss: 100.0,
         max_position_size: 50.0,
+        max_loss_per_trade: 50.0,
         max_open_positions: 3,
         min_usdc_balance: 10.0,
         min_matic_balance: 1.0,
         max_consecutive_errors: 5,
+        risk_score_alert_threshold: 70.0,
+        fee_rate_bps: 100,
+        max_anomalies_before_skip: 3,
+        max_concentration: 0.5,
+        max_loss_in_window: 50.0,
+        loss_window_secs: 3600,
     };
 
     println!("⚙️  Risk Configuration:");
@@ -54,7 +61,8 @@ fn main() {
 
     println!("Opening positions...");
     for i in 1..=3 {
-        match cb.open_position() {
+        let market_id = MarketId::new(format!("market-{}", i));
+        match cb.open_position(&market_id, 20.0) {
             Ok(_) => println!("  ✅ Position {}/3 opened", i),
             Err(e) => println!("  ❌ Failed: {}", e),
         }
@@ -62,7 +70,7 @@ fn main() {
     println!("   Current positions: {}/{}\n", cb.positions(), config.max_open_positions);
 
     println!("Trying to open 4th position (exceeds limit)...");
-    match cb.open_position() {
+    match cb.open_position(&MarketId::new("market-4"), 20.0) {
         Ok(_) => println!("  ✅ Position opened (unexpected!)"),
         Err(e) => println!("  ❌ Blocked: {}", e),
     }
@@ -78,7 +86,7 @@ fn main() {
     println!("═══════════════════════════════════════════════════\n");
 
     println!("Simulating losses...");
-    for i in 1..=4 {
+    for _ in 1..=4 {
         let loss = 25.0;
         match cb.record_trade(-loss) {
             Ok(_) => {
@@ -106,7 +114,7 @@ fn main() {
     cb.reset_daily();
 
     println!("Simulating consecutive errors...");
-    for i in 1..=6 {
+    for _ in 1..=6 {
         cb.record_error();
         println!("  ⚠️  Error {}/5 - Status: {}",
             cb.errors(),
@@ -122,7 +130,7 @@ fn main() {
     let tracker = PositionTracker::new();
 
     // Add some positions
-    let market1 = MarketId("TRUMP-WIN".to_string());
+    let market1 = MarketId::new("TRUMP-WIN");
     let token1 = TokenId("YES".to_string());
     tracker.update_position(
         market1.clone(),
@@ -137,10 +145,11 @@ fn main() {
             realized_pnl: 0.0,
             opened_at: 1000,
             updated_at: 1000,
+            order_hashes: Vec::new(),
         },
     );
 
-    let market2 = MarketId("BIDEN-WIN".to_string());
+    let market2 = MarketId::new("BIDEN-WIN");
     let token2 = TokenId("NO".to_string());
     tracker.update_position(
         market2.clone(),
@@ -155,6 +164,7 @@ fn main() {
             realized_pnl: 0.0,
             opened_at: 1000,
             updated_at: 1000,
+            order_hashes: Vec::new(),
         },
     );
 
@@ -168,14 +178,18 @@ fn main() {
     prices.insert((market1.clone(), token1.clone()), 0.75); // Price went up
     prices.insert((market2.clone(), token2.clone()), 0.55); // Price went down
 
-    let pnl = tracker.total_unrealized_pnl(&prices);
+    // Consistent view for reporting: exposure and P&L from the same positions
+    let snapshot = tracker.to_snapshot(Some(&prices));
+    let pnl = snapshot.total_unrealized_pnl.unwrap_or(0.0);
 
     println!("Price Update:");
     println!("  TRUMP-WIN: $0.70 → $0.75 (+$0.05)");
     println!("  BIDEN-WIN: $0.60 → $0.55 (-$0.05)");
     println!("\n  Position 1 P&L: (0.75 - 0.70) × 100 = +$5.00");
     println!("  Position 2 P&L: (0.60 - 0.55) × 50 = +$2.50");
-    println!("  Total Unrealized P&L: ${:.2} ✅\n", pnl);
+    println!("  Total Unrealized P&L: ${:.2} ✅", pnl);
+    println!("  Snapshot: {} positions, ${:.2} exposure\n",
+        snapshot.positions.len(), snapshot.total_exposure);
 
     println!("═══════════════════════════════════════════════════");
     println!("                  SUMMARY");
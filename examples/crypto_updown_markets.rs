@@ -5,6 +5,7 @@
 //!
 //! Run with: cargo run --example crypto_updown_markets
 
+use polymarket_hft_bot::services::polymarket::GammaClient;
 use polymarket_hft_bot::strategies::{
     CryptoAsset, CryptoUpDownConfig, CryptoUpDownFetcher, Timeframe,
 };
@@ -42,7 +43,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Create fetcher
     let gamma_api_url = "https://gamma-api.polymarket.com".to_string();
-    let fetcher = CryptoUpDownFetcher::new(config, gamma_api_url);
+    let fetcher = CryptoUpDownFetcher::new(config, GammaClient::new(gamma_api_url));
 
     // Fetch markets
     println!("📡 Fetching markets from Gamma API...\n");
@@ -14,7 +14,7 @@ fn create_order_book(
     bid_size: f64,
     ask_size: f64,
 ) -> (String, MarketId, TokenId, OrderBook) {
-    let market_id = MarketId(format!("market-{}", name));
+    let market_id = MarketId::new(format!("market-{}", name));
     let token_id = TokenId(format!("token-{}", name));
 
     let order_book = OrderBook {
@@ -54,6 +54,7 @@ fn main() {
         min_profit_margin: 0.02, // 2% minimum profit
         min_size: 10.0,          // $10 minimum
         max_spread: 0.50,        // 50% max spread (sanity check)
+        ..ArbitrageConfig::default()
     };
 
     println!("⚙️  Configuration:");
@@ -139,7 +140,7 @@ fn main() {
     println!("Found {} arbitrage opportunities:\n", simd_results.len());
 
     for opp in simd_results {
-        println!("✅ Market: {}", opp.market_id.0);
+        println!("✅ Market: {}", opp.market_id);
         println!("   Token:   {}", opp.token_id.0);
         println!("   Buy at:  ${:.4}", opp.ask_price);
         println!("   Sell at: ${:.4}", opp.bid_price);
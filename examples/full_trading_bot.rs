@@ -17,12 +17,19 @@
 
 use polymarket_hft_bot::clob::{ArbitrageExecutor, ClobClient, ClobConfig};
 use polymarket_hft_bot::core::arbitrage::{ArbitrageConfig, ScalarArbitrageDetector};
-use polymarket_hft_bot::core::risk::CircuitBreaker;
+use polymarket_hft_bot::core::execution::OrderRouter;
+use polymarket_hft_bot::core::risk::{CircuitBreaker, PositionTracker};
 use polymarket_hft_bot::services::websocket::{process_message, PolymarketWebSocket};
-use polymarket_hft_bot::types::{MarketId, RiskConfig, TokenId};
+use polymarket_hft_bot::types::config::BotConfig;
+use polymarket_hft_bot::types::{MarketId, Position, RiskConfig, TokenId};
+use polymarket_hft_bot::utils::metrics::{HealthReport, HftMetrics, DEFAULT_METRICS_ADDR};
+use polymarket_hft_bot::utils::shutdown::ShutdownCoordinator;
 use std::env;
+use std::path::Path;
 use std::sync::Arc;
-use tokio;
+
+/// Circuit breaker state file (restored on startup if saved in the last 24 hours)
+const CIRCUIT_BREAKER_STATE_FILE: &str = "circuit_breaker_state.json";
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -37,12 +44,21 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("           PHASE 2-5 COMPLETE INTEGRATION");
     println!("═══════════════════════════════════════════════════════════\n");
 
+    // Bot config (BOT__* env vars), falling back to defaults
+    let config = BotConfig::from_env().unwrap_or_default();
+    config.full_validate().check()?;
+
     // Phase 2: Configure SIMD arbitrage detector
     let arb_config = ArbitrageConfig {
-        min_profit_margin: 0.02, // 2% minimum profit
+        min_profit_margin: 0.02, // 2% minimum profit after fees
+        fee_rate_bps: 100,        // 1% per leg (same as the executor)
         min_size: 10.0,           // $10 minimum size
         max_spread: 0.5,          // 50% max spread (sanity check)
+        order_book_depth_required: config.trading.order_book_depth_required,
+        max_slippage: 0.01,       // Size past the best level up to 1% VWAP slippage
+        ..ArbitrageConfig::default()
     };
+    let max_slippage = arb_config.max_slippage;
 
     let detector = ScalarArbitrageDetector::new(arb_config);
 
@@ -55,13 +71,49 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let risk_config = RiskConfig {
         max_daily_loss: 100.0,
         max_position_size: 50.0,
+        max_loss_per_trade: 50.0,
         max_open_positions: 3,
         min_usdc_balance: 10.0,
         min_matic_balance: 1.0,
         max_consecutive_errors: 5,
+        risk_score_alert_threshold: 70.0,
+        fee_rate_bps: 100,
+        max_anomalies_before_skip: 3,
+        max_concentration: 0.5,
+        max_loss_in_window: 50.0,
+        loss_window_secs: 3600,
     };
 
-    let circuit_breaker = Arc::new(CircuitBreaker::new(risk_config));
+    let metrics = Arc::new(HftMetrics::new());
+
+    // Executed trades are tracked so concentrated trades can be rejected
+    let position_tracker = Arc::new(PositionTracker::new());
+    let circuit_breaker = Arc::new(
+        CircuitBreaker::new(risk_config)
+            .with_metrics(Arc::clone(&metrics))
+            .with_position_tracker(position_tracker.clone()),
+    );
+
+    // Keep the last 24 hours of losses across restarts
+    let state_path = Path::new(CIRCUIT_BREAKER_STATE_FILE);
+    match circuit_breaker.restore_state(state_path) {
+        Ok(true) => println!("♻️  Restored circuit breaker state from {}", state_path.display()),
+        Ok(false) => {}
+        Err(e) => eprintln!("⚠️  Could not restore circuit breaker state: {}", e),
+    }
+
+    // Persist state on shutdown (Ctrl+C or SIGTERM)
+    let shutdown = Arc::new(ShutdownCoordinator::new());
+    let mut shutdown_rx = shutdown.subscribe();
+    let _signal_handler = shutdown.install_signal_handler();
+    let cb_for_shutdown = Arc::clone(&circuit_breaker);
+    tokio::spawn(async move {
+        let _ = shutdown_rx.recv().await;
+        if let Err(e) = cb_for_shutdown.save_state(Path::new(CIRCUIT_BREAKER_STATE_FILE)) {
+            eprintln!("⚠️  Could not save circuit breaker state: {}", e);
+        }
+        std::process::exit(0);
+    });
 
     println!("✅ Phase 3: Circuit Breaker Risk Management");
     println!("   Performance: 1-5ns atomic operations");
@@ -74,6 +126,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             .unwrap_or_else(|_| "https://clob.polymarket.com".to_string()),
         api_key: env::var("POLYMARKET_API_KEY")
             .unwrap_or_else(|_| "demo_key".to_string()),
+        api_secret: env::var("POLYMARKET_API_SECRET").unwrap_or_default(),
+        api_passphrase: env::var("POLYMARKET_API_PASSPHRASE").unwrap_or_default(),
         private_key: env::var("PRIVATE_KEY")
             .unwrap_or_else(|_| "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef".to_string()),
         chain_id: 137, // Polygon
@@ -81,6 +135,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         maker_address: "0x0000000000000000000000000000000000000002".to_string(),
         taker_address: "0x0000000000000000000000000000000000000000".to_string(),
         timeout_secs: 10,
+        proxy: None,
+        warm_up_on_start: true,
+        pool_size: 10,
+        cancel_on_trip: true,
+        fill_confirmation_timeout_ms: 2_000,
+        auto_cancel_on_partial_failure: true,
+        requests_per_second: 100,
+        rpc_url: None,
+        retry_attempts: 3,
     };
 
     let clob_client = Arc::new(ClobClient::new(clob_config)?);
@@ -95,26 +158,55 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   - Optimistic nonce: 100ms → <1μs");
     println!("   - Pre-computed EIP-712: 10-20μs saved\n");
 
-    // Phase 4: Create arbitrage executor
-    let executor = Arc::new(ArbitrageExecutor::new(
-        Arc::clone(&clob_client),
-        Arc::clone(&circuit_breaker),
-        100, // 1% fee
-    ));
+    // Phase 4: Create arbitrage executor (thin books are executed leg by leg)
+    let router = Arc::new(OrderRouter::new(Arc::clone(&clob_client)));
+    let executor = Arc::new(
+        ArbitrageExecutor::new(
+            Arc::clone(&clob_client),
+            Arc::clone(&circuit_breaker),
+            100, // 1% fee
+        )
+        .with_router(Arc::clone(&router))
+        .with_max_slippage(max_slippage)
+        .with_metrics(Arc::clone(&metrics))
+        .with_shutdown(Arc::clone(&shutdown))
+        .with_environment(config.environment),
+    );
+
+    // Prometheus scrape and health endpoint (the bot keeps running without it)
+    let health = Arc::clone(&executor) as Arc<dyn HealthReport>;
+    if let Err(e) = Arc::clone(&metrics).serve_with_health(DEFAULT_METRICS_ADDR, health).await {
+        eprintln!("⚠️  Metrics endpoint disabled: {}", e);
+    }
+
+    // Production executions need a balance check from the last minute
+    match executor.check_balance().await {
+        Ok(available) => println!("✅ USDC available: ${:.2}", available),
+        Err(e) if config.environment.is_production() => return Err(e.into()),
+        Err(e) => println!("⚠️  Balance check failed: {}", e),
+    }
+    let _balance_monitor = executor.spawn_balance_monitor();
+
+    // Warn (without resyncing) if the optimistic nonce drifts from the API
+    let _nonce_monitor = executor.spawn_nonce_drift_monitor(false);
+
+    // Cancel every open order if the circuit breaker trips
+    let _cancel_on_trip = clob_client.cancel_orders_on_trip(&circuit_breaker);
 
     println!("✅ Arbitrage Executor Ready");
     println!("   Automatic rollback on partial fills");
-    println!("   Circuit breaker integration\n");
+    println!("   Sequential legs when the book is too thin to batch");
+    println!("   Circuit breaker integration (cancels open orders on trip)");
+    println!("   Nonce drift check every 5 minutes\n");
 
     // Phase 5: Configure WebSocket
     let markets = vec![
-        (MarketId("TRUMP-WIN".to_string()), TokenId("YES".to_string())),
-        (MarketId("BIDEN-WIN".to_string()), TokenId("YES".to_string())),
+        (MarketId::new("TRUMP-WIN"), TokenId("YES".to_string())),
+        (MarketId::new("BIDEN-WIN"), TokenId("YES".to_string())),
     ];
 
     let (ws_client, mut rx) = PolymarketWebSocket::new(
-        env::var("POLYMARKET_WS_URL")
-            .unwrap_or_else(|_| "wss://clob.polymarket.com/ws".to_string()),
+        env::var("POLYMARKET_WS_URL").unwrap_or_else(|_| config.polymarket.websocket_url.clone()),
         markets.clone(),
     );
 
@@ -125,7 +217,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     println!("📊 Monitoring markets:");
     for (market, token) in &markets {
-        println!("   - {}/{}", market.0, token.0);
+        println!("   - {}/{}", market, token.0);
     }
     println!();
 
@@ -148,6 +240,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(update) = process_message(message) {
             stats.updates_processed += 1;
 
+            // Crossed, empty, spiking or gapped snapshots count against the market
+            if update.is_snapshot {
+                let previous = router.order_book(&update.token_id);
+                if let Some(reason) = circuit_breaker.observe_order_book(
+                    &update.token_id,
+                    previous.as_ref(),
+                    &update.order_book,
+                ) {
+                    println!("   ⚠️  Orderbook anomaly on {}: {:?}", update.token_id.0, reason);
+                }
+            }
+            router.update_order_book(update.order_book.clone());
+
+            // Markets with repeated anomalies are skipped
+            if !circuit_breaker.can_trade_market(&update.token_id) {
+                continue;
+            }
+
             // Detect arbitrage with SIMD detector (47ns)
             if let Some(opportunity) = detector.detect(
                 &update.market_id,
@@ -157,7 +267,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 stats.opportunities_found += 1;
 
                 println!("\n🎯 ARBITRAGE OPPORTUNITY #{}", stats.opportunities_found);
-                println!("   Market: {}/{}", opportunity.market_id.0, opportunity.token_id.0);
+                println!("   Market: {}/{}", opportunity.market_id, opportunity.token_id.0);
                 println!("   Buy: ${:.4} | Sell: ${:.4} | Spread: ${:.4}",
                     opportunity.ask_price,
                     opportunity.bid_price,
@@ -188,6 +298,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                                 println!("   ✅ SUCCESS! P&L: ${:.2} in {}ms", pnl, latency_ms);
                                 println!("      BUY: {} | SELL: {}", buy_hash, sell_hash);
+
+                                let now = chrono::Utc::now().timestamp_millis();
+                                position_tracker.update_position(
+                                    opportunity.market_id.clone(),
+                                    opportunity.token_id.clone(),
+                                    Position {
+                                        market_id: opportunity.market_id.clone(),
+                                        token_id: opportunity.token_id.clone(),
+                                        size: opportunity.max_size,
+                                        entry_price: opportunity.ask_price,
+                                        current_price: opportunity.bid_price,
+                                        unrealized_pnl: 0.0,
+                                        realized_pnl: 0.0,
+                                        opened_at: now,
+                                        updated_at: now,
+                                        order_hashes: vec![buy_hash, sell_hash],
+                                    },
+                                );
                             }
                             ExecutionResult::PartialFill { filled_hash, rolled_back, .. } => {
                                 stats.partial_fills += 1;
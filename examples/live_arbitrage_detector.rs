@@ -8,7 +8,6 @@
 use polymarket_hft_bot::core::arbitrage::{ArbitrageConfig, ScalarArbitrageDetector};
 use polymarket_hft_bot::types::{MarketId, OrderBook, OrderBookEntry, TokenId};
 use serde_json::{json, Value};
-use tokio;
 use tokio::time::{sleep, Duration};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use futures_util::{SinkExt, StreamExt};
@@ -69,6 +68,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         min_profit_margin: 0.005, // 0.5% minimum (lower for real markets)
         min_size: 1.0,            // $1 minimum size
         max_spread: 0.5,          // 50% max spread
+        ..ArbitrageConfig::default()
     };
     let detector = ScalarArbitrageDetector::new(config);
 
@@ -94,7 +94,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut message_count = 0;
     let mut arbitrage_count = 0;
-    let mut last_orderbook: Option<(MarketId, TokenId, OrderBook)> = None;
 
     // Listen for messages
     while let Some(msg) = read.next().await {
@@ -111,7 +110,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 if event_type == "book" {
                                     // Process orderbook
                                     if let Some(orderbook) = parse_orderbook(book_data) {
-                                        let market_id = MarketId(book_data
+                                        let market_id = MarketId::new(book_data
                                             .get("market")
                                             .and_then(|v| v.as_str())
                                             .unwrap_or("unknown")
@@ -120,7 +119,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         let token_id = TokenId("YES".to_string());
 
                                         println!("\n📊 Orderbook Update:");
-                                        println!("   Market: {}", &market_id.0[..20]);
+                                        println!("   Market: {}", &market_id.to_string()[..20]);
                                         println!("   Bids: {} | Asks: {}",
                                             orderbook.bids.len(), orderbook.asks.len());
 
@@ -156,8 +155,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                                 println!("   ═══════════════════════════════════════════════════\n");
                                             }
                                         }
-
-                                        last_orderbook = Some((market_id, token_id, orderbook));
                                     }
                                 }
                             }
@@ -6,7 +6,6 @@
 
 use polymarket_hft_bot::core::arbitrage::{ArbitrageConfig, ScalarArbitrageDetector};
 use serde_json::json;
-use tokio;
 use tokio::time::{sleep, Duration};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use futures_util::{SinkExt, StreamExt};
@@ -56,8 +55,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         min_profit_margin: 0.01, // 1% minimum profit (lower for live data)
         min_size: 5.0,            // $5 minimum size
         max_spread: 0.5,          // 50% max spread
+        ..ArbitrageConfig::default()
     };
-    let detector = ScalarArbitrageDetector::new(config);
+    let _detector = ScalarArbitrageDetector::new(config);
 
     let mut message_count = 0;
     let mut ping_count = 0;
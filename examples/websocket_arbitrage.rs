@@ -12,7 +12,6 @@
 use polymarket_hft_bot::core::arbitrage::{ArbitrageConfig, ScalarArbitrageDetector};
 use polymarket_hft_bot::services::websocket::{process_message, PolymarketWebSocket};
 use polymarket_hft_bot::types::{MarketId, TokenId};
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -28,6 +27,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         min_profit_margin: 0.02, // 2% minimum profit
         min_size: 10.0,          // $10 minimum size
         max_spread: 0.5,          // 50% max spread (sanity check)
+        ..ArbitrageConfig::default()
     };
 
     let detector = ScalarArbitrageDetector::new(config);
@@ -39,14 +39,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Markets to monitor
     let markets = vec![
-        (MarketId("TRUMP-WIN".to_string()), TokenId("YES".to_string())),
-        (MarketId("BIDEN-WIN".to_string()), TokenId("YES".to_string())),
-        (MarketId("DeSANTIS-WIN".to_string()), TokenId("YES".to_string())),
+        (MarketId::new("TRUMP-WIN"), TokenId("YES".to_string())),
+        (MarketId::new("BIDEN-WIN"), TokenId("YES".to_string())),
+        (MarketId::new("DeSANTIS-WIN"), TokenId("YES".to_string())),
     ];
 
     println!("📊 Monitoring markets:");
     for (market, token) in &markets {
-        println!("   - {}/{}", market.0, token.0);
+        println!("   - {}/{}", market, token.0);
     }
     println!();
 
@@ -84,7 +84,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 arbitrage_count += 1;
 
                 println!("\n🎯 ARBITRAGE OPPORTUNITY #{}", arbitrage_count);
-                println!("   Market: {}/{}", opportunity.market_id.0, opportunity.token_id.0);
+                println!("   Market: {}/{}", opportunity.market_id, opportunity.token_id.0);
                 println!("   Buy at: ${:.4} (ask)", opportunity.ask_price);
                 println!("   Sell at: ${:.4} (bid)", opportunity.bid_price);
                 println!("   Spread: ${:.4}", opportunity.bid_price - opportunity.ask_price);
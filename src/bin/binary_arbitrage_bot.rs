@@ -20,66 +20,191 @@
 
 use anyhow::{anyhow, Result};
 use polymarket_hft_bot::{
-    clob::{ClobClient, ClobConfig, CreateOrderRequest},
+    clob::{ClobClient, ClobConfig},
+    config::FeatureStore,
     types::config::BotConfig,
-    core::redemption::{RedemptionManager, RedeemablePosition},
+    core::redemption::{PortfolioSnapshot, RedemptionManager, RedeemablePosition},
+    core::risk::{
+        CircuitBreaker, PersistentPositionTracker, PositionStore, SlidingWindowPnL,
+        MINUTES_PER_DAY,
+    },
     strategies::{
-        ArbitrageSide, BinaryArbitrageConfig, BinaryArbitrageDetector,
-        CryptoAsset, CryptoUpDownConfig, CryptoUpDownFetcher, Timeframe,
+        ArbitrageSide, BinaryArbitrageConfig, BinaryArbitrageDetector, ExecutionPlan,
+        CryptoAsset, CryptoUpDownConfig, CryptoUpDownFetcher, CryptoUpDownMarket, Timeframe,
+    },
+    services::polymarket::{ApiCredentials, PolymarketRestClient},
+    services::websocket::{PolymarketWebSocket, process_message},
+    types::{
+        BatchOrderResponse, EventBus, MarketId, OrderBook, OrderType, Position, TokenId,
+        TradingEvent,
     },
-    services::websocket::{PolymarketMessage, process_message},
-    types::{OrderBook, TokenId, OrderSide, MarketId},
 };
+#[cfg(feature = "alerts")]
+use polymarket_hft_bot::utils::alerts::{AlertSender, Severity};
+use polymarket_hft_bot::utils::metrics::{HftMetrics, DEFAULT_METRICS_ADDR};
+use polymarket_hft_bot::utils::shutdown::ShutdownCoordinator;
+use ethers::signers::{LocalWallet, Signer};
 use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::RwLock;
-use tracing::{info, warn, error};
+use tracing::{debug, info, warn, error};
 use chrono::Utc;
 
 /// Orderbook cache for all tracked tokens
 type OrderbookCache = Arc<RwLock<HashMap<TokenId, OrderBook>>>;
 
+/// Deltas received for tokens that have no base snapshot yet
+type DeltaBuffer = Arc<parking_lot::Mutex<HashMap<TokenId, Vec<OrderBook>>>>;
+
+/// Maximum buffered deltas per token before the oldest are dropped
+const MAX_PENDING_DELTAS: usize = 100;
+
+/// Snapshots closer together than this are treated as overlapping and merged
+const SNAPSHOT_MERGE_WINDOW_MS: u64 = 1_000;
+
+/// Insert an orderbook snapshot, merging with an overlapping cached snapshot
+///
+/// Partial snapshots of the same book may arrive from more than one connection.
+/// Snapshots whose entries are incoherent with their own timestamp are dropped.
+fn apply_snapshot(
+    cache: &mut HashMap<TokenId, OrderBook>,
+    token_id: TokenId,
+    order_book: OrderBook,
+) {
+    if !order_book.timestamp_coherent(SNAPSHOT_MERGE_WINDOW_MS) {
+        warn!("Dropping incoherent orderbook snapshot for {}", token_id);
+        return;
+    }
+
+    let book = match cache.get(&token_id) {
+        Some(existing)
+            if existing.timestamp.abs_diff(order_book.timestamp) <= SNAPSHOT_MERGE_WINDOW_MS =>
+        {
+            existing.merge(&order_book)
+        }
+        _ => order_book,
+    };
+
+    cache.insert(token_id, book);
+}
+
 /// Binary arbitrage bot
 struct BinaryArbitrageBot {
     config: BotConfig,
     clob_client: ClobClient,
     detector: BinaryArbitrageDetector,
     orderbook_cache: OrderbookCache,
+    pending_deltas: DeltaBuffer,
     redemption_manager: Arc<RwLock<RedemptionManager>>,
+    /// Trading fees and redemption P&L count towards its loss limits
+    circuit_breaker: Arc<CircuitBreaker>,
+    /// Realized P&L over the last 24 hours: fees at fill, payout less cost at redemption
+    realized_pnl: SlidingWindowPnL,
+    /// Filled YES/NO legs, marked to market in portfolio snapshots (kept across restarts)
+    position_tracker: Arc<PersistentPositionTracker>,
+    /// Market data, position and circuit breaker events
+    event_bus: EventBus<TradingEvent>,
+    /// Opportunity, trade and circuit breaker metrics (served on `DEFAULT_METRICS_ADDR`)
+    metrics: Arc<HftMetrics>,
     dry_run: bool,
 }
 
 impl BinaryArbitrageBot {
     /// Create new bot
-    fn new(config: BotConfig, dry_run: bool) -> Result<Self> {
+    ///
+    /// `credentials` (from `get_clob_auth`) populate the CLOB API key.
+    fn new(
+        config: BotConfig,
+        credentials: Option<ApiCredentials>,
+        dry_run: bool,
+    ) -> Result<Self> {
         // Create CLOB client configuration
-        let clob_config = ClobConfig {
+        let mut clob_config = ClobConfig {
             base_url: config.polymarket.clob_api_url.clone(),
             api_key: String::new(), // API key not required for basic operations
+            api_secret: String::new(),
+            api_passphrase: String::new(),
             private_key: config.wallet.private_key.clone(),
             chain_id: config.wallet.chain_id,
             verifying_contract: "0x0000000000000000000000000000000000000000".to_string(), // TODO: Get from config
             maker_address: config.wallet.address.clone(),
             taker_address: "0x0000000000000000000000000000000000000000".to_string(),
             timeout_secs: 10,
+            proxy: None,
+            warm_up_on_start: true,
+            pool_size: 10,
+            cancel_on_trip: true,
+            fill_confirmation_timeout_ms: 2_000,
+            auto_cancel_on_partial_failure: true,
+            requests_per_second: 100,
+            rpc_url: Some(config.polymarket.rpc_url.clone()),
+            retry_attempts: 3,
         };
+        if let Some(credentials) = &credentials {
+            credentials.apply_to(&mut clob_config);
+        }
 
         let clob_client = ClobClient::new(clob_config)?;
+        // Refreshed from the API once markets are known (see `start`)
+        clob_client.set_default_fee_rate(config.risk.fee_rate_bps);
 
         let arb_config = BinaryArbitrageConfig {
-            min_profit_margin: 0.02, // 2% minimum (covers fees)
+            min_profit_margin: 0.02, // 2% minimum (net of redemption fee)
             min_size: 5.0,            // $5 minimum
             max_cost: config.risk.max_position_size, // Use risk config
+            redemption_fee_bps: 200,  // Polymarket 2% redemption fee
+            min_expiry_minutes: 5,    // Too close to settlement to fill
+            // Longest timeframe we fetch (4h)
+            max_time_remaining_secs: Some(Timeframe::FourHour.duration_minutes() * 60),
+            max_market_data_age_ms: 5_000, // Cached books older than this are stale
+            require_both_sides_liquid: true, // Skip dust on either side
+            max_size_imbalance_ratio: 10.0,  // At most 10:1 YES/NO size
+            order_book_depth_required: config.trading.order_book_depth_required,
+            // Two FOK orders instead of a GTC batch (see `place_fok_legs`)
+            require_atomic: config.trading.require_atomic,
         };
 
-        let detector = BinaryArbitrageDetector::new(arb_config);
+        // Runtime switches, seeded from the startup feature config
+        let feature_store = Arc::new(FeatureStore::from_config(&config.features));
+        let detector = BinaryArbitrageDetector::new(arb_config).with_feature_store(feature_store);
+
+        let event_bus = EventBus::default();
+        let position_tracker =
+            PersistentPositionTracker::new(Path::new(&config.position_db_path))?;
+        // Unredeemed positions share the database, so a restart still redeems them
+        let redemption_manager =
+            RedemptionManager::with_store(position_tracker.open_tree("redemptions")?)?;
+        let position_tracker = Arc::new(position_tracker.with_event_bus(event_bus.clone()));
+
+        let metrics = Arc::new(HftMetrics::new());
+        let circuit_breaker = CircuitBreaker::new(config.risk.clone())
+            .with_event_bus(event_bus.clone())
+            .with_metrics(Arc::clone(&metrics))
+            .with_position_tracker(position_tracker.clone());
+        #[cfg(feature = "alerts")]
+        let circuit_breaker = if config.alert_channels.is_empty() {
+            circuit_breaker
+        } else {
+            let sender = AlertSender::new(config.alert_channels.clone());
+            circuit_breaker.with_alert_sender(Arc::new(sender))
+        };
+        let circuit_breaker = Arc::new(circuit_breaker);
 
         Ok(Self {
             config,
             clob_client,
             detector,
             orderbook_cache: Arc::new(RwLock::new(HashMap::new())),
-            redemption_manager: Arc::new(RwLock::new(RedemptionManager::new())),
+            pending_deltas: Arc::new(parking_lot::Mutex::new(HashMap::new())),
+            redemption_manager: Arc::new(RwLock::new(redemption_manager)),
+            circuit_breaker,
+            realized_pnl: SlidingWindowPnL::new(Duration::from_secs(MINUTES_PER_DAY as u64 * 60)),
+            position_tracker,
+            event_bus,
+            metrics,
             dry_run,
         })
     }
@@ -89,6 +214,38 @@ impl BinaryArbitrageBot {
         info!("🤖 Binary Arbitrage Bot Starting...");
         info!("Mode: {}", if self.dry_run { "DRY-RUN" } else { "LIVE" });
 
+        // Log everything but market data (too frequent)
+        let mut events = self.event_bus.subscribe();
+        tokio::spawn(async move {
+            while let Ok(event) = events.recv().await {
+                if !matches!(event, TradingEvent::MarketUpdate { .. }) {
+                    debug!("Event: {:?}", event);
+                }
+            }
+        });
+
+        // Prometheus scrape endpoint (the bot keeps running without it)
+        if let Err(e) = Arc::clone(&self.metrics).serve(DEFAULT_METRICS_ADDR).await {
+            warn!("Metrics endpoint disabled: {}", e);
+        }
+
+        // Open pooled CLOB connections before the first order needs them
+        // (a failed warm-up only costs the first orders a handshake)
+        if self.clob_client.warm_up_on_start() {
+            if let Err(e) = self.clob_client.warm_up(self.clob_client.warm_up_target()).await {
+                warn!("CLOB connection warm-up failed: {}", e);
+            }
+        }
+
+        // Production refuses to start without the minimum USDC balance and allowance
+        if !self.dry_run {
+            match self.clob_client.check_balance(self.config.risk.min_usdc_balance).await {
+                Ok(balance) => info!("💵 USDC available: ${:.2}", balance.available()),
+                Err(e) if self.config.environment.is_production() => return Err(e.into()),
+                Err(e) => warn!("Balance check failed: {}", e),
+            }
+        }
+
         // Step 1: Fetch crypto up/down markets
         info!("📡 Fetching crypto up/down markets...");
         let markets = self.fetch_markets().await?;
@@ -109,18 +266,58 @@ impl BinaryArbitrageBot {
             })
             .collect();
 
+        // Orders leave fee_rate_bps at 0 and use the client default
+        if let Some(token_id) = token_ids.first() {
+            match self.clob_client.get_fees(&token_id.0).await {
+                Ok(fee_rate_bps) => {
+                    info!("💸 Fee rate: {} bps", fee_rate_bps);
+                    self.clob_client.set_default_fee_rate(fee_rate_bps);
+                }
+                Err(e) => warn!(
+                    "Failed to fetch fee rate, using {} bps: {}",
+                    self.clob_client.default_fee_rate(),
+                    e
+                ),
+            }
+        }
+
         info!("📋 Subscribing to {} token orderbooks", token_ids.len());
 
-        // Step 3: Start WebSocket and process orderbook updates
-        self.run_websocket_loop(token_ids, markets).await?;
+        // Step 3: Start WebSocket and process orderbook updates (until SIGTERM or Ctrl-C)
+        let shutdown = Arc::new(ShutdownCoordinator::new());
+        let mut shutdown_rx = shutdown.subscribe();
+        let _signal_handler = shutdown.install_signal_handler();
+        tokio::select! {
+            result = self.run_websocket_loop(token_ids, markets) => result?,
+            _ = shutdown_rx.recv() => {
+                info!("🛑 Shutdown signal received, shutting down...");
+                self.cancel_open_orders().await;
+            }
+        }
 
         Ok(())
     }
 
+    /// Cancel all open orders so none are left orphaned after shutdown
+    async fn cancel_open_orders(&self) {
+        if self.dry_run {
+            return;
+        }
+
+        // Orders behind tracked positions first, then anything else still open
+        let tracker = self.position_tracker.tracker();
+        ShutdownCoordinator::cancel_all_open_orders(&self.clob_client, tracker).await;
+
+        match self.clob_client.cancel_all_orders().await {
+            Ok(cancelled) => info!("✅ Cancelled {} open orders", cancelled),
+            Err(e) => error!("Failed to cancel open orders: {}", e),
+        }
+    }
+
     /// Fetch active crypto up/down markets
     async fn fetch_markets(
         &self,
-    ) -> Result<Vec<polymarket_hft_bot::strategies::CryptoUpDownMarket>> {
+    ) -> Result<Vec<CryptoUpDownMarket>> {
         let config = CryptoUpDownConfig {
             assets: vec![
                 CryptoAsset::Bitcoin,
@@ -138,107 +335,193 @@ impl BinaryArbitrageBot {
 
         let gamma_url = self.config.polymarket.gamma_api_url.clone();
 
-        let fetcher = CryptoUpDownFetcher::new(config, gamma_url);
+        // Validate Gamma token IDs against the CLOB before subscribing
+        let rest_client = PolymarketRestClient::with_clob_rest_client(
+            self.clob_client.rest_client().clone(),
+            gamma_url,
+        );
+
+        let fetcher = CryptoUpDownFetcher::new(config, rest_client.gamma().clone())
+            .with_clob_validation(rest_client.clob().clone());
         fetcher.fetch_markets().await
     }
 
     /// Run WebSocket loop and process orderbook updates
     async fn run_websocket_loop(
         &mut self,
-        token_ids: Vec<TokenId>,
-        markets: Vec<polymarket_hft_bot::strategies::CryptoUpDownMarket>,
+        _token_ids: Vec<TokenId>,
+        markets: Vec<CryptoUpDownMarket>,
     ) -> Result<()> {
         // Create WebSocket client
-        // TODO: Add websocket_url to PolymarketConfig
-        let ws_url = "wss://ws-subscriptions-clob.polymarket.com/ws/market".to_string();
+        let ws_url = self.config.polymarket.websocket_url.clone();
 
         info!("🔌 Connecting to WebSocket: {}", ws_url);
 
-        // For now, use a simple channel-based approach
-        // In production, you'd use the full WebSocketManager
-        let (tx, mut rx) = tokio::sync::mpsc::channel::<PolymarketMessage>(1000);
+        let subscriptions: Vec<(MarketId, TokenId)> = markets
+            .iter()
+            .flat_map(|m| {
+                m.token_ids
+                    .iter()
+                    .map(|id| (m.market_id(), TokenId(id.clone())))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let (mut ws, mut rx) = PolymarketWebSocket::new(ws_url, subscriptions);
+        ws.publish_events(self.event_bus.clone());
 
-        // Spawn WebSocket listener (simplified for now)
-        // TODO: Integrate with WebSocketManager properly
-        let cache = self.orderbook_cache.clone();
+        // A full snapshot supersedes any deltas buffered for the token
+        let pending_deltas = self.pending_deltas.clone();
+        ws.on_orderbook_snapshot(Arc::new(move |market_id, token_id, _book| {
+            let dropped = pending_deltas.lock().remove(&token_id).map_or(0, |d| d.len());
+            info!("📸 Orderbook snapshot for {}/{} ({} buffered deltas cleared)",
+                market_id, token_id, dropped);
+        }));
+
+        // Shared so the detection loop can check market data freshness
+        let ws = Arc::new(ws);
+        let ws_task = ws.clone();
         tokio::spawn(async move {
-            // This is a placeholder - in production you'd connect to real WebSocket
-            info!("WebSocket listener started (placeholder)");
+            if let Err(e) = ws_task.start().await {
+                error!("WebSocket stopped: {}", e);
+            }
+        });
 
+        let cache = self.orderbook_cache.clone();
+        let pending_deltas = self.pending_deltas.clone();
+        let circuit_breaker = self.circuit_breaker.clone();
+        tokio::spawn(async move {
             while let Some(msg) = rx.recv().await {
                 if let Some(update) = process_message(msg) {
                     let mut cache = cache.write().await;
-                    cache.insert(update.token_id, update.order_book);
+
+                    // Anomalous books count against the market (see `can_trade_market`)
+                    if update.is_snapshot {
+                        let previous = cache.get(&update.token_id);
+                        if let Some(reason) = circuit_breaker.observe_order_book(
+                            &update.token_id,
+                            previous,
+                            &update.order_book,
+                        ) {
+                            warn!("Orderbook anomaly on {}: {:?}", update.token_id, reason);
+                        }
+                        apply_snapshot(&mut cache, update.token_id, update.order_book);
+                        continue;
+                    }
+
+                    // Deltas update the cached book level by level
+                    if let Some(book) = cache.get_mut(&update.token_id) {
+                        update.apply_to(book);
+                        if let Some(reason) =
+                            circuit_breaker.observe_order_book(&update.token_id, None, book)
+                        {
+                            warn!("Orderbook anomaly on {}: {:?}", update.token_id, reason);
+                        }
+                        continue;
+                    }
+
+                    // Deltas are meaningless without a base snapshot
+                    let mut pending = pending_deltas.lock();
+                    let deltas = pending.entry(update.token_id).or_default();
+                    if deltas.len() >= MAX_PENDING_DELTAS {
+                        deltas.remove(0);
+                    }
+                    deltas.push(update.order_book);
                 }
             }
         });
 
         // Main detection loop
         info!("🔍 Starting arbitrage detection loop...");
-        self.detection_loop(markets).await?;
+        self.detection_loop(markets, &ws).await?;
 
         Ok(())
     }
 
     /// Main detection loop - scans for arbitrage opportunities
+    ///
+    /// Markets whose YES or NO book is stale (per `ws`) or anomalous (per the
+    /// circuit breaker) are skipped.
     async fn detection_loop(
         &mut self,
-        markets: Vec<polymarket_hft_bot::strategies::CryptoUpDownMarket>,
+        markets: Vec<CryptoUpDownMarket>,
+        ws: &PolymarketWebSocket,
     ) -> Result<()> {
         let mut scan_count = 0;
         let mut opportunities_found = 0;
 
+        // Last (YES, NO) snapshot hashes per market - skip detection on unchanged books
+        let mut last_hashes: HashMap<MarketId, (u64, u64)> = HashMap::new();
+
         loop {
             scan_count += 1;
 
-            // Scan all markets for arbitrage
-            for market in &markets {
-                // Need at least 2 tokens (YES and NO)
-                if market.token_ids.len() < 2 {
-                    continue;
-                }
-
-                let yes_token_id = TokenId(market.token_ids[0].clone());
-                let no_token_id = TokenId(market.token_ids[1].clone());
-
-                // Get orderbooks from cache and check for arbitrage
-                let opportunity = {
-                    let cache = self.orderbook_cache.read().await;
-                    let yes_orderbook = cache.get(&yes_token_id);
-                    let no_orderbook = cache.get(&no_token_id);
-
-                    if let (Some(yes_ob), Some(no_ob)) = (yes_orderbook, no_orderbook) {
-                        // Detect arbitrage
-                        self.detector.detect(
-                            &MarketId(market.event_id.clone()),
-                            &yes_token_id,
-                            &no_token_id,
-                            yes_ob,
-                            no_ob,
-                            market.title.clone(),
-                            market.end_date.clone(),
-                        )
-                    } else {
-                        None
-                    }
-                }; // cache read guard is dropped here
-
-                if let Some(opportunity) = opportunity {
-                    opportunities_found += 1;
-
-                    info!("🎯 BINARY ARBITRAGE FOUND!");
-                    info!("   Market: {}", opportunity.title);
-                    info!("   Side: {:?}", opportunity.side);
-                    info!("   Price sum: ${:.3}", opportunity.price_sum);
-                    info!("   Profit: ${:.2} ({:.1}%)",
-                        opportunity.expected_profit,
-                        opportunity.profit_margin * 100.0
-                    );
-
-                    // Execute trade
-                    if let Err(e) = self.execute_arbitrage(&opportunity).await {
-                        error!("Failed to execute arbitrage: {}", e);
-                    }
+            // Fresh markets whose books changed since the last scan, detected in parallel
+            let opportunities = {
+                let cache = self.orderbook_cache.read().await;
+                let arb_config = self.detector.config();
+                let changed: Vec<CryptoUpDownMarket> = markets
+                    .iter()
+                    .filter(|market| {
+                        // Need at least 2 tokens (YES and NO)
+                        let [yes, no, ..] = market.token_ids.as_slice() else {
+                            return false;
+                        };
+                        let (yes_token_id, no_token_id) =
+                            (TokenId(yes.clone()), TokenId(no.clone()));
+
+                        // Never trade on stale books
+                        if !arb_config.is_market_data_fresh(ws.market_data_age(&yes_token_id))
+                            || !arb_config.is_market_data_fresh(ws.market_data_age(&no_token_id))
+                        {
+                            debug!("Skipping {}: stale market data", market.title);
+                            return false;
+                        }
+
+                        // Skip markets with repeated orderbook anomalies
+                        if !self.circuit_breaker.can_trade_market(&yes_token_id)
+                            || !self.circuit_breaker.can_trade_market(&no_token_id)
+                        {
+                            debug!("Skipping {}: orderbook anomalies", market.title);
+                            return false;
+                        }
+
+                        // Skip detection on unchanged books
+                        let (Some(yes_ob), Some(no_ob)) =
+                            (cache.get(&yes_token_id), cache.get(&no_token_id))
+                        else {
+                            return false;
+                        };
+                        let hashes = (yes_ob.snapshot_hash(), no_ob.snapshot_hash());
+                        last_hashes.insert(market.market_id(), hashes) != Some(hashes)
+                    })
+                    .cloned()
+                    .collect();
+
+                self.detector.detect_parallel_batch(&changed, &cache)
+            }; // cache read guard is dropped here
+
+            // Most profitable first
+            for opportunity in &opportunities {
+                opportunities_found += 1;
+                self.metrics.record_opportunity();
+
+                info!("🎯 BINARY ARBITRAGE FOUND!");
+                info!("   Market: {}", opportunity.title);
+                info!("   Side: {:?}", opportunity.side);
+                info!("   Price sum: ${:.3}", opportunity.price_sum);
+                info!("   Size: {:.2} (constrained by {:?})",
+                    opportunity.max_size,
+                    opportunity.size_constrained_by
+                );
+                info!("   Profit: ${:.2} ({:.1}%)",
+                    opportunity.expected_profit,
+                    opportunity.profit_margin * 100.0
+                );
+
+                // Execute trade
+                if let Err(e) = self.execute_arbitrage(opportunity).await {
+                    error!("Failed to execute arbitrage: {}", e);
                 }
             }
 
@@ -249,17 +532,113 @@ impl BinaryArbitrageBot {
                 // Show position status
                 let manager = self.redemption_manager.read().await;
                 manager.log_status();
+                drop(manager);
+
+                // Audit trail of the portfolio at each periodic update
+                match self.portfolio_snapshot().await.write_to_dir(Path::new(".")) {
+                    Ok(path) => debug!("Portfolio snapshot written to {}", path.display()),
+                    Err(e) => warn!("Failed to write portfolio snapshot: {}", e),
+                }
 
                 // Auto-redeem ready positions
-                drop(manager);
                 let mut manager = self.redemption_manager.write().await;
-                if let Err(e) = manager.auto_redeem_all(&self.clob_client).await {
-                    error!("Auto-redemption failed: {}", e);
+                match manager.auto_redeem_all(&self.clob_client).await {
+                    Ok(profit) if profit != 0.0 => {
+                        self.realized_pnl.record(profit);
+                        if let Err(e) = self.circuit_breaker.record_trade(profit) {
+                            error!("Failed to record redemption P&L: {}", e);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(e) => error!("Auto-redemption failed: {}", e),
+                }
+
+                // Redeemed legs are closed: stop marking them and cancelling their orders
+                for position in manager.redeemed_positions() {
+                    let tracked = [&position.yes_token_id, &position.no_token_id]
+                        .map(|token_id| {
+                            self.position_tracker.remove_position(&position.market_id, token_id)
+                        });
+                    if tracked.iter().any(Option::is_some) {
+                        self.circuit_breaker.close_position();
+                    }
                 }
             }
 
-            // Sleep briefly between scans
-            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            // Rescan immediately while opportunities keep appearing
+            if opportunities.is_empty() {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+            }
+        }
+    }
+
+    /// Current portfolio: unredeemed positions plus daily P&L
+    ///
+    /// Realized P&L is what fills and redemptions booked in the last 24
+    /// hours; unrealized P&L marks tracked legs at the cached best bid.
+    async fn portfolio_snapshot(&self) -> PortfolioSnapshot {
+        let positions = self.redemption_manager
+            .read()
+            .await
+            .prioritize()
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let prices: HashMap<(MarketId, TokenId), f64> = {
+            let cache = self.orderbook_cache.read().await;
+            self.position_tracker
+                .get_all_positions()
+                .into_iter()
+                .filter_map(|(key, _)| {
+                    let bid = cache.get(&key.1)?.best_bid()?.price;
+                    Some((key, bid))
+                })
+                .collect()
+        };
+        let unrealized = self.position_tracker
+            .to_snapshot(Some(&prices))
+            .total_unrealized_pnl
+            .unwrap_or(0.0);
+
+        PortfolioSnapshot::new(
+            positions,
+            self.realized_pnl.net_pnl(),
+            unrealized,
+            self.circuit_breaker.daily_fees(),
+        )
+    }
+
+    /// Submit both legs of `plan` as concurrent FOK orders
+    ///
+    /// The two orders are independent, so one can fill while the other is
+    /// killed. Combined like a batch response: a killed leg leaves an empty
+    /// hash, so that case is reported as a partial fill and handled as a
+    /// one-sided position.
+    async fn place_fok_legs(&self, plan: &ExecutionPlan) -> BatchOrderResponse {
+        let (yes, no) = tokio::join!(
+            self.clob_client.create_fok_order(&plan.yes_order),
+            self.clob_client.create_fok_order(&plan.no_order),
+        );
+
+        let mut errors = Vec::new();
+        let order_hashes = [yes, no]
+            .into_iter()
+            .map(|leg| match leg {
+                Ok(order_hash) => order_hash,
+                Err(e) => {
+                    errors.push(e.to_string());
+                    String::new()
+                }
+            })
+            .collect::<Vec<_>>();
+
+        BatchOrderResponse {
+            success: order_hashes.iter().any(|hash| !hash.is_empty()),
+            error_msg: errors.join("; "),
+            order_id: None,
+            order_hashes,
+            status: None,
         }
     }
 
@@ -298,43 +677,44 @@ impl BinaryArbitrageBot {
             return Ok(());
         }
 
-        // Create orders for both sides
-        let (yes_side, no_side) = match opportunity.side {
-            ArbitrageSide::Buy => (OrderSide::BUY, OrderSide::BUY),
-            ArbitrageSide::Sell => (OrderSide::SELL, OrderSide::SELL),
-        };
-
-        let yes_order = CreateOrderRequest {
-            token_id: opportunity.yes_token_id.0.clone(),
-            side: yes_side,
-            price: opportunity.yes_price,
-            size: opportunity.max_size,
-            order_type: "GTC".to_string(), // Good-til-cancelled
-            expiration: None,
-            fee_rate_bps: 0, // TODO: Get from config
-        };
+        // Orders for both sides at the client's default fee rate (see `start`)
+        let plan = opportunity.execution_plan(self.clob_client.default_fee_rate());
+        debug!(
+            "Execution plan: cost ${:.2}, est. fees ${:.2}",
+            plan.expected_execution_cost_usd,
+            plan.fee_estimate.total()
+        );
+
+        // Rejected without tripping if it would breach market concentration
+        let cost = opportunity.price_sum * opportunity.max_size;
+        if let Err(e) = self.circuit_breaker.open_position(&opportunity.market_id, cost) {
+            warn!("Skipping {}: {}", opportunity.market_id, e);
+            return Ok(());
+        }
 
-        let no_order = CreateOrderRequest {
-            token_id: opportunity.no_token_id.0.clone(),
-            side: no_side,
-            price: opportunity.no_price,
-            size: opportunity.max_size,
-            order_type: "GTC".to_string(),
-            expiration: None,
-            fee_rate_bps: 0,
+        let trading_fees = plan.fee_estimate.trading_fee_usd;
+        let started = Instant::now();
+        let batch_response = if self.detector.config().require_atomic {
+            info!("📤 Placing FOK orders...");
+            self.place_fok_legs(&plan.with_order_type(OrderType::FOK)).await
+        } else {
+            info!("📤 Placing batch orders...");
+            match self.clob_client.create_batch_orders(&plan.orders()).await {
+                Ok(response) => response,
+                Err(e) => {
+                    self.metrics.record_execution("failed");
+                    self.circuit_breaker.close_position();
+                    return Err(e.into());
+                }
+            }
         };
-
-        // Execute both orders atomically
-        info!("📤 Placing batch orders...");
-        let batch_response = self
-            .clob_client
-            .create_batch_orders(&[yes_order, no_order])
-            .await?;
+        self.metrics.observe_latency(started.elapsed().as_secs_f64() * 1_000.0);
 
         // Check if both orders were created successfully
-        let success = batch_response.success && batch_response.order_hashes.len() >= 2;
+        let success = batch_response.both_succeeded();
 
         if success {
+            self.metrics.record_execution("success");
             info!("✅ Both orders created successfully!");
             info!("   Order hashes: {:?}", batch_response.order_hashes);
 
@@ -352,7 +732,7 @@ impl BinaryArbitrageBot {
                 yes_token_id: opportunity.yes_token_id.clone(),
                 no_token_id: opportunity.no_token_id.clone(),
                 size: opportunity.max_size,
-                cost: opportunity.price_sum * opportunity.max_size,
+                cost,
                 expected_profit: opportunity.expected_profit,
                 expiry,
                 opened_at: Utc::now(),
@@ -361,15 +741,99 @@ impl BinaryArbitrageBot {
 
             self.redemption_manager.write().await.add_position(position);
 
+            // Trading fees are paid at fill; the payout is booked at redemption
+            self.circuit_breaker.record_fees(trading_fees);
+            self.realized_pnl.record(-trading_fees);
+
+            // Track both legs for mark-to-market P&L
+            let now = Utc::now().timestamp_millis();
+            let size = match opportunity.side {
+                ArbitrageSide::Buy => opportunity.max_size,
+                ArbitrageSide::Sell => -opportunity.max_size,
+            };
+            for (leg, (token_id, price)) in [
+                (&opportunity.yes_token_id, opportunity.yes_price),
+                (&opportunity.no_token_id, opportunity.no_price),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let order_hashes = batch_response.order_hashes.get(leg).cloned();
+                self.position_tracker.update_position(
+                    opportunity.market_id.clone(),
+                    token_id.clone(),
+                    Position {
+                        market_id: opportunity.market_id.clone(),
+                        token_id: token_id.clone(),
+                        size,
+                        entry_price: price,
+                        current_price: price,
+                        unrealized_pnl: 0.0,
+                        realized_pnl: 0.0,
+                        opened_at: now,
+                        updated_at: now,
+                        order_hashes: order_hashes.into_iter().collect(),
+                    },
+                );
+            }
+
             info!("📦 Position tracked - will redeem at expiry");
+        } else if batch_response.is_partial_fill() {
+            // Only one leg was accepted and nothing rolls it back here
+            self.metrics.record_execution("partial");
+            let filled = batch_response.succeeded_indices()[0];
+            let filled_hash = &batch_response.order_hashes[filled];
+            self.handle_manual_intervention(opportunity, std::slice::from_ref(filled_hash))
+                .await;
         } else {
+            self.metrics.record_execution("failed");
             error!("⚠️  Order creation failed!");
             error!("   Response: {:?}", batch_response);
-            error!("   TODO: Implement rollback logic");
+            self.circuit_breaker.close_position();
         }
 
         Ok(())
     }
+
+    /// Report an unhedged one-sided position, trip the circuit breaker and
+    /// optionally cancel all orders
+    ///
+    /// `order_hashes` are the accepted (filled) legs.
+    async fn handle_manual_intervention(
+        &self,
+        opportunity: &polymarket_hft_bot::strategies::BinaryArbitrageOpportunity,
+        order_hashes: &[String],
+    ) {
+        error!(
+            "⚠️ ONE-SIDED POSITION in {} ({:?}) - MANUAL INTERVENTION REQUIRED!",
+            opportunity.market_id,
+            order_hashes
+        );
+        self.circuit_breaker.trip();
+
+        #[cfg(feature = "alerts")]
+        if let Some(sender) = self.circuit_breaker.alert_sender() {
+            sender
+                .send(
+                    Severity::Critical,
+                    "Manual intervention required: one-sided binary arbitrage",
+                    serde_json::json!({
+                        "market_id": opportunity.market_id,
+                        "yes_token_id": opportunity.yes_token_id.0,
+                        "no_token_id": opportunity.no_token_id.0,
+                        "order_hashes": order_hashes,
+                        "timestamp": Utc::now().to_rfc3339(),
+                    }),
+                )
+                .await;
+        }
+
+        if self.clob_client.auto_cancel_on_partial_failure() {
+            if let Ok(cancelled) = self.clob_client.emergency_cancel_all().await {
+                warn!("Cancelled {} open orders after partial failure", cancelled);
+            }
+        }
+    }
 }
 
 #[tokio::main]
@@ -398,14 +862,27 @@ async fn main() -> Result<()> {
     // Load configuration
     info!("📋 Loading configuration...");
     let config = BotConfig::from_env()?;
+    config.full_validate().check().map_err(anyhow::Error::msg)?;
 
     info!("⚙️  Configuration:");
     info!("   Max position size: ${:.2}", config.risk.max_position_size);
     info!("   Max daily loss: ${:.2}", config.risk.max_daily_loss);
     info!("   Max open positions: {}", config.risk.max_open_positions);
 
+    // Derive CLOB API credentials from the wallet (no API key is configured)
+    let credentials = if config.auto_auth {
+        info!("🔑 Deriving CLOB API key from wallet...");
+        let wallet = LocalWallet::from_str(&config.wallet.private_key)
+            .map_err(|e| anyhow!("Invalid private key: {}", e))?
+            .with_chain_id(config.wallet.chain_id);
+        let rest_client = PolymarketRestClient::from_config(&config.polymarket);
+        Some(rest_client.get_clob_auth(&wallet).await?)
+    } else {
+        None
+    };
+
     // Create and start bot
-    let mut bot = BinaryArbitrageBot::new(config, dry_run)?;
+    let mut bot = BinaryArbitrageBot::new(config, credentials, dry_run)?;
     bot.start().await?;
 
     Ok(())
@@ -0,0 +1,510 @@
+//! Crypto Up/Down Directional Trading Bot
+//!
+//! Live directional trading bot for crypto up/down markets.
+//!
+//! # Strategy
+//! - Fetches active crypto up/down markets (BTC, ETH, SOL, XRP)
+//! - Subscribes to YES/NO orderbooks via WebSocket
+//! - Evaluates a pluggable directional signal (default: momentum)
+//! - Buys YES (up) or NO (down) sized with fractional Kelly
+//! - Tracks positions and redeems at expiry
+//!
+//! # Risk Warning
+//! This is DIRECTIONAL trading - full market risk, can lose 100% of a position!
+//!
+//! # Usage
+//! ```bash
+//! # Dry-run mode (no real trades)
+//! cargo run --bin crypto_updown_bot -- --dry-run
+//!
+//! # Custom momentum lookback (default 60s)
+//! cargo run --bin crypto_updown_bot -- --dry-run --lookback-ms=30000
+//!
+//! # Live trading
+//! cargo run --bin crypto_updown_bot
+//! ```
+
+use anyhow::{anyhow, Result};
+use polymarket_hft_bot::{
+    clob::{ClobClient, ClobConfig, CreateOrderRequest},
+    core::redemption::{RedeemablePosition, RedemptionManager},
+    core::risk::{CircuitBreaker, PositionTracker},
+    services::polymarket::PolymarketRestClient,
+    services::websocket::{process_message, PolymarketWebSocket},
+    strategies::{
+        capped_kelly_size, CryptoAsset, CryptoUpDownConfig, CryptoUpDownFetcher, CryptoUpDownMarket,
+        Direction, DirectionalSignal, Signal, SignalConfig, Timeframe,
+    },
+    types::config::BotConfig,
+    types::{MarketId, OrderBook, OrderSide, OrderType, Position, TokenId},
+    utils::metrics::{HftMetrics, DEFAULT_METRICS_ADDR},
+};
+use chrono::Utc;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::RwLock;
+use tracing::{error, info, warn};
+
+/// Orderbook cache for all tracked tokens
+type OrderbookCache = Arc<RwLock<HashMap<TokenId, OrderBook>>>;
+
+/// Default momentum lookback window
+const DEFAULT_LOOKBACK_MS: u64 = 60_000;
+
+/// Minimum YES mid-price move for the momentum signal
+const MOMENTUM_MIN_MOVE: f64 = 0.02;
+
+/// Fraction of full Kelly to stake (quarter Kelly), before `kelly_fraction_cap`
+const KELLY_MULTIPLIER: f64 = 0.25;
+
+/// Implied move ratio above which a threshold market already prices in the move
+///
+/// See `CryptoUpDownMarket::implied_move`. Up signals are skipped above it.
+const MAX_IMPLIED_MOVE: f64 = 1.0;
+
+/// Directional bot for crypto up/down markets
+struct CryptoUpDownBot {
+    config: BotConfig,
+    clob_client: ClobClient,
+    signal: Box<dyn Signal>,
+    orderbook_cache: OrderbookCache,
+    redemption_manager: Arc<RwLock<RedemptionManager>>,
+    /// Markets with an open position (one position per market)
+    open_markets: HashSet<MarketId>,
+    /// Open legs, checked for market concentration by `circuit_breaker`
+    position_tracker: Arc<PositionTracker>,
+    circuit_breaker: Arc<CircuitBreaker>,
+    /// Signal, trade and circuit breaker metrics (served on `DEFAULT_METRICS_ADDR`)
+    metrics: Arc<HftMetrics>,
+    dry_run: bool,
+}
+
+impl CryptoUpDownBot {
+    /// Create new bot
+    fn new(config: BotConfig, signal: Box<dyn Signal>, dry_run: bool) -> Result<Self> {
+        let clob_config = ClobConfig {
+            base_url: config.polymarket.clob_api_url.clone(),
+            api_key: String::new(),
+            api_secret: String::new(),
+            api_passphrase: String::new(),
+            private_key: config.wallet.private_key.clone(),
+            chain_id: config.wallet.chain_id,
+            verifying_contract: "0x0000000000000000000000000000000000000000".to_string(), // TODO: Get from config
+            maker_address: config.wallet.address.clone(),
+            taker_address: "0x0000000000000000000000000000000000000000".to_string(),
+            timeout_secs: 10,
+            proxy: None,
+            warm_up_on_start: true,
+            pool_size: 10,
+            cancel_on_trip: true,
+            fill_confirmation_timeout_ms: 2_000,
+            auto_cancel_on_partial_failure: true,
+            requests_per_second: 100,
+            rpc_url: Some(config.polymarket.rpc_url.clone()),
+            retry_attempts: 3,
+        };
+
+        let clob_client = ClobClient::new(clob_config)?;
+        clob_client.set_default_fee_rate(config.risk.fee_rate_bps);
+
+        let position_tracker = Arc::new(PositionTracker::new());
+        let metrics = Arc::new(HftMetrics::new());
+        let circuit_breaker = Arc::new(
+            CircuitBreaker::new(config.risk.clone())
+                .with_metrics(Arc::clone(&metrics))
+                .with_position_tracker(position_tracker.clone()),
+        );
+
+        Ok(Self {
+            config,
+            clob_client,
+            signal,
+            orderbook_cache: Arc::new(RwLock::new(HashMap::new())),
+            redemption_manager: Arc::new(RwLock::new(RedemptionManager::new())),
+            open_markets: HashSet::new(),
+            position_tracker,
+            circuit_breaker,
+            metrics,
+            dry_run,
+        })
+    }
+
+    /// Start the bot
+    async fn start(&mut self) -> Result<()> {
+        info!("🤖 Crypto Up/Down Bot Starting...");
+        info!("Mode: {}", if self.dry_run { "DRY-RUN" } else { "LIVE" });
+        info!("Signal: {}", self.signal.name());
+
+        // Prometheus scrape endpoint (the bot keeps running without it)
+        if let Err(e) = Arc::clone(&self.metrics).serve(DEFAULT_METRICS_ADDR).await {
+            warn!("Metrics endpoint disabled: {}", e);
+        }
+
+        // Open pooled CLOB connections before the first order needs them
+        // (a failed warm-up only costs the first orders a handshake)
+        if self.clob_client.warm_up_on_start() {
+            if let Err(e) = self.clob_client.warm_up(self.clob_client.warm_up_target()).await {
+                warn!("CLOB connection warm-up failed: {}", e);
+            }
+        }
+
+        // Production refuses to start without the minimum USDC balance and allowance
+        if !self.dry_run {
+            match self.clob_client.check_balance(self.config.risk.min_usdc_balance).await {
+                Ok(balance) => info!("💵 USDC available: ${:.2}", balance.available()),
+                Err(e) if self.config.environment.is_production() => return Err(e.into()),
+                Err(e) => warn!("Balance check failed: {}", e),
+            }
+        }
+
+        // Step 1: Fetch crypto up/down markets
+        info!("📡 Fetching crypto up/down markets...");
+        let markets = self.fetch_markets().await?;
+        info!("✅ Found {} active markets", markets.len());
+
+        if markets.is_empty() {
+            return Err(anyhow!("No markets found - check Gamma API or filters"));
+        }
+
+        // Step 2: Subscribe to YES/NO orderbooks
+        self.subscribe_orderbooks(&markets);
+
+        // Step 3: Trade on signals
+        info!("🔍 Starting signal loop...");
+        self.signal_loop(markets).await
+    }
+
+    /// Fetch active crypto up/down markets
+    async fn fetch_markets(&self) -> Result<Vec<CryptoUpDownMarket>> {
+        let config = CryptoUpDownConfig {
+            assets: vec![
+                CryptoAsset::Bitcoin,
+                CryptoAsset::Ethereum,
+                CryptoAsset::Solana,
+                CryptoAsset::XRP,
+            ],
+            timeframes: vec![Timeframe::FifteenMin, Timeframe::OneHour],
+            max_markets: 50,
+        };
+
+        let gamma_url = self.config.polymarket.gamma_api_url.clone();
+
+        // Validate Gamma token IDs against the CLOB before subscribing
+        let rest_client = PolymarketRestClient::with_clob_rest_client(
+            self.clob_client.rest_client().clone(),
+            gamma_url,
+        );
+
+        let fetcher = CryptoUpDownFetcher::new(config, rest_client.gamma().clone())
+            .with_clob_validation(rest_client.clob().clone());
+        fetcher.fetch_markets().await
+    }
+
+    /// Subscribe to orderbooks and keep the cache updated
+    fn subscribe_orderbooks(&self, markets: &[CryptoUpDownMarket]) {
+        let ws_url = self.config.polymarket.websocket_url.clone();
+
+        let subscriptions: Vec<(MarketId, TokenId)> = markets
+            .iter()
+            .flat_map(|m| {
+                m.token_ids
+                    .iter()
+                    .map(|id| (m.market_id(), TokenId(id.clone())))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        info!("🔌 Subscribing to {} token orderbooks on {}", subscriptions.len(), ws_url);
+
+        let (ws, mut rx) = PolymarketWebSocket::new(ws_url, subscriptions);
+
+        tokio::spawn(async move {
+            if let Err(e) = ws.start().await {
+                error!("WebSocket stopped: {}", e);
+            }
+        });
+
+        let cache = self.orderbook_cache.clone();
+        tokio::spawn(async move {
+            while let Some(msg) = rx.recv().await {
+                if let Some(update) = process_message(msg) {
+                    let mut cache = cache.write().await;
+                    match cache.get_mut(&update.token_id) {
+                        Some(book) => update.apply_to(book),
+                        None if update.is_snapshot => {
+                            cache.insert(update.token_id, update.order_book);
+                        }
+                        // Deltas are meaningless without a base snapshot
+                        None => {}
+                    }
+                }
+            }
+        });
+    }
+
+    /// Main loop - evaluates the signal for each market
+    async fn signal_loop(&mut self, markets: Vec<CryptoUpDownMarket>) -> Result<()> {
+        let mut scan_count = 0u64;
+        let mut signals_found = 0u64;
+
+        loop {
+            scan_count += 1;
+
+            for market in &markets {
+                let market_id = market.market_id();
+                if market.token_ids.len() < 2 || self.open_markets.contains(&market_id) {
+                    continue;
+                }
+
+                let yes_token_id = TokenId(market.token_ids[0].clone());
+                let no_token_id = TokenId(market.token_ids[1].clone());
+
+                let evaluation = {
+                    let cache = self.orderbook_cache.read().await;
+                    match (cache.get(&yes_token_id), cache.get(&no_token_id)) {
+                        (Some(yes_ob), Some(no_ob)) => self
+                            .signal
+                            .evaluate(&market_id, yes_ob, no_ob)
+                            .map(|signal| {
+                                let book = match signal.direction {
+                                    Direction::Up => yes_ob,
+                                    Direction::Down => no_ob,
+                                };
+                                let yes_price = yes_ob.best_ask().map(|ask| ask.price);
+                                (signal, book.best_ask().map(|ask| ask.price), yes_price)
+                            }),
+                        _ => None,
+                    }
+                }; // cache read guard is dropped here
+
+                if let Some((signal, Some(ask_price), yes_price)) = evaluation {
+                    let implied_move = yes_price.and_then(|price| market.implied_move(price));
+                    if !implied_move_aligns(signal.direction, implied_move) {
+                        continue;
+                    }
+
+                    signals_found += 1;
+                    self.metrics.record_opportunity();
+
+                    if let Err(e) = self.execute_signal(market, &signal, ask_price).await {
+                        error!("Failed to execute signal: {}", e);
+                    }
+                }
+            }
+
+            // Progress update every 100 scans
+            if scan_count.is_multiple_of(100) {
+                info!("📊 Scanned {} times, {} signals", scan_count, signals_found);
+
+                let manager = self.redemption_manager.read().await;
+                manager.log_status();
+
+                // Auto-redeem expired positions
+                drop(manager);
+                let mut manager = self.redemption_manager.write().await;
+                if let Err(e) = manager.auto_redeem_all(&self.clob_client).await {
+                    error!("Auto-redemption failed: {}", e);
+                }
+
+                // Redeemed legs no longer count towards concentration
+                for position in manager.redeemed_positions() {
+                    let tracked = [&position.yes_token_id, &position.no_token_id]
+                        .map(|token_id| {
+                            self.position_tracker.remove_position(&position.market_id, token_id)
+                        });
+                    if tracked.iter().any(Option::is_some) {
+                        self.circuit_breaker.close_position();
+                    }
+                }
+            }
+
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        }
+    }
+
+    /// Size and place a directional order for a signal
+    async fn execute_signal(
+        &mut self,
+        market: &CryptoUpDownMarket,
+        signal: &DirectionalSignal,
+        ask_price: f64,
+    ) -> Result<()> {
+        if self.open_markets.len() >= self.config.risk.max_open_positions {
+            warn!("Max open positions reached - skipping signal for {}", market.title);
+            return Ok(());
+        }
+
+        // Bankroll per trade is the configured default amount
+        let size = capped_kelly_size(
+            self.config.trading.default_amount,
+            signal.probability,
+            ask_price,
+            KELLY_MULTIPLIER,
+            self.config.trading.kelly_fraction_cap,
+            self.config.risk.max_position_size,
+        );
+
+        if size < CreateOrderRequest::MIN_SIZE {
+            return Ok(()); // No edge at this price
+        }
+
+        let (outcome, token_index) = match signal.direction {
+            Direction::Up => ("YES", 0),
+            Direction::Down => ("NO", 1),
+        };
+        let cost = size * ask_price;
+        let expected_profit = size * signal.probability - cost;
+
+        info!("🎯 SIGNAL: {} {:?}", market.title, signal.direction);
+        info!("   BUY {} {} at ${:.3} (p={:.2}, cost=${:.2}, EV=${:.2})",
+            size, outcome, ask_price, signal.probability, cost, expected_profit
+        );
+
+        if self.dry_run {
+            info!("💡 DRY-RUN: Order not placed");
+            return Ok(());
+        }
+
+        // Rejected without tripping if it would breach market concentration
+        let market_id = market.market_id();
+        if let Err(e) = self.circuit_breaker.open_position(&market_id, cost) {
+            warn!("Skipping {}: {}", market.title, e);
+            return Ok(());
+        }
+
+        let order = CreateOrderRequest {
+            token_id: market.token_ids[token_index].clone(),
+            side: OrderSide::BUY,
+            price: ask_price,
+            size,
+            order_type: OrderType::GTC,
+            expiration: None,
+            fee_rate_bps: 0, // Client default (RiskConfig::fee_rate_bps)
+            nonce: None,
+        };
+
+        let started = Instant::now();
+        let created = self.clob_client.create_order(&order).await;
+        self.metrics.observe_latency(started.elapsed().as_secs_f64() * 1_000.0);
+        let order_hash = match created {
+            Ok(order_hash) => order_hash,
+            Err(e) => {
+                self.metrics.record_execution("failed");
+                self.circuit_breaker.close_position();
+                return Err(e.into());
+            }
+        };
+        self.metrics.record_execution("success");
+        info!("✅ Order created: {}", order_hash);
+
+        let token_id = TokenId(order.token_id.clone());
+        let now = Utc::now().timestamp_millis();
+        self.position_tracker.update_position(
+            market_id.clone(),
+            token_id.clone(),
+            Position {
+                market_id: market_id.clone(),
+                token_id,
+                size,
+                entry_price: ask_price,
+                current_price: ask_price,
+                unrealized_pnl: 0.0,
+                realized_pnl: 0.0,
+                opened_at: now,
+                updated_at: now,
+                order_hashes: vec![order_hash],
+            },
+        );
+
+        // Parse expiry from string to DateTime
+        let expiry = market.end_date.as_ref().and_then(|exp_str| {
+            chrono::DateTime::parse_from_rfc3339(exp_str)
+                .ok()
+                .map(|dt| dt.with_timezone(&Utc))
+        });
+
+        let position = RedeemablePosition {
+            market_id: market_id.clone(),
+            title: market.title.clone(),
+            yes_token_id: TokenId(market.token_ids[0].clone()),
+            no_token_id: TokenId(market.token_ids[1].clone()),
+            size,
+            cost,
+            expected_profit,
+            expiry,
+            opened_at: Utc::now(),
+            redeemed: false,
+        };
+
+        self.redemption_manager.write().await.add_position(position);
+        self.open_markets.insert(market_id);
+
+        Ok(())
+    }
+}
+
+/// Whether a signal agrees with the move a threshold market prices in
+///
+/// Buying YES (up) is only worth it while the market has not priced in a
+/// larger move than the threshold. Down signals and markets without a
+/// target move always pass.
+fn implied_move_aligns(direction: Direction, implied_move: Option<f64>) -> bool {
+    match (direction, implied_move) {
+        (Direction::Up, Some(ratio)) => ratio <= MAX_IMPLIED_MOVE,
+        _ => true,
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    // Setup logging
+    tracing_subscriber::fmt()
+        .with_target(false)
+        .with_thread_ids(false)
+        .with_level(true)
+        .init();
+
+    info!("╔═══════════════════════════════════════════════════════════╗");
+    info!("║       CRYPTO UP/DOWN BOT - POLYMARKET HFT               ║");
+    info!("╚═══════════════════════════════════════════════════════════╝");
+
+    // Parse command-line arguments
+    let args: Vec<String> = std::env::args().collect();
+    let dry_run = args.contains(&"--dry-run".to_string());
+    let lookback_ms = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("--lookback-ms="))
+        .map(|value| value.parse::<u64>())
+        .transpose()
+        .map_err(|e| anyhow!("Invalid --lookback-ms: {}", e))?
+        .unwrap_or(DEFAULT_LOOKBACK_MS);
+
+    if dry_run {
+        warn!("⚠️  DRY-RUN MODE - No real trades will be executed");
+    } else {
+        warn!("🔴 LIVE MODE - Directional trades with real money!");
+    }
+
+    // Load configuration
+    info!("📋 Loading configuration...");
+    let config = BotConfig::from_env()?;
+    config.full_validate().check().map_err(anyhow::Error::msg)?;
+
+    info!("⚙️  Configuration:");
+    info!("   Bankroll per trade: ${:.2}", config.trading.default_amount);
+    info!("   Max position size: ${:.2}", config.risk.max_position_size);
+    info!("   Momentum lookback: {}ms", lookback_ms);
+
+    let signal = SignalConfig::Momentum {
+        lookback_ms,
+        min_move: MOMENTUM_MIN_MOVE,
+    }
+    .build();
+
+    // Create and start bot
+    let mut bot = CryptoUpDownBot::new(config, signal, dry_run)?;
+    bot.start().await?;
+
+    Ok(())
+}
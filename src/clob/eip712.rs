@@ -16,18 +16,33 @@
 //! 2. Hash order data using EIP-712 structure
 //! 3. Sign the hash with private key
 //!
+//! The `Order` type hash is the same for every order, so it is hashed once.
+//! Struct hashes are not cached: every order carries a fresh `salt`, so no
+//! two orders share one.
+//!
 //! # Thread Safety
-//! OrderSigner is not Send/Sync due to the private key.
-//! Each thread should have its own instance if needed.
+//! The wallet sits behind an `RwLock`, so [`OrderSigner::rotate_key`] can
+//! swap keys while other tasks keep signing. Take one [`OrderSigner::wallet`]
+//! snapshot per order to fill in its signer address and sign it with
+//! [`OrderSigner::sign_order_with`], so both come from the same key.
 
-use anyhow::{anyhow, Result};
 use ethers::signers::{LocalWallet, Signer};
-use ethers::types::{H160, H256, U256};
+use ethers::types::{Signature, H160, H256, U256};
 use ethers::utils::keccak256;
+use parking_lot::RwLock;
 use std::str::FromStr;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
 use tracing;
 
-use crate::types::SignedOrder;
+use crate::types::{HftError, SignedOrder};
+
+/// EIP-712 type hash of `Order`, shared by every order
+static ORDER_TYPE_HASH: LazyLock<[u8; 32]> = LazyLock::new(|| {
+    keccak256(
+        "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,uint256 feeRateBps,uint8 side,uint8 signatureType)"
+    )
+});
 
 /// EIP-712 domain separator for Polymarket CLOB
 ///
@@ -74,6 +89,53 @@ impl DomainSeparator {
     pub fn hash(&self) -> H256 {
         self.hash
     }
+
+    /// Compute EIP-712 digest from struct hash
+    ///
+    /// digest = keccak256("\x19\x01" || domainSeparator || structHash)
+    pub fn digest(&self, struct_hash: H256) -> H256 {
+        let mut digest_input = Vec::with_capacity(66);
+        digest_input.extend_from_slice(&[0x19, 0x01]);
+        digest_input.extend_from_slice(self.hash.as_bytes());
+        digest_input.extend_from_slice(struct_hash.as_bytes());
+
+        H256::from_slice(&keccak256(&digest_input))
+    }
+
+    /// Verify an order signature against an expected signer
+    ///
+    /// Re-computes the EIP-712 digest for `order`, recovers the signing
+    /// address from the ECDSA signature and compares it with `signer`.
+    /// Used to check orders from external sources (e.g. counterparty makers).
+    ///
+    /// Returns false for malformed orders or signatures rather than erroring.
+    pub fn verify(&self, order: &SignedOrder, signature: &str, signer: H160) -> bool {
+        let struct_hash = match hash_order_struct(order) {
+            Ok(hash) => hash,
+            Err(e) => {
+                tracing::debug!("Signature verification failed: {}", e);
+                return false;
+            }
+        };
+
+        let signature = match Signature::from_str(signature) {
+            Ok(sig) => sig,
+            Err(e) => {
+                tracing::debug!("Invalid signature encoding: {}", e);
+                return false;
+            }
+        };
+
+        // `sign_order` signs the digest as an EIP-191 message, so recover the same way
+        let digest = self.digest(struct_hash);
+        match signature.recover(digest.as_bytes()) {
+            Ok(recovered) => recovered == signer,
+            Err(e) => {
+                tracing::debug!("Failed to recover signer: {}", e);
+                false
+            }
+        }
+    }
 }
 
 /// EIP-712 order signer with pre-computed domain separator
@@ -81,11 +143,14 @@ impl DomainSeparator {
 /// **Performance:** Pre-computes domain separator once at initialization
 /// to save 10-20μs per signature.
 pub struct OrderSigner {
-    /// Ethereum wallet for signing
-    wallet: LocalWallet,
+    /// Ethereum wallet for signing (swapped by `rotate_key`)
+    wallet: Arc<RwLock<LocalWallet>>,
 
     /// Pre-computed domain separator
     domain_separator: DomainSeparator,
+
+    /// When the current key was loaded
+    rotated_at: RwLock<Instant>,
 }
 
 impl OrderSigner {
@@ -99,11 +164,8 @@ impl OrderSigner {
         private_key: &str,
         chain_id: u64,
         verifying_contract: H160,
-    ) -> Result<Self> {
-        // Parse private key
-        let wallet = LocalWallet::from_str(private_key)
-            .map_err(|e| anyhow!("Invalid private key: {}", e))?
-            .with_chain_id(chain_id);
+    ) -> Result<Self, HftError> {
+        let wallet = parse_wallet(private_key, chain_id)?;
 
         // Pre-compute domain separator (saves 10-20μs per order)
         let domain_separator = DomainSeparator::new(chain_id, verifying_contract);
@@ -115,14 +177,42 @@ impl OrderSigner {
         );
 
         Ok(Self {
-            wallet,
+            wallet: Arc::new(RwLock::new(wallet)),
             domain_separator,
+            rotated_at: RwLock::new(Instant::now()),
         })
     }
 
-    /// Get signer address
+    /// Get current signer address
     pub fn address(&self) -> H160 {
-        self.wallet.address()
+        self.wallet.read().address()
+    }
+
+    /// Snapshot of the current wallet, unaffected by later `rotate_key` calls
+    pub fn wallet(&self) -> LocalWallet {
+        self.wallet.read().clone()
+    }
+
+    /// Time since the current key was loaded or last rotated
+    pub fn key_age(&self) -> Duration {
+        self.rotated_at.read().elapsed()
+    }
+
+    /// Replace the signing key without restarting
+    ///
+    /// The domain separator is unchanged. Signatures already in progress
+    /// complete with the old key; later `sign_order` calls use the new one.
+    /// On error the current key is kept.
+    pub fn rotate_key(&self, new_private_key: &str) -> Result<(), HftError> {
+        let chain_id = self.wallet.read().chain_id();
+        let new_wallet = parse_wallet(new_private_key, chain_id)?;
+        let new_address = new_wallet.address();
+
+        let old_address = std::mem::replace(&mut *self.wallet.write(), new_wallet).address();
+        *self.rotated_at.write() = Instant::now();
+
+        tracing::warn!("🔑 Signing key rotated: {:?} -> {:?}", old_address, new_address);
+        Ok(())
     }
 
     /// Sign an order using EIP-712
@@ -134,89 +224,126 @@ impl OrderSigner {
     ///
     /// # Returns
     /// Hex-encoded signature (0x-prefixed)
-    pub async fn sign_order(&self, order: &SignedOrder) -> Result<String> {
+    pub async fn sign_order(&self, order: &SignedOrder) -> Result<String, HftError> {
+        // Snapshot of the wallet; the lock is not held across await
+        self.sign_order_with(&self.wallet(), order).await
+    }
+
+    /// Sign an order with a `wallet` snapshot (see `wallet`)
+    ///
+    /// Use the snapshot's address as the order's `signer` so a concurrent
+    /// `rotate_key` cannot leave the two mismatched.
+    pub async fn sign_order_with(
+        &self,
+        wallet: &LocalWallet,
+        order: &SignedOrder,
+    ) -> Result<String, HftError> {
         // Hash order struct
-        let struct_hash = self.hash_order_struct(order)?;
+        let struct_hash = hash_order_struct(order)?;
 
         // Compute EIP-712 digest
         let digest = self.compute_digest(struct_hash)?;
 
-        // Sign digest
-        let signature = self.wallet
+        let signature = wallet
             .sign_message(digest.as_bytes())
             .await
-            .map_err(|e| anyhow!("Failed to sign order: {}", e))?;
+            .map_err(|e| HftError::Signing(format!("Failed to sign order: {}", e)))?;
 
         // Return hex-encoded signature
         Ok(format!("0x{}", hex::encode(signature.to_vec())))
     }
 
-    /// Hash order struct according to EIP-712
-    fn hash_order_struct(&self, order: &SignedOrder) -> Result<H256> {
-        // Order type hash
-        let type_hash = keccak256(
-            "Order(uint256 salt,address maker,address signer,address taker,uint256 tokenId,uint256 makerAmount,uint256 takerAmount,uint256 expiration,uint256 nonce,uint256 feeRateBps,uint8 side,uint8 signatureType)"
-        );
+    /// Sign an order synchronously
+    ///
+    /// `LocalWallet` signing never awaits, so this produces the same signature
+    /// as [`sign_order`](Self::sign_order) without needing a Tokio runtime
+    /// (e.g. in benchmarks and plain `#[test]`s).
+    #[cfg(not(feature = "remote-signer"))]
+    pub fn sign_order_sync(&self, order: &SignedOrder) -> Result<String, HftError> {
+        let struct_hash = hash_order_struct(order)?;
+        let digest = self.compute_digest(struct_hash)?;
 
-        // Parse order fields
-        let salt = U256::from_str(&order.salt)
-            .map_err(|e| anyhow!("Invalid salt: {}", e))?;
-        let maker = H160::from_str(&order.maker)
-            .map_err(|e| anyhow!("Invalid maker: {}", e))?;
-        let signer = H160::from_str(&order.signer)
-            .map_err(|e| anyhow!("Invalid signer: {}", e))?;
-        let taker = H160::from_str(&order.taker)
-            .map_err(|e| anyhow!("Invalid taker: {}", e))?;
-        let token_id = U256::from_str(&order.token_id)
-            .map_err(|e| anyhow!("Invalid token_id: {}", e))?;
-        let maker_amount = U256::from_str(&order.maker_amount)
-            .map_err(|e| anyhow!("Invalid maker_amount: {}", e))?;
-        let taker_amount = U256::from_str(&order.taker_amount)
-            .map_err(|e| anyhow!("Invalid taker_amount: {}", e))?;
-        let expiration = U256::from_str(&order.expiration)
-            .map_err(|e| anyhow!("Invalid expiration: {}", e))?;
-        let nonce = U256::from_str(&order.nonce)
-            .map_err(|e| anyhow!("Invalid nonce: {}", e))?;
-        let fee_rate_bps = U256::from_str(&order.fee_rate_bps)
-            .map_err(|e| anyhow!("Invalid fee_rate_bps: {}", e))?;
-
-        // Encode struct hash
-        let struct_hash = keccak256(
-            ethers::abi::encode(&[
-                ethers::abi::Token::FixedBytes(type_hash.to_vec()),
-                ethers::abi::Token::Uint(salt),
-                ethers::abi::Token::Address(maker),
-                ethers::abi::Token::Address(signer),
-                ethers::abi::Token::Address(taker),
-                ethers::abi::Token::Uint(token_id),
-                ethers::abi::Token::Uint(maker_amount),
-                ethers::abi::Token::Uint(taker_amount),
-                ethers::abi::Token::Uint(expiration),
-                ethers::abi::Token::Uint(nonce),
-                ethers::abi::Token::Uint(fee_rate_bps),
-                ethers::abi::Token::Uint(U256::from(order.side)),
-                ethers::abi::Token::Uint(U256::from(order.signature_type)),
-            ])
-            .as_slice(),
-        );
+        // Same EIP-191 prefix as `sign_message`
+        let signature = self.wallet
+            .read()
+            .sign_hash(ethers::utils::hash_message(digest.as_bytes()))
+            .map_err(|e| HftError::Signing(format!("Failed to sign order: {}", e)))?;
+
+        Ok(format!("0x{}", hex::encode(signature.to_vec())))
+    }
+
+    /// EIP-712 hash of `order` (the digest that gets signed), which identifies it
+    pub fn order_hash(&self, order: &SignedOrder) -> Result<H256, HftError> {
+        self.compute_digest(hash_order_struct(order)?)
+    }
 
-        Ok(H256::from_slice(&struct_hash))
+    /// Get the pre-computed domain separator
+    pub fn domain_separator(&self) -> &DomainSeparator {
+        &self.domain_separator
     }
 
     /// Compute EIP-712 digest from struct hash
     ///
     /// Uses pre-computed domain separator for performance.
-    fn compute_digest(&self, struct_hash: H256) -> Result<H256> {
-        // EIP-712 digest = keccak256("\x19\x01" || domainSeparator || structHash)
-        let mut digest_input = vec![0x19, 0x01];
-        digest_input.extend_from_slice(self.domain_separator.hash().as_bytes());
-        digest_input.extend_from_slice(struct_hash.as_bytes());
-
-        let digest = keccak256(&digest_input);
-        Ok(H256::from_slice(&digest))
+    fn compute_digest(&self, struct_hash: H256) -> Result<H256, HftError> {
+        Ok(self.domain_separator.digest(struct_hash))
     }
 }
 
+/// Parse a hex private key (with or without 0x prefix) into a wallet
+fn parse_wallet(private_key: &str, chain_id: u64) -> Result<LocalWallet, HftError> {
+    Ok(LocalWallet::from_str(private_key)
+        .map_err(|e| HftError::Signing(format!("Invalid private key: {}", e)))?
+        .with_chain_id(chain_id))
+}
+
+/// Hash order struct according to EIP-712
+fn hash_order_struct(order: &SignedOrder) -> Result<H256, HftError> {
+    // Parse order fields
+    let salt = U256::from_str(&order.salt)
+        .map_err(|e| HftError::ParseError(format!("Invalid salt: {}", e)))?;
+    let maker = H160::from_str(&order.maker)
+        .map_err(|e| HftError::ParseError(format!("Invalid maker: {}", e)))?;
+    let signer = H160::from_str(&order.signer)
+        .map_err(|e| HftError::ParseError(format!("Invalid signer: {}", e)))?;
+    let taker = H160::from_str(&order.taker)
+        .map_err(|e| HftError::ParseError(format!("Invalid taker: {}", e)))?;
+    let token_id = U256::from_str(&order.token_id)
+        .map_err(|e| HftError::ParseError(format!("Invalid token_id: {}", e)))?;
+    let maker_amount = U256::from_str(&order.maker_amount)
+        .map_err(|e| HftError::ParseError(format!("Invalid maker_amount: {}", e)))?;
+    let taker_amount = U256::from_str(&order.taker_amount)
+        .map_err(|e| HftError::ParseError(format!("Invalid taker_amount: {}", e)))?;
+    let expiration = U256::from_str(&order.expiration)
+        .map_err(|e| HftError::ParseError(format!("Invalid expiration: {}", e)))?;
+    let nonce = U256::from_str(&order.nonce)
+        .map_err(|e| HftError::ParseError(format!("Invalid nonce: {}", e)))?;
+    let fee_rate_bps = U256::from_str(&order.fee_rate_bps)
+        .map_err(|e| HftError::ParseError(format!("Invalid fee_rate_bps: {}", e)))?;
+
+    // Encode struct hash
+    let struct_hash = keccak256(
+        ethers::abi::encode(&[
+            ethers::abi::Token::FixedBytes(ORDER_TYPE_HASH.to_vec()),
+            ethers::abi::Token::Uint(salt),
+            ethers::abi::Token::Address(maker),
+            ethers::abi::Token::Address(signer),
+            ethers::abi::Token::Address(taker),
+            ethers::abi::Token::Uint(token_id),
+            ethers::abi::Token::Uint(maker_amount),
+            ethers::abi::Token::Uint(taker_amount),
+            ethers::abi::Token::Uint(expiration),
+            ethers::abi::Token::Uint(nonce),
+            ethers::abi::Token::Uint(fee_rate_bps),
+            ethers::abi::Token::Uint(U256::from(order.side)),
+            ethers::abi::Token::Uint(U256::from(order.signature_type)),
+        ])
+        .as_slice(),
+    );
+
+    Ok(H256::from_slice(&struct_hash))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,4 +434,102 @@ mod tests {
         // Same order should produce same signature
         assert_eq!(sig1, sig2);
     }
+
+    #[cfg(not(feature = "remote-signer"))]
+    #[tokio::test]
+    async fn test_sign_order_sync_matches_async() {
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let chain_id = 137;
+        let contract = H160::from_str("0x0000000000000000000000000000000000000001").unwrap();
+
+        let signer = OrderSigner::new(private_key, chain_id, contract).unwrap();
+        let order = create_test_order();
+
+        let sync_sig = signer.sign_order_sync(&order).unwrap();
+        assert_eq!(sync_sig, signer.sign_order(&order).await.unwrap());
+        assert!(signer.domain_separator().verify(&order, &sync_sig, signer.address()));
+    }
+
+    #[tokio::test]
+    async fn test_verify_valid_signature() {
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let chain_id = 137;
+        let contract = H160::from_str("0x0000000000000000000000000000000000000001").unwrap();
+
+        let signer = OrderSigner::new(private_key, chain_id, contract).unwrap();
+        let order = create_test_order();
+        let signature = signer.sign_order(&order).await.unwrap();
+
+        // Independently constructed domain should verify the signature
+        let domain = DomainSeparator::new(chain_id, contract);
+        assert!(domain.verify(&order, &signature, signer.address()));
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_corrupted_signature() {
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let chain_id = 137;
+        let contract = H160::from_str("0x0000000000000000000000000000000000000001").unwrap();
+
+        let signer = OrderSigner::new(private_key, chain_id, contract).unwrap();
+        let order = create_test_order();
+        let signature = signer.sign_order(&order).await.unwrap();
+
+        // Flip a byte in the middle of the r component
+        let mut bytes = hex::decode(&signature[2..]).unwrap();
+        bytes[10] ^= 0xff;
+        let corrupted = format!("0x{}", hex::encode(bytes));
+
+        let domain = signer.domain_separator();
+        assert!(!domain.verify(&order, &corrupted, signer.address()));
+        assert!(!domain.verify(&order, "0xdeadbeef", signer.address()));
+    }
+
+    #[tokio::test]
+    async fn test_verify_rejects_wrong_signer_and_tampered_order() {
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let chain_id = 137;
+        let contract = H160::from_str("0x0000000000000000000000000000000000000001").unwrap();
+
+        let signer = OrderSigner::new(private_key, chain_id, contract).unwrap();
+        let order = create_test_order();
+        let signature = signer.sign_order(&order).await.unwrap();
+        let domain = signer.domain_separator();
+
+        let other = H160::from_str("0x0000000000000000000000000000000000000003").unwrap();
+        assert!(!domain.verify(&order, &signature, other));
+
+        let mut tampered = order.clone();
+        tampered.maker_amount = "2000000".to_string();
+        assert!(!domain.verify(&tampered, &signature, signer.address()));
+    }
+
+    #[tokio::test]
+    async fn test_rotate_key() {
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let new_key = "0xfedcba9876543210fedcba9876543210fedcba9876543210fedcba9876543210";
+        let contract = H160::from_str("0x0000000000000000000000000000000000000001").unwrap();
+
+        let signer = OrderSigner::new(private_key, 137, contract).unwrap();
+        let old_address = signer.address();
+        let domain_hash = signer.domain_separator().hash();
+        let order = create_test_order();
+
+        // Invalid keys leave the current key in place
+        assert!(signer.rotate_key("not-a-key").is_err());
+        assert_eq!(signer.address(), old_address);
+
+        std::thread::sleep(Duration::from_millis(5));
+        let age_before = signer.key_age();
+        signer.rotate_key(new_key).unwrap();
+        assert!(signer.key_age() < age_before);
+
+        let new_address = signer.address();
+        assert_ne!(new_address, old_address);
+        assert_eq!(signer.domain_separator().hash(), domain_hash);
+
+        let signature = signer.sign_order(&order).await.unwrap();
+        assert!(signer.domain_separator().verify(&order, &signature, new_address));
+        assert!(!signer.domain_separator().verify(&order, &signature, old_address));
+    }
 }
@@ -8,6 +8,9 @@
 //! 2. **Only one succeeds** → Immediate rollback (cancel successful order)
 //! 3. **Both fail** → Safe, no action needed
 //! 4. **Rollback fails** → Trip circuit breaker, alert operator
+//! 5. **Accepted but not fully filled** → Cancel what is left; unmatched
+//!    fills are hedged, and an unknown fill or failed hedge trips the circuit
+//!    breaker (see `ClobConfig::fill_confirmation_timeout_ms`)
 //!
 //! # Performance
 //! - Batch execution: ~150-200ms (vs 400ms sequential)
@@ -18,15 +21,70 @@
 //! - Uses ClobClient for HTTP requests
 //! - Uses CircuitBreaker for risk management
 //! - Reports P&L for successful arbitrage
+//! - Tracks running P&L statistics (mean, variance, Sharpe)
+//! - Tracks execution outcomes and latency (`statistics()`, also served as
+//!   JSON by `HftMetrics::serve_with_health`)
 
-use anyhow::{anyhow, Result};
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
 use tracing;
 
-use super::client::{ClobClient, CreateOrderRequest};
+use super::client::{BatchOrderBuilder, ClobClient, CreateOrderRequest};
+use crate::core::execution::{ExecutionStrategy, OrderRouter};
 use crate::core::risk::CircuitBreaker;
-use crate::types::{ArbitrageOpportunity, OrderSide};
+use crate::types::{
+    ArbitrageOpportunity, Environment, EventBus, Fill, HftError, OrderSide, OrderType, TokenId,
+    TradingEvent,
+};
+use crate::utils::math::RunningStatistics;
+use crate::utils::metrics::{HealthReport, HftMetrics, LatencyTracker};
+use crate::utils::shutdown::ShutdownCoordinator;
+#[cfg(feature = "alerts")]
+use crate::utils::alerts::Severity;
+
+/// Interval between background nonce drift checks
+pub const NONCE_DRIFT_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Maximum age of the last balance check for production executions
+pub const BALANCE_CHECK_MAX_AGE: Duration = Duration::from_secs(60);
+
+/// Interval between background balance checks (within `BALANCE_CHECK_MAX_AGE`)
+pub const BALANCE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default oldest opportunity `execute` still submits (see `with_max_orderbook_age_ms`)
+pub const DEFAULT_MAX_ORDERBOOK_AGE_MS: u64 = 500;
+
+/// How long executions are skipped after the CLOB returns 429
+pub const RATE_LIMIT_BACKOFF: Duration = Duration::from_secs(1);
+
+/// Interval between `get_fills` polls while confirming fills
+const FILL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Tolerance when comparing filled size to order size
+const FILL_SIZE_EPSILON: f64 = 1e-9;
+
+/// Snapshot of executor statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct ExecutorStatistics {
+    /// Executions attempted (including ones rejected before submission)
+    pub total_executions: u64,
+    /// Both orders filled
+    pub successes: u64,
+    /// One order filled (rolled back or not)
+    pub partial_fills: u64,
+    /// Rejected or both orders failed
+    pub failures: u64,
+    /// Sum of estimated P&L of successful executions
+    pub total_pnl: f64,
+    /// Mean `execute` latency
+    pub avg_latency_ms: f64,
+    /// 99th percentile `execute` latency over the last `LATENCY_SAMPLES` calls
+    pub p99_latency_ms: f64,
+}
 
 /// Result of arbitrage execution
 #[derive(Debug, Clone)]
@@ -43,11 +101,11 @@ pub enum ExecutionResult {
         latency_ms: u64,
     },
 
-    /// Only one order succeeded (rolled back)
+    /// One order filled more than the other (rolled back or hedged)
     PartialFill {
-        /// The order that succeeded
+        /// The order holding the excess (or unknown) fill
         filled_hash: String,
-        /// Whether rollback succeeded
+        /// Whether the excess was cancelled or hedged
         rolled_back: bool,
         /// Execution latency
         latency_ms: u64,
@@ -77,6 +135,15 @@ impl ExecutionResult {
         }
     }
 
+    /// Metrics label: "success", "partial" or "failed"
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExecutionResult::Success { .. } => "success",
+            ExecutionResult::PartialFill { .. } => "partial",
+            ExecutionResult::Failed { .. } => "failed",
+        }
+    }
+
     /// Get P&L (0.0 if not successful)
     pub fn pnl(&self) -> f64 {
         match self {
@@ -84,6 +151,14 @@ impl ExecutionResult {
             _ => 0.0,
         }
     }
+
+    /// Whether a one-sided position was left open (rollback failed)
+    ///
+    /// The bot cannot recover from this on its own; an operator must close
+    /// or hedge the filled order.
+    pub fn requires_manual_intervention(&self) -> bool {
+        matches!(self, ExecutionResult::PartialFill { rolled_back: false, .. })
+    }
 }
 
 /// Arbitrage executor with batch orders and rollback
@@ -96,6 +171,48 @@ pub struct ArbitrageExecutor {
 
     /// Fee rate in basis points
     fee_rate_bps: u16,
+
+    /// P&L statistics of successful executions
+    pnl_stats: Mutex<RunningStatistics>,
+
+    /// Deployment environment (production enables stricter checks)
+    environment: Environment,
+
+    /// When the wallet balance was last verified
+    last_balance_check: Mutex<Option<Instant>>,
+
+    /// Execution outcome counters
+    total_executions: AtomicU64,
+    successes: AtomicU64,
+    partial_fills: AtomicU64,
+    failures: AtomicU64,
+
+    /// Total P&L of successful executions in micro-USDC
+    total_pnl_micros: AtomicI64,
+
+    /// Receives `OrderSubmitted` and `FillReceived` events
+    event_bus: Option<EventBus<TradingEvent>>,
+
+    /// Chooses batch or sequential submission (None = always batch)
+    router: Option<Arc<OrderRouter>>,
+
+    /// Per-leg VWAP slippage cap when sizing against the router's books
+    max_slippage: Option<f64>,
+
+    /// Executions are skipped until then after a 429
+    rate_limited_until: Mutex<Option<Instant>>,
+
+    /// Opportunity, execution result and latency metrics
+    metrics: Option<Arc<HftMetrics>>,
+
+    /// New executions are refused once shutdown starts
+    shutdown: Option<Arc<ShutdownCoordinator>>,
+
+    /// Per-`execute` latency percentiles (nanoseconds)
+    latency_tracker: Arc<LatencyTracker>,
+
+    /// Opportunities detected longer ago than this are not submitted
+    max_orderbook_age_ms: u64,
 }
 
 impl ArbitrageExecutor {
@@ -114,6 +231,222 @@ impl ArbitrageExecutor {
             client,
             circuit_breaker,
             fee_rate_bps,
+            pnl_stats: Mutex::new(RunningStatistics::default()),
+            environment: Environment::default(),
+            last_balance_check: Mutex::new(None),
+            total_executions: AtomicU64::new(0),
+            successes: AtomicU64::new(0),
+            partial_fills: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            total_pnl_micros: AtomicI64::new(0),
+            event_bus: None,
+            router: None,
+            max_slippage: None,
+            rate_limited_until: Mutex::new(None),
+            metrics: None,
+            shutdown: None,
+            latency_tracker: Arc::new(LatencyTracker::new()),
+            max_orderbook_age_ms: DEFAULT_MAX_ORDERBOOK_AGE_MS,
+        }
+    }
+
+    /// Set the deployment environment
+    ///
+    /// In production, `execute` rejects trades unless the balance was checked
+    /// within `BALANCE_CHECK_MAX_AGE` (see `record_balance_check`).
+    pub fn with_environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Publish accepted orders and confirmed fills on `event_bus`
+    pub fn with_event_bus(mut self, event_bus: EventBus<TradingEvent>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Route each execution through `router`
+    ///
+    /// Legs on thin books are then submitted one at a time instead of batched.
+    pub fn with_router(mut self, router: Arc<OrderRouter>) -> Self {
+        self.router = Some(router);
+        self
+    }
+
+    /// Count opportunities and executions (by result, with latency) in `metrics`
+    pub fn with_metrics(mut self, metrics: Arc<HftMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Refuse new executions once `shutdown` starts
+    pub fn with_shutdown(mut self, shutdown: Arc<ShutdownCoordinator>) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    /// Record `execute` latencies into `latency_tracker` (e.g., one shared with other executors)
+    pub fn with_latency_tracker(mut self, latency_tracker: Arc<LatencyTracker>) -> Self {
+        self.latency_tracker = latency_tracker;
+        self
+    }
+
+    /// Latency percentiles of `execute` calls (see `LatencyTracker::to_summary`)
+    pub fn latency_tracker(&self) -> Arc<LatencyTracker> {
+        Arc::clone(&self.latency_tracker)
+    }
+
+    /// Skip opportunities whose orderbook is older than `max_age_ms` (default 500ms)
+    ///
+    /// The age is that of the router's cached book for the token, so a
+    /// stalled feed cannot send orders priced off old data. Without a router
+    /// or cached book, `detected_at` stands in for it.
+    pub fn with_max_orderbook_age_ms(mut self, max_age_ms: u64) -> Self {
+        self.max_orderbook_age_ms = max_age_ms;
+        self
+    }
+
+    /// Shrink each execution so neither leg's VWAP slips more than `max_slippage`
+    ///
+    /// Sized against the router's cached book for the token (see
+    /// `ArbitrageConfig::max_slippage`); needs `with_router`.
+    pub fn with_max_slippage(mut self, max_slippage: f64) -> Self {
+        self.max_slippage = Some(max_slippage);
+        self
+    }
+
+    /// Record that the wallet balance was just verified
+    pub fn record_balance_check(&self) {
+        *self.last_balance_check.lock() = Some(Instant::now());
+    }
+
+    /// Check the wallet holds the circuit breaker's `min_usdc_balance`
+    ///
+    /// Records the check on success (see `BALANCE_CHECK_MAX_AGE`); returns
+    /// the USDC available to trade.
+    pub async fn check_balance(&self) -> Result<f64, HftError> {
+        let min_usdc = self.circuit_breaker.min_usdc_balance();
+        let balance = self.client.check_balance(min_usdc).await?;
+        self.record_balance_check();
+        Ok(balance.available())
+    }
+
+    /// Whether the last balance check is recent enough to trade
+    fn balance_check_fresh(&self) -> bool {
+        self.last_balance_check
+            .lock()
+            .is_some_and(|checked_at| checked_at.elapsed() <= BALANCE_CHECK_MAX_AGE)
+    }
+
+    /// Skip executions for `RATE_LIMIT_BACKOFF` after a 429
+    fn back_off(&self) {
+        tracing::warn!("CLOB rate limit hit, backing off for {:?}", RATE_LIMIT_BACKOFF);
+        *self.rate_limited_until.lock() = Some(Instant::now() + RATE_LIMIT_BACKOFF);
+    }
+
+    /// Whether a rate limit backoff is still running
+    fn backing_off(&self) -> bool {
+        self.rate_limited_until.lock().is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Set the per-trade risk-free rate used for the Sharpe ratio
+    ///
+    /// Resets any recorded P&L statistics.
+    pub fn with_risk_free_rate(mut self, risk_free_rate: f64) -> Self {
+        self.pnl_stats = Mutex::new(RunningStatistics::new(risk_free_rate));
+        self
+    }
+
+    /// Spawn a background task checking nonce drift every 5 minutes
+    ///
+    /// Warns when drift exceeds the circuit breaker's
+    /// `nonce_drift_warning_threshold`, and resyncs the nonce if `resync` is set.
+    pub fn spawn_nonce_drift_monitor(&self, resync: bool) -> JoinHandle<()> {
+        let client = Arc::clone(&self.client);
+        let circuit_breaker = Arc::clone(&self.circuit_breaker);
+
+        tokio::spawn(async move {
+            let start = tokio::time::Instant::now() + NONCE_DRIFT_CHECK_INTERVAL;
+            let mut interval = tokio::time::interval_at(start, NONCE_DRIFT_CHECK_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                let threshold = circuit_breaker.nonce_drift_warning_threshold();
+                match client.check_nonce_drift(threshold, resync).await {
+                    Ok(drift) => tracing::debug!("Nonce drift: {}", drift),
+                    Err(e) => tracing::error!("Nonce drift check failed: {}", e),
+                }
+            }
+        })
+    }
+
+    /// Spawn a background task repeating `check_balance` every 30 seconds
+    ///
+    /// Keeps production executions trading while the balance holds; a
+    /// failed check is logged and lets the last one expire.
+    pub fn spawn_balance_monitor(self: &Arc<Self>) -> JoinHandle<()> {
+        let executor = Arc::clone(self);
+
+        tokio::spawn(async move {
+            let start = tokio::time::Instant::now() + BALANCE_CHECK_INTERVAL;
+            let mut interval = tokio::time::interval_at(start, BALANCE_CHECK_INTERVAL);
+
+            loop {
+                interval.tick().await;
+
+                match executor.check_balance().await {
+                    Ok(available) => tracing::debug!("USDC available: {:.2}", available),
+                    Err(e) => tracing::error!("Balance check failed: {}", e),
+                }
+            }
+        })
+    }
+
+    /// Snapshot of P&L statistics for successful executions
+    pub fn pnl_statistics(&self) -> RunningStatistics {
+        self.pnl_stats.lock().clone()
+    }
+
+    /// Reset P&L statistics (e.g., at the daily reset)
+    pub fn reset_pnl_statistics(&self) {
+        self.pnl_stats.lock().reset();
+    }
+
+    /// Snapshot of execution counters, P&L and latency
+    pub fn statistics(&self) -> ExecutorStatistics {
+        ExecutorStatistics {
+            total_executions: self.total_executions.load(Ordering::Acquire),
+            successes: self.successes.load(Ordering::Acquire),
+            partial_fills: self.partial_fills.load(Ordering::Acquire),
+            failures: self.failures.load(Ordering::Acquire),
+            total_pnl: self.total_pnl_micros.load(Ordering::Acquire) as f64 / 1_000_000.0,
+            avg_latency_ms: self.latency_tracker.mean() / 1_000_000.0,
+            p99_latency_ms: self.latency_tracker.p99() as f64 / 1_000_000.0,
+        }
+    }
+
+    /// Record the outcome of an execution
+    fn record_execution(&self, result: &ExecutionResult) {
+        self.total_executions.fetch_add(1, Ordering::AcqRel);
+
+        let counter = match result {
+            ExecutionResult::Success { pnl, .. } => {
+                let micros = (pnl * 1_000_000.0).round() as i64;
+                self.total_pnl_micros.fetch_add(micros, Ordering::AcqRel);
+                &self.successes
+            }
+            ExecutionResult::PartialFill { .. } => &self.partial_fills,
+            ExecutionResult::Failed { .. } => &self.failures,
+        };
+        counter.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Record the latency of one `execute` call
+    fn record_latency(&self, latency: Duration) {
+        self.latency_tracker.record(latency.as_nanos() as u64);
+        if let Some(metrics) = &self.metrics {
+            metrics.observe_latency(latency.as_secs_f64() * 1_000.0);
         }
     }
 
@@ -135,7 +468,73 @@ impl ArbitrageExecutor {
     pub async fn execute(
         &self,
         opportunity: &ArbitrageOpportunity,
-    ) -> Result<ExecutionResult> {
+    ) -> Result<ExecutionResult, HftError> {
+        // Nothing new is submitted while open orders are being cancelled
+        if self.shutdown.as_ref().is_some_and(|shutdown| shutdown.is_shutting_down()) {
+            return Ok(ExecutionResult::Failed {
+                error: "shutting_down".to_string(),
+                latency_ms: 0,
+            });
+        }
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_opportunity();
+        }
+
+        let started = Instant::now();
+        let result = self.execute_inner(opportunity).await;
+        let latency = started.elapsed();
+        self.record_latency(latency);
+
+        // Errors count as failures too
+        let failed;
+        let outcome = match &result {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                failed = ExecutionResult::Failed {
+                    error: e.to_string(),
+                    latency_ms: latency.as_millis() as u64,
+                };
+                &failed
+            }
+        };
+        self.record_execution(outcome);
+        if let Some(metrics) = &self.metrics {
+            metrics.record_execution(outcome.label());
+        }
+        result
+    }
+
+    /// Execute several opportunities, most valuable and fillable first
+    ///
+    /// Sorted by `ArbitrageOpportunity::priority` (`liquidity_score *
+    /// profit_margin`) descending, then executed one at a time so later ones
+    /// see the circuit breaker and positions left by earlier ones. Results
+    /// are returned in execution order.
+    pub async fn execute_batch(
+        &self,
+        mut opportunities: Vec<ArbitrageOpportunity>,
+    ) -> Vec<(ArbitrageOpportunity, Result<ExecutionResult, HftError>)> {
+        Self::prioritize(&mut opportunities);
+
+        let mut results = Vec::with_capacity(opportunities.len());
+        for opportunity in opportunities {
+            let result = self.execute(&opportunity).await;
+            results.push((opportunity, result));
+        }
+        results
+    }
+
+    /// Sort opportunities by `priority` descending
+    pub fn prioritize(opportunities: &mut [ArbitrageOpportunity]) {
+        opportunities.sort_by(|a, b| b.priority().total_cmp(&a.priority()));
+    }
+
+    /// Execute without recording outcome statistics
+    async fn execute_inner(
+        &self,
+        opportunity: &ArbitrageOpportunity,
+    ) -> Result<ExecutionResult, HftError> {
         // Circuit breaker check
         if !self.circuit_breaker.can_execute() {
             return Ok(ExecutionResult::Failed {
@@ -144,33 +543,115 @@ impl ArbitrageExecutor {
             });
         }
 
-        // Track positions before execution
-        self.circuit_breaker.open_position()
-            .map_err(|e| anyhow!(e))?;
-        self.circuit_breaker.open_position()
-            .map_err(|e| anyhow!(e))?; // Two positions (BUY + SELL)
-
-        // Build batch orders
-        let buy_request = CreateOrderRequest {
-            token_id: opportunity.token_id.0.clone(),
-            side: OrderSide::BUY,
-            price: opportunity.ask_price,
-            size: opportunity.max_size,
-            order_type: "GTC".to_string(),
-            expiration: None,
-            fee_rate_bps: self.fee_rate_bps,
-        };
+        // Stale data: skip without counting an error against the breaker
+        let age_ms = self.orderbook_age_ms(opportunity);
+        if age_ms > self.max_orderbook_age_ms {
+            tracing::warn!(
+                "Skipping {}: orderbook {}ms old (max {}ms)",
+                opportunity.market_id,
+                age_ms,
+                self.max_orderbook_age_ms
+            );
+            return Ok(ExecutionResult::Failed {
+                error: "stale orderbook".to_string(),
+                latency_ms: 0,
+            });
+        }
+
+        // Back off after a 429 instead of counting more rejections as errors
+        if self.backing_off() {
+            return Ok(ExecutionResult::Failed {
+                error: "rate_limited".to_string(),
+                latency_ms: 0,
+            });
+        }
+
+        // Markets with repeated anomalies are skipped without tripping the breaker
+        if !self.circuit_breaker.can_trade_market(&opportunity.token_id) {
+            return Ok(ExecutionResult::Failed {
+                error: "Market skipped after repeated anomalies".to_string(),
+                latency_ms: 0,
+            });
+        }
+
+        // Production: stricter validation
+        if self.environment.is_production() && !self.balance_check_fresh() {
+            tracing::warn!("Rejecting production execution without a recent balance check");
+            return Ok(ExecutionResult::Failed {
+                error: "No recent balance check".to_string(),
+                latency_ms: 0,
+            });
+        }
+
+        // Size against the latest cached book to keep slippage under the cap
+        let resized = self.size_for_market_impact(opportunity);
+        let opportunity = resized.as_ref().unwrap_or(opportunity);
+        if opportunity.max_size <= 0.0 {
+            return Ok(ExecutionResult::Failed {
+                error: "no_depth_within_slippage_cap".to_string(),
+                latency_ms: 0,
+            });
+        }
+
+        // Per-trade loss limit (full loss if the position goes to zero)
+        let worst_case_loss =
+            opportunity.max_size * opportunity.ask_price.max(opportunity.bid_price);
+        if worst_case_loss > self.circuit_breaker.max_loss_per_trade() {
+            tracing::warn!(
+                "Rejecting trade: worst-case loss ${:.2} exceeds max ${:.2}",
+                worst_case_loss,
+                self.circuit_breaker.max_loss_per_trade()
+            );
+            return Ok(ExecutionResult::Failed {
+                error: "trade_exceeds_max_loss_per_trade".to_string(),
+                latency_ms: 0,
+            });
+        }
 
-        let sell_request = CreateOrderRequest {
-            token_id: opportunity.token_id.0.clone(),
-            side: OrderSide::SELL,
-            price: opportunity.bid_price,
-            size: opportunity.max_size,
-            order_type: "GTC".to_string(),
-            expiration: None,
-            fee_rate_bps: self.fee_rate_bps,
+        // Build batch orders (BUY at ask, SELL at bid)
+        let requests = match BatchOrderBuilder::from(opportunity)
+            .with_fee_rate_bps(self.fee_rate_bps)
+            .build()
+        {
+            Ok(requests) => requests,
+            Err(e) => {
+                tracing::warn!("Invalid arbitrage orders: {}", e);
+                return Ok(ExecutionResult::Failed {
+                    error: e.to_string(),
+                    latency_ms: 0,
+                });
+            }
         };
 
+        // Track positions before execution (BUY + SELL)
+        let market_id = &opportunity.market_id;
+        let opened = self
+            .circuit_breaker
+            .open_position(market_id, opportunity.ask_price * opportunity.max_size)
+            .and_then(|()| {
+                self.circuit_breaker
+                    .open_position(market_id, opportunity.bid_price * opportunity.max_size)
+                    .inspect_err(|_| self.circuit_breaker.close_position())
+            });
+        match opened {
+            Ok(()) => {}
+            Err(e @ HftError::RiskLimitExceeded(_)) => {
+                tracing::warn!("Skipping execution: {}", e);
+                return Ok(ExecutionResult::Failed {
+                    error: e.to_string(),
+                    latency_ms: 0,
+                });
+            }
+            Err(e) => return Err(e),
+        }
+
+        // Reserve both legs' nonces in one step so a concurrent execution
+        // cannot interleave its own between them
+        let stride = self.client.nonce_manager().stride() as usize;
+        let nonces = self.client.reserve_nonces(requests.len()).step_by(stride);
+        let requests: Vec<CreateOrderRequest> =
+            requests.into_iter().zip(nonces).map(|(req, nonce)| req.with_nonce(nonce)).collect();
+
         // Execute batch (single HTTP request, ~150-200ms)
         tracing::info!(
             "Executing arbitrage: BUY@{:.4} SELL@{:.4} size={:.2} spread={:.4}",
@@ -180,8 +661,18 @@ impl ArbitrageExecutor {
             opportunity.profit_margin
         );
 
+        let strategy = self
+            .router
+            .as_ref()
+            .map_or(ExecutionStrategy::Batch, |router| router.route(&requests));
+        if let ExecutionStrategy::Sequential { order } = strategy {
+            let result = self.execute_sequential(&requests, &order, opportunity).await;
+            self.update_circuit_breaker(&result);
+            return Ok(result);
+        }
+
         let start = Instant::now();
-        let response = self.client.create_batch_orders(&[buy_request, sell_request]).await;
+        let response = self.client.create_batch_orders(&requests).await;
         let latency_ms = start.elapsed().as_millis() as u64;
 
         tracing::info!("Batch order latency: {}ms", latency_ms);
@@ -189,14 +680,31 @@ impl ArbitrageExecutor {
         // Handle response
         match response {
             Ok(batch_response) => {
+                for index in batch_response.succeeded_indices() {
+                    self.publish_submitted(&requests[index], &batch_response.order_hashes[index]);
+                }
+
                 // Verify and handle result
-                let result = self.verify_and_rollback(&batch_response, latency_ms, opportunity).await?;
+                let result = self
+                    .verify_and_rollback(&batch_response, &requests, latency_ms, opportunity)
+                    .await?;
 
                 // Update circuit breaker based on result
                 self.update_circuit_breaker(&result);
 
                 Ok(result)
             }
+            Err(e @ HftError::RateLimitExceeded) => {
+                // Nothing was submitted: not an error towards the breaker
+                self.back_off();
+                self.circuit_breaker.close_position();
+                self.circuit_breaker.close_position();
+
+                Ok(ExecutionResult::Failed {
+                    error: e.to_string(),
+                    latency_ms,
+                })
+            }
             Err(e) => {
                 // Both orders failed
                 tracing::error!("Batch order request failed: {}", e);
@@ -219,71 +727,88 @@ impl ArbitrageExecutor {
     async fn verify_and_rollback(
         &self,
         response: &crate::types::BatchOrderResponse,
+        requests: &[CreateOrderRequest],
         latency_ms: u64,
         opportunity: &ArbitrageOpportunity,
-    ) -> Result<ExecutionResult> {
+    ) -> Result<ExecutionResult, HftError> {
+        // Buy and sell were submitted as orders 0 and 1
+        const SUBMITTED: usize = 2;
+
         // Check if both succeeded
-        if response.both_succeeded() {
-            let buy_hash = response.buy_hash().unwrap().clone();
-            let sell_hash = response.sell_hash().unwrap().clone();
-
-            // Calculate P&L
-            let pnl = self.calculate_pnl(opportunity);
-
-            tracing::info!(
-                "✅ Arbitrage executed successfully: BUY={} SELL={} PNL=${:.2}",
-                buy_hash,
-                sell_hash,
-                pnl
-            );
+        if response.success && response.failed_count(SUBMITTED) == 0 {
+            let hashes = [response.order_hashes[0].clone(), response.order_hashes[1].clone()];
 
-            return Ok(ExecutionResult::Success {
-                buy_hash,
-                sell_hash,
-                pnl,
-                latency_ms,
-            });
+            // Accepted orders may still be resting: confirm fills before P&L
+            let legs = [hashes[0].as_str(), hashes[1].as_str()];
+            let filled = self.confirm_fills(&legs, opportunity.max_size).await;
+            let fills = [filled[0], filled[1]];
+            if fills.iter().all(|&fill| is_filled(fill, opportunity.max_size)) {
+                let [buy_hash, sell_hash] = hashes;
+                return Ok(self.record_success(opportunity, buy_hash, sell_hash, latency_ms));
+            }
+
+            // Stop what is left of either leg filling later
+            let resting: Vec<String> = hashes
+                .iter()
+                .zip(fills)
+                .filter(|&(_, fill)| !is_filled(fill, opportunity.max_size))
+                .map(|(hash, _)| hash.clone())
+                .collect();
+            tracing::warn!("Fills not confirmed ({:?}), cancelling {:?}", fills, resting);
+            let cancel_error = self
+                .client
+                .cancel_orders(&resting)
+                .await
+                .err()
+                .map(|e| format!("cancel of {:?} failed: {}", resting, e));
+
+            return Ok(self
+                .settle_fills(requests, hashes, fills, cancel_error, opportunity, latency_ms)
+                .await);
         }
 
         // Check for partial fill
-        if response.is_partial_fill() {
-            let filled_hash = response.order_hashes.get(0).unwrap().clone();
+        let succeeded = response.succeeded_indices();
+        if response.success && !succeeded.is_empty() {
+            let index = succeeded[0];
+            let filled_hash = response.order_hashes[index].clone();
 
             tracing::error!(
-                "⚠️ PARTIAL FILL DETECTED! Only one order succeeded: {}",
-                filled_hash
+                "⚠️ PARTIAL FILL DETECTED! Only order {} succeeded: {} (failed: {:?})",
+                index,
+                filled_hash,
+                response.failed_indices(SUBMITTED)
             );
 
-            // Attempt rollback
-            return match self.client.cancel_order(&filled_hash).await {
-                Ok(_) => {
-                    tracing::info!("✅ Rollback successful: cancelled {}", filled_hash);
+            // Attempt rollback, then check nothing filled before the cancel
+            let cancelled = self.client.cancel_order(&filled_hash).await;
+            let fill = if self.client.fill_confirmation_timeout().is_zero() {
+                Some(0.0)
+            } else {
+                self.filled_size(&filled_hash).await
+            };
+            if cancelled.is_ok() && fill.is_some_and(|size| size <= FILL_SIZE_EPSILON) {
+                tracing::info!("✅ Rollback successful: cancelled {}", filled_hash);
 
-                    Ok(ExecutionResult::PartialFill {
-                        filled_hash,
-                        rolled_back: true,
-                        latency_ms,
-                    })
-                }
-                Err(e) => {
-                    // CRITICAL: Rollback failed!
-                    tracing::error!(
-                        "❌ ROLLBACK FAILED for {}: {}",
-                        filled_hash,
-                        e
-                    );
-                    tracing::error!("⚠️ ONE-SIDED POSITION EXISTS - MANUAL INTERVENTION REQUIRED!");
-
-                    // Trip circuit breaker to prevent further trading
-                    self.circuit_breaker.trip();
+                return Ok(ExecutionResult::PartialFill {
+                    filled_hash,
+                    rolled_back: true,
+                    latency_ms,
+                });
+            }
 
-                    Ok(ExecutionResult::PartialFill {
-                        filled_hash,
-                        rolled_back: false,
-                        latency_ms,
-                    })
-                }
-            };
+            let cancel_error = cancelled.err().map(|e| {
+                tracing::error!("❌ ROLLBACK FAILED for {}: {}", filled_hash, e);
+                e.to_string()
+            });
+            let mut hashes = [String::new(), String::new()];
+            let mut fills = [Some(0.0); 2];
+            hashes[index] = filled_hash;
+            fills[index] = fill;
+
+            return Ok(self
+                .settle_fills(requests, hashes, fills, cancel_error, opportunity, latency_ms)
+                .await);
         }
 
         // Both failed
@@ -295,108 +820,498 @@ impl ArbitrageExecutor {
         })
     }
 
-    /// Calculate estimated P&L for successful arbitrage
-    fn calculate_pnl(&self, opportunity: &ArbitrageOpportunity) -> f64 {
-        // Spread per share
-        let spread = opportunity.bid_price - opportunity.ask_price;
-
-        // Gross profit
-        let gross_profit = spread * opportunity.max_size;
-
-        // Fees (buy side + sell side)
-        let fee_rate = self.fee_rate_bps as f64 / 10000.0;
-        let buy_fee = opportunity.ask_price * opportunity.max_size * fee_rate;
-        let sell_fee = opportunity.bid_price * opportunity.max_size * fee_rate;
-
-        // Net profit
-        gross_profit - buy_fee - sell_fee
-    }
-
-    /// Update circuit breaker based on execution result
-    fn update_circuit_breaker(&self, result: &ExecutionResult) {
-        match result {
-            ExecutionResult::Success { pnl, .. } => {
-                // Record profit/loss
-                if let Err(e) = self.circuit_breaker.record_trade(*pnl) {
-                    tracing::error!("Failed to record trade: {}", e);
+    /// Settle buy and sell legs that did not both fill completely
+    ///
+    /// `fills` are each leg's filled size (`None` if unknown); anything left
+    /// of the legs must already be cancelled, or `cancel_error` says why not.
+    /// No fills fail the execution and equal fills are a smaller complete
+    /// arbitrage. Otherwise the excess is hedged with a FOK order on the other
+    /// side. Unknown fills, a failed cancel or a killed hedge leave exposure
+    /// open and trip the breaker.
+    async fn settle_fills(
+        &self,
+        requests: &[CreateOrderRequest],
+        hashes: [String; 2],
+        fills: [Option<f64>; 2],
+        cancel_error: Option<String>,
+        opportunity: &ArbitrageOpportunity,
+        latency_ms: u64,
+    ) -> ExecutionResult {
+        if let [Some(buy), Some(sell)] = fills {
+            if buy.max(sell) <= FILL_SIZE_EPSILON {
+                if let Some(error) = &cancel_error {
+                    tracing::error!("❌ Failed to cancel unfilled orders: {}", error);
+                    self.circuit_breaker.trip();
                 }
-
-                // Positions automatically managed by circuit breaker
-                // (they remain open until manually closed)
+                return ExecutionResult::Failed {
+                    error: "fills_not_confirmed".to_string(),
+                    latency_ms,
+                };
             }
-            ExecutionResult::PartialFill { rolled_back, .. } => {
-                // Close both positions
-                self.circuit_breaker.close_position();
-                self.circuit_breaker.close_position();
+        }
 
-                // Record error
-                self.circuit_breaker.record_error();
+        // Leg holding the unknown or larger fill
+        let exposed = match fills {
+            [None, _] => 0,
+            [_, None] => 1,
+            [Some(buy), Some(sell)] => usize::from(sell > buy),
+        };
+        let filled_hash = hashes[exposed].clone();
+        tracing::error!("⚠️ ONE-SIDED FILL: {} filled {:?}", filled_hash, fills);
 
-                if !rolled_back {
-                    // Rollback failed - circuit breaker already tripped
-                    tracing::error!("Circuit breaker tripped due to rollback failure");
+        let error = match (cancel_error, fills) {
+            (Some(error), _) => error,
+            (None, [Some(buy), Some(sell)]) if (buy - sell).abs() <= FILL_SIZE_EPSILON => {
+                tracing::warn!("Both legs filled {:.2} of {:.2}", buy, opportunity.max_size);
+                let matched = ArbitrageOpportunity { max_size: buy, ..opportunity.clone() };
+                let [buy_hash, sell_hash] = hashes;
+                return self.record_success(&matched, buy_hash, sell_hash, latency_ms);
+            }
+            (None, [Some(buy), Some(sell)]) => {
+                let excess = (buy - sell).abs();
+                match self.hedge(&requests[1 - exposed], excess).await {
+                    Ok(hedge_hash) => {
+                        tracing::info!(
+                            "✅ Hedged {:.2} of {} with {}",
+                            excess,
+                            filled_hash,
+                            hedge_hash
+                        );
+                        return ExecutionResult::PartialFill {
+                            filled_hash,
+                            rolled_back: true,
+                            latency_ms,
+                        };
+                    }
+                    Err(e) => format!("hedge of {:.2} failed: {}", excess, e),
                 }
             }
-            ExecutionResult::Failed { .. } => {
-                // Close both positions
-                self.circuit_breaker.close_position();
-                self.circuit_breaker.close_position();
+            (None, _) => "fill status unknown".to_string(),
+        };
 
-                // Record error
-                self.circuit_breaker.record_error();
-            }
+        self.halt_one_sided(opportunity, &filled_hash, &error);
+        ExecutionResult::PartialFill {
+            filled_hash,
+            rolled_back: false,
+            latency_ms,
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::types::{MarketId, RiskConfig, TokenId};
+    /// Offset `size` shares of excess fill with a FOK order like `request`
+    ///
+    /// Priced off the router's cached book when it is deep enough, otherwise
+    /// at `request`'s limit. Returns the hedge's order hash.
+    async fn hedge(&self, request: &CreateOrderRequest, size: f64) -> Result<String, HftError> {
+        let price = self
+            .router
+            .as_ref()
+            .and_then(|router| router.order_book(&TokenId(request.token_id.clone())))
+            .and_then(|book| book.limit_price(request.side, size))
+            .unwrap_or(request.price);
+        let hedge = CreateOrderRequest {
+            price,
+            size,
+            order_type: OrderType::FOK,
+            nonce: Some(self.client.reserve_nonces(1).start),
+            ..request.clone()
+        }
+        .round_to_tick();
 
-    fn create_test_opportunity() -> ArbitrageOpportunity {
-        let bid_price = 0.75;
-        let ask_price = 0.70;
+        let hash = self.client.create_fok_order(&hedge).await?;
+        self.publish_submitted(&hedge, &hash);
+        Ok(hash)
+    }
+
+    /// Submit legs one at a time in `order`, each only after the previous filled
+    ///
+    /// A leg that is rejected or does not fully fill (and is cancelled) stops
+    /// the execution. Nothing needs rolling back if nothing filled yet;
+    /// otherwise the fills are settled like a batch's (see `settle_fills`).
+    async fn execute_sequential(
+        &self,
+        requests: &[CreateOrderRequest],
+        order: &[usize],
+        opportunity: &ArbitrageOpportunity,
+    ) -> ExecutionResult {
+        tracing::info!("Submitting {} legs sequentially: {:?}", requests.len(), order);
+
+        let mut hashes = [String::new(), String::new()];
+        let mut fills = [Some(0.0); 2];
+        let mut latency_ms = 0;
+
+        for &index in order {
+            let request = &requests[index];
+
+            let start = Instant::now();
+            let response = self.client.create_order(request).await;
+            latency_ms += start.elapsed().as_millis() as u64;
+
+            let nothing_filled = fills.iter().all(|&fill| fill == Some(0.0));
+            let mut cancel_error = None;
+            let error = match response {
+                Ok(hash) => {
+                    self.publish_submitted(request, &hash);
+                    let fill = self.confirm_fills(&[hash.as_str()], request.size).await[0];
+                    hashes[index] = hash.clone();
+                    fills[index] = fill;
+                    if is_filled(fill, request.size) {
+                        continue;
+                    }
+
+                    tracing::warn!("Leg {} not filled ({:?}), cancelling {}", index, fill, hash);
+                    if let Err(e) = self.client.cancel_order(&hash).await {
+                        cancel_error = Some(format!("cancel of {} failed: {}", hash, e));
+                    }
+                    format!("{} not filled", hash)
+                }
+                Err(e) => {
+                    if e == HftError::RateLimitExceeded {
+                        self.back_off();
+                    }
+                    e.to_string()
+                }
+            };
+
+            if nothing_filled && fills[index] == Some(0.0) {
+                if let Some(cancel_error) = cancel_error {
+                    tracing::error!("❌ Failed to cancel unfilled order: {}", cancel_error);
+                    self.circuit_breaker.trip();
+                }
+                return ExecutionResult::Failed { error, latency_ms };
+            }
+
+            tracing::warn!("Leg {} failed after fills: {}", index, error);
+            return self
+                .settle_fills(requests, hashes, fills, cancel_error, opportunity, latency_ms)
+                .await;
+        }
+
+        // Buy and sell are legs 0 and 1
+        let [buy_hash, sell_hash] = hashes;
+        self.record_success(opportunity, buy_hash, sell_hash, latency_ms)
+    }
+
+    /// Record fees and build the result once both legs filled
+    fn record_success(
+        &self,
+        opportunity: &ArbitrageOpportunity,
+        buy_hash: String,
+        sell_hash: String,
+        latency_ms: u64,
+    ) -> ExecutionResult {
+        let pnl = self.calculate_pnl(opportunity);
+        self.circuit_breaker.record_fees(self.calculate_fees(opportunity));
+
+        tracing::info!(
+            "✅ Arbitrage executed successfully: BUY={} SELL={} PNL=${:.2}",
+            buy_hash,
+            sell_hash,
+            pnl
+        );
+
+        ExecutionResult::Success {
+            buy_hash,
+            sell_hash,
+            pnl,
+            latency_ms,
+        }
+    }
+
+    /// Publish `OrderSubmitted` for an accepted order
+    fn publish_submitted(&self, request: &CreateOrderRequest, order_hash: &str) {
+        if let Some(bus) = &self.event_bus {
+            bus.publish(TradingEvent::OrderSubmitted {
+                order_hash: order_hash.to_string(),
+                token_id: TokenId(request.token_id.clone()),
+                side: request.side,
+                price: request.price,
+                size: request.size,
+            });
+        }
+    }
+
+    /// Wait until each order has fills totalling `size`
+    ///
+    /// Polls `get_fills` every 100ms until all orders fill or the client's
+    /// fill confirmation timeout elapses. Returns each order's filled size,
+    /// or `None` if its latest poll failed, so an unknown fill is never taken
+    /// for no fill (all `size` if confirmation is off). The fills found are
+    /// published once polling ends.
+    async fn confirm_fills(&self, order_hashes: &[&str], size: f64) -> Vec<Option<f64>> {
+        let timeout = self.client.fill_confirmation_timeout();
+        if timeout.is_zero() {
+            return vec![Some(size); order_hashes.len()];
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut fills: Vec<Option<Vec<Fill>>> = vec![None; order_hashes.len()];
+        let all_filled = |fills: &[Option<Vec<Fill>>]| {
+            fills.iter().all(|leg| is_filled(leg.as_deref().map(total_size), size))
+        };
+
+        loop {
+            for (hash, leg) in order_hashes.iter().zip(fills.iter_mut()) {
+                if is_filled(leg.as_deref().map(total_size), size) {
+                    continue;
+                }
+                *leg = match self.client.get_fills(hash).await {
+                    Ok(fills) => Some(fills),
+                    Err(e) => {
+                        tracing::warn!("Fill check failed for {}: {}", hash, e);
+                        None
+                    }
+                };
+            }
+
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if all_filled(&fills) || remaining.is_zero() {
+                break;
+            }
+            tokio::time::sleep(FILL_POLL_INTERVAL.min(remaining)).await;
+        }
+
+        let filled = fills.iter().map(|leg| leg.as_deref().map(total_size)).collect();
+        if let Some(bus) = &self.event_bus {
+            for fill in fills.into_iter().flatten().flatten() {
+                bus.publish(TradingEvent::FillReceived(fill));
+            }
+        }
+        filled
+    }
+
+    /// Filled size of an order right now (`None` if `get_fills` failed)
+    async fn filled_size(&self, order_hash: &str) -> Option<f64> {
+        match self.client.get_fills(order_hash).await {
+            Ok(fills) => Some(total_size(&fills)),
+            Err(e) => {
+                tracing::warn!("Fill check failed for {}: {}", order_hash, e);
+                None
+            }
+        }
+    }
+
+    /// Trip the circuit breaker after a one-sided position was left open
+    fn halt_one_sided(&self, opportunity: &ArbitrageOpportunity, filled_hash: &str, error: &str) {
+        tracing::error!(
+            "⚠️ ONE-SIDED POSITION EXISTS in {} ({}: {}) - MANUAL INTERVENTION REQUIRED!",
+            opportunity.market_id,
+            filled_hash,
+            error
+        );
+
+        #[cfg(feature = "alerts")]
+        if let Some(sender) = self.circuit_breaker.alert_sender() {
+            sender.spawn_send(
+                Severity::Warning,
+                "Rollback failed",
+                serde_json::json!({
+                    "market_id": opportunity.market_id,
+                    "token_id": opportunity.token_id.0,
+                    "filled_hash": filled_hash,
+                    "error": error,
+                }),
+            );
+        }
+
+        // Trip circuit breaker to prevent further trading
+        self.circuit_breaker.trip();
+    }
+
+    /// Age of the cached book `opportunity` was priced from, in milliseconds
+    ///
+    /// Falls back to the time since `detected_at` without a router or cached book.
+    fn orderbook_age_ms(&self, opportunity: &ArbitrageOpportunity) -> u64 {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        match self.router.as_ref().and_then(|r| r.order_book(&opportunity.token_id)) {
+            Some(book) => book.age_ms(now_ms.max(0) as u64),
+            None => now_ms.saturating_sub(opportunity.detected_at).max(0) as u64,
+        }
+    }
+
+    /// Opportunity resized to keep both legs within `max_slippage`
+    ///
+    /// `None` without a cap, router or cached book. Limit prices move to the
+    /// worst level each leg reaches, so the whole size can fill.
+    fn size_for_market_impact(
+        &self,
+        opportunity: &ArbitrageOpportunity,
+    ) -> Option<ArbitrageOpportunity> {
+        let max_slippage = self.max_slippage?;
+        let book = self.router.as_ref()?.order_book(&opportunity.token_id)?;
+
+        let size = opportunity
+            .max_size
+            .min(book.max_size_within_slippage(OrderSide::BUY, max_slippage))
+            .min(book.max_size_within_slippage(OrderSide::SELL, max_slippage));
+        let (Some(ask_limit), Some(bid_limit)) = (
+            book.limit_price(OrderSide::BUY, size),
+            book.limit_price(OrderSide::SELL, size),
+        ) else {
+            return Some(ArbitrageOpportunity { max_size: 0.0, ..opportunity.clone() });
+        };
+
+        let (ask_vwap, _) = book.compute_market_impact(OrderSide::BUY, size);
+        let (bid_vwap, _) = book.compute_market_impact(OrderSide::SELL, size);
+        if size < opportunity.max_size {
+            tracing::debug!(
+                "Sized {} down to {:.2} for slippage (VWAP BUY@{:.4} SELL@{:.4})",
+                opportunity.token_id,
+                size,
+                ask_vwap,
+                bid_vwap
+            );
+        }
+
+        let resized = ArbitrageOpportunity {
+            bid_price: bid_limit,
+            ask_price: ask_limit,
+            max_size: size,
+            ..opportunity.clone()
+        };
+        Some(resized.with_vwap(bid_vwap, ask_vwap).with_fee_rate(self.fee_rate_bps))
+    }
+
+    /// Calculate estimated P&L for successful arbitrage
+    fn calculate_pnl(&self, opportunity: &ArbitrageOpportunity) -> f64 {
+        // Spread per share
+        let spread = opportunity.bid_price - opportunity.ask_price;
+
+        // Gross profit
+        let gross_profit = spread * opportunity.max_size;
+
+        // Net profit
+        gross_profit - self.calculate_fees(opportunity)
+    }
+
+    /// Trading fees for both legs (buy side + sell side)
+    fn calculate_fees(&self, opportunity: &ArbitrageOpportunity) -> f64 {
+        let fee_rate = self.fee_rate_bps as f64 / 10000.0;
+        let buy_fee = opportunity.ask_price * opportunity.max_size * fee_rate;
+        let sell_fee = opportunity.bid_price * opportunity.max_size * fee_rate;
+        buy_fee + sell_fee
+    }
+
+    /// Update circuit breaker based on execution result
+    fn update_circuit_breaker(&self, result: &ExecutionResult) {
+        match result {
+            ExecutionResult::Success { pnl, .. } => {
+                self.pnl_stats.lock().update(*pnl);
+
+                // Record profit/loss
+                if let Err(e) = self.circuit_breaker.record_trade(*pnl) {
+                    tracing::error!("Failed to record trade: {}", e);
+                }
+
+                // Positions automatically managed by circuit breaker
+                // (they remain open until manually closed)
+            }
+            ExecutionResult::PartialFill { rolled_back, .. } => {
+                // The unhedged leg stays open as a position
+                self.circuit_breaker.close_position();
+                if *rolled_back {
+                    self.circuit_breaker.close_position();
+                }
+
+                // Record error
+                self.circuit_breaker.record_error();
+
+                if !rolled_back {
+                    // Rollback failed - circuit breaker already tripped
+                    tracing::error!("Circuit breaker tripped due to rollback failure");
+                }
+            }
+            ExecutionResult::Failed { .. } => {
+                // Close both positions
+                self.circuit_breaker.close_position();
+                self.circuit_breaker.close_position();
+
+                // Record error
+                self.circuit_breaker.record_error();
+            }
+        }
+    }
+}
+
+/// `statistics()` and `execute` latency percentiles, e.g., for Grafana
+impl HealthReport for ArbitrageExecutor {
+    fn health(&self) -> serde_json::Value {
+        serde_json::json!({
+            "executor": self.statistics(),
+            "latency": self.latency_tracker.to_summary(),
+        })
+    }
+}
+
+/// Total size of `fills`
+fn total_size(fills: &[Fill]) -> f64 {
+    fills.iter().map(|fill| fill.size).sum()
+}
+
+/// Whether a known filled size covers `size`
+fn is_filled(filled: Option<f64>, size: f64) -> bool {
+    filled.is_some_and(|filled| filled >= size - FILL_SIZE_EPSILON)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clob::client::ClobConfig;
+    use crate::core::risk::AnomalyReason;
+    use crate::types::{MarketId, RiskConfig, TokenId};
+
+    fn create_test_clob_config() -> ClobConfig {
+        ClobConfig {
+            private_key: "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+                .to_string(),
+            maker_address: "0x0000000000000000000000000000000000000002".to_string(),
+            ..ClobConfig::default()
+        }
+    }
+
+    fn create_test_risk_config() -> RiskConfig {
+        RiskConfig {
+            max_daily_loss: 100.0,
+            max_position_size: 50.0,
+            max_loss_per_trade: 100.0,
+            max_open_positions: 10,
+            min_usdc_balance: 10.0,
+            min_matic_balance: 1.0,
+            max_consecutive_errors: 5,
+            risk_score_alert_threshold: 70.0,
+            fee_rate_bps: 100,
+            max_anomalies_before_skip: 3,
+            max_concentration: 0.5,
+            max_loss_in_window: 50.0,
+            loss_window_secs: 3600,
+        }
+    }
+
+    fn create_test_opportunity() -> ArbitrageOpportunity {
+        let bid_price = 0.75;
+        let ask_price = 0.70;
         let max_size = 100.0;
         let spread = bid_price - ask_price;
         let expected_profit = spread * max_size;
 
         ArbitrageOpportunity {
-            market_id: MarketId("TRUMP-WIN".to_string()),
+            market_id: MarketId::new("TRUMP-WIN"),
             token_id: TokenId("YES".to_string()),
             bid_price,
             ask_price,
             max_size,
             profit_margin: 0.0714, // (0.75-0.70)/0.70
             expected_profit,
-            detected_at: 1000,
+            detected_at: chrono::Utc::now().timestamp_millis(),
+            liquidity_score: 1.0,
         }
     }
 
     #[test]
     fn test_pnl_calculation() {
-        use crate::clob::client::ClobConfig;
-
-        let config = ClobConfig {
-            base_url: "https://test.example.com".to_string(),
-            api_key: "test_key".to_string(),
-            private_key: "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef".to_string(),
-            chain_id: 137,
-            verifying_contract: "0x0000000000000000000000000000000000000001".to_string(),
-            maker_address: "0x0000000000000000000000000000000000000002".to_string(),
-            taker_address: "0x0000000000000000000000000000000000000000".to_string(),
-            timeout_secs: 10,
-        };
+        let config = create_test_clob_config();
 
         let client = Arc::new(ClobClient::new(config).unwrap());
-        let cb = Arc::new(CircuitBreaker::new(RiskConfig {
-            max_daily_loss: 100.0,
-            max_position_size: 50.0,
-            max_open_positions: 10,
-            min_usdc_balance: 10.0,
-            min_matic_balance: 1.0,
-            max_consecutive_errors: 5,
-        }));
+        let cb = Arc::new(CircuitBreaker::new(create_test_risk_config()));
 
         let executor = ArbitrageExecutor::new(client, cb, 100); // 1% fee
 
@@ -412,6 +1327,712 @@ mod tests {
         assert!((pnl - 3.55).abs() < 0.01, "Expected ~3.55, got {}", pnl);
     }
 
+    #[test]
+    fn test_pnl_statistics_tracking() {
+        let config = create_test_clob_config();
+
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(create_test_risk_config()));
+        let executor = ArbitrageExecutor::new(client, cb, 100).with_risk_free_rate(0.5);
+
+        for pnl in [1.0, 2.0, 3.0] {
+            executor.update_circuit_breaker(&ExecutionResult::Success {
+                buy_hash: "0xabc".to_string(),
+                sell_hash: "0xdef".to_string(),
+                pnl,
+                latency_ms: 150,
+            });
+        }
+
+        // Failed executions are not trades
+        executor.update_circuit_breaker(&ExecutionResult::Failed {
+            error: "test error".to_string(),
+            latency_ms: 50,
+        });
+
+        let stats = executor.pnl_statistics();
+        assert_eq!(stats.count(), 3);
+        assert_eq!(stats.mean(), 2.0);
+        assert_eq!(stats.sharpe_ratio(), Some(1.5)); // (2.0 - 0.5) / 1.0
+
+        executor.reset_pnl_statistics();
+        assert_eq!(executor.pnl_statistics().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_confirms_fills_before_success() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/orders")
+            .with_status(200)
+            .with_body(r#"{"success":true,"orderHashes":["0xbuy","0xsell"]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let by_hash = |hash: &str| mockito::Matcher::UrlEncoded("order_hash".into(), hash.into());
+        let buy_fills = server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xbuy"))
+            .with_status(200)
+            .with_body(r#"[{"orderHash":"0xbuy","price":0.7,"size":100.0}]"#)
+            .create_async()
+            .await;
+        let sell_fills = server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xsell"))
+            .with_status(200)
+            .with_body(r#"[{"orderHash":"0xsell","price":0.75,"size":100.0}]"#)
+            .create_async()
+            .await;
+
+        let config = ClobConfig {
+            base_url: server.url(),
+            fill_confirmation_timeout_ms: 300,
+            ..create_test_clob_config()
+        };
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(create_test_risk_config()));
+        let bus = EventBus::default();
+        let mut events = bus.subscribe();
+        let executor = ArbitrageExecutor::new(client, cb.clone(), 100).with_event_bus(bus);
+        let opportunity = ArbitrageOpportunity {
+            token_id: TokenId("12345".to_string()),
+            ..create_test_opportunity()
+        };
+
+        let result = executor.execute(&opportunity).await.unwrap();
+        assert!(result.is_success(), "Both legs filled: {:?}", result);
+        buy_fills.assert_async().await;
+
+        // Both accepted orders, then each order's fills
+        let mut published = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            published.push(match event {
+                TradingEvent::OrderSubmitted { order_hash, .. } => format!("order {}", order_hash),
+                TradingEvent::FillReceived(fill) => format!("fill {}", fill.order_hash),
+                other => panic!("Unexpected event: {:?}", other),
+            });
+        }
+        assert_eq!(published, ["order 0xbuy", "order 0xsell", "fill 0xbuy", "fill 0xsell"]);
+
+        // Sell leg still resting: cancel it and hedge the bought shares
+        sell_fills.remove_async().await;
+        server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xsell"))
+            .with_status(200)
+            .with_body(r#"[{"orderHash":"0xsell","price":0.75,"size":40.0}]"#)
+            .create_async()
+            .await;
+        let cancel = server
+            .mock("DELETE", "/orders")
+            .match_body(mockito::Matcher::Json(serde_json::json!(["0xsell"])))
+            .with_status(200)
+            .with_body(r#"{"canceled":["0xsell"]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let hedge = server
+            .mock("POST", "/order")
+            .match_body(mockito::Matcher::PartialJsonString(r#"{"orderType":"FOK"}"#.into()))
+            .with_status(200)
+            .with_body(r#"{"success":true,"orderHashes":["0xhedge"]}"#)
+            .create_async()
+            .await;
+
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::PartialFill { filled_hash, rolled_back, .. } => {
+                assert_eq!(filled_hash, "0xbuy");
+                assert!(rolled_back, "60 excess shares hedged");
+            }
+            other => panic!("Expected partial fill, got {:?}", other),
+        }
+        assert!(cb.can_execute());
+
+        // Hedge killed: the one-sided position halts trading
+        hedge.remove_async().await;
+        server
+            .mock("POST", "/order")
+            .with_status(200)
+            .with_body(r#"{"success":false,"errorMsg":"killed"}"#)
+            .create_async()
+            .await;
+
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::PartialFill { filled_hash, rolled_back, .. } => {
+                assert_eq!(filled_hash, "0xbuy");
+                assert!(!rolled_back);
+            }
+            other => panic!("Expected partial fill, got {:?}", other),
+        }
+        cancel.assert_async().await;
+        assert!(!cb.can_execute(), "One-sided fill should trip the breaker");
+    }
+
+    #[tokio::test]
+    async fn test_unknown_or_partial_fills_are_exposure() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/orders")
+            .with_status(200)
+            .with_body(r#"{"success":true,"orderHashes":["0xbuy","0xsell"]}"#)
+            .create_async()
+            .await;
+        server
+            .mock("DELETE", "/orders")
+            .with_status(200)
+            .with_body(r#"{"canceled":["0xbuy","0xsell"]}"#)
+            .create_async()
+            .await;
+        let fills = |hash: &str, size: f64| {
+            format!(r#"[{{"orderHash":"{}","price":0.7,"size":{}}}]"#, hash, size)
+        };
+        let by_hash = |hash: &str| mockito::Matcher::UrlEncoded("order_hash".into(), hash.into());
+        let mut buy_fills = server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xbuy"))
+            .with_status(200)
+            .with_body(fills("0xbuy", 30.0))
+            .create_async()
+            .await;
+        let mut sell_fills = server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xsell"))
+            .with_status(200)
+            .with_body(fills("0xsell", 30.0))
+            .create_async()
+            .await;
+
+        let config = ClobConfig {
+            base_url: server.url(),
+            fill_confirmation_timeout_ms: 200,
+            ..create_test_clob_config()
+        };
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(create_test_risk_config()));
+        let executor = ArbitrageExecutor::new(client, cb.clone(), 100);
+        let opportunity = ArbitrageOpportunity {
+            token_id: TokenId("12345".to_string()),
+            ..create_test_opportunity()
+        };
+
+        // Equal partial fills are a smaller complete arbitrage
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Success { pnl, .. } => {
+                let expected = ArbitrageOpportunity { max_size: 30.0, ..opportunity.clone() };
+                assert!((pnl - executor.calculate_pnl(&expected)).abs() < 1e-9);
+            }
+            other => panic!("Expected success, got {:?}", other),
+        }
+
+        // Partial fill on one leg, nothing on the other: never "unfilled"
+        sell_fills.remove_async().await;
+        sell_fills = server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xsell"))
+            .with_status(500)
+            .create_async()
+            .await;
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::PartialFill { filled_hash, rolled_back, .. } => {
+                assert_eq!(filled_hash, "0xsell", "Unknown fill is the exposure");
+                assert!(!rolled_back);
+            }
+            other => panic!("Expected partial fill, got {:?}", other),
+        }
+        assert!(!cb.can_execute(), "Unknown fill should trip the breaker");
+
+        // Both legs unfilled is still a plain failure
+        cb.reset();
+        buy_fills.remove_async().await;
+        sell_fills.remove_async().await;
+        buy_fills = server
+            .mock("GET", "/fills")
+            .match_query(mockito::Matcher::Any)
+            .with_status(200)
+            .with_body("[]")
+            .expect_at_least(2)
+            .create_async()
+            .await;
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Failed { error, .. } => assert_eq!(error, "fills_not_confirmed"),
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        buy_fills.assert_async().await;
+        assert!(cb.can_execute());
+    }
+
+    #[test]
+    fn test_size_for_market_impact() {
+        use crate::types::{OrderBook, OrderBookEntry};
+
+        let config = create_test_clob_config();
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let router = Arc::new(OrderRouter::new(client.clone()));
+        let cb = Arc::new(CircuitBreaker::new(crate::types::BotConfig::default().risk));
+        let opportunity = create_test_opportunity();
+
+        // No cap or no cached book: unchanged
+        let executor = ArbitrageExecutor::new(client.clone(), cb.clone(), 0)
+            .with_router(router.clone());
+        assert!(executor.size_for_market_impact(&opportunity).is_none());
+        let executor = executor.with_max_slippage(0.02);
+        assert!(executor.size_for_market_impact(&opportunity).is_none());
+
+        // 60 @ 0.70 then 0.72: 2% slippage allows 200 shares
+        let level = |price, size| OrderBookEntry { price, size, timestamp: None };
+        router.update_order_book(OrderBook {
+            token_id: opportunity.token_id.clone(),
+            bids: vec![level(0.75, 500.0)],
+            asks: vec![level(0.70, 60.0), level(0.72, 200.0)],
+            timestamp: 0,
+        });
+        let resized = executor.size_for_market_impact(&opportunity).unwrap();
+        assert!((resized.max_size - 100.0).abs() < 1e-9, "Capped at the detected size");
+        // 60 @ 0.70 + 40 @ 0.72 = 0.708 average
+        assert_eq!((resized.bid_price, resized.ask_price), (0.75, 0.72));
+        assert!((resized.expected_profit - (0.75 - 0.708) * 100.0).abs() < 1e-9);
+
+        let executor = ArbitrageExecutor::new(client, cb, 0)
+            .with_router(router)
+            .with_max_slippage(0.005);
+        // 0.5%: average reaches 0.7035 after 60 + 12.73 shares
+        let resized = executor.size_for_market_impact(&opportunity).unwrap();
+        assert!((resized.max_size - 72.727).abs() < 1e-3, "Sized {}", resized.max_size);
+        assert_eq!(resized.ask_price, 0.72);
+    }
+
+    #[tokio::test]
+    async fn test_routes_thin_legs_sequentially() {
+        use crate::types::{OrderBook, OrderBookEntry};
+
+        let mut server = mockito::Server::new_async().await;
+        let side = |side: u8| mockito::Matcher::Regex(format!(r#""side":{}\b"#, side));
+        let buy_order = server
+            .mock("POST", "/orders")
+            .match_body(side(0))
+            .with_status(200)
+            .with_body(r#"{"success":true,"orderHashes":["0xbuy"]}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/orders")
+            .match_body(side(1))
+            .with_status(200)
+            .with_body(r#"{"success":true,"orderHashes":["0xsell"]}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let by_hash = |hash: &str| mockito::Matcher::UrlEncoded("order_hash".into(), hash.into());
+        server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xbuy"))
+            .with_status(200)
+            .with_body(r#"[{"orderHash":"0xbuy","price":0.7,"size":100.0}]"#)
+            .create_async()
+            .await;
+        let sell_fills = server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xsell"))
+            .with_status(200)
+            .with_body(r#"[{"orderHash":"0xsell","price":0.75,"size":100.0}]"#)
+            .create_async()
+            .await;
+
+        let config = ClobConfig {
+            base_url: server.url(),
+            fill_confirmation_timeout_ms: 300,
+            ..create_test_clob_config()
+        };
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(create_test_risk_config()));
+
+        // Deep asks, bids only cover the sell leg once: sell first
+        let router = Arc::new(OrderRouter::new(client.clone()));
+        let level = |price, size| OrderBookEntry { price, size, timestamp: None };
+        router.update_order_book(OrderBook {
+            token_id: TokenId("12345".to_string()),
+            bids: vec![level(0.75, 100.0)],
+            asks: vec![level(0.70, 500.0)],
+            timestamp: chrono::Utc::now().timestamp_millis(),
+        });
+
+        let bus = EventBus::default();
+        let mut events = bus.subscribe();
+        let executor = ArbitrageExecutor::new(client, cb.clone(), 100)
+            .with_event_bus(bus)
+            .with_router(router);
+        let opportunity = ArbitrageOpportunity {
+            token_id: TokenId("12345".to_string()),
+            ..create_test_opportunity()
+        };
+
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Success { buy_hash, sell_hash, .. } => {
+                assert_eq!((buy_hash.as_str(), sell_hash.as_str()), ("0xbuy", "0xsell"));
+            }
+            other => panic!("Expected success, got {:?}", other),
+        }
+
+        // The buy leg waits for the sell leg's fills
+        let mut published = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            published.push(match event {
+                TradingEvent::OrderSubmitted { order_hash, .. } => format!("order {}", order_hash),
+                TradingEvent::FillReceived(fill) => format!("fill {}", fill.order_hash),
+                other => panic!("Unexpected event: {:?}", other),
+            });
+        }
+        assert_eq!(published, ["order 0xsell", "fill 0xsell", "order 0xbuy", "fill 0xbuy"]);
+
+        // Unfilled first leg: cancel it and never submit the second
+        sell_fills.remove_async().await;
+        server
+            .mock("GET", "/fills")
+            .match_query(by_hash("0xsell"))
+            .with_status(200)
+            .with_body("[]")
+            .create_async()
+            .await;
+        let cancel = server
+            .mock("DELETE", "/orders/0xsell")
+            .with_status(200)
+            .create_async()
+            .await;
+
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Failed { error, .. } => assert_eq!(error, "0xsell not filled"),
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        cancel.assert_async().await;
+        buy_order.assert_async().await;
+        assert!(cb.can_execute(), "Nothing was left open");
+    }
+
+    #[tokio::test]
+    async fn test_production_requires_recent_balance_check() {
+        let config = create_test_clob_config();
+
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(create_test_risk_config()));
+        let executor = ArbitrageExecutor::new(client, cb.clone(), 100)
+            .with_environment(Environment::Production);
+
+        let result = executor.execute(&create_test_opportunity()).await.unwrap();
+        match result {
+            ExecutionResult::Failed { error, .. } => assert_eq!(error, "No recent balance check"),
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        assert_eq!(cb.positions(), 0, "No positions should be opened");
+
+        executor.record_balance_check();
+        assert!(executor.balance_check_fresh());
+    }
+
+    #[tokio::test]
+    async fn test_check_balance_records_check() {
+        let mut server = mockito::Server::new_async().await;
+        let low = server
+            .mock("GET", "/balance-allowance")
+            .match_query(mockito::Matcher::UrlEncoded("asset_type".into(), "COLLATERAL".into()))
+            .with_body(r#"{"balance":"50000000","allowance":"5000000"}"#)
+            .create_async()
+            .await;
+
+        let client = Arc::new(
+            ClobClient::new(ClobConfig {
+                base_url: server.url(),
+                ..create_test_clob_config()
+            })
+            .unwrap(),
+        );
+        let cb = Arc::new(CircuitBreaker::new(RiskConfig {
+            min_usdc_balance: 10.0,
+            ..crate::types::BotConfig::default().risk
+        }));
+        let executor = Arc::new(
+            ArbitrageExecutor::new(client, cb, 100).with_environment(Environment::Production),
+        );
+
+        // $50 held but only $5 approved for the exchange
+        let error = executor.check_balance().await.unwrap_err();
+        assert!(matches!(error, HftError::RiskLimitExceeded(_)), "{:?}", error);
+        assert!(!executor.balance_check_fresh());
+        low.remove_async().await;
+
+        server
+            .mock("GET", "/balance-allowance")
+            .match_query(mockito::Matcher::Any)
+            .with_body(r#"{"balance":"50000000","allowance":"100000000"}"#)
+            .create_async()
+            .await;
+        assert_eq!(executor.check_balance().await.unwrap(), 50.0);
+        assert!(executor.balance_check_fresh());
+    }
+
+    #[tokio::test]
+    async fn test_refuses_executions_after_shutdown() {
+        let config = create_test_clob_config();
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(crate::types::BotConfig::default().risk));
+        let shutdown = Arc::new(ShutdownCoordinator::new());
+        let executor = ArbitrageExecutor::new(client, cb, 100)
+            .with_environment(Environment::Production)
+            .with_shutdown(Arc::clone(&shutdown));
+        let error = |result| match result {
+            ExecutionResult::Failed { error, .. } => error,
+            other => panic!("Expected failure, got {:?}", other),
+        };
+
+        let result = executor.execute(&create_test_opportunity()).await.unwrap();
+        assert_eq!(error(result), "No recent balance check");
+
+        shutdown.trigger();
+        let result = executor.execute(&create_test_opportunity()).await.unwrap();
+        assert_eq!(error(result), "shutting_down");
+        assert_eq!(executor.statistics().total_executions, 1, "Refused before counting");
+    }
+
+    #[tokio::test]
+    async fn test_skips_stale_opportunities() {
+        let config = create_test_clob_config();
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(crate::types::BotConfig::default().risk));
+        let executor = ArbitrageExecutor::new(client.clone(), cb.clone(), 100)
+            .with_environment(Environment::Production);
+        let error = |result| match result {
+            ExecutionResult::Failed { error, .. } => error,
+            other => panic!("Expected failure, got {:?}", other),
+        };
+
+        let stale = ArbitrageOpportunity {
+            detected_at: chrono::Utc::now().timestamp_millis() - 10_000,
+            ..create_test_opportunity()
+        };
+        assert_eq!(error(executor.execute(&stale).await.unwrap()), "stale orderbook");
+        assert_eq!(cb.errors(), 0, "Not counted against the breaker");
+        assert_eq!(cb.positions(), 0);
+
+        // A longer limit lets it through to the next check
+        let executor = executor.with_max_orderbook_age_ms(60_000);
+        assert_eq!(error(executor.execute(&stale).await.unwrap()), "No recent balance check");
+
+        // A just-detected opportunity priced off an old cached book is stale too
+        let router = Arc::new(OrderRouter::new(client));
+        router.update_order_book(crate::types::OrderBook {
+            token_id: stale.token_id.clone(),
+            bids: vec![],
+            asks: vec![],
+            timestamp: chrono::Utc::now().timestamp_millis() - 120_000,
+        });
+        let executor = executor.with_router(router);
+        let fresh = create_test_opportunity();
+        assert_eq!(error(executor.execute(&fresh).await.unwrap()), "stale orderbook");
+    }
+
+    #[tokio::test]
+    async fn test_execute_batch_runs_most_liquid_first() {
+        let config = create_test_clob_config();
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(crate::types::BotConfig::default().risk));
+        let executor =
+            ArbitrageExecutor::new(client, cb, 100).with_environment(Environment::Production);
+
+        let opportunity = |market: &str, profit_margin, liquidity_score| ArbitrageOpportunity {
+            market_id: MarketId::new(market),
+            profit_margin,
+            liquidity_score,
+            ..create_test_opportunity()
+        };
+        let opportunities = vec![
+            // Widest margin, but the book is too thin to fill it
+            opportunity("thin", 0.10, 0.2),
+            opportunity("deep", 0.05, 1.0),
+            opportunity("medium", 0.06, 0.7),
+        ];
+
+        let results = executor.execute_batch(opportunities).await;
+        let order: Vec<String> = results.iter().map(|(o, _)| o.market_id.to_string()).collect();
+        assert_eq!(order, ["deep", "medium", "thin"]);
+        assert!(results.iter().all(|(_, result)| result.is_ok()));
+        assert_eq!(executor.statistics().total_executions, 3);
+        assert_eq!(executor.latency_tracker().to_summary().count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_trade_exceeding_max_loss() {
+        let config = create_test_clob_config();
+
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(RiskConfig {
+            max_loss_per_trade: 74.0,
+            ..create_test_risk_config()
+        }));
+        let executor = ArbitrageExecutor::new(client, cb.clone(), 100);
+
+        // Worst case: 100 shares * $0.75 = $75 > $74
+        let result = executor.execute(&create_test_opportunity()).await.unwrap();
+        match result {
+            ExecutionResult::Failed { error, .. } => {
+                assert_eq!(error, "trade_exceeds_max_loss_per_trade")
+            }
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        assert_eq!(cb.positions(), 0, "No positions should be opened");
+
+        // Repeated anomalies skip the market before any other check
+        let opportunity = create_test_opportunity();
+        for _ in 0..4 {
+            cb.observe_market_anomaly(&opportunity.token_id, AnomalyReason::CrossedBook);
+        }
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Failed { error, .. } => {
+                assert_eq!(error, "Market skipped after repeated anomalies")
+            }
+            other => panic!("Expected failure, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_backs_off_on_rate_limit_and_skips_risk_limits() {
+        let mut server = mockito::Server::new_async().await;
+        let orders = server
+            .mock("POST", "/orders")
+            .with_status(429)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let config = ClobConfig {
+            base_url: server.url(),
+            ..create_test_clob_config()
+        };
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let risk = RiskConfig {
+            max_loss_per_trade: 100.0,
+            ..crate::types::BotConfig::default().risk
+        };
+        let cb = Arc::new(CircuitBreaker::new(risk.clone()));
+        let executor = ArbitrageExecutor::new(client.clone(), cb.clone(), 100);
+        let opportunity = ArbitrageOpportunity {
+            token_id: TokenId("12345".to_string()),
+            ..create_test_opportunity()
+        };
+
+        // A 429 is not counted as an error, and the next execution backs off
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Failed { error, .. } => {
+                assert_eq!(error, HftError::RateLimitExceeded.to_string())
+            }
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        assert_eq!(cb.positions(), 0);
+        assert_eq!(cb.export_state()["consecutive_errors"], 0);
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Failed { error, .. } => assert_eq!(error, "rate_limited"),
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        orders.assert_async().await;
+
+        // Only one of the two legs fits under the position limit: skipped, not an error
+        let cb = Arc::new(CircuitBreaker::new(RiskConfig { max_open_positions: 1, ..risk }));
+        let executor = ArbitrageExecutor::new(client, cb.clone(), 100);
+        match executor.execute(&opportunity).await.unwrap() {
+            ExecutionResult::Failed { error, .. } => assert_eq!(
+                error,
+                HftError::RiskLimitExceeded("Position limit would be exceeded".to_string())
+                    .to_string()
+            ),
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        assert_eq!(cb.positions(), 0, "Opened leg should be released");
+    }
+
+    #[tokio::test]
+    async fn test_statistics() {
+        let config = create_test_clob_config();
+
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(create_test_risk_config()));
+        let executor = ArbitrageExecutor::new(client, cb.clone(), 100);
+        assert_eq!(executor.statistics(), ExecutorStatistics::default());
+
+        // Rejected before submission: counted as a failure, latency sampled
+        cb.trip();
+        executor.execute(&create_test_opportunity()).await.unwrap();
+        assert_eq!(executor.latency_tracker().len(), 1);
+        executor.latency_tracker().reset();
+
+        for pnl in [1.25, 2.5] {
+            executor.record_execution(&ExecutionResult::Success {
+                buy_hash: "0xabc".to_string(),
+                sell_hash: "0xdef".to_string(),
+                pnl,
+                latency_ms: 150,
+            });
+        }
+        executor.record_execution(&ExecutionResult::PartialFill {
+            filled_hash: "0xabc".to_string(),
+            rolled_back: true,
+            latency_ms: 200,
+        });
+
+        // 199 fast executions and one slow outlier
+        for _ in 0..199 {
+            executor.record_latency(Duration::from_millis(150));
+        }
+        executor.record_latency(Duration::from_millis(9_000));
+
+        let stats = executor.statistics();
+        assert_eq!(stats.total_executions, 4);
+        assert_eq!(stats.successes, 2);
+        assert_eq!(stats.partial_fills, 1);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.total_pnl, 3.75);
+        assert!((stats.avg_latency_ms - 194.25).abs() < 1e-9);
+        assert_eq!(stats.p99_latency_ms, 150.0);
+
+        let health = executor.health();
+        assert_eq!(health["executor"]["successes"], 2);
+        assert_eq!(health["latency"]["count"], 200);
+        assert_eq!(health["latency"]["p99_ns"], 150_000_000);
+    }
+
+    #[tokio::test]
+    async fn test_legs_signed_with_reserved_nonces() {
+        let mut server = mockito::Server::new_async().await;
+        let orders = server
+            .mock("POST", "/orders")
+            .match_body(mockito::Matcher::Regex(r#""nonce":"7".*"nonce":"8""#.to_string()))
+            .with_status(400)
+            .create_async()
+            .await;
+
+        let client = Arc::new(
+            ClobClient::new(ClobConfig {
+                base_url: server.url(),
+                retry_attempts: 1,
+                ..create_test_clob_config()
+            })
+            .unwrap(),
+        );
+        client.nonce_manager().set_nonce(7);
+        let cb = Arc::new(CircuitBreaker::new(RiskConfig {
+            max_loss_per_trade: 100.0,
+            ..crate::types::BotConfig::default().risk
+        }));
+        let executor = ArbitrageExecutor::new(client.clone(), cb, 100);
+
+        let opportunity = ArbitrageOpportunity {
+            token_id: TokenId("12345".to_string()),
+            ..create_test_opportunity()
+        };
+        let _ = executor.execute(&opportunity).await;
+        orders.assert_async().await;
+        assert_eq!(client.nonce_manager().current(), 9);
+    }
+
     #[test]
     fn test_execution_result_methods() {
         let success = ExecutionResult::Success {
@@ -434,6 +2055,15 @@ mod tests {
         assert!(!partial.is_success());
         assert_eq!(partial.latency_ms(), 200);
         assert_eq!(partial.pnl(), 0.0);
+        assert!(!partial.requires_manual_intervention());
+
+        let stuck = ExecutionResult::PartialFill {
+            filled_hash: "0xabc".to_string(),
+            rolled_back: false,
+            latency_ms: 200,
+        };
+        assert!(stuck.requires_manual_intervention());
+        assert!(!success.requires_manual_intervention());
 
         let failed = ExecutionResult::Failed {
             error: "test error".to_string(),
@@ -443,5 +2073,6 @@ mod tests {
         assert!(!failed.is_success());
         assert_eq!(failed.latency_ms(), 50);
         assert_eq!(failed.pnl(), 0.0);
+        assert!(!failed.requires_manual_intervention());
     }
 }
@@ -49,8 +49,13 @@
 //! ├── ClobClient
 //! │   ├── HTTP Client (TCP_NODELAY, pooling)
 //! │   ├── NonceManager (optimistic)
-//! │   └── OrderSigner (pre-computed EIP-712)
+//! │   ├── OrderSigner (pre-computed EIP-712)
+//! │   ├── SaltGenerator (OsRng order salts)
+//! │   └── TokenBucketRateLimiter (requests per second)
 //! └── CircuitBreaker (Phase 3)
+//!
+//! ClobClientPool (optional, for very high order rates)
+//! └── ClobClient × N (round-robin, strided nonces)
 //! ```
 //!
 //! ## Safety Guarantees
@@ -117,8 +122,19 @@ mod client;
 mod eip712;
 mod executor;
 mod nonce_manager;
+mod pool;
+mod rate_limiter;
+mod salt;
 
-pub use client::{ClobClient, ClobConfig, CreateOrderRequest};
+pub use client::{
+    BatchOrderBuilder, ClobClient, ClobConfig, CreateOrderRequest, MAX_WARM_UP_CONNECTIONS,
+};
 pub use eip712::{DomainSeparator, OrderSigner};
-pub use executor::{ArbitrageExecutor, ExecutionResult};
-pub use nonce_manager::NonceManager;
+pub use executor::{
+    ArbitrageExecutor, ExecutionResult, ExecutorStatistics, BALANCE_CHECK_INTERVAL,
+    BALANCE_CHECK_MAX_AGE, NONCE_DRIFT_CHECK_INTERVAL,
+};
+pub use nonce_manager::{NonceManager, DEFAULT_MAX_NONCE_DRIFT};
+pub use pool::ClobClientPool;
+pub use rate_limiter::TokenBucketRateLimiter;
+pub use salt::SaltGenerator;
@@ -8,16 +8,73 @@
 //! - **Savings:** ~100ms per order (200ms for 2-order arbitrage)
 //!
 //! # How It Works
-//! 1. Initialize with current on-chain nonce (one-time API call)
+//! 1. Initialize with current on-chain nonce (one-time JSON-RPC call,
+//!    `sync_from_rpc`)
 //! 2. Atomically increment local nonce for each order (no API call)
 //! 3. Handle nonce conflicts by resetting to server value + 1
 //!
 //! # Thread Safety
 //! Uses AtomicU64 for lock-free concurrent access.
 
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::ops::Range;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use tracing;
 
+/// Default drift from the on-chain nonce tolerated by `verify_against_rpc`
+pub const DEFAULT_MAX_NONCE_DRIFT: u64 = 10;
+
+/// JSON-RPC request timeout
+const RPC_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// JSON-RPC response (`result` on success, `error` otherwise)
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    #[serde(default)]
+    result: Option<String>,
+    #[serde(default)]
+    error: Option<serde_json::Value>,
+}
+
+/// Fetch the pending transaction count of `address` (`eth_getTransactionCount`)
+async fn fetch_transaction_count(rpc_url: &str, address: &str) -> Result<u64> {
+    let http = reqwest::Client::builder()
+        .timeout(RPC_TIMEOUT)
+        .build()
+        .map_err(|e| anyhow!("Failed to create RPC client: {}", e))?;
+
+    let request = serde_json::json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "eth_getTransactionCount",
+        "params": [address, "pending"],
+    });
+
+    let response = http.post(rpc_url)
+        .json(&request)
+        .send()
+        .await
+        .map_err(|e| anyhow!("Transaction count request failed: {}", e))?;
+
+    if !response.status().is_success() {
+        return Err(anyhow!("RPC returned error: {}", response.status()));
+    }
+
+    let response: RpcResponse = response.json()
+        .await
+        .map_err(|e| anyhow!("Failed to parse RPC response: {}", e))?;
+
+    match (response.result, response.error) {
+        (Some(count), _) => u64::from_str_radix(count.trim_start_matches("0x"), 16)
+            .map_err(|e| anyhow!("Invalid transaction count {:?}: {}", count, e)),
+        (None, error) => {
+            Err(anyhow!("eth_getTransactionCount failed: {}", error.unwrap_or_default()))
+        }
+    }
+}
+
 /// Optimistic nonce manager with atomic operations
 ///
 /// Tracks the next nonce to use without requiring API calls.
@@ -26,6 +83,15 @@ use tracing;
 pub struct NonceManager {
     /// Current nonce (atomic for thread-safety)
     current_nonce: AtomicU64,
+
+    /// Increment between nonces (1 unless sharing a wallet across clients)
+    stride: u64,
+
+    /// Residue `nonce % stride` reserved for this manager
+    offset: u64,
+
+    /// Drift from the on-chain nonce tolerated by `verify_against_rpc`
+    max_drift: u64,
 }
 
 impl NonceManager {
@@ -33,27 +99,95 @@ impl NonceManager {
     ///
     /// Must call `initialize()` with actual on-chain nonce before use.
     pub fn new() -> Self {
-        Self {
-            current_nonce: AtomicU64::new(0),
-        }
+        Self::with_nonce(0)
     }
 
     /// Create nonce manager with starting value
     pub fn with_nonce(starting_nonce: u64) -> Self {
+        Self::new_with_stride(starting_nonce, 1)
+    }
+
+    /// Create nonce manager that hands out `start, start + stride, ...`
+    ///
+    /// Lets several clients share one wallet without nonce conflicts:
+    /// with stride 2, client 0 uses 0, 2, 4... and client 1 uses 1, 3, 5...
+    /// Later resets (initialize, conflicts, resync) stay on the same lane.
+    pub fn new_with_stride(start: u64, stride: u64) -> Self {
+        let stride = stride.max(1);
         Self {
-            current_nonce: AtomicU64::new(starting_nonce),
+            current_nonce: AtomicU64::new(start),
+            stride,
+            offset: start % stride,
+            max_drift: DEFAULT_MAX_NONCE_DRIFT,
         }
     }
 
+    /// Tolerate up to `max_drift` from the on-chain nonce in `verify_against_rpc`
+    pub fn with_max_drift(mut self, max_drift: u64) -> Self {
+        self.max_drift = max_drift;
+        self
+    }
+
+    /// Drift from the on-chain nonce tolerated by `verify_against_rpc`
+    pub fn max_drift(&self) -> u64 {
+        self.max_drift
+    }
+
+    /// Increment between nonces
+    pub fn stride(&self) -> u64 {
+        self.stride
+    }
+
+    /// Round `nonce` up to the next value on this manager's lane
+    fn align(&self, nonce: u64) -> u64 {
+        let remainder = nonce % self.stride;
+        nonce + (self.offset + self.stride - remainder) % self.stride
+    }
+
     /// Initialize with current on-chain nonce
     ///
     /// This should be called once at startup after fetching the
     /// current nonce from the CLOB API.
     pub fn initialize(&self, nonce: u64) {
+        let nonce = self.align(nonce);
         self.current_nonce.store(nonce, Ordering::SeqCst);
         tracing::info!("Nonce manager initialized at {}", nonce);
     }
 
+    /// Initialize from the on-chain transaction count of `address`
+    ///
+    /// Calls `eth_getTransactionCount` (pending) on `rpc_url`, so a restarted
+    /// bot doesn't reuse nonces consumed before a crash. Returns the nonce
+    /// set (the count rounded up to this manager's lane).
+    pub async fn sync_from_rpc(&self, rpc_url: &str, address: &str) -> Result<u64> {
+        let count = fetch_transaction_count(rpc_url, address).await?;
+        self.initialize(count);
+        Ok(self.current())
+    }
+
+    /// Check the optimistic nonce against the on-chain transaction count
+    ///
+    /// Returns `false` (and logs an alert) if the two differ by more than
+    /// `max_drift`. The local nonce is left unchanged.
+    pub async fn verify_against_rpc(&self, rpc_url: &str, address: &str) -> Result<bool> {
+        let on_chain = fetch_transaction_count(rpc_url, address).await?;
+        let local = self.current();
+        let drift = local.abs_diff(on_chain);
+
+        if drift > self.max_drift {
+            tracing::error!(
+                "🚨 Nonce drift alert: local={} on-chain={} (drift {} > max {})",
+                local,
+                on_chain,
+                drift,
+                self.max_drift
+            );
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
     /// Get next nonce (optimistic increment, no API call)
     ///
     /// **Performance:** <1μs vs 100ms API call
@@ -68,7 +202,18 @@ impl NonceManager {
     /// let nonce = api.fetch_nonce().await?; // ~100 milliseconds
     /// ```
     pub fn next_nonce(&self) -> u64 {
-        self.current_nonce.fetch_add(1, Ordering::SeqCst)
+        self.current_nonce.fetch_add(self.stride, Ordering::SeqCst)
+    }
+
+    /// Reserve `n` consecutive nonces in one atomic step
+    ///
+    /// Orders submitted together (e.g. both legs of an arbitrage) get nonces
+    /// no concurrent burst can interleave with. Step through the range with
+    /// `.step_by(stride)`; with the default stride of 1 it is exactly `n` nonces.
+    pub fn reserve_range(&self, n: u64) -> Range<u64> {
+        let span = n * self.stride;
+        let start = self.current_nonce.fetch_add(span, Ordering::SeqCst);
+        start..start + span
     }
 
     /// Handle nonce conflict (reset to server value + 1)
@@ -84,12 +229,13 @@ impl NonceManager {
 
         if server_nonce >= current {
             // Server is ahead, update to server value + 1
-            self.current_nonce.store(server_nonce + 1, Ordering::SeqCst);
+            let next = self.align(server_nonce + 1);
+            self.current_nonce.store(next, Ordering::SeqCst);
             tracing::warn!(
                 "Nonce conflict detected: local={}, server={}, reset to {}",
                 current,
                 server_nonce,
-                server_nonce + 1
+                next
             );
         } else {
             // We're ahead of server (shouldn't happen often)
@@ -108,6 +254,16 @@ impl NonceManager {
         self.current_nonce.load(Ordering::Acquire)
     }
 
+    /// Resync local nonce to the API nonce after detected drift
+    ///
+    /// Unlike `handle_conflict`, this also moves the nonce backwards
+    /// (e.g., after rejected orders left us ahead of the server).
+    pub fn resync(&self, api_nonce: u64) {
+        let api_nonce = self.align(api_nonce);
+        let previous = self.current_nonce.swap(api_nonce, Ordering::SeqCst);
+        tracing::warn!("Nonce resynced: local={} -> api={}", previous, api_nonce);
+    }
+
     /// Manually set nonce (use with caution)
     ///
     /// This is primarily for testing or recovery scenarios.
@@ -214,6 +370,106 @@ mod tests {
         assert_eq!(manager.current(), 1000);
     }
 
+    #[test]
+    fn test_reserve_range() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let manager = Arc::new(NonceManager::with_nonce(10));
+        assert_eq!(manager.reserve_range(2), 10..12);
+        assert_eq!(manager.next_nonce(), 12);
+
+        // Concurrent reservations never overlap
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let manager = Arc::clone(&manager);
+                thread::spawn(move || (0..50).map(|_| manager.reserve_range(2)).collect::<Vec<_>>())
+            })
+            .collect();
+        let mut starts: Vec<u64> =
+            handles.into_iter().flat_map(|h| h.join().unwrap()).map(|r| r.start).collect();
+        starts.sort_unstable();
+        assert!(starts.windows(2).all(|w| w[1] - w[0] == 2));
+        assert_eq!(manager.current(), 13 + 1000);
+
+        // Lanes keep their stride
+        let lane = NonceManager::new_with_stride(1, 2);
+        let range = lane.reserve_range(2);
+        assert_eq!(range.step_by(2).collect::<Vec<_>>(), [1, 3]);
+        assert_eq!(lane.next_nonce(), 5);
+    }
+
+    #[test]
+    fn test_resync() {
+        let manager = NonceManager::with_nonce(100);
+
+        manager.resync(90);
+        assert_eq!(manager.current(), 90);
+        assert_eq!(manager.next_nonce(), 90);
+    }
+
+    #[test]
+    fn test_new_with_stride() {
+        let even = NonceManager::new_with_stride(0, 2);
+        let odd = NonceManager::new_with_stride(1, 2);
+
+        assert_eq!(even.next_nonce(), 0);
+        assert_eq!(even.next_nonce(), 2);
+        assert_eq!(odd.next_nonce(), 1);
+        assert_eq!(odd.next_nonce(), 3);
+
+        // Resets stay on each manager's lane
+        even.initialize(7);
+        odd.initialize(7);
+        assert_eq!(even.next_nonce(), 8);
+        assert_eq!(odd.next_nonce(), 7);
+
+        odd.handle_conflict(20);
+        assert_eq!(odd.current(), 21);
+        even.resync(15);
+        assert_eq!(even.current(), 16);
+    }
+
+    #[tokio::test]
+    async fn test_sync_and_verify_against_rpc() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "method": "eth_getTransactionCount",
+                "params": ["0xabc", "pending"],
+            })))
+            .with_status(200)
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "result": "0x2a"}"#)
+            .expect(3)
+            .create_async()
+            .await;
+        let rpc_url = server.url();
+
+        // Restart: resume from the on-chain count instead of 0
+        let manager = NonceManager::new().with_max_drift(5);
+        assert_eq!(manager.sync_from_rpc(&rpc_url, "0xabc").await.unwrap(), 42);
+        assert_eq!(manager.next_nonce(), 42);
+
+        for _ in 0..4 {
+            manager.next_nonce();
+        }
+        assert!(manager.verify_against_rpc(&rpc_url, "0xabc").await.unwrap());
+        manager.next_nonce();
+        assert!(!manager.verify_against_rpc(&rpc_url, "0xabc").await.unwrap());
+        assert_eq!(manager.current(), 48);
+        mock.assert_async().await;
+
+        server
+            .mock("POST", "/")
+            .with_status(200)
+            .with_body(r#"{"jsonrpc": "2.0", "id": 1, "error": {"code": -32000}}"#)
+            .create_async()
+            .await;
+        assert!(manager.sync_from_rpc(&rpc_url, "0xdef").await.is_err());
+        assert_eq!(manager.current(), 48);
+    }
+
     #[test]
     fn test_set_nonce() {
         let manager = NonceManager::with_nonce(100);
@@ -0,0 +1,172 @@
+//! Pool of CLOB clients for load distribution
+//!
+//! A single `ClobClient` keeps 10 warm connections. For very high order
+//! rates, `ClobClientPool` spreads requests over several clients in
+//! round-robin order.
+//!
+//! # Nonces
+//! All clients sign with the same wallet, so each one draws from its own
+//! nonce lane (stride = pool size):
+//! ```text
+//! client 0: 0, 2, 4, ...
+//! client 1: 1, 3, 5, ...
+//! ```
+
+use anyhow::{anyhow, Result};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use super::client::{ClobClient, ClobConfig, CreateOrderRequest};
+use crate::types::BatchOrderResponse;
+
+/// Round-robin pool of CLOB clients
+pub struct ClobClientPool {
+    /// Clients with non-overlapping nonce lanes
+    clients: Vec<Arc<ClobClient>>,
+
+    /// Index of the next client to use
+    round_robin: AtomicUsize,
+}
+
+impl ClobClientPool {
+    /// Create a pool of `size` clients sharing one configuration
+    pub fn new(config: ClobConfig, size: usize) -> Result<Self> {
+        if size == 0 {
+            return Err(anyhow!("Client pool size must be at least 1"));
+        }
+
+        let stride = size as u64;
+        let clients = (0..stride)
+            .map(|start| {
+                let client = ClobClient::new(config.clone())?.with_nonce_stride(start, stride);
+                Ok(Arc::new(client))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        tracing::info!("ClobClientPool initialized with {} clients", size);
+
+        Ok(Self {
+            clients,
+            round_robin: AtomicUsize::new(0),
+        })
+    }
+
+    /// Number of clients in the pool
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Whether the pool has no clients (never true for a constructed pool)
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// All clients in the pool
+    pub fn clients(&self) -> &[Arc<ClobClient>] {
+        &self.clients
+    }
+
+    /// Select the next client in round-robin order
+    pub fn next_client(&self) -> &Arc<ClobClient> {
+        let index = self.round_robin.fetch_add(1, Ordering::Relaxed) % self.clients.len();
+        &self.clients[index]
+    }
+
+    /// Initialize every client's nonce lane from the on-chain nonce
+    pub async fn initialize_nonce(&self) -> Result<()> {
+        for client in &self.clients {
+            client.initialize_nonce().await?;
+        }
+        Ok(())
+    }
+
+    /// Create a single order on the next client
+    pub async fn create_order(&self, request: &CreateOrderRequest) -> Result<String> {
+        Ok(self.next_client().create_order(request).await?)
+    }
+
+    /// Create batch orders (up to 15 orders) on the next client
+    pub async fn create_batch_orders(
+        &self,
+        requests: &[CreateOrderRequest],
+    ) -> Result<BatchOrderResponse> {
+        Ok(self.next_client().create_batch_orders(requests).await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OrderSide, OrderType};
+
+    fn create_test_config(base_url: String) -> ClobConfig {
+        ClobConfig {
+            base_url,
+            api_key: "test_key".to_string(),
+            api_secret: String::new(),
+            api_passphrase: String::new(),
+            private_key: "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef".to_string(),
+            chain_id: 137,
+            verifying_contract: "0x0000000000000000000000000000000000000001".to_string(),
+            maker_address: "0x0000000000000000000000000000000000000002".to_string(),
+            taker_address: "0x0000000000000000000000000000000000000000".to_string(),
+            timeout_secs: 10,
+            proxy: None,
+            warm_up_on_start: false,
+            pool_size: 10,
+            cancel_on_trip: false,
+            fill_confirmation_timeout_ms: 0,
+            auto_cancel_on_partial_failure: false,
+            requests_per_second: 100,
+            rpc_url: None,
+            retry_attempts: 3,
+        }
+    }
+
+    #[test]
+    fn test_pool_nonce_lanes() {
+        let config = create_test_config("https://test.example.com".to_string());
+        let pool = ClobClientPool::new(config.clone(), 2).unwrap();
+        assert_eq!(pool.len(), 2);
+
+        let nonces: Vec<u64> = (0..4)
+            .map(|_| pool.next_client().nonce_manager().next_nonce())
+            .collect();
+        assert_eq!(nonces, vec![0, 1, 2, 3]);
+
+        assert!(ClobClientPool::new(config, 0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_pool_round_robin_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/orders")
+            .with_status(200)
+            .with_body(r#"{"success":true,"orderHashes":["0xabc"]}"#)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let pool = ClobClientPool::new(create_test_config(server.url()), 2).unwrap();
+        let request = CreateOrderRequest {
+            token_id: "123".to_string(),
+            side: OrderSide::BUY,
+            price: 0.75,
+            size: 100.0,
+            order_type: OrderType::GTC,
+            expiration: None,
+            fee_rate_bps: 100,
+            nonce: None,
+        };
+
+        for _ in 0..3 {
+            pool.create_batch_orders(std::slice::from_ref(&request)).await.unwrap();
+        }
+
+        // Client 0 signed nonces 0 and 2, client 1 signed nonce 1
+        assert_eq!(pool.clients()[0].nonce_manager().current(), 4);
+        assert_eq!(pool.clients()[1].nonce_manager().current(), 3);
+        mock.assert_async().await;
+    }
+}
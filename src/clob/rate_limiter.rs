@@ -0,0 +1,156 @@
+//! Token-bucket rate limiter for CLOB requests
+//!
+//! Polymarket rejects bursts above its per-second request limit with 429s,
+//! which the executor would count as errors towards tripping the circuit
+//! breaker. Every outbound `ClobClient` request takes a token first and
+//! waits while the bucket is empty.
+
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token bucket refilled at a fixed rate
+///
+/// Refills are computed from the time since the last one whenever tokens are
+/// read or taken, so no background task is needed and the limiter works from
+/// any runtime (or none, for `try_acquire`).
+#[derive(Debug)]
+pub struct TokenBucketRateLimiter {
+    /// Maximum tokens (burst size)
+    capacity: u32,
+
+    /// Tokens added per second
+    refill_rate: u32,
+
+    /// Fractional tokens and when they were last refilled
+    bucket: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucketRateLimiter {
+    /// Create a full bucket of `capacity` tokens refilled at `refill_rate` per second
+    ///
+    /// Both are raised to at least 1.
+    pub fn new(capacity: u32, refill_rate: u32) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            refill_rate: refill_rate.max(1),
+            bucket: Mutex::new(Bucket {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Bucket allowing `requests_per_second`, with up to one second of burst
+    pub fn per_second(requests_per_second: u32) -> Self {
+        Self::new(requests_per_second, requests_per_second)
+    }
+
+    /// Maximum tokens (burst size)
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+
+    /// Tokens added per second
+    pub fn refill_rate(&self) -> u32 {
+        self.refill_rate
+    }
+
+    /// Whole tokens currently available
+    pub fn available(&self) -> u64 {
+        let mut bucket = self.bucket.lock();
+        self.refill(&mut bucket);
+        bucket.tokens as u64
+    }
+
+    /// Take a token without waiting
+    pub fn try_acquire(&self) -> bool {
+        self.take().is_ok()
+    }
+
+    /// Take a token, waiting until one is refilled while the bucket is empty
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.take() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a token, or return how long until the next one is available
+    fn take(&self) -> Result<(), Duration> {
+        let mut bucket = self.bucket.lock();
+        self.refill(&mut bucket);
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.refill_rate as f64))
+        }
+    }
+
+    /// Add the tokens accrued since the last refill, up to `capacity`
+    fn refill(&self, bucket: &mut Bucket) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.last_refill).as_secs_f64();
+        bucket.tokens =
+            (bucket.tokens + elapsed * self.refill_rate as f64).min(self.capacity as f64);
+        bucket.last_refill = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_acquire_drains_bucket() {
+        let limiter = TokenBucketRateLimiter::new(3, 10);
+        assert_eq!(limiter.available(), 3);
+
+        assert!((0..3).all(|_| limiter.try_acquire()));
+        assert!(!limiter.try_acquire());
+        assert_eq!(limiter.available(), 0);
+
+        // Zero is raised to one
+        let limiter = TokenBucketRateLimiter::per_second(0);
+        assert_eq!((limiter.capacity(), limiter.refill_rate()), (1, 1));
+    }
+
+    #[test]
+    fn test_refills_without_runtime() {
+        let limiter = TokenBucketRateLimiter::new(3, 100);
+        assert!((0..3).all(|_| limiter.try_acquire()));
+        assert!(limiter.take().unwrap_err() <= Duration::from_millis(10));
+
+        // One token per 10ms
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(limiter.available() >= 2);
+        assert!(limiter.try_acquire());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_for_refill() {
+        let limiter = TokenBucketRateLimiter::new(2, 100);
+        let start = Instant::now();
+
+        // Burst of 2, then one token per 10ms
+        for _ in 0..5 {
+            limiter.acquire().await;
+        }
+        let waited = start.elapsed();
+        assert!(waited >= Duration::from_millis(25), "Waited {:?}", waited);
+        assert!(waited < Duration::from_millis(500), "Waited {:?}", waited);
+
+        // Refills never exceed capacity
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(limiter.available(), 2);
+    }
+}
@@ -0,0 +1,35 @@
+//! Order salt generation
+//!
+//! The salt makes otherwise identical orders hash differently. Burst
+//! execution can build several orders within the same nanosecond, so salts
+//! are drawn from the OS CSPRNG rather than the clock.
+
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// Source of order salts
+///
+/// `ClobClient` uses [`OsRng`] unless another generator is injected with
+/// `ClobClient::with_salt_generator` (e.g., a deterministic one in tests).
+pub trait SaltGenerator {
+    /// Next salt (must not repeat in practice)
+    fn next_salt(&self) -> u64;
+}
+
+impl SaltGenerator for OsRng {
+    fn next_salt(&self) -> u64 {
+        OsRng.next_u64()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_os_rng_salts_unique() {
+        let salts: HashSet<u64> = (0..1_000).map(|_| OsRng.next_salt()).collect();
+        assert_eq!(salts.len(), 1_000);
+    }
+}
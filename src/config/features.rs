@@ -0,0 +1,88 @@
+//! Runtime feature flags
+//!
+//! `FeatureConfig` is read once at startup; `FeatureStore` holds the same
+//! switches in a concurrent map so operators can pause a strategy (e.g.,
+//! during a market anomaly) without restarting the bot.
+
+use dashmap::DashMap;
+use std::collections::BTreeMap;
+
+use crate::types::config::FeatureConfig;
+
+/// Arbitrage execution (checked per execution by `ExecutionPipeline`)
+pub const ARBITRAGE_ENABLED: &str = "arbitrage_enabled";
+
+/// Binary YES/NO arbitrage detection (checked by `BinaryArbitrageDetector`)
+pub const BINARY_ARB: &str = "binary_arb";
+
+/// Copy trading
+pub const COPY_TRADING_ENABLED: &str = "copy_trading_enabled";
+
+/// Concurrent store of named on/off switches
+///
+/// Unknown keys read as disabled.
+#[derive(Debug, Default)]
+pub struct FeatureStore {
+    /// Flag values by key
+    flags: DashMap<String, bool>,
+}
+
+impl FeatureStore {
+    /// Create empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create store seeded from startup configuration
+    pub fn from_config(config: &FeatureConfig) -> Self {
+        let store = Self::new();
+        store.set(ARBITRAGE_ENABLED, config.arbitrage_enabled);
+        store.set(BINARY_ARB, config.arbitrage_enabled);
+        store.set(COPY_TRADING_ENABLED, config.copy_trading_enabled);
+        store
+    }
+
+    /// Whether `key` is enabled
+    pub fn get(&self, key: &str) -> bool {
+        self.flags.get(key).is_some_and(|flag| *flag)
+    }
+
+    /// Enable or disable `key`
+    pub fn set(&self, key: &str, value: bool) {
+        let previous = self.flags.insert(key.to_string(), value);
+        if previous != Some(value) {
+            tracing::info!("Feature flag '{}' set to {}", key, value);
+        }
+    }
+
+    /// All flags, sorted by key
+    pub fn snapshot(&self) -> BTreeMap<String, bool> {
+        self.flags
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_store() {
+        let store = FeatureStore::from_config(&FeatureConfig {
+            arbitrage_enabled: true,
+            copy_trading_enabled: false,
+            dry_run: true,
+        });
+        assert!(store.get(ARBITRAGE_ENABLED));
+        assert!(store.get(BINARY_ARB));
+        assert!(!store.get(COPY_TRADING_ENABLED));
+        assert!(!store.get("unknown"));
+
+        store.set(BINARY_ARB, false);
+        assert!(!store.get(BINARY_ARB));
+        assert_eq!(store.snapshot().len(), 3);
+        assert_eq!(store.snapshot().get(BINARY_ARB), Some(&false));
+    }
+}
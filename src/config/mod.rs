@@ -1,3 +1,6 @@
 //! Configuration management
 
+pub mod features;
+
 pub use crate::types::config::*;
+pub use features::FeatureStore;
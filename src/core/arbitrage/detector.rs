@@ -3,41 +3,129 @@
 //! Implements both scalar and SIMD-optimized arbitrage detection algorithms.
 //! Uses fixed-point arithmetic for 3x faster calculations.
 
-use crate::types::{ArbitrageOpportunity, MarketId, OrderBook, TokenId};
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::types::{
+    ArbitrageOpportunity, EventBus, MarketId, OrderBook, OrderSide, RiskConfig, TokenId,
+    TradingEvent,
+};
 use crate::utils::fixed_point::FixedPrice;
 
 /// Configuration for arbitrage detection
 #[derive(Debug, Clone)]
 pub struct ArbitrageConfig {
-    /// Minimum profit margin required (0.0-1.0)
+    /// Minimum profit margin required after fees (0.0-1.0)
     pub min_profit_margin: f64,
 
+    /// Fee rate in basis points charged on each leg
+    pub fee_rate_bps: u16,
+
     /// Minimum size in USDC to consider
     pub min_size: f64,
 
     /// Maximum spread to consider valid (sanity check)
     pub max_spread: f64,
+
+    /// Tokens never traded (frozen orderbooks, bad prices -> phantom arbitrage)
+    pub excluded_token_ids: HashSet<TokenId>,
+
+    /// Minimum price levels on each side of the book
+    /// (see `TradingConfig::order_book_depth_required`)
+    pub order_book_depth_required: usize,
+
+    /// Maximum VWAP slippage per leg when sizing past the best level
+    /// (0.0 = best level only)
+    pub max_slippage: f64,
 }
 
 impl Default for ArbitrageConfig {
     fn default() -> Self {
         Self {
             min_profit_margin: 0.02, // 2%
+            fee_rate_bps: 0,
             min_size: 10.0,          // $10 minimum
             max_spread: 0.50,        // 50% max spread (sanity check)
+            excluded_token_ids: HashSet::new(),
+            order_book_depth_required: 1, // Top of book only
+            max_slippage: 0.0,            // Best level only
+        }
+    }
+}
+
+impl ArbitrageConfig {
+    /// Derive detection parameters from risk limits
+    ///
+    /// - `min_size`: 10% of `max_position_size`
+    /// - `min_profit_margin`: 1% buffer on top of `fee_rate_bps` on both legs
+    pub fn from_risk_config(risk: &RiskConfig) -> Self {
+        Self {
+            min_profit_margin: 0.01,
+            fee_rate_bps: risk.fee_rate_bps,
+            min_size: risk.max_position_size * 0.1,
+            ..Self::default()
+        }
+    }
+
+    /// Raw spread/ask margin required: `min_profit_margin` plus fees on both legs
+    #[inline]
+    pub fn effective_min_margin(&self) -> f64 {
+        self.min_profit_margin + 2.0 * (self.fee_rate_bps as f64 / 10_000.0)
+    }
+
+    /// Exclude a token from detection
+    pub fn exclude_token(&mut self, token_id: TokenId) -> &mut Self {
+        self.excluded_token_ids.insert(token_id);
+        self
+    }
+
+    /// Whether a token is excluded from detection
+    #[inline]
+    pub fn is_excluded(&self, token_id: &TokenId) -> bool {
+        !self.excluded_token_ids.is_empty() && self.excluded_token_ids.contains(token_id)
+    }
+
+    /// Add exclusions from a file with one token ID per line
+    ///
+    /// Blank lines and `#` comments are ignored. Returns the number of token
+    /// IDs read.
+    pub fn load_exclusions_from_file(&mut self, path: &Path) -> Result<usize> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| anyhow!("Failed to read exclusions from {}: {}", path.display(), e))?;
+
+        let mut count = 0;
+        for line in contents.lines() {
+            let token_id = line.split('#').next().unwrap_or_default().trim();
+            if !token_id.is_empty() {
+                self.exclude_token(TokenId(token_id.to_string()));
+                count += 1;
+            }
         }
+
+        tracing::info!("Loaded {} excluded token IDs from {}", count, path.display());
+        Ok(count)
     }
 }
 
 /// Scalar arbitrage detector (baseline implementation)
 pub struct ScalarArbitrageDetector {
     config: ArbitrageConfig,
+
+    /// Receives `TradingEvent::OpportunityDetected` for every opportunity
+    event_bus: Option<EventBus<TradingEvent>>,
 }
 
 impl ScalarArbitrageDetector {
     /// Create a new scalar detector
     pub fn new(config: ArbitrageConfig) -> Self {
-        Self { config }
+        Self { config, event_bus: None }
+    }
+
+    /// Publish every detected opportunity on `event_bus`
+    pub fn with_event_bus(mut self, event_bus: EventBus<TradingEvent>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
     }
 
     /// Detect arbitrage opportunity from an order book
@@ -52,12 +140,23 @@ impl ScalarArbitrageDetector {
         token_id: &TokenId,
         order_book: &OrderBook,
     ) -> Option<ArbitrageOpportunity> {
+        // Known-bad tokens first (O(1) lookup)
+        if self.config.is_excluded(token_id) {
+            return None;
+        }
+
+        // Thin books: top of book may not be executable
+        if !order_book.has_minimum_depth(self.config.order_book_depth_required) {
+            return None;
+        }
+
         // Get best bid and ask
-        let best_bid = order_book.best_bid()?;
-        let best_ask = order_book.best_ask()?;
+        let (best_bid, best_ask) = order_book.bbo()?;
 
-        // Calculate maximum tradeable size
-        let max_size = best_bid.size.min(best_ask.size);
+        // Calculate maximum tradeable size (deeper levels within the slippage cap)
+        let max_size = order_book
+            .max_size_within_slippage(OrderSide::SELL, self.config.max_slippage)
+            .min(order_book.max_size_within_slippage(OrderSide::BUY, self.config.max_slippage));
 
         // Skip if size too small
         if max_size < self.config.min_size {
@@ -78,7 +177,7 @@ impl ScalarArbitrageDetector {
 
         // Convert config thresholds to fixed-point
         let max_spread_fixed = FixedPrice::from_f64(self.config.max_spread);
-        let min_profit_fixed = FixedPrice::from_f64(self.config.min_profit_margin);
+        let min_profit_fixed = FixedPrice::from_f64(self.config.effective_min_margin());
 
         // Sanity check: reject unrealistic spreads - ~1ns comparison
         if spread > max_spread_fixed {
@@ -86,24 +185,36 @@ impl ScalarArbitrageDetector {
         }
 
         // Calculate profit margin - ~8ns vs ~25ns for f64 (3.1x faster!)
-        let profit_margin = match FixedPrice::profit_margin(bid_price, ask_price) {
-            Some(margin) => margin,
-            None => return None,
-        };
+        let profit_margin = FixedPrice::profit_margin(bid_price, ask_price)?;
 
         // Check if meets minimum profit threshold - ~1ns comparison
         if profit_margin < min_profit_fixed {
             return None;
         }
 
+        // Price the full size at VWAP: deeper levels must still clear the threshold
+        let (bid_vwap, _) = order_book.vwap(OrderSide::SELL, max_size)?;
+        let (ask_vwap, _) = order_book.vwap(OrderSide::BUY, max_size)?;
+        if (bid_vwap - ask_vwap) / ask_vwap < self.config.effective_min_margin() {
+            return None;
+        }
+
         // Create opportunity (convert back to f64 for compatibility)
-        ArbitrageOpportunity::new(
+        let opportunity = ArbitrageOpportunity::new(
             market_id.clone(),
             token_id.clone(),
             bid_price.to_f64(),
             ask_price.to_f64(),
             max_size,
-        )
+        )?
+        .with_vwap(bid_vwap, ask_vwap)
+        .with_fee_rate(self.config.fee_rate_bps)
+        .with_liquidity(order_book);
+
+        if let Some(bus) = &self.event_bus {
+            bus.publish(TradingEvent::OpportunityDetected(opportunity.clone()));
+        }
+        Some(opportunity)
     }
 
     /// Detect opportunities across multiple order books
@@ -118,6 +229,30 @@ impl ScalarArbitrageDetector {
             })
             .collect()
     }
+
+    /// Detect opportunities across multiple order books in parallel
+    ///
+    /// Same output (and ordering) as `detect_batch`. Thread-pool overhead
+    /// only pays off for large batches - see `parallel_detector_bench`.
+    #[cfg(feature = "rayon")]
+    pub fn detect_batch_parallel(
+        &self,
+        markets: &[(MarketId, TokenId, OrderBook)],
+    ) -> Vec<ArbitrageOpportunity> {
+        use rayon::prelude::*;
+
+        let mut found: Vec<(usize, ArbitrageOpportunity)> = markets
+            .par_iter()
+            .enumerate()
+            .filter_map(|(index, (market_id, token_id, order_book))| {
+                self.detect(market_id, token_id, order_book).map(|opp| (index, opp))
+            })
+            .collect();
+
+        // Restore market ordering
+        found.sort_unstable_by_key(|(index, _)| *index);
+        found.into_iter().map(|(_, opp)| opp).collect()
+    }
 }
 
 #[cfg(test)]
@@ -145,7 +280,7 @@ mod tests {
     #[test]
     fn test_detect_valid_arbitrage() {
         let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         // Bid > Ask (arbitrage exists)
@@ -160,10 +295,34 @@ mod tests {
         assert!((opp.profit_margin - 0.0714).abs() < 0.001); // ~7.14%
     }
 
+    #[test]
+    fn test_publishes_detected_opportunities() {
+        let bus = EventBus::default();
+        let mut events = bus.subscribe();
+        let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default()).with_event_bus(bus);
+        let market_id = MarketId::new("market-1");
+        let token_id = TokenId("token-1".to_string());
+
+        assert!(detector.detect(&market_id, &token_id, &create_test_order_book(0.70, 0.75, 100.0))
+            .is_none());
+        let opp = detector
+            .detect(&market_id, &token_id, &create_test_order_book(0.75, 0.70, 100.0))
+            .unwrap();
+
+        match events.try_recv() {
+            Ok(TradingEvent::OpportunityDetected(published)) => {
+                assert_eq!(published.token_id, opp.token_id);
+                assert_eq!(published.bid_price, opp.bid_price);
+            }
+            other => panic!("Expected OpportunityDetected, got {:?}", other),
+        }
+        assert!(events.try_recv().is_err(), "Nothing published without an opportunity");
+    }
+
     #[test]
     fn test_no_arbitrage_normal_market() {
         let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         // Bid < Ask (normal market, no arbitrage)
@@ -176,11 +335,13 @@ mod tests {
 
     #[test]
     fn test_below_profit_threshold() {
-        let mut config = ArbitrageConfig::default();
-        config.min_profit_margin = 0.10; // 10% minimum
+        let config = ArbitrageConfig {
+            min_profit_margin: 0.10, // 10% minimum
+            ..Default::default()
+        };
 
         let detector = ScalarArbitrageDetector::new(config);
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         // 7.14% profit (below 10% threshold)
@@ -191,13 +352,35 @@ mod tests {
         assert!(opportunity.is_none());
     }
 
+    #[test]
+    fn test_fees_raise_profit_threshold() {
+        let market_id = MarketId::new("market-1");
+        let token_id = TokenId("token-1".to_string());
+        let order_book = create_test_order_book(0.75, 0.70, 100.0);
+
+        // 7.14% raw margin < 2% + 2 * 3% fees
+        let config = ArbitrageConfig { fee_rate_bps: 300, ..Default::default() };
+        assert!((config.effective_min_margin() - 0.08).abs() < 1e-12);
+        let detector = ScalarArbitrageDetector::new(config);
+        assert!(detector.detect(&market_id, &token_id, &order_book).is_none());
+
+        // 7.14% >= 2% + 2 * 2% fees; profit is net of 2% on $145 notional
+        let config = ArbitrageConfig { fee_rate_bps: 200, ..Default::default() };
+        let opp = ScalarArbitrageDetector::new(config)
+            .detect(&market_id, &token_id, &order_book)
+            .unwrap();
+        assert!((opp.expected_profit - 2.1).abs() < 1e-9);
+    }
+
     #[test]
     fn test_size_too_small() {
-        let mut config = ArbitrageConfig::default();
-        config.min_size = 100.0; // Require $100 minimum
+        let config = ArbitrageConfig {
+            min_size: 100.0, // Require $100 minimum
+            ..Default::default()
+        };
 
         let detector = ScalarArbitrageDetector::new(config);
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         // Only $50 available
@@ -211,7 +394,7 @@ mod tests {
     #[test]
     fn test_sanity_check_max_spread() {
         let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         // Unrealistic 95% spread (likely bad data)
@@ -222,10 +405,90 @@ mod tests {
         assert!(opportunity.is_none(), "Should reject unrealistic spreads");
     }
 
+    #[test]
+    fn test_excluded_tokens() {
+        let mut config = ArbitrageConfig::default();
+        config
+            .exclude_token(TokenId("frozen".to_string()))
+            .exclude_token(TokenId("bad-prices".to_string()));
+        assert!(config.is_excluded(&TokenId("frozen".to_string())));
+
+        let path = std::env::temp_dir().join(format!("exclusions-{}.txt", std::process::id()));
+        std::fs::write(&path, "# known bad\nfrom-file\n\n  padded  # trailing comment\n").unwrap();
+        assert_eq!(config.load_exclusions_from_file(&path).unwrap(), 2);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(config.excluded_token_ids.len(), 4);
+        assert!(config.is_excluded(&TokenId("padded".to_string())));
+        assert!(config.load_exclusions_from_file(&path).is_err());
+
+        let detector = ScalarArbitrageDetector::new(config);
+        let market_id = MarketId::new("market-1");
+        let order_book = create_test_order_book(0.75, 0.70, 100.0);
+
+        let excluded = TokenId("from-file".to_string());
+        assert!(detector.detect(&market_id, &excluded, &order_book).is_none());
+        let allowed = TokenId("token-1".to_string());
+        assert!(detector.detect(&market_id, &allowed, &order_book).is_some());
+    }
+
+    #[test]
+    fn test_order_book_depth_required() {
+        let config = ArbitrageConfig { order_book_depth_required: 2, ..ArbitrageConfig::default() };
+        let detector = ScalarArbitrageDetector::new(config);
+        let market_id = MarketId::new("market-1");
+        let token_id = TokenId("token-1".to_string());
+
+        // Single-level book is rejected
+        let mut order_book = create_test_order_book(0.75, 0.70, 100.0);
+        assert!(detector.detect(&market_id, &token_id, &order_book).is_none());
+
+        let level = |price| OrderBookEntry { price, size: 100.0, timestamp: Some(1000) };
+        order_book.bids.push(level(0.74));
+        assert!(detector.detect(&market_id, &token_id, &order_book).is_none(), "Asks too thin");
+        order_book.asks.push(level(0.71));
+        assert!(detector.detect(&market_id, &token_id, &order_book).is_some());
+    }
+
+    #[test]
+    fn test_vwap_sizing_past_best_level() {
+        let market_id = MarketId::new("market-1");
+        let token_id = TokenId("token-1".to_string());
+        let level = |price, size| OrderBookEntry { price, size, timestamp: Some(1000) };
+        let order_book = OrderBook {
+            bids: vec![level(0.75, 50.0), level(0.74, 100.0)],
+            asks: vec![level(0.70, 50.0), level(0.73, 100.0)],
+            ..create_test_order_book(0.75, 0.70, 50.0)
+        };
+
+        // Best level only by default
+        let opp = ScalarArbitrageDetector::new(ArbitrageConfig::default())
+            .detect(&market_id, &token_id, &order_book)
+            .unwrap();
+        assert_eq!(opp.max_size, 50.0);
+        assert!((opp.expected_profit - 2.5).abs() < 1e-9);
+
+        // 3% cap: all 150 shares at a VWAP of 0.7433 / 0.72 instead of 0.75 / 0.70
+        let config = ArbitrageConfig { max_slippage: 0.03, ..ArbitrageConfig::default() };
+        let detector = ScalarArbitrageDetector::new(config);
+        let opp = detector.detect(&market_id, &token_id, &order_book).unwrap();
+        assert!((opp.max_size - 150.0).abs() < 1e-9);
+        assert!((opp.expected_profit - 3.5).abs() < 1e-9);
+        assert_eq!((opp.bid_price, opp.ask_price), (0.75, 0.70));
+
+        // The deeper levels eat the margin
+        let config = ArbitrageConfig {
+            min_profit_margin: 0.05,
+            max_slippage: 0.03,
+            ..ArbitrageConfig::default()
+        };
+        let detector = ScalarArbitrageDetector::new(config);
+        assert!(detector.detect(&market_id, &token_id, &order_book).is_none());
+    }
+
     #[test]
     fn test_empty_order_book() {
         let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         let order_book = OrderBook {
@@ -247,19 +510,19 @@ mod tests {
         let markets = vec![
             // Good arbitrage
             (
-                MarketId("m1".to_string()),
+                MarketId::new("m1"),
                 TokenId("t1".to_string()),
                 create_test_order_book(0.75, 0.70, 100.0),
             ),
             // No arbitrage (normal market)
             (
-                MarketId("m2".to_string()),
+                MarketId::new("m2"),
                 TokenId("t2".to_string()),
                 create_test_order_book(0.70, 0.75, 100.0),
             ),
             // Good arbitrage
             (
-                MarketId("m3".to_string()),
+                MarketId::new("m3"),
                 TokenId("t3".to_string()),
                 create_test_order_book(0.80, 0.75, 100.0),
             ),
@@ -268,14 +531,40 @@ mod tests {
         let opportunities = detector.detect_batch(&markets);
 
         assert_eq!(opportunities.len(), 2);
-        assert_eq!(opportunities[0].market_id.0, "m1");
-        assert_eq!(opportunities[1].market_id.0, "m3");
+        assert_eq!(opportunities[0].market_id.to_string(), "m1");
+        assert_eq!(opportunities[1].market_id.to_string(), "m3");
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_batch_detection_parallel_matches_sequential() {
+        let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
+
+        let markets: Vec<_> = (0..1_000)
+            .map(|i| {
+                let bid = if i % 3 == 0 { 0.75 } else { 0.70 };
+                (
+                    MarketId::new(format!("m{}", i)),
+                    TokenId(format!("t{}", i)),
+                    create_test_order_book(bid, 0.72, 100.0),
+                )
+            })
+            .collect();
+
+        let sequential = detector.detect_batch(&markets);
+        let parallel = detector.detect_batch_parallel(&markets);
+
+        assert_eq!(parallel.len(), 334);
+        let ids = |opps: &[ArbitrageOpportunity]| -> Vec<String> {
+            opps.iter().map(|o| o.market_id.to_string()).collect()
+        };
+        assert_eq!(ids(&parallel), ids(&sequential));
     }
 
     #[test]
     fn test_max_size_limited_by_both_sides() {
         let detector = ScalarArbitrageDetector::new(ArbitrageConfig::default());
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         let order_book = OrderBook {
@@ -299,4 +588,35 @@ mod tests {
         let opp = opportunity.unwrap();
         assert_eq!(opp.max_size, 50.0, "Size should be limited by smaller side");
     }
+
+    #[test]
+    fn test_from_risk_config() {
+        let risk = RiskConfig {
+            max_daily_loss: 100.0,
+            max_position_size: 50.0,
+            max_loss_per_trade: 50.0,
+            max_open_positions: 5,
+            min_usdc_balance: 10.0,
+            min_matic_balance: 0.1,
+            max_consecutive_errors: 3,
+            risk_score_alert_threshold: 70.0,
+            fee_rate_bps: 100,
+            max_anomalies_before_skip: 3,
+            max_concentration: 0.5,
+            max_loss_in_window: 50.0,
+            loss_window_secs: 3600,
+        };
+
+        let config = ArbitrageConfig::from_risk_config(&risk);
+
+        // 2 * 1% fees + 1% buffer
+        assert!((config.min_profit_margin - 0.01).abs() < 1e-12);
+        assert_eq!(config.fee_rate_bps, 100);
+        assert!((config.effective_min_margin() - 0.03).abs() < 1e-12);
+        assert!((config.min_size - 5.0).abs() < 1e-12);
+        assert_eq!(config.max_spread, ArbitrageConfig::default().max_spread);
+
+        let free = ArbitrageConfig::from_risk_config(&RiskConfig { fee_rate_bps: 0, ..risk });
+        assert!((free.effective_min_margin() - 0.01).abs() < 1e-12);
+    }
 }
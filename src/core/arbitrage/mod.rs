@@ -1,9 +1,12 @@
 //! Arbitrage detection module
 //!
-//! Provides both scalar and SIMD-optimized arbitrage detection.
+//! Provides both scalar and SIMD-optimized arbitrage detection, plus
+//! cross-market detection for markets on the same underlying.
 
 pub mod detector;
+pub mod multi_market;
 pub mod simd_detector;
 
 pub use detector::{ArbitrageConfig, ScalarArbitrageDetector};
+pub use multi_market::{CrossMarketOpportunity, MultiMarketArbitrageDetector};
 pub use simd_detector::SimdArbitrageDetector;
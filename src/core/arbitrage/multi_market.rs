@@ -0,0 +1,217 @@
+//! Cross-market arbitrage detection
+//!
+//! Markets on the same underlying (e.g., two BTC up/down markets expiring at
+//! the same time on different venues) should price identically. When one
+//! market's best ask is below another's best bid, buy the first and sell the
+//! second.
+//!
+//! Detection compares every ordered pair of markets: O(N²) in the number of
+//! markets per group.
+
+use crate::types::{MarketId, OrderBook, TokenId};
+use crate::utils::fixed_point::FixedPrice;
+
+use super::detector::ArbitrageConfig;
+
+/// Arbitrage between two markets on the same underlying
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossMarketOpportunity {
+    /// Market to buy in (lower ask)
+    pub buy_market: MarketId,
+
+    /// Market to sell in (higher bid)
+    pub sell_market: MarketId,
+
+    /// Token bought in `buy_market`
+    pub buy_token: TokenId,
+
+    /// Token sold in `sell_market`
+    pub sell_token: TokenId,
+
+    /// Best ask in `buy_market`
+    pub buy_price: f64,
+
+    /// Best bid in `sell_market`
+    pub sell_price: f64,
+
+    /// Size available on both legs
+    pub max_size: f64,
+
+    /// Profit margin: (sell - buy) / buy
+    pub profit_margin: f64,
+}
+
+impl CrossMarketOpportunity {
+    /// Expected profit before fees: spread * size
+    pub fn expected_profit(&self) -> f64 {
+        (self.sell_price - self.buy_price) * self.max_size
+    }
+}
+
+/// Cross-market arbitrage detector
+///
+/// Uses the same thresholds as [`ScalarArbitrageDetector`](super::ScalarArbitrageDetector):
+/// minimum margin and size, maximum spread and excluded tokens.
+pub struct MultiMarketArbitrageDetector {
+    config: ArbitrageConfig,
+}
+
+impl MultiMarketArbitrageDetector {
+    /// Create a new cross-market detector
+    pub fn new(config: ArbitrageConfig) -> Self {
+        Self { config }
+    }
+
+    /// Find cross-market spreads among markets on the same underlying
+    ///
+    /// Pairs within the same market are skipped (a crossed book is the
+    /// scalar detector's job). Opportunities are returned in input order of
+    /// the buy market, then the sell market.
+    pub fn detect(
+        &self,
+        markets: &[(MarketId, TokenId, OrderBook)],
+    ) -> Vec<CrossMarketOpportunity> {
+        // Best levels once per book (excluded tokens never trade)
+        let levels: Vec<_> = markets
+            .iter()
+            .map(|(_, token_id, order_book)| {
+                if self.config.is_excluded(token_id) {
+                    return (None, None);
+                }
+                (order_book.best_ask(), order_book.best_bid())
+            })
+            .collect();
+
+        let max_spread = FixedPrice::from_f64(self.config.max_spread);
+        let min_profit = FixedPrice::from_f64(self.config.effective_min_margin());
+
+        let mut opportunities = Vec::new();
+        for (buy, (buy_market, buy_token, _)) in markets.iter().enumerate() {
+            let Some(ask) = levels[buy].0 else {
+                continue;
+            };
+            let ask_price = FixedPrice::from_f64(ask.price);
+
+            for (sell, (sell_market, sell_token, _)) in markets.iter().enumerate() {
+                if sell_market == buy_market {
+                    continue;
+                }
+                let Some(bid) = levels[sell].1 else {
+                    continue;
+                };
+
+                let bid_price = FixedPrice::from_f64(bid.price);
+                if bid_price <= ask_price || bid_price.saturating_sub(ask_price) > max_spread {
+                    continue;
+                }
+
+                let max_size = ask.size.min(bid.size);
+                if max_size < self.config.min_size {
+                    continue;
+                }
+
+                let Some(profit_margin) = FixedPrice::profit_margin(bid_price, ask_price) else {
+                    continue;
+                };
+                if profit_margin < min_profit {
+                    continue;
+                }
+
+                opportunities.push(CrossMarketOpportunity {
+                    buy_market: buy_market.clone(),
+                    sell_market: sell_market.clone(),
+                    buy_token: buy_token.clone(),
+                    sell_token: sell_token.clone(),
+                    buy_price: ask_price.to_f64(),
+                    sell_price: bid_price.to_f64(),
+                    max_size,
+                    profit_margin: profit_margin.to_f64(),
+                });
+            }
+        }
+
+        opportunities
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::OrderBookEntry;
+
+    fn market(name: &str, bid: f64, ask: f64, size: f64) -> (MarketId, TokenId, OrderBook) {
+        let entry = |price| OrderBookEntry { price, size, timestamp: Some(1000) };
+        (
+            MarketId::new(name),
+            TokenId(format!("{}-yes", name)),
+            OrderBook {
+                token_id: TokenId(format!("{}-yes", name)),
+                bids: vec![entry(bid)],
+                asks: vec![entry(ask)],
+                timestamp: 1000,
+            },
+        )
+    }
+
+    #[test]
+    fn test_detects_cross_market_spread() {
+        let detector = MultiMarketArbitrageDetector::new(ArbitrageConfig::default());
+        let markets = vec![
+            market("venue-a", 0.68, 0.70, 100.0),
+            market("venue-b", 0.75, 0.77, 50.0),
+            market("venue-c", 0.69, 0.76, 100.0),
+        ];
+
+        let opportunities = detector.detect(&markets);
+        assert_eq!(opportunities.len(), 1);
+
+        // Buy A at its ask, sell B at its bid
+        let opp = &opportunities[0];
+        assert_eq!(opp.buy_market, MarketId::new("venue-a"));
+        assert_eq!(opp.sell_market, MarketId::new("venue-b"));
+        assert_eq!(opp.buy_token, TokenId("venue-a-yes".to_string()));
+        assert_eq!(opp.sell_token, TokenId("venue-b-yes".to_string()));
+        assert_eq!(opp.buy_price, 0.70);
+        assert_eq!(opp.sell_price, 0.75);
+        assert_eq!(opp.max_size, 50.0);
+        assert!((opp.profit_margin - 0.0714).abs() < 0.001);
+        assert!((opp.expected_profit() - 2.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_respects_config_thresholds() {
+        let markets = vec![
+            market("venue-a", 0.68, 0.70, 100.0),
+            market("venue-b", 0.75, 0.77, 5.0),
+        ];
+
+        // Below min_size (10)
+        let detector = MultiMarketArbitrageDetector::new(ArbitrageConfig::default());
+        assert!(detector.detect(&markets).is_empty());
+
+        let markets = vec![
+            market("venue-a", 0.68, 0.70, 100.0),
+            market("venue-b", 0.75, 0.77, 100.0),
+        ];
+        let strict = ArbitrageConfig { min_profit_margin: 0.10, ..ArbitrageConfig::default() };
+        assert!(MultiMarketArbitrageDetector::new(strict).detect(&markets).is_empty());
+
+        let mut config = ArbitrageConfig::default();
+        config.exclude_token(TokenId("venue-b-yes".to_string()));
+        assert!(MultiMarketArbitrageDetector::new(config).detect(&markets).is_empty());
+    }
+
+    #[test]
+    fn test_skips_same_market_and_empty_books() {
+        let detector = MultiMarketArbitrageDetector::new(ArbitrageConfig::default());
+
+        // Crossed book within one market is not a cross-market opportunity
+        let mut crossed = market("venue-a", 0.75, 0.70, 100.0);
+        assert!(detector.detect(std::slice::from_ref(&crossed)).is_empty());
+
+        crossed.2.bids.clear();
+        let markets = vec![crossed, market("venue-b", 0.60, 0.62, 100.0)];
+        assert!(detector.detect(&markets).is_empty());
+        assert!(detector.detect(&[]).is_empty());
+    }
+}
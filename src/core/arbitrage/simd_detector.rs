@@ -1,7 +1,8 @@
 //! SIMD-optimized arbitrage detection
 //!
 //! Uses SIMD vectorization to detect arbitrage opportunities in parallel.
-//! Processes 4 order books simultaneously for 4x speedup.
+//! Processes 4 order books simultaneously for 4x speedup, or 8 as two
+//! f64x4 passes to keep both SIMD pipelines busy.
 //! Phase 7b.2: Now uses fixed-point arithmetic with u64x4 for 3x faster math!
 
 use crate::types::{ArbitrageOpportunity, MarketId, OrderBook, TokenId};
@@ -34,28 +35,36 @@ impl SimdArbitrageDetector {
         &self,
         markets: &[(MarketId, TokenId, OrderBook); 4],
     ) -> [Option<ArbitrageOpportunity>; 4] {
-        // Extract bid/ask prices and convert to fixed-point
-        let mut bid_raw = [0u64; 4];
-        let mut ask_raw = [0u64; 4];
+        // Fast path: nothing to detect in empty books (backtest/replay gaps)
+        if !Self::any_tradeable(markets) {
+            return [None, None, None, None];
+        }
+
+        // Extract bid/ask prices (or sentinel values)
+        let mut prices = [0.0f64; 8];
         let mut bid_size = [0.0f64; 4];
         let mut ask_size = [0.0f64; 4];
 
         for i in 0..4 {
             let (_, _, order_book) = &markets[i];
 
-            // Convert to fixed-point (or use sentinel values)
-            bid_raw[i] = order_book.best_bid()
-                .map(|b| FixedPrice::from_f64(b.price).raw())
-                .unwrap_or(0); // 0 if no bid
-
-            ask_raw[i] = order_book.best_ask()
-                .map(|a| FixedPrice::from_f64(a.price).raw())
-                .unwrap_or(FixedPrice::from_f64(1.0).raw()); // 1.0 if no ask
-
-            bid_size[i] = order_book.best_bid().map(|b| b.size).unwrap_or(0.0);
-            ask_size[i] = order_book.best_ask().map(|a| a.size).unwrap_or(0.0);
+            // One-sided books get bid 0 / ask 1.0, which never cross
+            if let Some((bid, ask)) = order_book.bbo() {
+                prices[i] = bid.price;
+                prices[4 + i] = ask.price;
+                bid_size[i] = bid.size;
+                ask_size[i] = ask.size;
+            } else {
+                prices[4 + i] = 1.0;
+            }
         }
 
+        // Convert all 8 prices to fixed-point at once
+        let mut fixed = [FixedPrice::ZERO; 8];
+        FixedPrice::batch_from_f64_into(&prices, &mut fixed);
+        let bid_raw: [u64; 4] = std::array::from_fn(|i| fixed[i].raw());
+        let ask_raw: [u64; 4] = std::array::from_fn(|i| fixed[4 + i].raw());
+
         // Load into SIMD vectors (4 u64 values at once)
         let bid_vec = u64x4::new(bid_raw);
         let ask_vec = u64x4::new(ask_raw);
@@ -69,7 +78,7 @@ impl SimdArbitrageDetector {
 
         // Convert config thresholds to fixed-point
         let max_spread_raw = FixedPrice::from_f64(self.config.max_spread).raw();
-        let min_profit_raw = FixedPrice::from_f64(self.config.min_profit_margin).raw();
+        let min_profit_raw = FixedPrice::from_f64(self.config.effective_min_margin()).raw();
 
         // Extract back to scalar for detailed processing
         let has_arb_array: [u64; 4] = has_arbitrage.into();
@@ -110,14 +119,17 @@ impl SimdArbitrageDetector {
             }
 
             // Create opportunity
-            let (market_id, token_id, _) = &markets[i];
+            let (market_id, token_id, order_book) = &markets[i];
             opportunities[i] = ArbitrageOpportunity::new(
                 market_id.clone(),
                 token_id.clone(),
                 bid_fixed.to_f64(),
                 ask_fixed.to_f64(),
                 max_size,
-            );
+            )
+            .map(|opportunity| {
+                opportunity.with_fee_rate(self.config.fee_rate_bps).with_liquidity(order_book)
+            });
         }
 
         opportunities
@@ -133,21 +145,15 @@ impl SimdArbitrageDetector {
         &self,
         markets: &[(MarketId, TokenId, OrderBook); 4],
     ) -> [Option<ArbitrageOpportunity>; 4] {
-        // Extract bid prices (or 0.0 if no bid)
-        let bid_prices = f64x4::new([
-            markets[0].2.best_bid().map(|b| b.price).unwrap_or(0.0),
-            markets[1].2.best_bid().map(|b| b.price).unwrap_or(0.0),
-            markets[2].2.best_bid().map(|b| b.price).unwrap_or(0.0),
-            markets[3].2.best_bid().map(|b| b.price).unwrap_or(0.0),
-        ]);
-
-        // Extract ask prices (or 1.0 if no ask)
-        let ask_prices = f64x4::new([
-            markets[0].2.best_ask().map(|a| a.price).unwrap_or(1.0),
-            markets[1].2.best_ask().map(|a| a.price).unwrap_or(1.0),
-            markets[2].2.best_ask().map(|a| a.price).unwrap_or(1.0),
-            markets[3].2.best_ask().map(|a| a.price).unwrap_or(1.0),
-        ]);
+        // Fast path: nothing to detect in empty books (backtest/replay gaps)
+        if !Self::any_tradeable(markets) {
+            return [None, None, None, None];
+        }
+
+        // Extract bid/ask prices (0.0 / 1.0 for one-sided books, which never cross)
+        let bbo = markets.each_ref().map(|(_, _, order_book)| order_book.bbo());
+        let bid_prices = f64x4::new(bbo.map(|bbo| bbo.map_or(0.0, |(bid, _)| bid.price)));
+        let ask_prices = f64x4::new(bbo.map(|bbo| bbo.map_or(1.0, |(_, ask)| ask.price)));
 
         // Calculate spreads (bid - ask) for all 4 simultaneously
         let spreads = bid_prices - ask_prices;
@@ -173,7 +179,7 @@ impl SimdArbitrageDetector {
             }
 
             // Check if meets minimum profit threshold
-            if margin_array[i] < self.config.min_profit_margin {
+            if margin_array[i] < self.config.effective_min_margin() {
                 continue;
             }
 
@@ -183,9 +189,7 @@ impl SimdArbitrageDetector {
             }
 
             // Get sizes
-            let bid_size = order_book.best_bid().map(|b| b.size).unwrap_or(0.0);
-            let ask_size = order_book.best_ask().map(|a| a.size).unwrap_or(0.0);
-            let max_size = bid_size.min(ask_size);
+            let max_size = bbo[i].map_or(0.0, |(bid, ask)| bid.size.min(ask.size));
 
             // Check minimum size
             if max_size < self.config.min_size {
@@ -199,7 +203,89 @@ impl SimdArbitrageDetector {
                 bid_array[i],
                 ask_array[i],
                 max_size,
-            );
+            )
+            .map(|opportunity| {
+                opportunity.with_fee_rate(self.config.fee_rate_bps).with_liquidity(order_book)
+            });
+        }
+
+        opportunities
+    }
+
+    /// Detect arbitrage opportunities from 8 order books simultaneously (F64 VERSION)
+    ///
+    /// Same checks as `detect_batch_simd`, computed as two independent f64x4
+    /// passes that the CPU can interleave.
+    pub fn detect_batch_simd_8(
+        &self,
+        markets: &[(MarketId, TokenId, OrderBook); 8],
+    ) -> [Option<ArbitrageOpportunity>; 8] {
+        // Fast path: nothing to detect in empty books (backtest/replay gaps)
+        if !Self::any_tradeable(markets) {
+            return Default::default();
+        }
+
+        // Extract bid/ask prices (0.0 / 1.0 for one-sided books, which never cross)
+        let bbo = markets.each_ref().map(|(_, _, order_book)| order_book.bbo());
+        let bid_all = bbo.map(|bbo| bbo.map_or(0.0, |(bid, _)| bid.price));
+        let ask_all = bbo.map(|bbo| bbo.map_or(1.0, |(_, ask)| ask.price));
+        let half = |prices: &[f64; 8], offset: usize| {
+            f64x4::new(std::array::from_fn(|i| prices[offset + i]))
+        };
+        let (bid_lo, bid_hi) = (half(&bid_all, 0), half(&bid_all, 4));
+        let (ask_lo, ask_hi) = (half(&ask_all, 0), half(&ask_all, 4));
+
+        // Spreads and profit margins for both halves
+        let (spread_lo, spread_hi) = (bid_lo - ask_lo, bid_hi - ask_hi);
+        let (margin_lo, margin_hi) = (spread_lo / ask_lo, spread_hi / ask_hi);
+
+        // Merge both halves back into 8 lanes
+        let merge = |lo: f64x4, hi: f64x4| {
+            let (lo, hi): ([f64; 4], [f64; 4]) = (lo.into(), hi.into());
+            let merged: [f64; 8] = std::array::from_fn(|i| if i < 4 { lo[i] } else { hi[i - 4] });
+            merged
+        };
+        let spread_array = merge(spread_lo, spread_hi);
+        let margin_array = merge(margin_lo, margin_hi);
+
+        // Create opportunities for valid arbitrage
+        let mut opportunities: [Option<ArbitrageOpportunity>; 8] = Default::default();
+
+        for i in 0..8 {
+            let (market_id, token_id, order_book) = &markets[i];
+
+            // Check if arbitrage exists (bid > ask)
+            if spread_array[i] <= 0.0 {
+                continue;
+            }
+
+            // Check if meets minimum profit threshold
+            if margin_array[i] < self.config.effective_min_margin() {
+                continue;
+            }
+
+            // Sanity check: reject unrealistic spreads
+            if spread_array[i] > self.config.max_spread {
+                continue;
+            }
+
+            // Check minimum size
+            let max_size = bbo[i].map_or(0.0, |(bid, ask)| bid.size.min(ask.size));
+            if max_size < self.config.min_size {
+                continue;
+            }
+
+            // Create opportunity
+            opportunities[i] = ArbitrageOpportunity::new(
+                market_id.clone(),
+                token_id.clone(),
+                bid_all[i],
+                ask_all[i],
+                max_size,
+            )
+            .map(|opportunity| {
+                opportunity.with_fee_rate(self.config.fee_rate_bps).with_liquidity(order_book)
+            });
         }
 
         opportunities
@@ -207,7 +293,8 @@ impl SimdArbitrageDetector {
 
     /// Detect opportunities from any number of order books
     ///
-    /// Processes in batches of 4 using SIMD, falls back to scalar for remainder.
+    /// Processes in batches of 8, then 4, using SIMD, falls back to scalar for remainder.
+    /// Tokens in `ArbitrageConfig::excluded_token_ids` are skipped.
     ///
     /// # Performance
     /// Uses f64x4 SIMD (32ns for 4 = 8ns each) which is faster than u64x4 (56ns for 4 = 14ns each)
@@ -216,8 +303,24 @@ impl SimdArbitrageDetector {
     pub fn detect_batch(&self, markets: &[(MarketId, TokenId, OrderBook)]) -> Vec<ArbitrageOpportunity> {
         let mut opportunities = Vec::new();
 
-        // Process in chunks of 4
-        for chunk in markets.chunks(4) {
+        // Drop excluded tokens before they reach the SIMD lanes
+        let markets: Vec<&(MarketId, TokenId, OrderBook)> = markets
+            .iter()
+            .filter(|(_, token_id, _)| !self.config.is_excluded(token_id))
+            .collect();
+
+        // Process in chunks of 8 (two f64x4 passes)
+        let mut wide_chunks = markets.chunks_exact(8);
+        for chunk in &mut wide_chunks {
+            let batch: [(MarketId, TokenId, OrderBook); 8] =
+                std::array::from_fn(|i| chunk[i].clone());
+
+            let results = self.detect_batch_simd_8(&batch);
+            opportunities.extend(results.into_iter().flatten());
+        }
+
+        // Then chunks of 4
+        for chunk in wide_chunks.remainder().chunks(4) {
             if chunk.len() == 4 {
                 // SIMD path (4 at once) - using f64x4 for better performance
                 let batch: [(MarketId, TokenId, OrderBook); 4] = [
@@ -242,6 +345,57 @@ impl SimdArbitrageDetector {
         opportunities
     }
 
+    /// Detect opportunities across a large slice of order books in parallel
+    ///
+    /// Splits the (non-excluded) markets into chunks of 4, runs
+    /// `detect_batch_simd` on each across rayon's thread pool, and returns the
+    /// opportunities sorted by `profit_margin`, best first. Only worth it for
+    /// slices in the hundreds or more - see `parallel_detector_bench`.
+    #[cfg(feature = "rayon")]
+    pub fn detect_batch_parallel(
+        &self,
+        markets: &[(MarketId, TokenId, OrderBook)],
+    ) -> Vec<ArbitrageOpportunity> {
+        use rayon::prelude::*;
+
+        let markets: Vec<&(MarketId, TokenId, OrderBook)> = markets
+            .iter()
+            .filter(|(_, token_id, _)| !self.config.is_excluded(token_id))
+            .collect();
+
+        let mut opportunities: Vec<ArbitrageOpportunity> = markets
+            .par_chunks(4)
+            .flat_map_iter(|chunk| -> Vec<ArbitrageOpportunity> {
+                match <&[_; 4]>::try_from(chunk) {
+                    Ok(chunk) => {
+                        let batch = chunk.map(|market| market.clone());
+                        self.detect_batch_simd(&batch).into_iter().flatten().collect()
+                    }
+                    Err(_) => chunk
+                        .iter()
+                        .filter_map(|(market_id, token_id, order_book)| {
+                            self.detect_scalar(market_id, token_id, order_book)
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        opportunities.sort_by(|a, b| b.profit_margin.total_cmp(&a.profit_margin));
+        opportunities
+    }
+
+    /// Check whether any of the N books has both bids and asks
+    ///
+    /// Uses non-short-circuit `|`/`&` so all N slots are checked with a single
+    /// branch at the end instead of one branch per slot.
+    #[inline(always)]
+    fn any_tradeable<const N: usize>(markets: &[(MarketId, TokenId, OrderBook); N]) -> bool {
+        markets.iter().fold(false, |any, (_, _, book)| {
+            any | ((book.bid_levels() > 0) & (book.ask_levels() > 0))
+        })
+    }
+
     /// Scalar fallback for single detection
     fn detect_scalar(
         &self,
@@ -249,8 +403,7 @@ impl SimdArbitrageDetector {
         token_id: &TokenId,
         order_book: &OrderBook,
     ) -> Option<ArbitrageOpportunity> {
-        let best_bid = order_book.best_bid()?;
-        let best_ask = order_book.best_ask()?;
+        let (best_bid, best_ask) = order_book.bbo()?;
 
         if best_bid.price <= best_ask.price {
             return None;
@@ -259,7 +412,7 @@ impl SimdArbitrageDetector {
         let spread = best_bid.price - best_ask.price;
         let profit_margin = spread / best_ask.price;
 
-        if spread > self.config.max_spread || profit_margin < self.config.min_profit_margin {
+        if spread > self.config.max_spread || profit_margin < self.config.effective_min_margin() {
             return None;
         }
 
@@ -275,6 +428,9 @@ impl SimdArbitrageDetector {
             best_ask.price,
             max_size,
         )
+        .map(|opportunity| {
+            opportunity.with_fee_rate(self.config.fee_rate_bps).with_liquidity(order_book)
+        })
     }
 }
 
@@ -307,25 +463,25 @@ mod tests {
         let markets: [(MarketId, TokenId, OrderBook); 4] = [
             // Arbitrage opportunity
             (
-                MarketId("m1".to_string()),
+                MarketId::new("m1"),
                 TokenId("t1".to_string()),
                 create_test_order_book(0.75, 0.70, 100.0),
             ),
             // No arbitrage (normal market)
             (
-                MarketId("m2".to_string()),
+                MarketId::new("m2"),
                 TokenId("t2".to_string()),
                 create_test_order_book(0.70, 0.75, 100.0),
             ),
             // Arbitrage opportunity
             (
-                MarketId("m3".to_string()),
+                MarketId::new("m3"),
                 TokenId("t3".to_string()),
                 create_test_order_book(0.80, 0.75, 100.0),
             ),
             // No arbitrage (equal prices)
             (
-                MarketId("m4".to_string()),
+                MarketId::new("m4"),
                 TokenId("t4".to_string()),
                 create_test_order_book(0.72, 0.72, 100.0),
             ),
@@ -347,6 +503,33 @@ mod tests {
         assert!((opp3.profit_margin - 0.0667).abs() < 0.001);
     }
 
+    #[test]
+    fn test_simd_paths_apply_fees() {
+        // 2% + 2 * 2.5% fees = 7%: only the 7.14% market clears it
+        let config = ArbitrageConfig { fee_rate_bps: 250, ..ArbitrageConfig::default() };
+        let detector = SimdArbitrageDetector::new(config);
+        let market = |id: &str, bid, ask| {
+            let order_book = create_test_order_book(bid, ask, 100.0);
+            (MarketId::new(id), TokenId(id.to_string()), order_book)
+        };
+        let markets = [
+            market("m1", 0.75, 0.70),
+            market("m2", 0.80, 0.75),
+            market("m3", 0.70, 0.75),
+            market("m4", 0.72, 0.72),
+        ];
+
+        let fixed = detector.detect_batch_simd_fixed(&markets);
+        for results in [fixed, detector.detect_batch_simd(&markets)] {
+            let found: Vec<bool> = results.iter().map(Option::is_some).collect();
+            assert_eq!(found, [true, false, false, false]);
+
+            // $5 spread - 2.5% of $145 notional
+            let opp = results[0].as_ref().unwrap();
+            assert!((opp.expected_profit - 1.375).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn test_simd_batch_detection() {
         let detector = SimdArbitrageDetector::new(ArbitrageConfig::default());
@@ -354,25 +537,25 @@ mod tests {
         let markets: [(MarketId, TokenId, OrderBook); 4] = [
             // Arbitrage opportunity
             (
-                MarketId("m1".to_string()),
+                MarketId::new("m1"),
                 TokenId("t1".to_string()),
                 create_test_order_book(0.75, 0.70, 100.0),
             ),
             // No arbitrage (normal market)
             (
-                MarketId("m2".to_string()),
+                MarketId::new("m2"),
                 TokenId("t2".to_string()),
                 create_test_order_book(0.70, 0.75, 100.0),
             ),
             // Arbitrage opportunity
             (
-                MarketId("m3".to_string()),
+                MarketId::new("m3"),
                 TokenId("t3".to_string()),
                 create_test_order_book(0.80, 0.75, 100.0),
             ),
             // No arbitrage (equal prices)
             (
-                MarketId("m4".to_string()),
+                MarketId::new("m4"),
                 TokenId("t4".to_string()),
                 create_test_order_book(0.72, 0.72, 100.0),
             ),
@@ -394,6 +577,77 @@ mod tests {
         assert!((opp3.profit_margin - 0.0667).abs() < 0.001);
     }
 
+    #[test]
+    fn test_simd_8_matches_simd_4() {
+        let config = ArbitrageConfig { fee_rate_bps: 100, ..ArbitrageConfig::default() };
+        let detector = SimdArbitrageDetector::new(config);
+        let prices = [
+            (0.75, 0.70),
+            (0.70, 0.75),
+            (0.80, 0.75),
+            (0.72, 0.72),
+            (0.51, 0.50),
+            (0.95, 0.40),
+            (0.66, 0.61),
+            (0.30, 0.25),
+        ];
+        let mut markets: [(MarketId, TokenId, OrderBook); 8] = std::array::from_fn(|i| {
+            let (bid, ask) = prices[i];
+            let id = format!("m{}", i);
+            (MarketId::new(id.clone()), TokenId(id), create_test_order_book(bid, ask, 100.0))
+        });
+        markets[6].2.asks.clear();
+
+        let wide = detector.detect_batch_simd_8(&markets);
+        let lo = detector.detect_batch_simd(markets[..4].try_into().unwrap());
+        let hi = detector.detect_batch_simd(markets[4..].try_into().unwrap());
+        let narrow: Vec<&Option<ArbitrageOpportunity>> = lo.iter().chain(hi.iter()).collect();
+
+        let found: Vec<bool> = wide.iter().map(Option::is_some).collect();
+        assert_eq!(found, [true, false, true, false, false, false, false, true]);
+        for (wide, narrow) in wide.iter().zip(narrow) {
+            assert_eq!(
+                wide.as_ref().map(|opp| (&opp.token_id, opp.expected_profit)),
+                narrow.as_ref().map(|opp| (&opp.token_id, opp.expected_profit))
+            );
+        }
+
+        // 8 + 4 + 1 markets: every path agrees with the scalar detector
+        let markets: Vec<_> = markets.iter().chain(&markets[..5]).cloned().collect();
+        let scalar = super::super::ScalarArbitrageDetector::new(detector.config.clone());
+        assert_eq!(detector.detect_batch(&markets).len(), scalar.detect_batch(&markets).len());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_detect_batch_parallel() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<SimdArbitrageDetector>();
+
+        let mut config = ArbitrageConfig::default();
+        config.exclude_token(TokenId("t3".to_string()));
+        let detector = SimdArbitrageDetector::new(config);
+
+        // 1,003 markets: the last 3 (after exclusion) take the scalar path
+        let markets: Vec<_> = (0..1_004)
+            .map(|i| {
+                let bid = [0.70, 0.74, 0.75, 0.80][i % 4];
+                let id = format!("t{}", i);
+                (MarketId::new(id.clone()), TokenId(id), create_test_order_book(bid, 0.70, 100.0))
+            })
+            .collect();
+
+        let parallel = detector.detect_batch_parallel(&markets);
+        let sequential = detector.detect_batch(&markets);
+        assert_eq!(parallel.len(), sequential.len());
+        assert_eq!(parallel.len(), 3 * 251 - 1);
+        assert!(parallel.iter().all(|opp| opp.token_id.0 != "t3"));
+        assert!(parallel
+            .windows(2)
+            .all(|pair| pair[0].profit_margin >= pair[1].profit_margin));
+        assert!((parallel[0].profit_margin - 0.1 / 0.7).abs() < 1e-9);
+    }
+
     #[test]
     fn test_simd_vs_scalar_equivalence() {
         let config = ArbitrageConfig::default();
@@ -402,22 +656,22 @@ mod tests {
         // Create test data
         let markets_vec = vec![
             (
-                MarketId("m1".to_string()),
+                MarketId::new("m1"),
                 TokenId("t1".to_string()),
                 create_test_order_book(0.75, 0.70, 100.0),
             ),
             (
-                MarketId("m2".to_string()),
+                MarketId::new("m2"),
                 TokenId("t2".to_string()),
                 create_test_order_book(0.70, 0.75, 100.0),
             ),
             (
-                MarketId("m3".to_string()),
+                MarketId::new("m3"),
                 TokenId("t3".to_string()),
                 create_test_order_book(0.80, 0.75, 100.0),
             ),
             (
-                MarketId("m4".to_string()),
+                MarketId::new("m4"),
                 TokenId("t4".to_string()),
                 create_test_order_book(0.72, 0.72, 100.0),
             ),
@@ -435,13 +689,35 @@ mod tests {
         assert_eq!(simd_results.len(), scalar_results.len());
     }
 
+    #[test]
+    fn test_detect_batch_skips_excluded_tokens() {
+        let mut config = ArbitrageConfig::default();
+        config.exclude_token(TokenId("t2".to_string()));
+        let detector = SimdArbitrageDetector::new(config);
+
+        // 5 arbitrage books: 4 after exclusion fill exactly one SIMD batch
+        let markets: Vec<(MarketId, TokenId, OrderBook)> = (1..=5)
+            .map(|i| {
+                (
+                    MarketId::new(format!("m{}", i)),
+                    TokenId(format!("t{}", i)),
+                    create_test_order_book(0.75, 0.70, 100.0),
+                )
+            })
+            .collect();
+
+        let results = detector.detect_batch(&markets);
+        let tokens: Vec<&str> = results.iter().map(|opp| opp.token_id.0.as_str()).collect();
+        assert_eq!(tokens, vec!["t1", "t3", "t4", "t5"]);
+    }
+
     #[test]
     fn test_simd_with_empty_books() {
         let detector = SimdArbitrageDetector::new(ArbitrageConfig::default());
 
         let markets: [(MarketId, TokenId, OrderBook); 4] = [
             (
-                MarketId("m1".to_string()),
+                MarketId::new("m1"),
                 TokenId("t1".to_string()),
                 OrderBook {
                     token_id: TokenId("t1".to_string()),
@@ -451,12 +727,12 @@ mod tests {
                 },
             ),
             (
-                MarketId("m2".to_string()),
+                MarketId::new("m2"),
                 TokenId("t2".to_string()),
                 create_test_order_book(0.75, 0.70, 100.0),
             ),
             (
-                MarketId("m3".to_string()),
+                MarketId::new("m3"),
                 TokenId("t3".to_string()),
                 OrderBook {
                     token_id: TokenId("t3".to_string()),
@@ -466,7 +742,7 @@ mod tests {
                 },
             ),
             (
-                MarketId("m4".to_string()),
+                MarketId::new("m4"),
                 TokenId("t4".to_string()),
                 create_test_order_book(0.80, 0.75, 100.0),
             ),
@@ -480,4 +756,38 @@ mod tests {
         assert!(results[2].is_none());
         assert!(results[3].is_some());
     }
+
+    #[test]
+    fn test_simd_all_empty_books_fast_path() {
+        let detector = SimdArbitrageDetector::new(ArbitrageConfig::default());
+
+        // One-sided books can never be arbitrage either
+        let one_sided = OrderBook {
+            asks: vec![],
+            ..create_test_order_book(0.75, 0.70, 100.0)
+        };
+        let empty = OrderBook {
+            bids: vec![],
+            asks: vec![],
+            ..create_test_order_book(0.75, 0.70, 100.0)
+        };
+
+        let mut markets: [(MarketId, TokenId, OrderBook); 4] = [
+            (MarketId::new("m1"), TokenId("t1".to_string()), empty.clone()),
+            (MarketId::new("m2"), TokenId("t2".to_string()), one_sided),
+            (MarketId::new("m3"), TokenId("t3".to_string()), empty.clone()),
+            (MarketId::new("m4"), TokenId("t4".to_string()), empty),
+        ];
+
+        assert!(!SimdArbitrageDetector::any_tradeable(&markets));
+        assert!(detector.detect_batch_simd(&markets).iter().all(Option::is_none));
+        assert!(detector.detect_batch_simd_fixed(&markets).iter().all(Option::is_none));
+        assert!(detector.detect_batch(&markets).is_empty());
+
+        // A single tradeable book disables the fast path
+        markets[3].2 = create_test_order_book(0.75, 0.70, 100.0);
+        assert!(SimdArbitrageDetector::any_tradeable(&markets));
+        assert!(detector.detect_batch_simd(&markets)[3].is_some());
+        assert!(detector.detect_batch_simd_fixed(&markets)[3].is_some());
+    }
 }
@@ -0,0 +1,157 @@
+//! Execution hooks and built-in implementations
+
+use anyhow::Result;
+use async_trait::async_trait;
+use prometheus::{IntCounter, IntCounterVec, Opts, Registry};
+use tracing;
+
+use crate::clob::ExecutionResult;
+use crate::types::ArbitrageOpportunity;
+
+/// Callback run before and after every pipeline execution
+///
+/// Implement this trait for custom alerts or logging. Both methods default
+/// to doing nothing; errors are logged by the pipeline and otherwise ignored.
+#[async_trait]
+pub trait ExecutionHook: Send + Sync {
+    /// Hook name for logging
+    fn name(&self) -> &str;
+
+    /// Called before the opportunity is submitted
+    async fn pre_execute(&self, _opportunity: &ArbitrageOpportunity) -> Result<()> {
+        Ok(())
+    }
+
+    /// Called with the execution result
+    async fn post_execute(
+        &self,
+        _opportunity: &ArbitrageOpportunity,
+        _result: &ExecutionResult,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Logs every execution and its outcome
+#[derive(Debug, Default)]
+pub struct LoggingHook;
+
+#[async_trait]
+impl ExecutionHook for LoggingHook {
+    fn name(&self) -> &str {
+        "logging"
+    }
+
+    async fn pre_execute(&self, opportunity: &ArbitrageOpportunity) -> Result<()> {
+        tracing::info!(
+            "Executing {}/{}: size={:.2} expected_profit=${:.2}",
+            opportunity.market_id,
+            opportunity.token_id.0,
+            opportunity.max_size,
+            opportunity.expected_profit
+        );
+        Ok(())
+    }
+
+    async fn post_execute(
+        &self,
+        opportunity: &ArbitrageOpportunity,
+        result: &ExecutionResult,
+    ) -> Result<()> {
+        match result {
+            ExecutionResult::Success { pnl, latency_ms, .. } => tracing::info!(
+                "Executed {}: pnl=${:.2} in {}ms",
+                opportunity.market_id,
+                pnl,
+                latency_ms
+            ),
+            ExecutionResult::PartialFill { rolled_back, .. } => tracing::warn!(
+                "Partial fill on {} (rolled back: {})",
+                opportunity.market_id,
+                rolled_back
+            ),
+            ExecutionResult::Failed { error, .. } => {
+                tracing::warn!("Execution failed on {}: {}", opportunity.market_id, error)
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Prometheus counters for pipeline executions
+///
+/// - `pipeline_executions_started_total`
+/// - `pipeline_executions_total{outcome="success|partial_fill|failed"}`
+pub struct MetricsHook {
+    /// Executions started
+    started: IntCounter,
+
+    /// Executions finished, by outcome
+    finished: IntCounterVec,
+}
+
+impl MetricsHook {
+    /// Create hook with unregistered counters
+    pub fn new() -> Self {
+        Self {
+            started: IntCounter::new(
+                "pipeline_executions_started_total",
+                "Arbitrage executions started",
+            )
+            .expect("valid counter definition"),
+            finished: IntCounterVec::new(
+                Opts::new("pipeline_executions_total", "Arbitrage executions by outcome"),
+                &["outcome"],
+            )
+            .expect("valid counter definition"),
+        }
+    }
+
+    /// Register counters with a Prometheus registry
+    pub fn register_metrics(&self, registry: &Registry) -> prometheus::Result<()> {
+        registry.register(Box::new(self.started.clone()))?;
+        registry.register(Box::new(self.finished.clone()))
+    }
+
+    /// Executions started
+    pub fn started(&self) -> u64 {
+        self.started.get()
+    }
+
+    /// Executions finished with `outcome` ("success", "partial_fill" or "failed")
+    pub fn finished(&self, outcome: &str) -> u64 {
+        self.finished.with_label_values(&[outcome]).get()
+    }
+}
+
+impl Default for MetricsHook {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ExecutionHook for MetricsHook {
+    fn name(&self) -> &str {
+        "metrics"
+    }
+
+    async fn pre_execute(&self, _opportunity: &ArbitrageOpportunity) -> Result<()> {
+        self.started.inc();
+        Ok(())
+    }
+
+    async fn post_execute(
+        &self,
+        _opportunity: &ArbitrageOpportunity,
+        result: &ExecutionResult,
+    ) -> Result<()> {
+        let outcome = match result {
+            ExecutionResult::Success { .. } => "success",
+            ExecutionResult::PartialFill { .. } => "partial_fill",
+            ExecutionResult::Failed { .. } => "failed",
+        };
+        self.finished.with_label_values(&[outcome]).inc();
+        Ok(())
+    }
+}
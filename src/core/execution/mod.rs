@@ -1,2 +1,246 @@
-// Order execution module
-// TODO: Implement order executor
+//! Execution pipeline with pre/post-execution hooks
+//!
+//! Wraps `ArbitrageExecutor` so operators can plug in custom logic
+//! (alerts, logging, metrics) without forking the executor.
+//!
+//! # Flow
+//! ```text
+//! pre_execute  (all hooks, concurrently)
+//!     ↓
+//! ArbitrageExecutor::execute
+//!     ↓
+//! post_execute (all hooks, concurrently)
+//! ```
+//!
+//! Hook errors are logged and never change the execution result. With a
+//! [`FeatureStore`], executions are skipped while `arbitrage_enabled` is off.
+//!
+//! [`OrderRouter`] decides whether the executor batches an opportunity's
+//! legs or submits them one at a time.
+
+mod hooks;
+mod router;
+
+pub use hooks::{ExecutionHook, LoggingHook, MetricsHook};
+pub use router::{ExecutionStrategy, OrderRouter, DEFAULT_MIN_DEPTH_RATIO};
+
+use anyhow::Result;
+use futures_util::future::join_all;
+use std::sync::Arc;
+use tracing;
+
+use crate::clob::{ArbitrageExecutor, ExecutionResult};
+use crate::config::features::{FeatureStore, ARBITRAGE_ENABLED};
+use crate::types::ArbitrageOpportunity;
+
+/// Arbitrage execution with pluggable hooks
+pub struct ExecutionPipeline {
+    /// Underlying executor
+    executor: Arc<ArbitrageExecutor>,
+
+    /// Hooks run around every execution
+    hooks: Vec<Arc<dyn ExecutionHook>>,
+
+    /// Runtime feature flags (None = always enabled)
+    feature_store: Option<Arc<FeatureStore>>,
+}
+
+impl ExecutionPipeline {
+    /// Create pipeline without hooks
+    pub fn new(executor: Arc<ArbitrageExecutor>) -> Self {
+        Self {
+            executor,
+            hooks: Vec::new(),
+            feature_store: None,
+        }
+    }
+
+    /// Check `arbitrage_enabled` in `feature_store` before every execution
+    pub fn with_feature_store(mut self, feature_store: Arc<FeatureStore>) -> Self {
+        self.feature_store = Some(feature_store);
+        self
+    }
+
+    /// Add pre/post-execution hooks (appended to any existing ones)
+    pub fn with_hooks(mut self, hooks: Vec<Arc<dyn ExecutionHook>>) -> Self {
+        self.hooks.extend(hooks);
+        self
+    }
+
+    /// Number of registered hooks
+    pub fn hook_count(&self) -> usize {
+        self.hooks.len()
+    }
+
+    /// Underlying executor
+    pub fn executor(&self) -> &Arc<ArbitrageExecutor> {
+        &self.executor
+    }
+
+    /// Execute an opportunity, running hooks before and after
+    ///
+    /// Returns `Failed` without running hooks when arbitrage is disabled.
+    pub async fn execute(&self, opportunity: &ArbitrageOpportunity) -> Result<ExecutionResult> {
+        if let Some(store) = &self.feature_store {
+            if !store.get(ARBITRAGE_ENABLED) {
+                tracing::debug!("Skipping {}: arbitrage disabled", opportunity.market_id);
+                return Ok(ExecutionResult::Failed {
+                    error: "Arbitrage disabled by feature flag".to_string(),
+                    latency_ms: 0,
+                });
+            }
+        }
+
+        let pre = join_all(self.hooks.iter().map(|hook| hook.pre_execute(opportunity))).await;
+        self.log_hook_errors("pre_execute", pre);
+
+        let result = self.executor.execute(opportunity).await?;
+
+        let post = join_all(
+            self.hooks.iter().map(|hook| hook.post_execute(opportunity, &result)),
+        )
+        .await;
+        self.log_hook_errors("post_execute", post);
+
+        Ok(result)
+    }
+
+    /// Log hook failures (results are in hook registration order)
+    fn log_hook_errors(&self, stage: &str, results: Vec<Result<()>>) {
+        for (hook, result) in self.hooks.iter().zip(results) {
+            if let Err(e) = result {
+                tracing::warn!("Execution hook '{}' failed in {}: {}", hook.name(), stage, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clob::{ClobClient, ClobConfig};
+    use crate::core::risk::CircuitBreaker;
+    use crate::types::{MarketId, RiskConfig, TokenId};
+    use anyhow::anyhow;
+    use async_trait::async_trait;
+    use parking_lot::Mutex;
+
+    /// Records hook calls; optionally fails every call
+    struct RecordingHook {
+        calls: Mutex<Vec<String>>,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl ExecutionHook for RecordingHook {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        async fn pre_execute(&self, opportunity: &ArbitrageOpportunity) -> Result<()> {
+            self.calls.lock().push(format!("pre:{}", opportunity.market_id));
+            if self.fail {
+                return Err(anyhow!("hook failure"));
+            }
+            Ok(())
+        }
+
+        async fn post_execute(
+            &self,
+            _opportunity: &ArbitrageOpportunity,
+            result: &ExecutionResult,
+        ) -> Result<()> {
+            self.calls.lock().push(format!("post:{}", result.is_success()));
+            if self.fail {
+                return Err(anyhow!("hook failure"));
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hooks_run_around_execution() {
+        let config = ClobConfig {
+            private_key: "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+                .to_string(),
+            ..ClobConfig::default()
+        };
+        let client = Arc::new(ClobClient::new(config).unwrap());
+        let cb = Arc::new(CircuitBreaker::new(RiskConfig {
+            max_daily_loss: 100.0,
+            max_position_size: 50.0,
+            max_loss_per_trade: 100.0,
+            max_open_positions: 10,
+            min_usdc_balance: 10.0,
+            min_matic_balance: 1.0,
+            max_consecutive_errors: 5,
+            risk_score_alert_threshold: 70.0,
+            fee_rate_bps: 100,
+            max_anomalies_before_skip: 3,
+            max_concentration: 0.5,
+            max_loss_in_window: 50.0,
+            loss_window_secs: 3600,
+        }));
+        // Tripped breaker: execution fails without any network request
+        cb.trip();
+        let executor = Arc::new(ArbitrageExecutor::new(client, cb, 100));
+
+        let ok_hook = Arc::new(RecordingHook { calls: Mutex::new(Vec::new()), fail: false });
+        let failing_hook = Arc::new(RecordingHook { calls: Mutex::new(Vec::new()), fail: true });
+        let metrics = Arc::new(MetricsHook::new());
+        metrics.register_metrics(&prometheus::Registry::new()).unwrap();
+        let pipeline = ExecutionPipeline::new(executor.clone()).with_hooks(vec![
+            ok_hook.clone(),
+            failing_hook.clone(),
+            Arc::new(LoggingHook),
+            metrics.clone(),
+        ]);
+        assert_eq!(pipeline.hook_count(), 4);
+
+        let opportunity = ArbitrageOpportunity {
+            market_id: MarketId::new("TRUMP-WIN"),
+            token_id: TokenId("YES".to_string()),
+            bid_price: 0.75,
+            ask_price: 0.70,
+            max_size: 100.0,
+            profit_margin: 0.0714,
+            expected_profit: 5.0,
+            detected_at: 1000,
+            liquidity_score: 1.0,
+        };
+
+        // Failing hooks do not affect the result
+        let result = pipeline.execute(&opportunity).await.unwrap();
+        match result {
+            ExecutionResult::Failed { error, .. } => assert_eq!(error, "Circuit breaker tripped"),
+            other => panic!("Expected failure, got {:?}", other),
+        }
+
+        let expected = vec!["pre:TRUMP-WIN".to_string(), "post:false".to_string()];
+        assert_eq!(*ok_hook.calls.lock(), expected);
+        assert_eq!(*failing_hook.calls.lock(), expected);
+        assert_eq!(executor.statistics().total_executions, 1);
+        assert_eq!(metrics.started(), 1);
+        assert_eq!(metrics.finished("failed"), 1);
+        assert_eq!(metrics.finished("success"), 0);
+
+        // Disabled arbitrage skips hooks and the executor
+        let store = Arc::new(FeatureStore::new());
+        let pipeline = ExecutionPipeline::new(executor.clone())
+            .with_hooks(vec![ok_hook.clone()])
+            .with_feature_store(store.clone());
+        let result = pipeline.execute(&opportunity).await.unwrap();
+        match result {
+            ExecutionResult::Failed { error, .. } => {
+                assert_eq!(error, "Arbitrage disabled by feature flag")
+            }
+            other => panic!("Expected failure, got {:?}", other),
+        }
+        assert_eq!(ok_hook.calls.lock().len(), 2);
+        assert_eq!(executor.statistics().total_executions, 1);
+
+        store.set(ARBITRAGE_ENABLED, true);
+        pipeline.execute(&opportunity).await.unwrap();
+        assert_eq!(executor.statistics().total_executions, 2);
+    }
+}
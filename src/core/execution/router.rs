@@ -0,0 +1,199 @@
+//! Batch vs sequential order routing
+//!
+//! A batch submits every leg in one request, which is fastest but leaves a
+//! one-sided position to roll back whenever a thin leg fails to fill.
+//! [`OrderRouter`] checks each leg against cached orderbook depth and, when
+//! any leg is thin, submits the legs one at a time instead: least liquid
+//! first, so the leg most likely to fail does so before anything else is
+//! exposed.
+
+use anyhow::Result;
+use dashmap::DashMap;
+use std::sync::Arc;
+
+use crate::clob::{ClobClient, CreateOrderRequest};
+use crate::types::{OrderBook, OrderSide, TokenId};
+
+/// Default visible depth required per leg, as a multiple of the leg size
+pub const DEFAULT_MIN_DEPTH_RATIO: f64 = 2.0;
+
+/// Price tolerance when summing depth at or better than a leg's limit
+const PRICE_EPSILON: f64 = 1e-9;
+
+/// How the legs of an execution are submitted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionStrategy {
+    /// All legs in a single batch request
+    Batch,
+
+    /// One leg at a time; each leg is only submitted once the previous fills
+    Sequential {
+        /// Leg indices in submission order
+        order: Vec<usize>,
+    },
+}
+
+/// Picks the execution strategy from cached orderbook depth
+pub struct OrderRouter {
+    /// CLOB client (refreshes books missing from the cache)
+    clob_client: Arc<ClobClient>,
+
+    /// Latest orderbook per token
+    order_books: DashMap<TokenId, OrderBook>,
+
+    /// Depth required per leg (multiple of the leg size) for batching
+    min_depth_ratio: f64,
+}
+
+impl OrderRouter {
+    /// Create router with an empty book cache
+    pub fn new(clob_client: Arc<ClobClient>) -> Self {
+        Self {
+            clob_client,
+            order_books: DashMap::new(),
+            min_depth_ratio: DEFAULT_MIN_DEPTH_RATIO,
+        }
+    }
+
+    /// Require `ratio` times each leg's size in visible depth before batching
+    pub fn with_min_depth_ratio(mut self, ratio: f64) -> Self {
+        self.min_depth_ratio = ratio;
+        self
+    }
+
+    /// Cache the latest book of a token (e.g., from the WebSocket feed)
+    pub fn update_order_book(&self, order_book: OrderBook) {
+        self.order_books.insert(order_book.token_id.clone(), order_book);
+    }
+
+    /// Cached book of a token
+    pub fn order_book(&self, token_id: &TokenId) -> Option<OrderBook> {
+        self.order_books.get(token_id).map(|order_book| order_book.clone())
+    }
+
+    /// Fetch a fresh snapshot of a token's book over REST into the cache
+    pub async fn refresh_order_book(&self, token_id: &TokenId) -> Result<()> {
+        let order_book = self.clob_client.rest_client().get_orderbook(&token_id.0).await?;
+        self.update_order_book(order_book);
+        Ok(())
+    }
+
+    /// Visible depth at or better than the leg's limit, as a multiple of its size
+    ///
+    /// Buys take asks, sells take bids. 0.0 without a cached book.
+    pub fn liquidity(&self, leg: &CreateOrderRequest) -> f64 {
+        let Some(order_book) = self.order_books.get(&TokenId(leg.token_id.clone())) else {
+            return 0.0;
+        };
+
+        let depth: f64 = match leg.side {
+            OrderSide::BUY => order_book
+                .asks
+                .iter()
+                .take_while(|ask| ask.price <= leg.price + PRICE_EPSILON)
+                .map(|ask| ask.size)
+                .sum(),
+            OrderSide::SELL => order_book
+                .bids
+                .iter()
+                .take_while(|bid| bid.price >= leg.price - PRICE_EPSILON)
+                .map(|bid| bid.size)
+                .sum(),
+        };
+
+        if leg.size > 0.0 {
+            depth / leg.size
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// Batch when every leg has enough depth, otherwise sequential (least liquid first)
+    pub fn route(&self, legs: &[CreateOrderRequest]) -> ExecutionStrategy {
+        let liquidity: Vec<f64> = legs.iter().map(|leg| self.liquidity(leg)).collect();
+        if legs.len() < 2 || liquidity.iter().all(|&l| l >= self.min_depth_ratio) {
+            return ExecutionStrategy::Batch;
+        }
+
+        let mut order: Vec<usize> = (0..legs.len()).collect();
+        order.sort_by(|&a, &b| liquidity[a].total_cmp(&liquidity[b]));
+
+        tracing::debug!("Routing {} legs sequentially (liquidity {:?})", legs.len(), liquidity);
+        ExecutionStrategy::Sequential { order }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clob::{BatchOrderBuilder, ClobConfig};
+    use crate::types::OrderBookEntry;
+
+    fn create_router() -> OrderRouter {
+        let config = ClobConfig {
+            private_key: "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+                .to_string(),
+            ..ClobConfig::default()
+        };
+        OrderRouter::new(Arc::new(ClobClient::new(config).unwrap()))
+    }
+
+    fn create_book(token_id: &str, bids: &[(f64, f64)], asks: &[(f64, f64)]) -> OrderBook {
+        let entries = |levels: &[(f64, f64)]| {
+            levels
+                .iter()
+                .map(|&(price, size)| OrderBookEntry { price, size, timestamp: None })
+                .collect()
+        };
+        OrderBook {
+            token_id: TokenId(token_id.to_string()),
+            bids: entries(bids),
+            asks: entries(asks),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_routes_on_depth() {
+        let router = create_router();
+        let legs = BatchOrderBuilder::new()
+            .add_buy("yes", 0.45, 100.0, 0)
+            .add_buy("no", 0.50, 100.0, 0)
+            .build()
+            .unwrap();
+
+        // No cached books: nothing is known to be liquid
+        assert_eq!(router.route(&legs), ExecutionStrategy::Sequential { order: vec![0, 1] });
+
+        // Only depth at or below the limit counts
+        router.update_order_book(create_book("yes", &[], &[(0.44, 150.0), (0.45, 100.0)]));
+        router.update_order_book(create_book("no", &[], &[(0.50, 120.0), (0.51, 500.0)]));
+        assert_eq!(router.liquidity(&legs[0]), 2.5);
+        assert_eq!(router.liquidity(&legs[1]), 1.2);
+        assert_eq!(router.route(&legs), ExecutionStrategy::Sequential { order: vec![1, 0] });
+
+        router.update_order_book(create_book("no", &[], &[(0.49, 200.0)]));
+        assert_eq!(router.route(&legs), ExecutionStrategy::Batch);
+        assert_eq!(
+            router.with_min_depth_ratio(3.0).route(&legs),
+            ExecutionStrategy::Sequential { order: vec![1, 0] }
+        );
+    }
+
+    #[test]
+    fn test_sell_legs_take_bids() {
+        let router = create_router();
+        router.update_order_book(create_book("yes", &[(0.76, 50.0), (0.75, 100.0)], &[]));
+        router.update_order_book(create_book("no", &[(0.25, 50.0)], &[(0.20, 500.0)]));
+        let legs = BatchOrderBuilder::new()
+            .add_sell("yes", 0.75, 100.0, 0)
+            .add_sell("no", 0.25, 100.0, 0)
+            .build()
+            .unwrap();
+
+        assert_eq!(router.liquidity(&legs[0]), 1.5);
+        assert_eq!(router.liquidity(&legs[1]), 0.5);
+        assert_eq!(router.route(&legs), ExecutionStrategy::Sequential { order: vec![1, 0] });
+        assert_eq!(router.route(&legs[..1]), ExecutionStrategy::Batch);
+    }
+}
@@ -1,16 +1,20 @@
 //! Position Redemption Module
 //!
 //! Handles tracking and redeeming binary arbitrage positions after market expiry.
+//! With a store ([`RedemptionManager::with_store`]) unredeemed positions are
+//! kept in a sled tree, so a restart still redeems them.
 
 use crate::types::{MarketId, TokenId};
 use crate::clob::ClobClient;
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use tracing::{info, warn, error};
+use std::path::{Path, PathBuf};
+use tracing::{info, error};
 
 /// Position to be redeemed
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RedeemablePosition {
     /// Market ID
     pub market_id: MarketId,
@@ -63,11 +67,27 @@ impl RedeemablePosition {
     pub fn time_until_expiry(&self) -> Option<chrono::Duration> {
         self.expiry.map(|expiry| expiry - Utc::now())
     }
+
+    /// Redemption priority at `now`: expected profit per second until expiry
+    ///
+    /// Expired positions count as 1 second away. No expiry = lowest priority.
+    fn priority(&self, now: DateTime<Utc>) -> f64 {
+        match self.expiry {
+            Some(expiry) => {
+                let secs = (expiry - now).num_seconds().max(1);
+                self.expected_profit / secs as f64
+            }
+            None => f64::NEG_INFINITY,
+        }
+    }
 }
 
 /// Position redemption manager
 pub struct RedemptionManager {
     positions: HashMap<MarketId, RedeemablePosition>,
+
+    /// Unredeemed positions keyed by market ID (bincode), if persisted
+    store: Option<sled::Tree>,
 }
 
 impl RedemptionManager {
@@ -75,6 +95,47 @@ impl RedemptionManager {
     pub fn new() -> Self {
         Self {
             positions: HashMap::new(),
+            store: None,
+        }
+    }
+
+    /// Redemption manager persisted to `store`, loading its saved positions
+    pub fn with_store(store: sled::Tree) -> Result<Self> {
+        let mut positions = HashMap::new();
+        for entry in store.iter() {
+            let (_, value) = entry?;
+            let position: RedeemablePosition = bincode::deserialize(&value)
+                .map_err(|e| anyhow!("Invalid saved redemption: {}", e))?;
+            positions.insert(position.market_id.clone(), position);
+        }
+        if !positions.is_empty() {
+            info!("📦 Restored {} positions awaiting redemption", positions.len());
+        }
+
+        Ok(Self {
+            positions,
+            store: Some(store),
+        })
+    }
+
+    /// Save an unredeemed `position` to `store`, or delete it once redeemed
+    ///
+    /// Failures are logged: the in-memory state stays current.
+    fn persist(store: &Option<sled::Tree>, position: &RedeemablePosition) {
+        let Some(store) = store else {
+            return;
+        };
+        let write = || -> Result<()> {
+            let key = position.market_id.to_string();
+            if position.redeemed {
+                store.remove(key.as_bytes())?;
+            } else {
+                store.insert(key.as_bytes(), bincode::serialize(position)?)?;
+            }
+            Ok(())
+        };
+        if let Err(e) = write() {
+            error!("Failed to persist redemption for {}: {}", position.market_id, e);
         }
     }
 
@@ -89,6 +150,7 @@ impl RedemptionManager {
             }
         }
 
+        Self::persist(&self.store, &position);
         self.positions.insert(position.market_id.clone(), position);
     }
 
@@ -100,18 +162,49 @@ impl RedemptionManager {
             .collect()
     }
 
+    /// Unredeemed positions, highest priority first
+    ///
+    /// Sorted by `expected_profit / seconds_until_expiry` (descending) so
+    /// high-value, near-expiry positions are redeemed first when the API is
+    /// rate-limited. Ties are broken by market ID for a deterministic order.
+    pub fn prioritize(&self) -> Vec<&RedeemablePosition> {
+        let now = Utc::now();
+        let mut positions: Vec<_> = self.positions.values().filter(|p| !p.redeemed).collect();
+        positions.sort_by(|a, b| {
+            b.priority(now)
+                .total_cmp(&a.priority(now))
+                .then_with(|| a.market_id.cmp(&b.market_id))
+        });
+        positions
+    }
+
+    /// Total expected profit of unredeemed positions
+    pub fn total_expected_profit(&self) -> f64 {
+        self.positions
+            .values()
+            .filter(|p| !p.redeemed)
+            .map(|p| p.expected_profit)
+            .sum()
+    }
+
     /// Mark position as redeemed
     pub fn mark_redeemed(&mut self, market_id: &MarketId) -> Result<()> {
         let position = self.positions
             .get_mut(market_id)
-            .ok_or_else(|| anyhow!("Position not found: {}", market_id.0))?;
+            .ok_or_else(|| anyhow!("Position not found: {}", market_id))?;
 
         position.redeemed = true;
         info!("✅ Position marked as redeemed: {}", position.title);
 
+        Self::persist(&self.store, position);
         Ok(())
     }
 
+    /// Redeemed positions (their legs no longer need tracking)
+    pub fn redeemed_positions(&self) -> impl Iterator<Item = &RedeemablePosition> {
+        self.positions.values().filter(|p| p.redeemed)
+    }
+
     /// Get position count
     pub fn position_count(&self) -> usize {
         self.positions.len()
@@ -126,11 +219,11 @@ impl RedemptionManager {
     pub async fn redeem_position(
         &mut self,
         market_id: &MarketId,
-        clob_client: &ClobClient,
+        _clob_client: &ClobClient,
     ) -> Result<f64> {
         let position = self.positions
             .get(market_id)
-            .ok_or_else(|| anyhow!("Position not found: {}", market_id.0))?;
+            .ok_or_else(|| anyhow!("Position not found: {}", market_id))?;
 
         if position.redeemed {
             return Err(anyhow!("Position already redeemed"));
@@ -141,7 +234,7 @@ impl RedemptionManager {
         }
 
         info!("💰 Redeeming position: {}", position.title);
-        info!("   Market ID: {}", position.market_id.0);
+        info!("   Market ID: {}", position.market_id);
         info!("   Size: {}", position.size);
         info!("   Cost: ${:.2}", position.cost);
         info!("   Expected profit: ${:.2}", position.expected_profit);
@@ -166,14 +259,15 @@ impl RedemptionManager {
         Ok(profit)
     }
 
-    /// Auto-redeem all ready positions
+    /// Auto-redeem all ready positions (in [`prioritize`](Self::prioritize) order)
     pub async fn auto_redeem_all(
         &mut self,
         clob_client: &ClobClient,
     ) -> Result<f64> {
         let redeemable: Vec<MarketId> = self
-            .get_redeemable_positions()
-            .iter()
+            .prioritize()
+            .into_iter()
+            .filter(|p| p.is_ready_to_redeem())
             .map(|p| p.market_id.clone())
             .collect();
 
@@ -191,7 +285,7 @@ impl RedemptionManager {
                     total_profit += profit;
                 }
                 Err(e) => {
-                    error!("Failed to redeem {}: {}", market_id.0, e);
+                    error!("Failed to redeem {}: {}", market_id, e);
                 }
             }
         }
@@ -256,6 +350,73 @@ impl Default for RedemptionManager {
     }
 }
 
+/// Point-in-time portfolio view for reporting and audit files
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioSnapshot {
+    /// Unredeemed binary arbitrage positions
+    pub positions: Vec<RedeemablePosition>,
+
+    /// Number of unredeemed positions
+    pub open_count: usize,
+
+    /// Total cost paid for open positions
+    pub total_cost: f64,
+
+    /// Total expected profit of open positions
+    pub total_expected_profit: f64,
+
+    /// Realized P&L over the last 24 hours (net of trading fees)
+    pub daily_realized_pnl: f64,
+
+    /// Unrealized P&L of tracked positions at current prices
+    pub daily_unrealized_pnl: f64,
+
+    /// Trading fees paid over the last 24 hours
+    pub daily_fees_paid: f64,
+
+    /// `daily_realized_pnl + daily_unrealized_pnl` (fees are already deducted)
+    pub net_daily_pnl: f64,
+
+    /// When the snapshot was taken
+    pub captured_at: DateTime<Utc>,
+}
+
+impl PortfolioSnapshot {
+    /// Build snapshot, computing totals from `positions`
+    pub fn new(
+        positions: Vec<RedeemablePosition>,
+        daily_realized_pnl: f64,
+        daily_unrealized_pnl: f64,
+        daily_fees_paid: f64,
+    ) -> Self {
+        Self {
+            open_count: positions.len(),
+            total_cost: positions.iter().map(|p| p.cost).sum(),
+            total_expected_profit: positions.iter().map(|p| p.expected_profit).sum(),
+            positions,
+            daily_realized_pnl,
+            daily_unrealized_pnl,
+            daily_fees_paid,
+            net_daily_pnl: daily_realized_pnl + daily_unrealized_pnl,
+            captured_at: Utc::now(),
+        }
+    }
+
+    /// Audit file name: `portfolio_YYYY-MM-DD_HH-MM-SS.json` (UTC)
+    pub fn file_name(&self) -> String {
+        format!("portfolio_{}.json", self.captured_at.format("%Y-%m-%d_%H-%M-%S"))
+    }
+
+    /// Write snapshot as pretty JSON to `dir`, returning the file path
+    pub fn write_to_dir(&self, dir: &Path) -> Result<PathBuf> {
+        let path = dir.join(self.file_name());
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(&path, json)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -268,7 +429,7 @@ mod tests {
         };
 
         RedeemablePosition {
-            market_id: MarketId("test-market".to_string()),
+            market_id: MarketId::new("test-market"),
             title: "Test Market".to_string(),
             yes_token_id: TokenId("yes-token".to_string()),
             no_token_id: TokenId("no-token".to_string()),
@@ -298,8 +459,9 @@ mod tests {
         let pos1 = create_test_position(true);
         manager.add_position(pos1);
 
-        // Add active position
-        let pos2 = create_test_position(false);
+        // Add active position (distinct market so it isn't overwritten)
+        let mut pos2 = create_test_position(false);
+        pos2.market_id = MarketId::new("test-market-2");
         manager.add_position(pos2);
 
         assert_eq!(manager.position_count(), 2);
@@ -323,4 +485,98 @@ mod tests {
 
         assert_eq!(manager.unredeemed_count(), 0);
     }
+
+    #[test]
+    fn test_store_keeps_unredeemed_positions() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let tree = db.open_tree("redemptions").unwrap();
+        let mut manager = RedemptionManager::with_store(tree).unwrap();
+        manager.add_position(create_test_position(true));
+        let mut pending = create_test_position(false);
+        pending.market_id = MarketId::new("test-market-2");
+        manager.add_position(pending);
+
+        manager.mark_redeemed(&MarketId::new("test-market")).unwrap();
+        assert_eq!(manager.redeemed_positions().count(), 1);
+        drop(manager);
+
+        // Only the unredeemed position comes back
+        let tree = db.open_tree("redemptions").unwrap();
+        let restored = RedemptionManager::with_store(tree).unwrap();
+        assert_eq!(restored.position_count(), 1);
+        let position = restored.prioritize()[0];
+        assert_eq!(position.market_id.to_string(), "test-market-2");
+        assert!(position.expiry.is_some() && !position.redeemed);
+    }
+
+    #[test]
+    fn test_prioritize_by_profit_and_urgency() {
+        let mut manager = RedemptionManager::new();
+
+        // (market, expected profit, minutes until expiry)
+        let specs = [
+            ("far-large", 20.0, Some(120)),   // 20 / 7200s
+            ("near-small", 2.0, Some(10)),    //  2 /  600s
+            ("expired", 1.0, Some(-5)),       //  1 /    1s
+            ("near-large", 10.0, Some(15)),   // 10 /  900s
+            ("no-expiry", 50.0, None),
+        ];
+        for (id, profit, minutes) in specs {
+            let mut pos = create_test_position(false);
+            pos.market_id = MarketId::new(id);
+            pos.expected_profit = profit;
+            pos.expiry = minutes.map(|m| Utc::now() + chrono::Duration::minutes(m));
+            manager.add_position(pos);
+        }
+
+        let prioritized = manager.prioritize();
+        let order: Vec<String> = prioritized.iter().map(|p| p.market_id.to_string()).collect();
+        assert_eq!(order, vec!["expired", "near-large", "near-small", "far-large", "no-expiry"]);
+        assert!((manager.total_expected_profit() - 83.0).abs() < 1e-9);
+
+        manager.mark_redeemed(&MarketId::new("expired")).unwrap();
+        assert_eq!(manager.prioritize().len(), 4);
+        assert!((manager.total_expected_profit() - 82.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_portfolio_snapshot() {
+        let position = |market: &str, cost: f64, expected_profit: f64| RedeemablePosition {
+            market_id: MarketId::new(market),
+            title: market.to_string(),
+            yes_token_id: TokenId(format!("{}-yes", market)),
+            no_token_id: TokenId(format!("{}-no", market)),
+            size: 100.0,
+            cost,
+            expected_profit,
+            expiry: None,
+            opened_at: Utc::now(),
+            redeemed: false,
+        };
+
+        let snapshot = PortfolioSnapshot::new(
+            vec![position("btc", 93.0, 5.0), position("eth", 95.0, 3.0)],
+            -2.5,
+            1.0,
+            0.5,
+        );
+        assert_eq!(snapshot.open_count, 2);
+        assert_eq!(snapshot.total_cost, 188.0);
+        assert_eq!(snapshot.total_expected_profit, 8.0);
+        assert_eq!(snapshot.net_daily_pnl, -1.5);
+
+        let name = snapshot.file_name();
+        assert!(name.starts_with("portfolio_") && name.ends_with(".json"));
+        assert_eq!(name.len(), "portfolio_YYYY-MM-DD_HH-MM-SS.json".len());
+
+        let dir = std::env::temp_dir().join(format!("portfolio_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = snapshot.write_to_dir(&dir).unwrap();
+        let restored: PortfolioSnapshot =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(restored.open_count, 2);
+        assert_eq!(restored.positions[1].market_id, MarketId::new("eth"));
+        assert_eq!(restored.captured_at, snapshot.captured_at);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
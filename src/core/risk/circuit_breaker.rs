@@ -3,16 +3,115 @@
 //! Implements a thread-safe circuit breaker using atomic operations for
 //! lock-free concurrency. Prevents excessive losses and manages risk limits.
 
-use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
+use chrono::Utc;
+use dashmap::DashMap;
 use parking_lot::RwLock;
-use crate::types::RiskConfig;
+use prometheus::{Gauge, Registry};
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+use crate::types::{EventBus, HftError, MarketId, OrderBook, RiskConfig, TokenId, TradingEvent};
+use crate::utils::fixed_point::SignedFixedPrice;
+use crate::utils::metrics::HftMetrics;
+#[cfg(feature = "alerts")]
+use crate::utils::alerts::{AlertSender, Severity};
+
+use super::{PositionStore, RollingDailyLoss, SlidingWindowPnL, MINUTES_PER_DAY};
+
+/// Risk score weight for daily loss utilisation
+const DAILY_LOSS_WEIGHT: f64 = 40.0;
+/// Risk score weight for open position utilisation
+const POSITIONS_WEIGHT: f64 = 30.0;
+/// Risk score weight for consecutive error utilisation
+const ERRORS_WEIGHT: f64 = 20.0;
+/// Risk score weight for a tripped breaker (saturates the score)
+const TRIPPED_WEIGHT: f64 = 10.0;
+
+/// Default nonce drift (local - API) above which a warning is emitted
+pub const DEFAULT_NONCE_DRIFT_WARNING_THRESHOLD: i64 = 10;
+
+/// Rolling window for per-market anomaly counts
+const ANOMALY_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Mid-price move between consecutive snapshots reported as a spike
+const PRICE_SPIKE_THRESHOLD: f64 = 0.2;
+
+/// Time between consecutive snapshots reported as a data gap
+const DATA_GAP_THRESHOLD: Duration = Duration::from_secs(30);
+
+/// Oldest saved state `restore_state` accepts (the span of the loss window)
+const MAX_STATE_AGE_SECS: i64 = MINUTES_PER_DAY as i64 * 60;
+
+/// Market behavior that warrants caution without tripping the breaker
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AnomalyReason {
+    /// Best bid at or above best ask
+    CrossedBook,
+    /// Sudden price move (fractional change, e.g., 0.2 = 20%)
+    PriceSpike(f64),
+    /// No market data for this long
+    DataGap(Duration),
+    /// No liquidity on either side
+    ZeroLiquidity,
+}
+
+impl AnomalyReason {
+    /// Anomaly shown by `book`, compared with the `previous` book of the token if known
+    ///
+    /// Checks in order: crossed book, zero liquidity, a mid-price move above
+    /// 20%, and more than 30s between the two books' timestamps.
+    pub fn detect(previous: Option<&OrderBook>, book: &OrderBook) -> Option<Self> {
+        if let Some((bid, ask)) = book.bbo() {
+            if bid.price >= ask.price {
+                return Some(Self::CrossedBook);
+            }
+        }
+        if book.bids.is_empty() && book.asks.is_empty() {
+            return Some(Self::ZeroLiquidity);
+        }
+
+        let previous = previous?;
+        let mid = |book: &OrderBook| book.bbo().map(|(bid, ask)| (bid.price + ask.price) / 2.0);
+        if let (Some(before), Some(after)) = (mid(previous), mid(book)) {
+            let change = (after - before).abs() / before;
+            if change > PRICE_SPIKE_THRESHOLD {
+                return Some(Self::PriceSpike(change));
+            }
+        }
+
+        let gap_ms = book.timestamp.saturating_sub(previous.timestamp).max(0);
+        let gap = Duration::from_millis(gap_ms as u64);
+        (gap > DATA_GAP_THRESHOLD).then_some(Self::DataGap(gap))
+    }
+}
+
+/// Persisted circuit breaker counters (see `export_state`)
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedState {
+    /// Net loss over the last 24 hours at export time
+    daily_loss_cents: u64,
+    /// Per-minute loss buckets behind `daily_loss_cents`
+    #[serde(default)]
+    loss_buckets: Vec<(u32, i32)>,
+    /// Per-minute fee buckets of the last 24 hours
+    #[serde(default)]
+    fee_buckets: Vec<(u32, i32)>,
+    consecutive_errors: u32,
+    open_positions: u32,
+    tripped: bool,
+    /// Unix timestamp (seconds) when the state was exported
+    #[serde(default)]
+    saved_at: i64,
+}
 
 /// Circuit breaker state for risk management
 ///
 /// Uses lock-free atomic operations for high-performance concurrent access.
-/// Tracks daily losses, position counts, and error rates.
+/// Tracks losses over the last 24 hours, position counts, and error rates.
 #[derive(Debug)]
 pub struct CircuitBreaker {
     /// Whether the circuit breaker is tripped (trading halted)
@@ -21,18 +120,48 @@ pub struct CircuitBreaker {
     /// Number of consecutive errors
     consecutive_errors: AtomicU32,
 
-    /// Daily loss in cents (u64 for atomic operations)
-    /// Store as cents to avoid floating-point atomics
-    daily_loss_cents: AtomicU64,
+    /// Net loss over the last 24 hours, in per-minute cent buckets
+    daily_loss: RollingDailyLoss,
 
     /// Number of currently open positions
     open_positions: AtomicU32,
 
+    /// Trading fees paid over the last 24 hours, in per-minute cent buckets
+    daily_fees: RollingDailyLoss,
+
+    /// Trade P&L over the last `loss_window_secs`
+    rolling_pnl: SlidingWindowPnL,
+
     /// Configuration
     config: RiskConfig,
 
     /// Last reset time (protected by RwLock for infrequent writes)
     last_reset: RwLock<Instant>,
+
+    /// Prometheus gauge mirroring the latest risk score (`cb_risk_score`)
+    risk_score_gauge: Gauge,
+
+    /// Nonce drift above which the drift monitor warns (and may resync)
+    nonce_drift_warning_threshold: AtomicI64,
+
+    /// Broadcasts the tripped state on every `trip`/`reset`
+    trip_tx: watch::Sender<bool>,
+
+    /// Recent anomaly times per market (within `ANOMALY_WINDOW`)
+    market_anomalies: DashMap<TokenId, VecDeque<Instant>>,
+
+    /// Positions checked against `max_concentration` on `open_position`
+    position_tracker: Option<Arc<dyn PositionStore>>,
+
+    /// Receives `CircuitBreakerStateChanged` on every `trip`/`reset`
+    event_bus: Option<EventBus<TradingEvent>>,
+
+    /// Trip count, daily loss and open position metrics
+    metrics: Option<Arc<HftMetrics>>,
+
+    /// Operator alerts sent on `trip`
+    #[cfg(feature = "alerts")]
+    alert_sender: Option<Arc<AlertSender>>,
 }
 
 impl CircuitBreaker {
@@ -41,13 +170,84 @@ impl CircuitBreaker {
         Self {
             tripped: AtomicBool::new(false),
             consecutive_errors: AtomicU32::new(0),
-            daily_loss_cents: AtomicU64::new(0),
+            daily_loss: RollingDailyLoss::new(),
             open_positions: AtomicU32::new(0),
+            daily_fees: RollingDailyLoss::new(),
+            rolling_pnl: SlidingWindowPnL::new(Duration::from_secs(config.loss_window_secs)),
             config,
             last_reset: RwLock::new(Instant::now()),
+            risk_score_gauge: Gauge::new("cb_risk_score", "Circuit breaker risk score (0-100)")
+                .expect("valid gauge definition"),
+            nonce_drift_warning_threshold: AtomicI64::new(DEFAULT_NONCE_DRIFT_WARNING_THRESHOLD),
+            trip_tx: watch::channel(false).0,
+            market_anomalies: DashMap::new(),
+            position_tracker: None,
+            event_bus: None,
+            metrics: None,
+            #[cfg(feature = "alerts")]
+            alert_sender: None,
         }
     }
 
+    /// Check market concentration against `tracker` on every `open_position`
+    pub fn with_position_tracker(mut self, tracker: Arc<dyn PositionStore>) -> Self {
+        self.position_tracker = Some(tracker);
+        self
+    }
+
+    /// Publish `CircuitBreakerStateChanged` on every `trip`/`reset`
+    pub fn with_event_bus(mut self, event_bus: EventBus<TradingEvent>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Update trip, daily loss and open position metrics
+    ///
+    /// The risk score gauge (see `register_metrics`) is registered with
+    /// `metrics`' registry, so one scrape covers both.
+    pub fn with_metrics(mut self, metrics: Arc<HftMetrics>) -> Self {
+        if let Err(e) = self.register_metrics(metrics.registry()) {
+            tracing::warn!("Failed to register circuit breaker metrics: {}", e);
+        }
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Refresh the daily loss and open position gauges, if metrics are set
+    fn update_metrics(&self) {
+        if let Some(metrics) = &self.metrics {
+            metrics.set_daily_loss(self.daily_loss());
+            metrics.set_open_positions(self.positions());
+        }
+    }
+
+    /// Publish the tripped state, if an event bus is set
+    fn publish_state(&self, tripped: bool) {
+        if let Some(bus) = &self.event_bus {
+            bus.publish(TradingEvent::CircuitBreakerStateChanged { tripped });
+        }
+    }
+
+    /// Send a critical alert on every `trip`
+    ///
+    /// `ArbitrageExecutor` also uses this sender to report failed rollbacks.
+    #[cfg(feature = "alerts")]
+    pub fn with_alert_sender(mut self, alert_sender: Arc<AlertSender>) -> Self {
+        self.alert_sender = Some(alert_sender);
+        self
+    }
+
+    /// Alert sender, if configured
+    #[cfg(feature = "alerts")]
+    pub fn alert_sender(&self) -> Option<&Arc<AlertSender>> {
+        self.alert_sender.as_ref()
+    }
+
+    /// Register circuit breaker metrics with a Prometheus registry
+    pub fn register_metrics(&self, registry: &Registry) -> prometheus::Result<()> {
+        registry.register(Box::new(self.risk_score_gauge.clone()))
+    }
+
     /// Check if trading is allowed
     ///
     /// Returns true if the circuit breaker is not tripped.
@@ -57,21 +257,95 @@ impl CircuitBreaker {
     }
 
     /// Trip the circuit breaker (halt trading)
+    ///
+    /// Notifies [`subscribe_trips`](Self::subscribe_trips) receivers, e.g.
+    /// `ClobClient::cancel_orders_on_trip`. The trip metric and the alert
+    /// sender (if set) only see trips of an untripped breaker.
     pub fn trip(&self) {
-        self.tripped.store(true, Ordering::Release);
+        let was_tripped = self.tripped.swap(true, Ordering::AcqRel);
+        if let Some(metrics) = self.metrics.as_ref().filter(|_| !was_tripped) {
+            metrics.record_trip();
+        }
+        self.trip_tx.send_replace(true);
+        self.publish_state(true);
         tracing::error!("🚨 Circuit breaker TRIPPED - Trading halted!");
+
+        #[cfg(feature = "alerts")]
+        if let Some(sender) = self.alert_sender.as_ref().filter(|_| !was_tripped) {
+            sender.spawn_send(
+                Severity::Critical,
+                "Circuit breaker tripped",
+                serde_json::json!({
+                    "daily_loss": self.daily_loss(),
+                    "open_positions": self.positions(),
+                    "consecutive_errors": self.errors(),
+                }),
+            );
+        }
     }
 
     /// Reset the circuit breaker (resume trading)
     pub fn reset(&self) {
         self.tripped.store(false, Ordering::Release);
+        self.trip_tx.send_replace(false);
+        self.publish_state(false);
         tracing::info!("✅ Circuit breaker RESET - Trading resumed");
     }
 
+    /// Receiver notified on every trip (`true`) and reset (`false`)
+    pub fn subscribe_trips(&self) -> watch::Receiver<bool> {
+        self.trip_tx.subscribe()
+    }
+
+    /// Aggregate risk exposure into a single 0-100 score
+    ///
+    /// Weighted sum of daily loss (40), open positions (30) and consecutive
+    /// errors (20) as fractions of their limits. A tripped breaker always
+    /// scores 100. Also updates the `cb_risk_score` gauge.
+    pub fn risk_score(&self) -> f64 {
+        fn utilisation(value: f64, limit: f64) -> f64 {
+            if limit <= 0.0 {
+                0.0
+            } else {
+                (value / limit).clamp(0.0, 1.0)
+            }
+        }
+
+        let loss = utilisation(self.daily_loss(), self.config.max_daily_loss);
+        let positions = utilisation(
+            self.positions() as f64,
+            self.config.max_open_positions as f64,
+        );
+        let errors = utilisation(
+            self.errors() as f64,
+            self.config.max_consecutive_errors as f64,
+        );
+        let tripped = if self.can_execute() { 0.0 } else { 100.0 };
+
+        let score = (loss * DAILY_LOSS_WEIGHT
+            + positions * POSITIONS_WEIGHT
+            + errors * ERRORS_WEIGHT
+            + tripped * TRIPPED_WEIGHT)
+            .clamp(0.0, 100.0);
+
+        self.risk_score_gauge.set(score);
+        score
+    }
+
     /// Check if circuit breaker should be tripped based on current state
     fn check_and_trip(&self) -> bool {
+        // Early warning before any hard limit fires
+        let score = self.risk_score();
+        if score > self.config.risk_score_alert_threshold {
+            tracing::warn!(
+                "Risk score {:.1} exceeds alert threshold {:.1}",
+                score,
+                self.config.risk_score_alert_threshold
+            );
+        }
+
         // Check daily loss limit
-        let daily_loss = self.daily_loss_cents.load(Ordering::Acquire) as f64 / 100.0;
+        let daily_loss = self.daily_loss();
         if daily_loss >= self.config.max_daily_loss {
             tracing::error!(
                 "Daily loss limit exceeded: ${:.2} >= ${:.2}",
@@ -82,6 +356,19 @@ impl CircuitBreaker {
             return true;
         }
 
+        // Check rolling window loss limit
+        let rolling_loss = self.rolling_loss();
+        if rolling_loss > self.config.max_loss_in_window {
+            tracing::error!(
+                "Loss in {}s window exceeded: ${:.2} > ${:.2}",
+                self.config.loss_window_secs,
+                rolling_loss,
+                self.config.max_loss_in_window
+            );
+            self.trip();
+            return true;
+        }
+
         // Check max open positions
         let positions = self.open_positions.load(Ordering::Acquire);
         if positions as usize > self.config.max_open_positions {
@@ -107,31 +394,24 @@ impl CircuitBreaker {
 
     /// Record a trade and update P&L
     ///
-    /// Updates daily loss atomically. Positive P&L reduces loss, negative increases it.
-    pub fn record_trade(&self, pnl: f64) -> Result<(), String> {
+    /// Adds the P&L to the current minute of the 24-hour loss window: losses
+    /// increase it, profits offset it. Accepts `SignedFixedPrice` or `f64`
+    /// (converted once, then integer math).
+    pub fn record_trade(&self, pnl: impl Into<SignedFixedPrice>) -> Result<(), HftError> {
         if !self.can_execute() {
-            return Err("Circuit breaker is tripped".to_string());
+            return Err(HftError::RiskLimitExceeded("Circuit breaker is tripped".to_string()));
         }
 
-        // Convert to cents for atomic storage
-        let pnl_cents = (pnl * 100.0) as i64;
-
-        // Atomic update of daily loss
-        if pnl < 0.0 {
-            // Loss: add absolute value
-            self.daily_loss_cents.fetch_add(pnl_cents.unsigned_abs(), Ordering::AcqRel);
-        } else {
-            // Profit: subtract (but don't go below 0)
-            let current = self.daily_loss_cents.load(Ordering::Acquire);
-            let new_loss = current.saturating_sub(pnl_cents.unsigned_abs());
-            self.daily_loss_cents.store(new_loss, Ordering::Release);
-        }
+        let pnl = pnl.into();
+        self.daily_loss.record_cents(pnl.to_cents());
+        self.rolling_pnl.record(pnl.to_f64());
 
         // Reset consecutive errors on successful trade
         self.consecutive_errors.store(0, Ordering::Release);
 
         // Check if limits exceeded
         self.check_and_trip();
+        self.update_metrics();
 
         Ok(())
     }
@@ -146,9 +426,29 @@ impl CircuitBreaker {
     }
 
     /// Increment open positions
-    pub fn open_position(&self) -> Result<(), String> {
+    ///
+    /// With a position tracker set, rejects the position (without tripping)
+    /// if adding `exposure` (USDC) in `market_id` would put more than
+    /// `max_concentration` of total exposure in that market. The first
+    /// position of an empty portfolio is exempt.
+    pub fn open_position(&self, market_id: &MarketId, exposure: f64) -> Result<(), HftError> {
         if !self.can_execute() {
-            return Err("Circuit breaker is tripped".to_string());
+            return Err(HftError::RiskLimitExceeded("Circuit breaker is tripped".to_string()));
+        }
+
+        if let Some(tracker) = self.position_tracker.as_ref().filter(|t| t.position_count() > 0) {
+            let concentration = tracker.position_concentration_after(market_id, exposure);
+            if concentration > self.config.max_concentration {
+                tracing::warn!(
+                    "Market concentration exceeded for {}: {:.1}% > {:.1}%",
+                    market_id,
+                    concentration * 100.0,
+                    self.config.max_concentration * 100.0
+                );
+                return Err(HftError::RiskLimitExceeded(
+                    "Position concentration limit would be exceeded".to_string(),
+                ));
+            }
         }
 
         let positions = self.open_positions.fetch_add(1, Ordering::AcqRel);
@@ -158,9 +458,11 @@ impl CircuitBreaker {
         if self.check_and_trip() {
             // Rollback position increment if we tripped
             self.open_positions.fetch_sub(1, Ordering::AcqRel);
-            return Err("Position limit would be exceeded".to_string());
+            self.update_metrics();
+            return Err(HftError::RiskLimitExceeded("Position limit would be exceeded".to_string()));
         }
 
+        self.update_metrics();
         Ok(())
     }
 
@@ -168,11 +470,79 @@ impl CircuitBreaker {
     pub fn close_position(&self) {
         let positions = self.open_positions.fetch_sub(1, Ordering::AcqRel);
         tracing::debug!("Closed position (remaining: {})", positions.saturating_sub(1));
+        self.update_metrics();
     }
 
-    /// Get current daily loss
+    /// Record anomalous behavior on a market
+    ///
+    /// Once a market has more than `max_anomalies_before_skip` anomalies
+    /// within 5 minutes, `can_trade_market` returns false for it until older
+    /// anomalies age out. The breaker itself is not tripped.
+    pub fn observe_market_anomaly(&self, token_id: &TokenId, reason: AnomalyReason) {
+        let now = Instant::now();
+        let mut anomalies = self.market_anomalies.entry(token_id.clone()).or_default();
+        while anomalies.front().is_some_and(|&at| now.duration_since(at) > ANOMALY_WINDOW) {
+            anomalies.pop_front();
+        }
+        anomalies.push_back(now);
+
+        let count = anomalies.len();
+        tracing::warn!("Market anomaly on {}: {:?} ({} in window)", token_id, reason, count);
+        if count as u32 == self.config.max_anomalies_before_skip + 1 {
+            tracing::warn!("Skipping {} after {} anomalies", token_id, count);
+        }
+    }
+
+    /// Record the anomaly `book` shows, if any (see `AnomalyReason::detect`)
+    ///
+    /// Call with each incoming book before it replaces `previous` in the
+    /// cache. Returns the anomaly recorded.
+    pub fn observe_order_book(
+        &self,
+        token_id: &TokenId,
+        previous: Option<&OrderBook>,
+        book: &OrderBook,
+    ) -> Option<AnomalyReason> {
+        let reason = AnomalyReason::detect(previous, book)?;
+        self.observe_market_anomaly(token_id, reason);
+        Some(reason)
+    }
+
+    /// Number of anomalies on a market within the last 5 minutes
+    pub fn anomaly_count(&self, token_id: &TokenId) -> u32 {
+        self.market_anomalies.get(token_id).map_or(0, |anomalies| {
+            anomalies.iter().filter(|at| at.elapsed() <= ANOMALY_WINDOW).count() as u32
+        })
+    }
+
+    /// Whether opportunities on a market may be traded
+    ///
+    /// False while the market has more than `max_anomalies_before_skip`
+    /// recent anomalies. Independent of `can_execute`.
+    pub fn can_trade_market(&self, token_id: &TokenId) -> bool {
+        self.anomaly_count(token_id) <= self.config.max_anomalies_before_skip
+    }
+
+    /// Net loss over the last 24 hours (0.0 when net positive)
     pub fn daily_loss(&self) -> f64 {
-        self.daily_loss_cents.load(Ordering::Acquire) as f64 / 100.0
+        self.daily_loss.loss_cents() as f64 / 100.0
+    }
+
+    /// Net loss over the last `loss_window_secs` (default 1 hour)
+    pub fn rolling_loss(&self) -> f64 {
+        self.rolling_pnl.loss_in_window()
+    }
+
+    /// Record trading fees paid (in USDC)
+    pub fn record_fees(&self, fees: f64) {
+        // A fee is a loss in its own window, so it rolls off like the losses
+        let cents = (fees.max(0.0) * 100.0).round() as i64;
+        self.daily_fees.record_cents(-cents);
+    }
+
+    /// Trading fees paid over the last 24 hours
+    pub fn daily_fees(&self) -> f64 {
+        self.daily_fees.loss_cents() as f64 / 100.0
     }
 
     /// Get current open positions count
@@ -185,11 +555,121 @@ impl CircuitBreaker {
         self.consecutive_errors.load(Ordering::Acquire)
     }
 
-    /// Reset daily counters (call at start of new trading day)
+    /// Maximum worst-case loss of a single trade in USDC
+    pub fn max_loss_per_trade(&self) -> f64 {
+        self.config.max_loss_per_trade
+    }
+
+    /// Minimum USDC balance required to trade
+    pub fn min_usdc_balance(&self) -> f64 {
+        self.config.min_usdc_balance
+    }
+
+    /// Nonce drift warning threshold (default 10)
+    pub fn nonce_drift_warning_threshold(&self) -> i64 {
+        self.nonce_drift_warning_threshold.load(Ordering::Acquire)
+    }
+
+    /// Set nonce drift warning threshold
+    pub fn set_nonce_drift_warning_threshold(&self, threshold: i64) {
+        self.nonce_drift_warning_threshold.store(threshold, Ordering::Release);
+    }
+
+    /// Export counters as JSON for persistence across restarts
+    ///
+    /// Includes `daily_loss_cents` with its per-minute `loss_buckets`,
+    /// `fee_buckets`, `consecutive_errors`, `open_positions`, `tripped`, and a
+    /// `saved_at` Unix timestamp.
+    pub fn export_state(&self) -> serde_json::Value {
+        let state = PersistedState {
+            daily_loss_cents: self.daily_loss.loss_cents(),
+            loss_buckets: self.daily_loss.buckets(),
+            fee_buckets: self.daily_fees.buckets(),
+            consecutive_errors: self.consecutive_errors.load(Ordering::Acquire),
+            open_positions: self.open_positions.load(Ordering::Acquire),
+            tripped: self.tripped.load(Ordering::Acquire),
+            saved_at: Utc::now().timestamp(),
+        };
+
+        serde_json::to_value(state).unwrap_or_default()
+    }
+
+    /// Restore counters previously produced by `export_state`
+    pub fn import_state(&self, state: serde_json::Value) -> Result<(), HftError> {
+        let state: PersistedState = serde_json::from_value(state)
+            .map_err(|e| HftError::ParseError(format!("Invalid circuit breaker state: {}", e)))?;
+
+        // Buckets keep their minutes, so losses and fees still leave the
+        // window 24 hours after they happened
+        self.daily_loss.clear();
+        if state.loss_buckets.is_empty() {
+            // Saved without a breakdown: the whole loss restarts its 24 hours now
+            self.daily_loss.record_cents(-(state.daily_loss_cents.min(i64::MAX as u64) as i64));
+        } else {
+            self.daily_loss.restore_buckets(&state.loss_buckets);
+        }
+        self.daily_fees.clear();
+        self.daily_fees.restore_buckets(&state.fee_buckets);
+        self.consecutive_errors.store(state.consecutive_errors, Ordering::Release);
+        self.open_positions.store(state.open_positions, Ordering::Release);
+        self.tripped.store(state.tripped, Ordering::Release);
+        self.trip_tx.send_replace(state.tripped);
+        self.update_metrics();
+
+        tracing::info!(
+            "Circuit breaker restored (loss=${:.2}, errors={}, positions={}, tripped={})",
+            self.daily_loss(),
+            state.consecutive_errors,
+            state.open_positions,
+            state.tripped
+        );
+        Ok(())
+    }
+
+    /// Write `export_state` to `path`
+    pub fn save_state(&self, path: &Path) -> Result<(), HftError> {
+        std::fs::write(path, self.export_state().to_string())
+            .map_err(|e| HftError::Io(format!("Failed to write {}: {}", path.display(), e)))
+    }
+
+    /// Restore state saved by `save_state` within the last 24 hours
+    ///
+    /// Returns `Ok(false)` when the file is missing or older, so counters
+    /// start fresh. Restored buckets older than 24 hours age out on their own.
+    pub fn restore_state(&self, path: &Path) -> Result<bool, HftError> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => {
+                return Err(HftError::Io(format!("Failed to read {}: {}", path.display(), e)))
+            }
+        };
+
+        let state: serde_json::Value = serde_json::from_str(&contents)
+            .map_err(|e| {
+                HftError::ParseError(format!("Invalid circuit breaker state file: {}", e))
+            })?;
+
+        let saved_at = state["saved_at"].as_i64().unwrap_or_default();
+        if Utc::now().timestamp() - saved_at >= MAX_STATE_AGE_SECS {
+            tracing::info!("Ignoring circuit breaker state saved over 24 hours ago");
+            return Ok(false);
+        }
+
+        self.import_state(state)?;
+        Ok(true)
+    }
+
+    /// Reset daily counters
+    ///
+    /// The loss window rolls on its own; this clears it early along with the
+    /// fee and error counters (e.g., after a manual review).
     pub fn reset_daily(&self) {
-        self.daily_loss_cents.store(0, Ordering::Release);
+        self.daily_loss.clear();
+        self.daily_fees.clear();
         self.consecutive_errors.store(0, Ordering::Release);
         *self.last_reset.write() = Instant::now();
+        self.update_metrics();
         tracing::info!("Daily counters reset");
     }
 
@@ -206,7 +686,9 @@ impl CircuitBreaker {
             if last_reset.elapsed() >= cooldown {
                 // Check if safe to reset
                 let daily_loss = self.daily_loss();
-                if daily_loss < self.config.max_daily_loss * 0.9 {
+                if daily_loss < self.config.max_daily_loss * 0.9
+                    && self.rolling_loss() <= self.config.max_loss_in_window
+                {
                     self.reset();
                     return true;
                 }
@@ -222,18 +704,31 @@ pub type SharedCircuitBreaker = Arc<CircuitBreaker>;
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::risk::PositionTracker;
+    use crate::types::OrderBookEntry;
 
     fn create_test_config() -> RiskConfig {
         RiskConfig {
             max_daily_loss: 100.0,
             max_position_size: 50.0,
+            max_loss_per_trade: 50.0,
             max_open_positions: 5,
             min_usdc_balance: 10.0,
             min_matic_balance: 1.0,
             max_consecutive_errors: 10,
+            risk_score_alert_threshold: 70.0,
+            fee_rate_bps: 100,
+            max_anomalies_before_skip: 3,
+            max_concentration: 0.5,
+            max_loss_in_window: 100.0,
+            loss_window_secs: 3600,
         }
     }
 
+    fn test_market() -> MarketId {
+        MarketId::new("test-market")
+    }
+
     #[test]
     fn test_can_execute_initial() {
         let cb = CircuitBreaker::new(create_test_config());
@@ -253,6 +748,25 @@ mod tests {
         assert!(cb.can_execute());
     }
 
+    #[test]
+    fn test_publishes_state_changes() {
+        let bus = EventBus::default();
+        let mut events = bus.subscribe();
+        let cb = CircuitBreaker::new(create_test_config()).with_event_bus(bus);
+
+        cb.trip();
+        cb.reset();
+
+        for expected in [true, false] {
+            match events.try_recv() {
+                Ok(TradingEvent::CircuitBreakerStateChanged { tripped }) => {
+                    assert_eq!(tripped, expected)
+                }
+                other => panic!("Unexpected event: {:?}", other),
+            }
+        }
+    }
+
     #[test]
     fn test_record_loss() {
         let cb = CircuitBreaker::new(create_test_config());
@@ -277,6 +791,10 @@ mod tests {
         // Record a $20 profit
         cb.record_trade(20.0).unwrap();
         assert_eq!(cb.daily_loss(), 30.0);
+
+        // Fixed-point P&L, e.g. from `Position::unrealized_pnl_fixed`
+        cb.record_trade(-SignedFixedPrice::from_f64(12.34)).unwrap();
+        assert!((cb.daily_loss() - 42.34).abs() < 1e-9);
     }
 
     #[test]
@@ -298,10 +816,10 @@ mod tests {
 
         assert_eq!(cb.positions(), 0);
 
-        cb.open_position().unwrap();
+        cb.open_position(&test_market(), 10.0).unwrap();
         assert_eq!(cb.positions(), 1);
 
-        cb.open_position().unwrap();
+        cb.open_position(&test_market(), 10.0).unwrap();
         assert_eq!(cb.positions(), 2);
 
         cb.close_position();
@@ -317,19 +835,60 @@ mod tests {
 
         // Open 5 positions (at limit)
         for _ in 0..5 {
-            cb.open_position().unwrap();
+            cb.open_position(&test_market(), 10.0).unwrap();
         }
 
         assert_eq!(cb.positions(), 5);
         assert!(cb.can_execute(), "Should still be able to execute at limit");
 
         // Try to open 6th position (should fail and trip)
-        let result = cb.open_position();
+        let result = cb.open_position(&test_market(), 10.0);
         assert!(result.is_err(), "Should not allow exceeding max positions");
         assert!(!cb.can_execute(), "Circuit breaker should trip");
         assert_eq!(cb.positions(), 5, "Position count should not increase after failure");
     }
 
+    #[test]
+    fn test_concentration_limit() {
+        use crate::types::Position;
+
+        let tracker = Arc::new(PositionTracker::new());
+        let cb = CircuitBreaker::new(create_test_config()).with_position_tracker(tracker.clone());
+        let position = |market: &str| Position {
+            market_id: MarketId::new(market),
+            token_id: TokenId(format!("{}-yes", market)),
+            size: 100.0,
+            entry_price: 0.50,
+            current_price: 0.50,
+            unrealized_pnl: 0.0,
+            realized_pnl: 0.0,
+            opened_at: 0,
+            updated_at: 0,
+            order_hashes: Vec::new(),
+        };
+
+        // First position of an empty portfolio is always 100%
+        let m1 = MarketId::new("m1");
+        cb.open_position(&m1, 50.0).unwrap();
+        tracker.update_position(m1.clone(), TokenId("m1-yes".to_string()), position("m1"));
+
+        // Spread across markets: $50 more in m2 is exactly 50%
+        let m2 = MarketId::new("m2");
+        cb.open_position(&m2, 50.0).unwrap();
+        tracker.update_position(m2.clone(), TokenId("m2-yes".to_string()), position("m2"));
+        assert!(cb.can_execute());
+
+        // $100 more in m1 would be $150 / $200 = 75%
+        let result = cb.open_position(&m1, 100.0);
+        assert!(result.is_err(), "Should not allow concentrated position");
+        assert!(cb.can_execute(), "Only the concentrated trade is rejected");
+        assert_eq!(cb.positions(), 2, "Position count should not increase after failure");
+
+        // Other markets can still trade: $50 in m3 is 25%
+        cb.open_position(&MarketId::new("m3"), 50.0).unwrap();
+        assert_eq!(cb.positions(), 3);
+    }
+
     #[test]
     fn test_consecutive_errors() {
         let cb = CircuitBreaker::new(create_test_config());
@@ -366,15 +925,19 @@ mod tests {
         // Record loss and errors
         cb.record_trade(-50.0).unwrap();
         cb.record_error();
+        cb.record_fees(1.45);
+        cb.record_fees(0.70);
 
         assert_eq!(cb.daily_loss(), 50.0);
         assert_eq!(cb.errors(), 1);
+        assert_eq!(cb.daily_fees(), 2.15);
 
         // Reset daily counters
         cb.reset_daily();
 
         assert_eq!(cb.daily_loss(), 0.0);
         assert_eq!(cb.errors(), 0);
+        assert_eq!(cb.daily_fees(), 0.0);
     }
 
     #[test]
@@ -417,4 +980,257 @@ mod tests {
         // All 50 losing trades processed (50 * $1 = $50 loss)
         assert_eq!(cb.daily_loss(), 50.0);
     }
+
+    #[test]
+    fn test_rolling_loss_limit_concurrent() {
+        use std::thread;
+
+        let config = RiskConfig { max_loss_in_window: 30.0, ..create_test_config() };
+        let cb = Arc::new(CircuitBreaker::new(config));
+
+        let record_losses = |count: usize, loss: f64| {
+            let handles: Vec<_> = (0..10)
+                .map(|_| {
+                    let cb = Arc::clone(&cb);
+                    thread::spawn(move || {
+                        for _ in 0..count {
+                            let _ = cb.record_trade(-loss);
+                        }
+                    })
+                })
+                .collect();
+            handles.into_iter().for_each(|handle| handle.join().unwrap());
+        };
+
+        // 10 threads * 3 * $1 reaches the $30 limit without exceeding it
+        record_losses(3, 1.0);
+        assert_eq!(cb.rolling_loss(), 30.0);
+        assert!(cb.can_execute(), "Should not trip at the limit");
+
+        // The first loss past $30 trips; later trades are rejected
+        record_losses(5, 0.01);
+        assert!(!cb.can_execute(), "Should trip once the window loss exceeds the limit");
+        assert!(cb.rolling_loss() > 30.0);
+        assert!(cb.rolling_loss() <= 30.10);
+        assert!(cb.daily_loss() < 100.0, "Daily limit not reached");
+    }
+
+    #[test]
+    fn test_risk_score() {
+        let cb = CircuitBreaker::new(create_test_config());
+        assert_eq!(cb.risk_score(), 0.0);
+
+        // 50% of daily loss limit -> 20 points
+        cb.record_trade(-50.0).unwrap();
+        assert!((cb.risk_score() - 20.0).abs() < 1e-9);
+
+        // + 2/5 positions -> 12 points
+        cb.open_position(&test_market(), 10.0).unwrap();
+        cb.open_position(&test_market(), 10.0).unwrap();
+        assert!((cb.risk_score() - 32.0).abs() < 1e-9);
+
+        // + 5/10 errors -> 10 points
+        for _ in 0..5 {
+            cb.record_error();
+        }
+        assert!((cb.risk_score() - 42.0).abs() < 1e-9);
+
+        // Tripped breaker saturates the score
+        cb.trip();
+        assert_eq!(cb.risk_score(), 100.0);
+    }
+
+    #[test]
+    fn test_risk_score_gauge_registered() {
+        let cb = CircuitBreaker::new(create_test_config());
+        let registry = Registry::new();
+        cb.register_metrics(&registry).unwrap();
+
+        cb.record_trade(-25.0).unwrap();
+        cb.risk_score();
+
+        let families = registry.gather();
+        let family = families.iter().find(|f| f.get_name() == "cb_risk_score").unwrap();
+        let value = family.get_metric()[0].get_gauge().get_value();
+        assert!((value - 10.0).abs() < 1e-9);
+
+        // with_metrics serves it with the bot-wide metrics
+        let metrics = Arc::new(HftMetrics::new());
+        let cb = CircuitBreaker::new(create_test_config()).with_metrics(Arc::clone(&metrics));
+        cb.risk_score();
+        assert!(metrics.render().contains("cb_risk_score"));
+    }
+
+    #[test]
+    fn test_nonce_drift_warning_threshold() {
+        let cb = CircuitBreaker::new(create_test_config());
+        assert_eq!(cb.nonce_drift_warning_threshold(), DEFAULT_NONCE_DRIFT_WARNING_THRESHOLD);
+
+        cb.set_nonce_drift_warning_threshold(25);
+        assert_eq!(cb.nonce_drift_warning_threshold(), 25);
+    }
+
+    #[test]
+    fn test_export_import_state_across_restart() {
+        let cb = CircuitBreaker::new(create_test_config());
+        cb.record_trade(-42.50).unwrap();
+        cb.open_position(&test_market(), 10.0).unwrap();
+        cb.record_error();
+        cb.record_error();
+
+        let state = cb.export_state();
+        assert_eq!(state["daily_loss_cents"], 4250);
+
+        // Simulate restart: fresh breaker, then mutate before restoring
+        let restarted = CircuitBreaker::new(create_test_config());
+        restarted.record_error();
+        restarted.trip();
+        restarted.import_state(state).unwrap();
+
+        assert_eq!(restarted.daily_loss(), 42.50);
+        assert_eq!(restarted.positions(), 1);
+        assert_eq!(restarted.errors(), 2);
+        assert!(restarted.can_execute());
+
+        assert!(restarted.import_state(serde_json::json!({ "tripped": true })).is_err());
+    }
+
+    #[test]
+    fn test_restore_state_file() {
+        let path = std::env::temp_dir()
+            .join(format!("cb_state_test_{}.json", std::process::id()));
+        let cb = CircuitBreaker::new(create_test_config());
+        assert_eq!(cb.restore_state(&path), Ok(false)); // Missing file
+
+        cb.record_trade(-10.0).unwrap();
+        cb.save_state(&path).unwrap();
+
+        let restarted = CircuitBreaker::new(create_test_config());
+        assert_eq!(restarted.restore_state(&path), Ok(true));
+        assert_eq!(restarted.daily_loss(), 10.0);
+
+        // Saved 23 hours ago (possibly the previous UTC day): restored, but
+        // only the loss from the last 24 hours still counts
+        let now_minute = (Utc::now().timestamp() / 60) as u32;
+        let mut state = cb.export_state();
+        state["saved_at"] = (Utc::now().timestamp() - 23 * 3600).into();
+        let (recent, expired) = (now_minute - 1_380, now_minute - 1_500);
+        state["loss_buckets"] = serde_json::json!([[recent, 700], [expired, 900]]);
+        state["fee_buckets"] = serde_json::json!([[recent, 25]]);
+        std::fs::write(&path, state.to_string()).unwrap();
+        let restarted = CircuitBreaker::new(create_test_config());
+        assert_eq!(restarted.restore_state(&path), Ok(true));
+        assert_eq!(restarted.daily_loss(), 7.0);
+        assert_eq!(restarted.daily_fees(), 0.25);
+
+        // Saved over 24 hours ago: ignored
+        state["saved_at"] = (Utc::now().timestamp() - 25 * 3600).into();
+        std::fs::write(&path, state.to_string()).unwrap();
+        let restarted = CircuitBreaker::new(create_test_config());
+        assert_eq!(restarted.restore_state(&path), Ok(false));
+        assert_eq!(restarted.daily_loss(), 0.0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_market_anomalies() {
+        let cb = CircuitBreaker::new(create_test_config()); // max_anomalies_before_skip = 3
+        let token = TokenId("YES".to_string());
+        let other = TokenId("NO".to_string());
+
+        cb.observe_market_anomaly(&token, AnomalyReason::CrossedBook);
+        cb.observe_market_anomaly(&token, AnomalyReason::PriceSpike(0.25));
+        cb.observe_market_anomaly(&token, AnomalyReason::DataGap(Duration::from_secs(30)));
+        assert_eq!(cb.anomaly_count(&token), 3);
+        assert!(cb.can_trade_market(&token));
+
+        cb.observe_market_anomaly(&token, AnomalyReason::ZeroLiquidity);
+        assert!(!cb.can_trade_market(&token));
+        assert!(cb.can_trade_market(&other), "Other markets unaffected");
+        assert!(cb.can_execute(), "Anomalies do not trip the breaker");
+
+        // Anomalies outside the 5-minute window no longer count
+        let Some(expired) = Instant::now().checked_sub(ANOMALY_WINDOW + Duration::from_secs(1))
+        else {
+            return;
+        };
+        cb.market_anomalies.insert(token.clone(), VecDeque::from(vec![expired; 4]));
+        assert_eq!(cb.anomaly_count(&token), 0);
+        assert!(cb.can_trade_market(&token));
+
+        cb.observe_market_anomaly(&token, AnomalyReason::CrossedBook);
+        assert_eq!(cb.market_anomalies.get(&token).unwrap().len(), 1, "Expired entries pruned");
+    }
+
+    #[test]
+    fn test_observe_order_book() {
+        let cb = CircuitBreaker::new(create_test_config());
+        let token = TokenId("YES".to_string());
+        let book = |bid: f64, ask: f64, timestamp: i64| OrderBook {
+            token_id: token.clone(),
+            bids: vec![OrderBookEntry { price: bid, size: 100.0, timestamp: None }],
+            asks: vec![OrderBookEntry { price: ask, size: 100.0, timestamp: None }],
+            timestamp,
+        };
+        let healthy = book(0.48, 0.50, 1_000);
+
+        assert_eq!(cb.observe_order_book(&token, None, &healthy), None);
+        assert_eq!(cb.observe_order_book(&token, Some(&healthy), &book(0.49, 0.51, 2_000)), None);
+        assert_eq!(
+            cb.observe_order_book(&token, None, &book(0.52, 0.50, 1_000)),
+            Some(AnomalyReason::CrossedBook)
+        );
+        let empty = OrderBook { bids: vec![], asks: vec![], ..healthy.clone() };
+        assert_eq!(
+            cb.observe_order_book(&token, None, &empty),
+            Some(AnomalyReason::ZeroLiquidity)
+        );
+        assert!(matches!(
+            cb.observe_order_book(&token, Some(&healthy), &book(0.70, 0.72, 2_000)),
+            Some(AnomalyReason::PriceSpike(change)) if change > 0.4
+        ));
+        assert_eq!(
+            cb.observe_order_book(&token, Some(&healthy), &book(0.48, 0.50, 61_000)),
+            Some(AnomalyReason::DataGap(Duration::from_secs(60)))
+        );
+        assert_eq!(cb.anomaly_count(&token), 4);
+        assert!(!cb.can_trade_market(&token));
+    }
+
+    #[cfg(feature = "alerts")]
+    #[tokio::test]
+    async fn test_trip_sends_alert() {
+        use crate::types::config::AlertChannel;
+
+        let mut server = mockito::Server::new_async().await;
+        let webhook = server
+            .mock("POST", "/alerts")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "severity": "critical",
+                "message": "Circuit breaker tripped",
+            })))
+            .with_status(200)
+            .create_async()
+            .await;
+
+        let sender = Arc::new(AlertSender::new(vec![AlertChannel::Webhook {
+            url: format!("{}/alerts", server.url()),
+            headers: Default::default(),
+        }]));
+        let cb = CircuitBreaker::new(create_test_config()).with_alert_sender(sender);
+        cb.trip();
+
+        // Delivery happens on a spawned task
+        for _ in 0..50 {
+            if webhook.matched_async().await {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        // Tripping an already tripped breaker sends no second alert
+        cb.trip();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        webhook.assert_async().await;
+    }
 }
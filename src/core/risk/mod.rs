@@ -3,7 +3,15 @@
 //! Provides circuit breaker and position tracking for safe trading.
 
 pub mod circuit_breaker;
+pub mod persistent_position_tracker;
 pub mod position_tracker;
+pub mod sliding_window;
 
-pub use circuit_breaker::{CircuitBreaker, SharedCircuitBreaker};
-pub use position_tracker::{PositionTracker, SharedPositionTracker};
+pub use circuit_breaker::{
+    AnomalyReason, CircuitBreaker, SharedCircuitBreaker, DEFAULT_NONCE_DRIFT_WARNING_THRESHOLD,
+};
+pub use persistent_position_tracker::PersistentPositionTracker;
+pub use position_tracker::{
+    PositionSnapshot, PositionStore, PositionTracker, SharedPositionTracker,
+};
+pub use sliding_window::{RollingDailyLoss, SlidingWindowPnL, MINUTES_PER_DAY};
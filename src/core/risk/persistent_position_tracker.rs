@@ -0,0 +1,204 @@
+//! Position tracking that survives restarts
+//!
+//! [`PersistentPositionTracker`] keeps the in-memory [`PositionTracker`] for
+//! reads and writes every change through to a `sled` database, one
+//! bincode-encoded [`Position`] per `{market_id}:{token_id}` key, so open
+//! positions are still marked to market and redeemed after a restart. Each
+//! change touches only its own key; sled flushes to disk in the background
+//! (every 500ms by default), so a crash loses at most the last flush interval.
+
+use parking_lot::Mutex;
+use std::path::{Path, PathBuf};
+
+use super::{PositionStore, PositionTracker};
+use crate::types::{EventBus, HftError, MarketId, Position, TokenId, TradingEvent};
+
+/// Position tracker persisted to a sled database
+#[derive(Debug)]
+pub struct PersistentPositionTracker {
+    /// In-memory positions
+    tracker: PositionTracker,
+
+    /// Position database (default tree holds the positions)
+    db: sled::Db,
+
+    /// Database directory
+    db_path: PathBuf,
+
+    /// Keeps the database in the same order as the in-memory tracker
+    write_lock: Mutex<()>,
+}
+
+impl PersistentPositionTracker {
+    /// Open the position database at `db_path`, loading any saved positions
+    ///
+    /// A missing database is created empty.
+    pub fn new(db_path: &Path) -> Result<Self, HftError> {
+        let db = sled::open(db_path).map_err(|e| {
+            HftError::Io(format!("Failed to open {}: {}", db_path.display(), e))
+        })?;
+
+        let tracker = PositionTracker::new();
+        for entry in db.iter() {
+            let (key, value) = entry.map_err(|e| {
+                HftError::Io(format!("Failed to read {}: {}", db_path.display(), e))
+            })?;
+            let position: Position = bincode::deserialize(&value).map_err(|e| {
+                HftError::ParseError(format!(
+                    "Invalid position {} in {}: {}",
+                    String::from_utf8_lossy(&key),
+                    db_path.display(),
+                    e
+                ))
+            })?;
+            let (market_id, token_id) = (position.market_id.clone(), position.token_id.clone());
+            tracker.update_position(market_id, token_id, position);
+        }
+        tracing::info!("Loaded {} positions from {}", tracker.position_count(), db_path.display());
+
+        Ok(Self {
+            tracker,
+            db,
+            db_path: db_path.to_path_buf(),
+            write_lock: Mutex::new(()),
+        })
+    }
+
+    /// Publish new and removed positions on `event_bus`
+    ///
+    /// Positions loaded from the database are not published.
+    pub fn with_event_bus(mut self, event_bus: EventBus<TradingEvent>) -> Self {
+        self.tracker = self.tracker.with_event_bus(event_bus);
+        self
+    }
+
+    /// Database directory
+    pub fn db_path(&self) -> &Path {
+        &self.db_path
+    }
+
+    /// Named tree in the same database (e.g. for the redemption queue)
+    pub fn open_tree(&self, name: &str) -> Result<sled::Tree, HftError> {
+        self.db
+            .open_tree(name)
+            .map_err(|e| HftError::Io(format!("Failed to open tree {}: {}", name, e)))
+    }
+
+    /// Storage key of a position
+    fn key(market_id: &MarketId, token_id: &TokenId) -> String {
+        format!("{}:{}", market_id, token_id.0)
+    }
+
+    /// Log a failed write (the in-memory state stays current)
+    fn log_failure<T>(result: Result<T, HftError>) {
+        if let Err(e) = result {
+            tracing::error!("Failed to persist positions: {}", e);
+        }
+    }
+
+    /// Write error for the database
+    fn write_error(&self, e: sled::Error) -> HftError {
+        HftError::Io(format!("Failed to write {}: {}", self.db_path.display(), e))
+    }
+
+    /// Write one position
+    fn insert(&self, key: String, position: &Position) -> Result<(), HftError> {
+        let value = bincode::serialize(position)
+            .map_err(|e| HftError::ParseError(format!("Failed to serialize position: {}", e)))?;
+        self.db.insert(key, value).map(|_| ()).map_err(|e| self.write_error(e))
+    }
+}
+
+impl PositionStore for PersistentPositionTracker {
+    fn update_position(&self, market_id: MarketId, token_id: TokenId, position: Position) {
+        let _guard = self.write_lock.lock();
+        Self::log_failure(self.insert(Self::key(&market_id, &token_id), &position));
+        self.tracker.update_position(market_id, token_id, position);
+    }
+
+    fn remove_position(&self, market_id: &MarketId, token_id: &TokenId) -> Option<Position> {
+        let _guard = self.write_lock.lock();
+        let removed = self.tracker.remove_position(market_id, token_id);
+        if removed.is_some() {
+            let key = Self::key(market_id, token_id);
+            Self::log_failure(self.db.remove(key).map_err(|e| self.write_error(e)));
+        }
+        removed
+    }
+
+    fn clear(&self) {
+        let _guard = self.write_lock.lock();
+        self.tracker.clear();
+        Self::log_failure(self.db.clear().map_err(|e| self.write_error(e)));
+    }
+
+    fn tracker(&self) -> &PositionTracker {
+        &self.tracker
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_position(market_id: &str, token_id: &str, size: f64) -> Position {
+        Position {
+            market_id: MarketId::new(market_id),
+            token_id: TokenId(token_id.to_string()),
+            size,
+            entry_price: 0.5,
+            current_price: 0.5,
+            unrealized_pnl: 0.0,
+            realized_pnl: 0.0,
+            opened_at: 1000,
+            updated_at: 1000,
+            order_hashes: Vec::new(),
+        }
+    }
+
+    fn insert(store: &dyn PositionStore, position: Position) {
+        store.update_position(position.market_id.clone(), position.token_id.clone(), position);
+    }
+
+    #[test]
+    fn test_positions_survive_restart() {
+        let dir = std::env::temp_dir().join(format!("positions-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("positions.db");
+        let _ = std::fs::remove_dir_all(&db_path);
+
+        let tracker = PersistentPositionTracker::new(&db_path).unwrap();
+        assert_eq!(tracker.position_count(), 0);
+
+        insert(&tracker, create_test_position("m1", "yes", 100.0));
+        insert(&tracker, create_test_position("m1", "no", 100.0));
+        insert(&tracker, create_test_position("m2", "yes", 40.0));
+        insert(&tracker, create_test_position("m2", "yes", 50.0));
+        let (m1, no) = (MarketId::new("m1"), TokenId("no".to_string()));
+        assert!(tracker.remove_position(&m1, &no).is_some());
+        drop(tracker);
+
+        let db = sled::open(&db_path).unwrap();
+        let keys: Vec<_> = db.iter().keys().map(|key| key.unwrap().to_vec()).collect();
+        assert_eq!(keys, [b"m1:yes".to_vec(), b"m2:yes".to_vec()]);
+        drop(db);
+
+        let restored = PersistentPositionTracker::new(&db_path).unwrap();
+        assert_eq!(restored.position_count(), 2);
+        assert!(!restored.has_position(&m1, &no));
+        let (m2, yes) = (MarketId::new("m2"), TokenId("yes".to_string()));
+        assert_eq!(restored.get_position(&m2, &yes).unwrap().size, 50.0);
+        assert_eq!(restored.total_exposure(), 75.0);
+
+        restored.clear();
+        drop(restored);
+        assert_eq!(PersistentPositionTracker::new(&db_path).unwrap().position_count(), 0);
+
+        sled::open(&db_path).unwrap().insert("m3:yes", &b"not a position"[..]).unwrap();
+        assert!(matches!(
+            PersistentPositionTracker::new(&db_path),
+            Err(HftError::ParseError(_))
+        ));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -4,8 +4,29 @@
 
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::Instant;
 use parking_lot::RwLock;
-use crate::types::{MarketId, Position, TokenId};
+use crate::types::{EventBus, MarketId, Position, TokenId, TradingEvent};
+use crate::utils::fixed_point::SignedFixedPrice;
+
+/// Point-in-time view of all positions
+///
+/// Derived fields are computed from the same copy of the positions,
+/// so they are always consistent with each other.
+#[derive(Debug, Clone)]
+pub struct PositionSnapshot {
+    /// All open positions
+    pub positions: Vec<((MarketId, TokenId), Position)>,
+
+    /// When the snapshot was taken
+    pub captured_at: Instant,
+
+    /// Total position size (in dollars)
+    pub total_exposure: f64,
+
+    /// Total unrealized P&L (only if current prices were provided)
+    pub total_unrealized_pnl: Option<f64>,
+}
 
 /// Position tracker for managing open positions
 ///
@@ -15,6 +36,9 @@ use crate::types::{MarketId, Position, TokenId};
 pub struct PositionTracker {
     /// Map of (market_id, token_id) -> Position
     positions: RwLock<HashMap<(MarketId, TokenId), Position>>,
+
+    /// Receives `PositionOpened` and `PositionClosed` events
+    event_bus: Option<EventBus<TradingEvent>>,
 }
 
 impl PositionTracker {
@@ -22,13 +46,25 @@ impl PositionTracker {
     pub fn new() -> Self {
         Self {
             positions: RwLock::new(HashMap::new()),
+            event_bus: None,
         }
     }
 
+    /// Publish new and removed positions on `event_bus`
+    pub fn with_event_bus(mut self, event_bus: EventBus<TradingEvent>) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
     /// Add or update a position
+    ///
+    /// Only a position not tracked before is published as `PositionOpened`.
     pub fn update_position(&self, market_id: MarketId, token_id: TokenId, position: Position) {
-        let mut positions = self.positions.write();
-        positions.insert((market_id, token_id), position);
+        let opened = self.event_bus.as_ref().map(|bus| (bus, position.clone()));
+        let previous = self.positions.write().insert((market_id, token_id), position);
+        if let (None, Some((bus, position))) = (previous, opened) {
+            bus.publish(TradingEvent::PositionOpened(position));
+        }
     }
 
     /// Get a position
@@ -39,8 +75,11 @@ impl PositionTracker {
 
     /// Remove a position (when closed)
     pub fn remove_position(&self, market_id: &MarketId, token_id: &TokenId) -> Option<Position> {
-        let mut positions = self.positions.write();
-        positions.remove(&(market_id.clone(), token_id.clone()))
+        let removed = self.positions.write().remove(&(market_id.clone(), token_id.clone()));
+        if let (Some(bus), Some(position)) = (&self.event_bus, &removed) {
+            bus.publish(TradingEvent::PositionClosed(position.clone()));
+        }
+        removed
     }
 
     /// Get total number of open positions
@@ -57,12 +96,13 @@ impl PositionTracker {
             .iter()
             .map(|((market_id, token_id), position)| {
                 if let Some(&current_price) = current_prices.get(&(market_id.clone(), token_id.clone())) {
-                    position.calculate_unrealized_pnl(current_price)
+                    position.unrealized_pnl_fixed(current_price)
                 } else {
-                    0.0 // No current price available
+                    SignedFixedPrice::ZERO // No current price available
                 }
             })
-            .sum()
+            .fold(SignedFixedPrice::ZERO, |total, pnl| total + pnl)
+            .to_f64()
     }
 
     /// Get total position size (in dollars)
@@ -75,6 +115,35 @@ impl PositionTracker {
             .sum()
     }
 
+    /// Get total position size (in dollars) in one market
+    pub fn market_exposure(&self, market_id: &MarketId) -> f64 {
+        let positions = self.positions.read();
+
+        positions
+            .iter()
+            .filter(|((m, _), _)| m == market_id)
+            .map(|(_, position)| position.entry_price * position.abs_size())
+            .sum()
+    }
+
+    /// Share of total exposure held in one market (0.0-1.0)
+    ///
+    /// Returns 0.0 when there is no exposure.
+    pub fn position_concentration(&self, market_id: &MarketId) -> f64 {
+        self.position_concentration_after(market_id, 0.0)
+    }
+
+    /// Share of total exposure in one market after adding `additional`
+    /// dollars of exposure to it
+    pub fn position_concentration_after(&self, market_id: &MarketId, additional: f64) -> f64 {
+        let total = self.total_exposure() + additional;
+        if total <= 0.0 {
+            return 0.0;
+        }
+
+        ((self.market_exposure(market_id) + additional) / total).clamp(0.0, 1.0)
+    }
+
     /// Get all positions
     pub fn get_all_positions(&self) -> Vec<((MarketId, TokenId), Position)> {
         let positions = self.positions.read();
@@ -84,6 +153,40 @@ impl PositionTracker {
             .collect()
     }
 
+    /// Take a consistent snapshot of all positions
+    ///
+    /// The read lock is held only while copying the positions; exposure and
+    /// P&L are computed from the copy after the lock is released.
+    pub fn to_snapshot(
+        &self,
+        current_prices: Option<&HashMap<(MarketId, TokenId), f64>>,
+    ) -> PositionSnapshot {
+        let positions = self.get_all_positions();
+        let captured_at = Instant::now();
+
+        let total_exposure = positions
+            .iter()
+            .map(|(_, position)| position.entry_price * position.abs_size())
+            .sum();
+
+        let total_unrealized_pnl = current_prices.map(|prices| {
+            positions
+                .iter()
+                .filter_map(|(key, position)| {
+                    prices.get(key).map(|&price| position.unrealized_pnl_fixed(price))
+                })
+                .fold(SignedFixedPrice::ZERO, |total, pnl| total + pnl)
+                .to_f64()
+        });
+
+        PositionSnapshot {
+            positions,
+            captured_at,
+            total_exposure,
+            total_unrealized_pnl,
+        }
+    }
+
     /// Clear all positions
     pub fn clear(&self) {
         let mut positions = self.positions.write();
@@ -116,13 +219,107 @@ impl Default for PositionTracker {
 /// Thread-safe wrapper for position tracker
 pub type SharedPositionTracker = Arc<PositionTracker>;
 
+/// Position tracker API shared by in-memory and persistent trackers
+///
+/// Implementors provide the writes and an in-memory [`PositionTracker`] view;
+/// reads default to that view.
+pub trait PositionStore: Send + Sync + std::fmt::Debug {
+    /// Add or update a position
+    fn update_position(&self, market_id: MarketId, token_id: TokenId, position: Position);
+
+    /// Remove a position (when closed)
+    fn remove_position(&self, market_id: &MarketId, token_id: &TokenId) -> Option<Position>;
+
+    /// Clear all positions
+    fn clear(&self);
+
+    /// In-memory view of the positions
+    fn tracker(&self) -> &PositionTracker;
+
+    /// Get a position
+    fn get_position(&self, market_id: &MarketId, token_id: &TokenId) -> Option<Position> {
+        self.tracker().get_position(market_id, token_id)
+    }
+
+    /// Get total number of open positions
+    fn position_count(&self) -> usize {
+        self.tracker().position_count()
+    }
+
+    /// Calculate total unrealized P&L across all positions
+    fn total_unrealized_pnl(&self, current_prices: &HashMap<(MarketId, TokenId), f64>) -> f64 {
+        self.tracker().total_unrealized_pnl(current_prices)
+    }
+
+    /// Get total position size (in dollars)
+    fn total_exposure(&self) -> f64 {
+        self.tracker().total_exposure()
+    }
+
+    /// Get total position size (in dollars) in one market
+    fn market_exposure(&self, market_id: &MarketId) -> f64 {
+        self.tracker().market_exposure(market_id)
+    }
+
+    /// Share of total exposure held in one market (0.0-1.0)
+    fn position_concentration(&self, market_id: &MarketId) -> f64 {
+        self.tracker().position_concentration(market_id)
+    }
+
+    /// Share of total exposure in one market after adding `additional` dollars to it
+    fn position_concentration_after(&self, market_id: &MarketId, additional: f64) -> f64 {
+        self.tracker().position_concentration_after(market_id, additional)
+    }
+
+    /// Get all positions
+    fn get_all_positions(&self) -> Vec<((MarketId, TokenId), Position)> {
+        self.tracker().get_all_positions()
+    }
+
+    /// Take a consistent snapshot of all positions
+    fn to_snapshot(
+        &self,
+        current_prices: Option<&HashMap<(MarketId, TokenId), f64>>,
+    ) -> PositionSnapshot {
+        self.tracker().to_snapshot(current_prices)
+    }
+
+    /// Check if position exists
+    fn has_position(&self, market_id: &MarketId, token_id: &TokenId) -> bool {
+        self.tracker().has_position(market_id, token_id)
+    }
+
+    /// Get positions for a specific market
+    fn positions_for_market(&self, market_id: &MarketId) -> Vec<(TokenId, Position)> {
+        self.tracker().positions_for_market(market_id)
+    }
+}
+
+impl PositionStore for PositionTracker {
+    fn update_position(&self, market_id: MarketId, token_id: TokenId, position: Position) {
+        PositionTracker::update_position(self, market_id, token_id, position)
+    }
+
+    fn remove_position(&self, market_id: &MarketId, token_id: &TokenId) -> Option<Position> {
+        PositionTracker::remove_position(self, market_id, token_id)
+    }
+
+    fn clear(&self) {
+        PositionTracker::clear(self)
+    }
+
+    fn tracker(&self) -> &PositionTracker {
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn create_test_position(size: f64, entry_price: f64) -> Position {
         Position {
-            market_id: MarketId("test-market".to_string()),
+            market_id: MarketId::new("test-market"),
             token_id: TokenId("test-token".to_string()),
             size,
             entry_price,
@@ -131,13 +328,34 @@ mod tests {
             realized_pnl: 0.0,
             opened_at: 1000,
             updated_at: 1000,
+            order_hashes: Vec::new(),
         }
     }
 
+    #[test]
+    fn test_publishes_position_events() {
+        let bus = EventBus::default();
+        let mut events = bus.subscribe();
+        let tracker = PositionTracker::new().with_event_bus(bus);
+        let market_id = MarketId::new("test-market");
+        let token_id = TokenId("test-token".to_string());
+
+        for size in [10.0, 20.0] {
+            let position = create_test_position(size, 0.5);
+            tracker.update_position(market_id.clone(), token_id.clone(), position);
+        }
+        tracker.remove_position(&market_id, &token_id);
+        assert!(tracker.remove_position(&market_id, &token_id).is_none());
+
+        assert!(matches!(events.try_recv(), Ok(TradingEvent::PositionOpened(p)) if p.size == 10.0));
+        assert!(matches!(events.try_recv(), Ok(TradingEvent::PositionClosed(p)) if p.size == 20.0));
+        assert!(events.try_recv().is_err(), "Updates and missing removals are not published");
+    }
+
     #[test]
     fn test_add_and_get_position() {
         let tracker = PositionTracker::new();
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
         let position = create_test_position(100.0, 0.75);
 
@@ -151,7 +369,7 @@ mod tests {
     #[test]
     fn test_remove_position() {
         let tracker = PositionTracker::new();
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
         let position = create_test_position(100.0, 0.75);
 
@@ -171,7 +389,7 @@ mod tests {
         assert_eq!(tracker.position_count(), 0);
 
         tracker.update_position(
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_test_position(100.0, 0.75),
         );
@@ -179,7 +397,7 @@ mod tests {
         assert_eq!(tracker.position_count(), 1);
 
         tracker.update_position(
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_test_position(50.0, 0.60),
         );
@@ -193,14 +411,14 @@ mod tests {
 
         // Position 1: 100 shares @ $0.75 = $75
         tracker.update_position(
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_test_position(100.0, 0.75),
         );
 
         // Position 2: 50 shares @ $0.60 = $30
         tracker.update_position(
-            MarketId("m2".to_string()),
+            MarketId::new("m2"),
             TokenId("t2".to_string()),
             create_test_position(50.0, 0.60),
         );
@@ -209,13 +427,84 @@ mod tests {
         assert_eq!(tracker.total_exposure(), 105.0);
     }
 
+    #[test]
+    fn test_position_concentration_equal_distribution() {
+        let tracker = PositionTracker::new();
+        assert_eq!(tracker.position_concentration(&MarketId::new("m1")), 0.0);
+
+        // $50 in each of four markets
+        for i in 1..=4 {
+            tracker.update_position(
+                MarketId::new(format!("m{}", i)),
+                TokenId(format!("t{}", i)),
+                create_test_position(100.0, 0.50),
+            );
+        }
+
+        let m1 = MarketId::new("m1");
+        assert!((tracker.position_concentration(&m1) - 0.25).abs() < 1e-9);
+        assert_eq!(tracker.position_concentration(&MarketId::new("other")), 0.0);
+
+        // Another $50 in m1: $100 / $250
+        assert!((tracker.position_concentration_after(&m1, 50.0) - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_position_concentration_single_market_domination() {
+        let tracker = PositionTracker::new();
+        let m1 = MarketId::new("m1");
+
+        // $90 across two tokens in m1, $10 in m2
+        let yes = create_test_position(100.0, 0.60);
+        let no = create_test_position(-100.0, 0.30);
+        tracker.update_position(m1.clone(), TokenId("yes".to_string()), yes);
+        tracker.update_position(m1.clone(), TokenId("no".to_string()), no);
+        tracker.update_position(
+            MarketId::new("m2"),
+            TokenId("t2".to_string()),
+            create_test_position(20.0, 0.50),
+        );
+
+        assert_eq!(tracker.market_exposure(&m1), 90.0);
+        assert!((tracker.position_concentration(&m1) - 0.9).abs() < 1e-9);
+        assert!((tracker.position_concentration(&MarketId::new("m2")) - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_to_snapshot() {
+        let tracker = PositionTracker::new();
+        let key = (MarketId::new("m1"), TokenId("t1".to_string()));
+
+        tracker.update_position(key.0.clone(), key.1.clone(), create_test_position(100.0, 0.75));
+        tracker.update_position(
+            MarketId::new("m2"),
+            TokenId("t2".to_string()),
+            create_test_position(50.0, 0.60),
+        );
+
+        let snapshot = tracker.to_snapshot(None);
+        assert_eq!(snapshot.positions.len(), 2);
+        assert_eq!(snapshot.total_exposure, 105.0);
+        assert!(snapshot.total_unrealized_pnl.is_none());
+
+        // Only priced positions contribute to P&L: 100 * (0.80 - 0.75) = $5
+        let prices = HashMap::from([(key, 0.80)]);
+        let snapshot = tracker.to_snapshot(Some(&prices));
+        assert!((snapshot.total_unrealized_pnl.unwrap() - 5.0).abs() < 1e-9);
+        assert_eq!(snapshot.total_unrealized_pnl, Some(tracker.total_unrealized_pnl(&prices)));
+
+        // Snapshot is unaffected by later changes
+        tracker.clear();
+        assert_eq!(snapshot.positions.len(), 2);
+    }
+
     #[test]
     fn test_total_unrealized_pnl() {
         let tracker = PositionTracker::new();
 
-        let market1 = MarketId("m1".to_string());
+        let market1 = MarketId::new("m1");
         let token1 = TokenId("t1".to_string());
-        let market2 = MarketId("m2".to_string());
+        let market2 = MarketId::new("m2");
         let token2 = TokenId("t2".to_string());
 
         // Position 1: Long 100 @ $0.70
@@ -240,7 +529,7 @@ mod tests {
     #[test]
     fn test_has_position() {
         let tracker = PositionTracker::new();
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         assert!(!tracker.has_position(&market_id, &token_id));
@@ -257,7 +546,7 @@ mod tests {
     #[test]
     fn test_positions_for_market() {
         let tracker = PositionTracker::new();
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
 
         // Add 2 positions for same market
         tracker.update_position(
@@ -274,7 +563,7 @@ mod tests {
 
         // Add position for different market
         tracker.update_position(
-            MarketId("market-2".to_string()),
+            MarketId::new("market-2"),
             TokenId("token-3".to_string()),
             create_test_position(75.0, 0.80),
         );
@@ -288,7 +577,7 @@ mod tests {
         let tracker = PositionTracker::new();
 
         tracker.update_position(
-            MarketId("m1".to_string()),
+            MarketId::new("m1"),
             TokenId("t1".to_string()),
             create_test_position(100.0, 0.75),
         );
@@ -303,7 +592,7 @@ mod tests {
     #[test]
     fn test_update_existing_position() {
         let tracker = PositionTracker::new();
-        let market_id = MarketId("market-1".to_string());
+        let market_id = MarketId::new("market-1");
         let token_id = TokenId("token-1".to_string());
 
         // Add initial position
@@ -344,7 +633,7 @@ mod tests {
             let tracker_clone = Arc::clone(&tracker);
             let handle = thread::spawn(move || {
                 tracker_clone.update_position(
-                    MarketId(format!("market-{}", i)),
+                    MarketId::new(format!("market-{}", i)),
                     TokenId(format!("token-{}", i)),
                     create_test_position(100.0, 0.75),
                 );
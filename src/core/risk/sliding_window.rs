@@ -0,0 +1,283 @@
+//! Rolling-window P&L for intra-day drawdown limits
+//!
+//! A daily loss cap only fires once the whole day's budget is gone, so a
+//! sharp drawdown within an hour goes unnoticed until it is too late.
+//! [`SlidingWindowPnL`] keeps timestamped trade P&L and reports the net loss
+//! over the most recent window. [`RollingDailyLoss`] does the same for the
+//! last 24 hours in fixed memory, one lock-free bucket per UTC minute.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use chrono::Utc;
+use parking_lot::Mutex;
+
+/// Minutes covered by [`RollingDailyLoss`]
+pub const MINUTES_PER_DAY: usize = 1440;
+
+/// Net P&L over a rolling time window
+///
+/// Thread-safe; entries older than the window are pruned on every access.
+#[derive(Debug)]
+pub struct SlidingWindowPnL {
+    /// Window length
+    window: Duration,
+
+    /// Trade P&L in cents with the time it was recorded (oldest first)
+    entries: Mutex<VecDeque<(Instant, i64)>>,
+}
+
+impl SlidingWindowPnL {
+    /// Create an empty window of length `window`
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            entries: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Window length
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Record trade P&L (negative for a loss) and return the loss in window
+    ///
+    /// The returned loss includes this trade, so concurrent callers each see
+    /// the total at the moment their own trade was added.
+    pub fn record(&self, pnl: f64) -> f64 {
+        self.record_at(pnl, Instant::now())
+    }
+
+    /// Net loss (positive USDC) over the window, or 0.0 when net positive
+    pub fn loss_in_window(&self) -> f64 {
+        let mut entries = self.entries.lock();
+        Self::prune(&mut entries, self.window, Instant::now());
+        Self::loss(&entries)
+    }
+
+    /// Net P&L (USDC, negative for a loss) over the window
+    pub fn net_pnl(&self) -> f64 {
+        let mut entries = self.entries.lock();
+        Self::prune(&mut entries, self.window, Instant::now());
+        entries.iter().map(|&(_, cents)| cents).sum::<i64>() as f64 / 100.0
+    }
+
+    /// Drop all entries
+    pub fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    fn record_at(&self, pnl: f64, now: Instant) -> f64 {
+        let mut entries = self.entries.lock();
+        Self::prune(&mut entries, self.window, now);
+        entries.push_back((now, (pnl * 100.0).round() as i64));
+        Self::loss(&entries)
+    }
+
+    fn prune(entries: &mut VecDeque<(Instant, i64)>, window: Duration, now: Instant) {
+        while entries.front().is_some_and(|&(at, _)| now.duration_since(at) > window) {
+            entries.pop_front();
+        }
+    }
+
+    fn loss(entries: &VecDeque<(Instant, i64)>) -> f64 {
+        let net_cents: i64 = entries.iter().map(|&(_, cents)| cents).sum();
+        (-net_cents).max(0) as f64 / 100.0
+    }
+}
+
+/// Net loss over the last 24 hours in per-minute buckets
+///
+/// Each bucket packs the UTC minute it belongs to (high 32 bits) with the
+/// net loss in cents for that minute (low 32 bits, signed), so a bucket left
+/// over from the previous day is recognised by its stamp: it is ignored when
+/// summing and restarted on its next write. The window rolls without any
+/// reset call or background task.
+#[derive(Debug)]
+pub struct RollingDailyLoss {
+    /// Packed (minute, net loss cents), indexed by minute of day
+    buckets: Box<[AtomicU64]>,
+}
+
+impl RollingDailyLoss {
+    /// Empty window
+    pub fn new() -> Self {
+        Self {
+            buckets: (0..MINUTES_PER_DAY).map(|_| AtomicU64::new(0)).collect(),
+        }
+    }
+
+    /// Record trade P&L in cents (negative for a loss) in the current minute
+    pub fn record_cents(&self, pnl_cents: i64) {
+        self.record_at(pnl_cents, current_minute());
+    }
+
+    /// Net loss in cents over the last 24 hours, or 0 when net positive
+    pub fn loss_cents(&self) -> u64 {
+        self.loss_at(current_minute())
+    }
+
+    /// Non-empty `(minute, net loss cents)` buckets of the last 24 hours
+    ///
+    /// Minutes are since the Unix epoch; pass them to `restore_buckets`
+    /// after a restart so each keeps aging out on schedule.
+    pub fn buckets(&self) -> Vec<(u32, i32)> {
+        self.buckets_at(current_minute())
+    }
+
+    /// Add buckets saved by `buckets`, dropping those no longer in the window
+    pub fn restore_buckets(&self, buckets: &[(u32, i32)]) {
+        self.restore_at(buckets, current_minute());
+    }
+
+    /// Empty every bucket
+    pub fn clear(&self) {
+        for bucket in self.buckets.iter() {
+            bucket.store(0, Ordering::Release);
+        }
+    }
+
+    fn record_at(&self, pnl_cents: i64, minute: u32) {
+        let bucket = &self.buckets[minute as usize % MINUTES_PER_DAY];
+        let loss = pnl_cents.saturating_neg();
+        let _ = bucket.fetch_update(Ordering::AcqRel, Ordering::Acquire, |packed| {
+            let (stamp, cents) = unpack(packed);
+            let current = if stamp == minute { cents } else { 0 };
+            Some(pack(minute, current.saturating_add(saturate_i32(loss))))
+        });
+    }
+
+    fn buckets_at(&self, minute: u32) -> Vec<(u32, i32)> {
+        self.buckets
+            .iter()
+            .map(|bucket| unpack(bucket.load(Ordering::Acquire)))
+            .filter(|&(stamp, cents)| cents != 0 && in_window(stamp, minute))
+            .collect()
+    }
+
+    fn restore_at(&self, buckets: &[(u32, i32)], minute: u32) {
+        for &(stamp, cents) in buckets {
+            if in_window(stamp, minute) {
+                self.record_at(-i64::from(cents), stamp);
+            }
+        }
+    }
+
+    fn loss_at(&self, minute: u32) -> u64 {
+        let net: i64 = self
+            .buckets
+            .iter()
+            .map(|bucket| unpack(bucket.load(Ordering::Acquire)))
+            .filter(|&(stamp, _)| in_window(stamp, minute))
+            .map(|(_, cents)| i64::from(cents))
+            .sum();
+        net.max(0) as u64
+    }
+}
+
+impl Default for RollingDailyLoss {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Minutes since the Unix epoch (UTC)
+fn current_minute() -> u32 {
+    (Utc::now().timestamp() / 60) as u32
+}
+
+/// Whether a bucket stamped `stamp` is within the 24 hours ending at `minute`
+fn in_window(stamp: u32, minute: u32) -> bool {
+    minute.wrapping_sub(stamp) < MINUTES_PER_DAY as u32
+}
+
+fn pack(minute: u32, cents: i32) -> u64 {
+    (u64::from(minute) << 32) | u64::from(cents as u32)
+}
+
+fn unpack(packed: u64) -> (u32, i32) {
+    ((packed >> 32) as u32, packed as u32 as i32)
+}
+
+fn saturate_i32(cents: i64) -> i32 {
+    cents.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_net_loss_in_window() {
+        let pnl = SlidingWindowPnL::new(Duration::from_secs(3600));
+        assert_eq!(pnl.loss_in_window(), 0.0);
+
+        assert_eq!(pnl.record(-10.0), 10.0);
+        assert_eq!(pnl.record(-5.5), 15.5);
+
+        // Profits offset losses but never produce a negative loss
+        assert_eq!(pnl.record(4.0), 11.5);
+        assert_eq!(pnl.record(20.0), 0.0);
+        assert_eq!(pnl.net_pnl(), 8.5);
+
+        pnl.clear();
+        assert_eq!(pnl.loss_in_window(), 0.0);
+    }
+
+    #[test]
+    fn test_old_entries_age_out() {
+        let pnl = SlidingWindowPnL::new(Duration::from_secs(60));
+        let start = Instant::now();
+
+        pnl.record_at(-30.0, start);
+        assert_eq!(pnl.record_at(-10.0, start + Duration::from_secs(30)), 40.0);
+
+        // The first loss is more than 60s old by now
+        assert_eq!(pnl.record_at(-1.0, start + Duration::from_secs(61)), 11.0);
+    }
+
+    #[test]
+    fn test_rolling_daily_loss() {
+        let losses = RollingDailyLoss::new();
+        let start = 29_000_000; // Some UTC minute in 2025
+
+        losses.record_at(-5_000, start);
+        losses.record_at(2_000, start);
+        losses.record_at(-1_000, start + 600);
+        assert_eq!(losses.loss_at(start + 600), 4_000);
+
+        // A day later the first minute's bucket has aged out...
+        assert_eq!(losses.loss_at(start + 1_440), 1_000);
+
+        // ...and is restarted rather than added to when written again
+        losses.record_at(-700, start + 1_440);
+        assert_eq!(losses.loss_at(start + 1_440), 1_700);
+
+        // Profits offset losses but never produce a negative loss
+        losses.record_at(10_000, start + 1_441);
+        assert_eq!(losses.loss_at(start + 1_441), 0);
+
+        losses.clear();
+        assert_eq!(losses.loss_cents(), 0);
+    }
+
+    #[test]
+    fn test_restore_buckets_keeps_their_minutes() {
+        let losses = RollingDailyLoss::new();
+        let start = 29_000_000;
+        losses.record_at(-5_000, start);
+        losses.record_at(-1_000, start + 600);
+        let mut saved = losses.buckets_at(start + 600);
+        saved.sort_unstable();
+        assert_eq!(saved, [(start, 5_000), (start + 600, 1_000)]);
+
+        // Restored 23 hours later only the second minute is still in the window...
+        let restored = RollingDailyLoss::new();
+        restored.restore_at(&saved, start + 1_380 + 560);
+        assert_eq!(restored.loss_at(start + 1_380 + 560), 1_000);
+
+        // ...and it ages out a day after it was recorded
+        assert_eq!(restored.loss_at(start + 600 + 1_440), 0);
+    }
+}
@@ -0,0 +1,173 @@
+//! CLOB API key derivation (L1 authentication)
+//!
+//! The CLOB issues API credentials to whoever proves control of a wallet:
+//! sign a `ClobAuth` EIP-712 message (address + timestamp + nonce) and POST
+//! it to `/auth/api-key`. The returned credentials authenticate order
+//! placement (`ClobConfig::api_key`).
+
+use anyhow::{anyhow, Result};
+use ethers::abi::Token;
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::{H160, H256, U256};
+use ethers::utils::keccak256;
+use serde::Deserialize;
+use std::fmt;
+
+use super::PolymarketRestClient;
+use crate::clob::ClobConfig;
+
+/// Statement the wallet signs to prove ownership
+const CLOB_AUTH_MESSAGE: &str = "This message attests that I control the given wallet";
+
+/// CLOB API credentials
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+pub struct ApiCredentials {
+    /// API key
+    #[serde(rename = "apiKey")]
+    pub api_key: String,
+
+    /// API secret
+    #[serde(rename = "secret")]
+    pub api_secret: String,
+
+    /// API passphrase
+    #[serde(rename = "passphrase")]
+    pub api_passphrase: String,
+}
+
+impl ApiCredentials {
+    /// Copy the credentials into a CLOB client configuration
+    pub fn apply_to(&self, config: &mut ClobConfig) {
+        config.api_key = self.api_key.clone();
+        config.api_secret = self.api_secret.clone();
+        config.api_passphrase = self.api_passphrase.clone();
+    }
+}
+
+impl fmt::Debug for ApiCredentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ApiCredentials")
+            .field("api_key", &self.api_key)
+            .field("api_secret", &"[REDACTED]")
+            .field("api_passphrase", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl PolymarketRestClient {
+    /// Create CLOB API credentials for `wallet`
+    ///
+    /// Signs the `ClobAuth` message for the current timestamp (nonce 0) with
+    /// the wallet's chain ID and POSTs it to `/auth/api-key`.
+    pub async fn get_clob_auth(&self, wallet: &LocalWallet) -> Result<ApiCredentials> {
+        let address = wallet.address();
+        let timestamp = chrono::Utc::now().timestamp().to_string();
+        let nonce = 0u64;
+
+        let digest = clob_auth_digest(wallet.chain_id(), address, &timestamp, nonce);
+        let signature = wallet
+            .sign_hash(digest)
+            .map_err(|e| anyhow!("Failed to sign CLOB auth message: {}", e))?;
+
+        let response = self
+            .clob_rest_client
+            .http()
+            .post(format!("{}/auth/api-key", self.clob_rest_client.base_url()))
+            .header("POLY_ADDRESS", format!("{:?}", address))
+            .header("POLY_SIGNATURE", format!("0x{}", hex::encode(signature.to_vec())))
+            .header("POLY_TIMESTAMP", &timestamp)
+            .header("POLY_NONCE", nonce.to_string())
+            .send()
+            .await
+            .map_err(|e| anyhow!("CLOB auth request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!("CLOB auth failed with status {}: {}", status, error_text));
+        }
+
+        let credentials: ApiCredentials = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse CLOB credentials: {}", e))?;
+
+        tracing::info!("Obtained CLOB API key for {:?}", address);
+        Ok(credentials)
+    }
+}
+
+/// EIP-712 digest of the `ClobAuth` message
+fn clob_auth_digest(chain_id: u64, address: H160, timestamp: &str, nonce: u64) -> H256 {
+    let domain_separator = keccak256(ethers::abi::encode(&[
+        Token::FixedBytes(
+            keccak256("EIP712Domain(string name,string version,uint256 chainId)").to_vec(),
+        ),
+        Token::FixedBytes(keccak256("ClobAuthDomain").to_vec()),
+        Token::FixedBytes(keccak256("1").to_vec()),
+        Token::Uint(U256::from(chain_id)),
+    ]));
+
+    let struct_hash = keccak256(ethers::abi::encode(&[
+        Token::FixedBytes(
+            keccak256("ClobAuth(address address,string timestamp,uint256 nonce,string message)")
+                .to_vec(),
+        ),
+        Token::Address(address),
+        Token::FixedBytes(keccak256(timestamp).to_vec()),
+        Token::Uint(U256::from(nonce)),
+        Token::FixedBytes(keccak256(CLOB_AUTH_MESSAGE).to_vec()),
+    ]));
+
+    let mut digest_input = Vec::with_capacity(66);
+    digest_input.extend_from_slice(&[0x19, 0x01]);
+    digest_input.extend_from_slice(&domain_separator);
+    digest_input.extend_from_slice(&struct_hash);
+    H256::from(keccak256(digest_input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_get_clob_auth() {
+        let wallet = LocalWallet::from_str(
+            "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+        )
+        .unwrap()
+        .with_chain_id(137u64);
+
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/auth/api-key")
+            .match_header("POLY_ADDRESS", format!("{:?}", wallet.address()).as_str())
+            .match_header("POLY_NONCE", "0")
+            .match_header("POLY_SIGNATURE", mockito::Matcher::Regex("^0x[0-9a-f]{130}$".into()))
+            .with_status(200)
+            .with_body(r#"{"apiKey": "key", "secret": "hmac-secret", "passphrase": "phrase-123"}"#)
+            .create_async()
+            .await;
+
+        let client = PolymarketRestClient::new(server.url(), server.url());
+        let credentials = client.get_clob_auth(&wallet).await.unwrap();
+        mock.assert_async().await;
+        assert_eq!(credentials.api_key, "key");
+        let debug = format!("{:?}", credentials);
+        assert!(!debug.contains("hmac-secret") && !debug.contains("phrase-123"));
+
+        let mut config = ClobConfig::default();
+        credentials.apply_to(&mut config);
+        assert_eq!(config.api_key, "key");
+        assert_eq!(config.api_secret, "hmac-secret");
+        assert_eq!(config.api_passphrase, "phrase-123");
+
+        // Signature recovers to the wallet
+        let timestamp = "1700000000";
+        let digest = clob_auth_digest(137, wallet.address(), timestamp, 0);
+        let signature = wallet.sign_hash(digest).unwrap();
+        assert_eq!(signature.recover(digest).unwrap(), wallet.address());
+        assert_ne!(digest, clob_auth_digest(137, wallet.address(), "1700000001", 0));
+    }
+}
@@ -0,0 +1,451 @@
+//! Read-only CLOB REST endpoints
+//!
+//! Orderbook snapshots, market info, fee rates, fills and the collateral
+//! balance. Nothing here signs
+//! orders; placement and cancellation live in `clob::ClobClient`, which
+//! delegates its read-only calls to a [`ClobRestClient`] on the same
+//! connection pool.
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::types::{Fill, MarketId, OrderBook, OrderBookEntry, TokenId};
+use crate::utils::fixed_point::FixedPrice;
+
+/// CLOB market info (`GET /markets/{condition_id}`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarketInfo {
+    /// Market condition ID
+    pub condition_id: String,
+
+    /// Outcome tokens (YES/NO)
+    pub tokens: Vec<TokenInfo>,
+
+    /// Whether the market is accepting orders
+    pub active: bool,
+
+    /// Whether the market is closed
+    pub closed: bool,
+
+    /// Question ID (used for settlement)
+    pub question_id: String,
+
+    /// Market slug
+    #[serde(default)]
+    pub market_slug: Option<String>,
+}
+
+impl MarketInfo {
+    /// Condition ID as a `MarketId`
+    pub fn market_id(&self) -> MarketId {
+        MarketId::from_condition_id(&self.condition_id)
+    }
+
+    /// Token IDs in CLOB order
+    pub fn token_ids(&self) -> Vec<String> {
+        self.tokens.iter().map(|t| t.token_id.clone()).collect()
+    }
+}
+
+/// Outcome token of a CLOB market
+#[derive(Debug, Clone, Deserialize)]
+pub struct TokenInfo {
+    /// Token ID
+    pub token_id: String,
+
+    /// Outcome name (e.g., "Up", "Down", "Yes", "No")
+    pub outcome: String,
+}
+
+/// Orderbook price level as returned by `GET /book`
+///
+/// Accepts both `["price", "size"]` pairs and `{"price", "size"}` objects.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RestLevel {
+    Pair(String, String),
+    Object { price: String, size: String },
+}
+
+impl RestLevel {
+    /// Parse into an orderbook entry using exact decimal parsing
+    fn to_entry(&self, timestamp: i64) -> Result<OrderBookEntry> {
+        let (price, size) = match self {
+            RestLevel::Pair(price, size) | RestLevel::Object { price, size } => (price, size),
+        };
+
+        let parse = |value: &str| {
+            FixedPrice::from_decimal_str(value)
+                .ok_or_else(|| anyhow!("Invalid decimal: {:?}", value))
+        };
+
+        Ok(OrderBookEntry {
+            price: parse(price)?.to_f64(),
+            size: parse(size)?.to_f64(),
+            timestamp: Some(timestamp),
+        })
+    }
+}
+
+/// `GET /book` response
+#[derive(Debug, Deserialize)]
+struct RestOrderbook {
+    #[serde(default)]
+    bids: Vec<RestLevel>,
+    #[serde(default)]
+    asks: Vec<RestLevel>,
+    /// Milliseconds, as a number or numeric string
+    #[serde(default)]
+    timestamp: Option<serde_json::Value>,
+}
+
+/// `GET /fills` response (bare list or paginated)
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Fills {
+    List(Vec<Fill>),
+    Page { data: Vec<Fill> },
+}
+
+/// `GET /fee-rate` response
+#[derive(Debug, Deserialize)]
+struct FeeRateResponse {
+    /// Fee rate in basis points
+    base_fee: u16,
+}
+
+/// `GET /balance-allowance` response (6-decimal base units)
+#[derive(Debug, Deserialize)]
+struct BalanceAllowanceResponse {
+    balance: String,
+    #[serde(default)]
+    allowance: Option<String>,
+}
+
+/// USDC collateral the wallet holds and has approved for the exchange
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BalanceAllowance {
+    /// Balance in USDC
+    pub balance: f64,
+
+    /// Allowance granted to the exchange in USDC (`None` if not reported)
+    pub allowance: Option<f64>,
+}
+
+impl BalanceAllowance {
+    /// USDC available to trade: the balance, capped by the allowance
+    pub fn available(&self) -> f64 {
+        self.allowance.map_or(self.balance, |allowance| self.balance.min(allowance))
+    }
+}
+
+/// Polymarket CLOB REST client (read-only endpoints)
+///
+/// Fetches full orderbook snapshots, e.g. to recover from gaps in the
+/// WebSocket delta stream, and market metadata. Cheap to clone.
+#[derive(Debug, Clone)]
+pub struct ClobRestClient {
+    /// HTTP client (shared with `ClobClient` and `GammaClient`)
+    http: Arc<reqwest::Client>,
+
+    /// CLOB API base URL
+    base_url: String,
+
+    /// API key sent as `Authorization` on authenticated reads (fills)
+    api_key: Option<String>,
+}
+
+impl ClobRestClient {
+    /// Create client with its own connection pool
+    pub fn new(base_url: String) -> Self {
+        Self::with_client(super::http_client(), base_url)
+    }
+
+    /// Create client reusing an existing HTTP client (and its pool)
+    pub fn with_client(http: Arc<reqwest::Client>, base_url: String) -> Self {
+        Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: None,
+        }
+    }
+
+    /// Authenticate reads that require an API key (fills, balance)
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.api_key = Some(api_key).filter(|key| !key.is_empty());
+        self
+    }
+
+    /// CLOB API base URL (without trailing slash)
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Shared HTTP client
+    pub(crate) fn http(&self) -> &Arc<reqwest::Client> {
+        &self.http
+    }
+
+    /// Fetch a full orderbook snapshot for a token
+    ///
+    /// Bids are sorted highest first, asks lowest first.
+    pub async fn get_orderbook(&self, token_id: &str) -> Result<OrderBook> {
+        let url = format!("{}/book", self.base_url);
+
+        let response = self.http.get(&url)
+            .query(&[("token_id", token_id)])
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to fetch orderbook for {}: {}", token_id, e))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Orderbook for {} returned error: {}",
+                token_id,
+                response.status()
+            ));
+        }
+
+        let book: RestOrderbook = response.json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse orderbook for {}: {}", token_id, e))?;
+
+        let timestamp = book.timestamp
+            .and_then(|ts| ts.as_i64().or_else(|| ts.as_str()?.parse().ok()))
+            .unwrap_or_else(|| chrono::Utc::now().timestamp_millis());
+
+        let parse_side = |levels: &[RestLevel]| -> Result<Vec<OrderBookEntry>> {
+            levels.iter().map(|level| level.to_entry(timestamp)).collect()
+        };
+
+        let mut bids = parse_side(&book.bids)?;
+        let mut asks = parse_side(&book.asks)?;
+        bids.sort_by(|a, b| b.price.total_cmp(&a.price));
+        asks.sort_by(|a, b| a.price.total_cmp(&b.price));
+
+        Ok(OrderBook {
+            token_id: TokenId(token_id.to_string()),
+            bids,
+            asks,
+            timestamp,
+        })
+    }
+
+    /// Fetch market info for a condition ID
+    pub async fn get_market_info(&self, condition_id: &str) -> Result<MarketInfo> {
+        let url = format!("{}/markets/{}", self.base_url, condition_id);
+
+        let response = self.http.get(&url)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to fetch market {}: {}", condition_id, e))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "Market info for {} returned error: {}",
+                condition_id,
+                response.status()
+            ));
+        }
+
+        response.json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse market info for {}: {}", condition_id, e))
+    }
+
+    /// Fee rate in basis points charged on a token (`GET /fee-rate?token_id=`)
+    pub async fn get_fees(&self, token_id: &str) -> Result<u16> {
+        let response = self
+            .http
+            .get(format!("{}/fee-rate", self.base_url))
+            .query(&[("token_id", token_id)])
+            .send()
+            .await
+            .map_err(|e| anyhow!("Fee rate request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Failed to get fee rate for {}: {}", token_id, error_text));
+        }
+
+        let fee_rate: FeeRateResponse = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse fee rate: {}", e))?;
+
+        Ok(fee_rate.base_fee)
+    }
+
+    /// USDC collateral balance and allowance (`GET /balance-allowance`)
+    pub async fn get_balance_allowance(&self) -> Result<BalanceAllowance> {
+        let mut request = self
+            .http
+            .get(format!("{}/balance-allowance", self.base_url))
+            .query(&[("asset_type", "COLLATERAL")]);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("Authorization", api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("Balance request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Failed to get balance: {}", error_text));
+        }
+
+        let body: BalanceAllowanceResponse = response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse balance: {}", e))?;
+        let usdc = |units: &str| {
+            units
+                .parse::<u64>()
+                .map(|units| units as f64 / 1_000_000.0)
+                .map_err(|e| anyhow!("Invalid USDC amount {:?}: {}", units, e))
+        };
+
+        Ok(BalanceAllowance {
+            balance: usdc(&body.balance)?,
+            allowance: body.allowance.as_deref().map(usdc).transpose()?,
+        })
+    }
+
+    /// Fills of an order (`GET /fills?order_hash=`)
+    ///
+    /// An accepted order may still be resting; an empty list means nothing
+    /// has filled yet.
+    pub async fn get_fills(&self, order_hash: &str) -> Result<Vec<Fill>> {
+        let mut request = self
+            .http
+            .get(format!("{}/fills", self.base_url))
+            .query(&[("order_hash", order_hash)]);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("Authorization", api_key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| anyhow!("Fills request failed: {}", e))?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(anyhow!("Failed to get fills for {}: {}", order_hash, error_text));
+        }
+
+        match response
+            .json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse fills: {}", e))?
+        {
+            Fills::List(fills) | Fills::Page { data: fills } => Ok(fills),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_balance_allowance() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/balance-allowance")
+            .match_query(mockito::Matcher::UrlEncoded("asset_type".into(), "COLLATERAL".into()))
+            .match_header("Authorization", "key")
+            .with_status(200)
+            .with_body(r#"{"balance":"12500000","allowance":"10000000"}"#)
+            .create_async()
+            .await;
+
+        let client = ClobRestClient::new(server.url()).with_api_key("key".to_string());
+        let balance = client.get_balance_allowance().await.unwrap();
+        assert_eq!(balance, BalanceAllowance { balance: 12.5, allowance: Some(10.0) });
+        assert_eq!(balance.available(), 10.0);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_orderbook() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/book")
+            .match_query(mockito::Matcher::UrlEncoded("token_id".into(), "111".into()))
+            .with_status(200)
+            .with_body(r#"{
+                "bids": [["0.48", "50"], ["0.50", "100.5"]],
+                "asks": [{"price": "0.53", "size": "10"}, {"price": "0.52", "size": "20"}],
+                "timestamp": "1700000000000"
+            }"#)
+            .create_async()
+            .await;
+
+        let client = ClobRestClient::new(server.url());
+        let book = client.get_orderbook("111").await.unwrap();
+
+        assert_eq!(book.token_id.0, "111");
+        assert_eq!(book.timestamp, 1_700_000_000_000);
+        assert_eq!(book.best_bid().unwrap().price, 0.50);
+        assert_eq!(book.best_bid().unwrap().size, 100.5);
+        assert_eq!(book.best_ask().unwrap().price, 0.52);
+        assert_eq!(book.asks.len(), 2);
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_market_info() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/markets/0xcond")
+            .with_status(200)
+            .with_body(r#"{
+                "condition_id": "0xcond",
+                "question_id": "0xquestion",
+                "active": true,
+                "closed": false,
+                "tokens": [
+                    {"token_id": "111", "outcome": "Up", "price": 0.5},
+                    {"token_id": "222", "outcome": "Down", "price": 0.5}
+                ]
+            }"#)
+            .create_async()
+            .await;
+        server.mock("GET", "/markets/missing").with_status(404).create_async().await;
+
+        let client = ClobRestClient::new(format!("{}/", server.url()));
+        assert_eq!(client.base_url(), server.url());
+
+        let info = client.get_market_info("0xcond").await.unwrap();
+        assert_eq!(info.question_id, "0xquestion");
+        assert_eq!(info.token_ids(), vec!["111", "222"]);
+        assert_eq!(info.tokens[1].outcome, "Down");
+        assert!(info.active && !info.closed);
+        mock.assert_async().await;
+
+        assert!(client.get_market_info("missing").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_fills_sends_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/fills")
+            .match_query(mockito::Matcher::UrlEncoded("order_hash".into(), "0xabc".into()))
+            .match_header("Authorization", "test-key")
+            .with_status(200)
+            .with_body(r#"[{"id": "f1", "orderHash": "0xabc", "price": 0.45, "size": 10.0}]"#)
+            .create_async()
+            .await;
+
+        let client = ClobRestClient::new(server.url()).with_api_key("test-key".to_string());
+        let fills = client.get_fills("0xabc").await.unwrap();
+        assert_eq!(fills.len(), 1);
+        assert_eq!(fills[0].size, 10.0);
+        mock.assert_async().await;
+    }
+}
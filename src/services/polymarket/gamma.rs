@@ -0,0 +1,162 @@
+//! Gamma API client (event and market metadata)
+
+use anyhow::{anyhow, Result};
+use serde::Deserialize;
+use std::sync::Arc;
+
+use crate::types::MarketId;
+
+/// Gamma API event response
+#[derive(Debug, Clone, Deserialize)]
+pub struct GammaEvent {
+    /// Event ID
+    pub id: String,
+
+    /// Event slug (URL-friendly identifier)
+    pub slug: String,
+
+    /// Event title
+    pub title: String,
+
+    /// CLOB token IDs (YES/NO tokens)
+    #[serde(rename = "clobTokenIds")]
+    pub clob_token_ids: Vec<String>,
+
+    /// CLOB condition ID (used to validate token IDs)
+    #[serde(default, rename = "conditionId")]
+    pub condition_id: Option<String>,
+
+    /// Whether event is active
+    pub active: bool,
+
+    /// Whether event is closed
+    pub closed: bool,
+
+    /// End date timestamp
+    #[serde(rename = "endDate")]
+    pub end_date: Option<String>,
+}
+
+/// Gamma API response (returns array directly)
+pub type GammaResponse = Vec<GammaEvent>;
+
+/// Gamma market entry (only the fields needed to resolve IDs)
+#[derive(Debug, Deserialize)]
+struct GammaMarketRef {
+    #[serde(rename = "conditionId")]
+    condition_id: String,
+}
+
+/// Polymarket Gamma API client
+#[derive(Debug, Clone)]
+pub struct GammaClient {
+    /// HTTP client (shared with `ClobRestClient`)
+    http: Arc<reqwest::Client>,
+
+    /// Gamma API base URL
+    base_url: String,
+}
+
+impl GammaClient {
+    /// Create client with its own connection pool
+    ///
+    /// # Arguments
+    /// * `base_url` - Gamma API URL (e.g., "https://gamma-api.polymarket.com")
+    pub fn new(base_url: String) -> Self {
+        Self::with_client(super::http_client(), base_url)
+    }
+
+    /// Create client reusing an existing HTTP client (and its pool)
+    pub fn with_client(http: Arc<reqwest::Client>, base_url: String) -> Self {
+        Self {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Gamma API base URL (without trailing slash)
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Fetch up to `limit` open events, newest first
+    pub async fn get_events(&self, limit: usize) -> Result<GammaResponse> {
+        let url = format!(
+            "{}/events?closed=false&archived=false&limit={}&offset=0&order=id&ascending=false",
+            self.base_url,
+            limit
+        );
+
+        tracing::info!("Fetching events from: {}", url);
+
+        let response = self.http.get(&url)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to fetch events: {}", e))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("API returned error: {}", response.status()));
+        }
+
+        response.json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse response: {}", e))
+    }
+
+    /// Look up the condition ID of a market slug
+    pub async fn get_condition_id(&self, slug: &str) -> Result<MarketId> {
+        let response = self.http.get(format!("{}/markets", self.base_url))
+            .query(&[("slug", slug)])
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to resolve market {}: {}", slug, e))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("Resolving {} returned error: {}", slug, response.status()));
+        }
+
+        let markets: Vec<GammaMarketRef> = response.json()
+            .await
+            .map_err(|e| anyhow!("Failed to parse Gamma markets for {}: {}", slug, e))?;
+
+        markets.first()
+            .map(|market| MarketId::from_condition_id(&market.condition_id))
+            .ok_or_else(|| anyhow!("No Gamma market with slug {}", slug))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_get_events() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/events")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("closed".into(), "false".into()),
+                mockito::Matcher::UrlEncoded("limit".into(), "2".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"[{
+                "id": "1",
+                "slug": "btc-updown-15m-est",
+                "title": "BTC Up or Down",
+                "clobTokenIds": ["yes", "no"],
+                "active": true,
+                "closed": false,
+                "endDate": null
+            }]"#)
+            .create_async()
+            .await;
+
+        let client = GammaClient::new(format!("{}/", server.url()));
+        let events = client.get_events(2).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].slug, "btc-updown-15m-est");
+        assert_eq!(events[0].clob_token_ids, vec!["yes", "no"]);
+        assert!(events[0].condition_id.is_none());
+        mock.assert_async().await;
+    }
+}
@@ -1,2 +1,174 @@
-// Polymarket CLOB client
-// TODO: Implement HTTP and WebSocket client
+//! Polymarket REST clients
+//!
+//! All non-signing REST calls go through two clients that share one
+//! `reqwest::Client` (and so one connection pool):
+//! - [`GammaClient`]: Gamma API events and market metadata
+//! - [`ClobRestClient`]: read-only CLOB endpoints (orderbooks, market info,
+//!   fees, fills)
+//!
+//! [`PolymarketRestClient`] bundles both, resolves market IDs across them and
+//! derives API keys (`get_clob_auth`). Order placement lives in
+//! `clob::ClobClient`.
+
+mod auth;
+mod clob_rest;
+mod gamma;
+
+pub use auth::ApiCredentials;
+pub use clob_rest::{BalanceAllowance, ClobRestClient, MarketInfo, TokenInfo};
+pub use gamma::{GammaClient, GammaEvent, GammaResponse};
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::types::{MarketId, MarketIdKind, MarketIdResolver, PolymarketConfig};
+
+/// HTTP client for standalone REST clients
+fn http_client() -> Arc<reqwest::Client> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .tcp_nodelay(true)
+        .build()
+        .unwrap_or_default();
+
+    Arc::new(client)
+}
+
+/// Polymarket Gamma + CLOB REST client
+#[derive(Debug, Clone)]
+pub struct PolymarketRestClient {
+    /// Gamma API client
+    gamma_client: GammaClient,
+
+    /// CLOB REST client (same connection pool as `gamma_client`)
+    clob_rest_client: ClobRestClient,
+
+    /// Resolved market IDs, both directions (shared between clones)
+    market_id_cache: Arc<Mutex<HashMap<MarketId, MarketId>>>,
+}
+
+impl PolymarketRestClient {
+    /// Create REST client with its own connection pool
+    ///
+    /// # Arguments
+    /// * `clob_api_url` - CLOB API URL (e.g., "https://clob.polymarket.com")
+    /// * `gamma_api_url` - Gamma API URL (e.g., "https://gamma-api.polymarket.com")
+    pub fn new(clob_api_url: String, gamma_api_url: String) -> Self {
+        Self::with_clob_rest_client(ClobRestClient::new(clob_api_url), gamma_api_url)
+    }
+
+    /// Create REST client for the configured API URLs
+    pub fn from_config(config: &PolymarketConfig) -> Self {
+        Self::new(config.clob_api_url.clone(), config.gamma_api_url.clone())
+    }
+
+    /// Create REST client around an existing CLOB REST client
+    ///
+    /// The Gamma client shares its HTTP client, e.g. to reuse
+    /// `ClobClient::rest_client()`'s connection pool.
+    pub fn with_clob_rest_client(clob_rest_client: ClobRestClient, gamma_api_url: String) -> Self {
+        Self {
+            gamma_client: GammaClient::with_client(clob_rest_client.http().clone(), gamma_api_url),
+            clob_rest_client,
+            market_id_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Gamma API client
+    pub fn gamma(&self) -> &GammaClient {
+        &self.gamma_client
+    }
+
+    /// CLOB REST client
+    pub fn clob(&self) -> &ClobRestClient {
+        &self.clob_rest_client
+    }
+
+    /// Resolve a market ID to the other kind
+    ///
+    /// Condition IDs resolve to the CLOB market slug, slugs to the condition ID
+    /// (via Gamma). Results are cached.
+    pub async fn resolve_market_id(&self, id: &MarketId) -> Result<MarketId> {
+        if let Some(resolved) = self.market_id_cache.lock().get(id) {
+            return Ok(resolved.clone());
+        }
+
+        let resolved = match id.kind() {
+            MarketIdKind::ConditionId(_) => {
+                let info = self.get_market_info(&id.to_string()).await?;
+                let slug = info.market_slug
+                    .ok_or_else(|| anyhow!("Market {} has no slug", id))?;
+                MarketId::from_event_slug(&slug)
+            }
+            MarketIdKind::EventSlug(slug) => self.gamma_client.get_condition_id(slug).await?,
+        };
+
+        let mut cache = self.market_id_cache.lock();
+        cache.insert(id.clone(), resolved.clone());
+        cache.insert(resolved.clone(), id.clone());
+        Ok(resolved)
+    }
+
+    /// Fetch market info for a condition ID
+    pub async fn get_market_info(&self, condition_id: &str) -> Result<MarketInfo> {
+        self.clob_rest_client.get_market_info(condition_id).await
+    }
+}
+
+/// Lets `MarketId::normalize` resolve slugs through Gamma (cached)
+#[async_trait]
+impl MarketIdResolver for PolymarketRestClient {
+    async fn resolve_market_id(&self, id: &MarketId) -> Result<MarketId> {
+        PolymarketRestClient::resolve_market_id(self, id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolve_market_id() {
+        let condition = format!("0x{}", "ab".repeat(32));
+
+        let mut server = mockito::Server::new_async().await;
+        let gamma = server
+            .mock("GET", "/gamma/markets")
+            .match_query(mockito::Matcher::UrlEncoded("slug".into(), "btc-15m".into()))
+            .with_status(200)
+            .with_body(format!(r#"[{{"conditionId": "{}", "slug": "btc-15m"}}]"#, condition))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = PolymarketRestClient::new(server.url(), format!("{}/gamma", server.url()));
+        let slug = MarketId::from_event_slug("btc-15m");
+
+        let resolved = slug.normalize(&client).await.unwrap();
+        assert_eq!(resolved, MarketId::from_condition_id(&condition));
+
+        // Both directions are cached; the CLOB is never queried
+        assert_eq!(client.resolve_market_id(&slug).await.unwrap(), resolved);
+        assert_eq!(client.resolve_market_id(&resolved).await.unwrap(), slug);
+        assert_eq!(resolved.normalize(&client).await.unwrap(), resolved);
+        gamma.assert_async().await;
+
+        // Unknown slugs are not cached
+        let unknown = server
+            .mock("GET", "/gamma/markets")
+            .match_query(mockito::Matcher::UrlEncoded("slug".into(), "eth-1h".into()))
+            .with_status(200)
+            .with_body("[]")
+            .expect(2)
+            .create_async()
+            .await;
+        let eth = MarketId::from_event_slug("eth-1h");
+        assert!(client.resolve_market_id(&eth).await.is_err());
+        assert!(client.resolve_market_id(&eth).await.is_err());
+        unknown.assert_async().await;
+    }
+}
@@ -3,17 +3,25 @@
 //! Provides:
 //! - Generic WebSocket manager with auto-reconnect
 //! - Polymarket-specific WebSocket client
+//! - Multiplexer sharing one connection between consumers
 //! - Zero-copy message buffers
 //! - TCP_NODELAY optimization
 
 mod manager;
+mod multiplexer;
 mod polymarket_ws;
 
-pub use manager::WebSocketManager;
+pub use manager::{
+    ReconnectPolicy, ReconnectStrategy, SubscriptionState, WebSocketConfig, WebSocketManager,
+    WebSocketManagerBuilder,
+};
+pub use multiplexer::{Subscribers, WebSocketMultiplexer, DEFAULT_CONSUMER_CAPACITY};
 pub use polymarket_ws::{
     PolymarketWebSocket,
     PolymarketMessage,
     PolymarketOrderbookUpdate,
     OrderbookUpdate,
+    PriceChangeUpdate,
+    OrderbookSnapshotCallback,
     process_message,
 };
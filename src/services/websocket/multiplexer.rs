@@ -0,0 +1,195 @@
+//! One WebSocket feed fanned out to several consumers
+//!
+//! Detection strategies (scalar, SIMD, binary arbitrage) each want every
+//! orderbook message. [`WebSocketMultiplexer`] keeps a single connection and
+//! clones each message into one channel per consumer. A consumer whose
+//! channel is full misses that message (with a warning) rather than slowing
+//! the feed down for everyone else.
+//!
+//! # Usage
+//! ```rust,ignore
+//! let mut multiplexer = WebSocketMultiplexer::<PolymarketMessage>::new(url)?;
+//! let scalar_rx = multiplexer.subscribe();
+//! let simd_rx = multiplexer.subscribe();
+//! tokio::spawn(multiplexer.start());
+//! ```
+
+use anyhow::Result;
+use tokio::sync::mpsc;
+use tokio::sync::mpsc::error::TrySendError;
+use tracing;
+
+use super::manager::WebSocketManager;
+
+/// Capacity of the channel between the connection and the fan-out task
+const FEED_CAPACITY: usize = 1000;
+
+/// Default capacity of each consumer's channel
+pub const DEFAULT_CONSUMER_CAPACITY: usize = 1000;
+
+/// Consumers of a multiplexed feed
+#[derive(Debug)]
+pub struct Subscribers<T> {
+    /// One sender per consumer
+    senders: Vec<mpsc::Sender<T>>,
+
+    /// Capacity of channels created by `subscribe`
+    capacity: usize,
+}
+
+impl<T: Clone> Subscribers<T> {
+    /// No consumers yet; each `subscribe` channel holds `capacity` messages
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            senders: Vec::new(),
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// Register a consumer
+    pub fn subscribe(&mut self) -> mpsc::Receiver<T> {
+        let (tx, rx) = mpsc::channel(self.capacity);
+        self.senders.push(tx);
+        rx
+    }
+
+    /// Number of registered consumers
+    pub fn len(&self) -> usize {
+        self.senders.len()
+    }
+
+    /// Whether no consumer is registered
+    pub fn is_empty(&self) -> bool {
+        self.senders.is_empty()
+    }
+
+    /// Clone `msg` into every consumer's channel without waiting
+    ///
+    /// Full channels are skipped with a warning; consumers whose receiver was
+    /// dropped are removed. Returns the number of consumers that got `msg`.
+    pub fn broadcast(&mut self, msg: &T) -> usize {
+        let mut delivered = 0;
+        self.senders.retain(|tx| match tx.try_send(msg.clone()) {
+            Ok(()) => {
+                delivered += 1;
+                true
+            }
+            Err(TrySendError::Full(_)) => {
+                tracing::warn!("WebSocket consumer channel full, message skipped");
+                true
+            }
+            Err(TrySendError::Closed(_)) => false,
+        });
+        delivered
+    }
+}
+
+/// Single WebSocket connection shared by several consumers
+pub struct WebSocketMultiplexer<T> {
+    /// Connection producing the feed
+    manager: WebSocketManager<T>,
+
+    /// Messages from the connection
+    feed: mpsc::Receiver<T>,
+
+    /// Registered consumers
+    subscribers: Subscribers<T>,
+}
+
+impl<T> WebSocketMultiplexer<T>
+where
+    T: serde::de::DeserializeOwned + Clone + Send + 'static,
+{
+    /// Multiplexer over a new connection to `url`
+    pub fn new(url: String) -> Result<Self> {
+        let (tx, feed) = mpsc::channel(FEED_CAPACITY);
+        let manager = WebSocketManager::builder(url, tx).build()?;
+        Ok(Self {
+            manager,
+            feed,
+            subscribers: Subscribers::new(DEFAULT_CONSUMER_CAPACITY),
+        })
+    }
+
+    /// Capacity of consumer channels created from now on (default 1000)
+    pub fn with_consumer_capacity(mut self, capacity: usize) -> Self {
+        self.subscribers.capacity = capacity.max(1);
+        self
+    }
+
+    /// Underlying connection (e.g., for `subscribe_dynamic` or `outbound_sender`)
+    pub fn manager_mut(&mut self) -> &mut WebSocketManager<T> {
+        &mut self.manager
+    }
+
+    /// Register a consumer that receives a clone of every message
+    ///
+    /// Subscribe before `start`: the consumer set is fixed once it runs.
+    pub fn subscribe(&mut self) -> mpsc::Receiver<T> {
+        self.subscribers.subscribe()
+    }
+
+    /// Number of registered consumers
+    pub fn consumer_count(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Connect and fan messages out until the connection is closed for good
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub async fn start(self) -> Result<()> {
+        tokio::spawn(relay(self.feed, self.subscribers));
+        self.manager.start().await
+    }
+}
+
+/// Broadcast every feed message to `subscribers` until the feed closes
+pub(super) async fn relay<T: Clone>(mut feed: mpsc::Receiver<T>, mut subscribers: Subscribers<T>) {
+    while let Some(msg) = feed.recv().await {
+        subscribers.broadcast(&msg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_broadcast_skips_full_and_drops_closed() {
+        let mut subscribers = Subscribers::new(1);
+        let mut fast = subscribers.subscribe();
+        let mut slow = subscribers.subscribe();
+        let closed = subscribers.subscribe();
+        drop(closed);
+
+        assert_eq!(subscribers.broadcast(&1), 2);
+        assert_eq!(subscribers.len(), 2, "Closed consumer removed");
+        assert_eq!(fast.try_recv(), Ok(1));
+
+        // `slow` still holds 1: it misses 2, `fast` does not
+        assert_eq!(subscribers.broadcast(&2), 1);
+        assert_eq!(fast.try_recv(), Ok(2));
+        assert_eq!(slow.try_recv(), Ok(1));
+        assert!(slow.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_relay_clones_to_every_consumer() {
+        let multiplexer =
+            WebSocketMultiplexer::<String>::new("wss://example.com/ws".to_string()).unwrap();
+        let mut multiplexer = multiplexer.with_consumer_capacity(10);
+        let mut receivers: Vec<_> = (0..3).map(|_| multiplexer.subscribe()).collect();
+        assert_eq!(multiplexer.consumer_count(), 3);
+
+        let (tx, feed) = mpsc::channel(10);
+        tokio::spawn(relay(feed, multiplexer.subscribers));
+        for msg in ["a", "b"] {
+            tx.send(msg.to_string()).await.unwrap();
+        }
+
+        for rx in &mut receivers {
+            assert_eq!(rx.recv().await.as_deref(), Some("a"));
+            assert_eq!(rx.recv().await.as_deref(), Some("b"));
+        }
+    }
+}
@@ -16,17 +16,40 @@
 //!   "asks": [[price, size], ...]
 //! }
 //! ```
+//!
+//! An `orderbook` message replaces the whole book unless its optional
+//! `event_type` is `"price_change"`, which marks an incremental delta.
+//!
+//! Single-level changes arrive between snapshots as `price_change` messages:
+//! ```json
+//! {
+//!   "type": "price_change",
+//!   "market_id": "TRUMP-WIN",
+//!   "token_id": "YES",
+//!   "side": "BUY",
+//!   "price": 0.75,
+//!   "size": 0
+//! }
+//! ```
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
 use tokio::net::TcpStream;
 use futures_util::SinkExt;
+use parking_lot::{Mutex, RwLock};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing;
 
-use crate::types::{MarketId, TokenId, OrderBook, OrderBookEntry};
+use crate::services::polymarket::ClobRestClient;
+use crate::types::{
+    EventBus, MarketId, OrderBook, OrderBookEntry, OrderSide, TokenId, TradingEvent,
+};
 use super::manager::WebSocketManager;
+use super::multiplexer::{relay, Subscribers, DEFAULT_CONSUMER_CAPACITY};
 
 /// Polymarket WebSocket message types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +58,9 @@ pub enum PolymarketMessage {
     /// Orderbook snapshot or update
     Orderbook(OrderbookUpdate),
 
+    /// Single price-level change (incremental delta)
+    PriceChange(PriceChangeUpdate),
+
     /// Trade execution
     Trade(TradeUpdate),
 
@@ -63,9 +89,33 @@ pub struct OrderbookUpdate {
     /// Timestamp
     #[serde(default)]
     pub timestamp: i64,
+
+    /// Event type: "price_change" for a delta; absent or anything else is a full snapshot
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub event_type: Option<String>,
 }
 
 impl OrderbookUpdate {
+    /// Build a full snapshot message from an orderbook (e.g. one fetched over REST)
+    pub fn snapshot(market_id: &str, book: &OrderBook) -> Self {
+        Self {
+            market_id: market_id.to_string(),
+            token_id: book.token_id.0.clone(),
+            bids: book.bids.iter().map(|e| (e.price, e.size)).collect(),
+            asks: book.asks.iter().map(|e| (e.price, e.size)).collect(),
+            timestamp: book.timestamp,
+            event_type: Some("book".to_string()),
+        }
+    }
+
+    /// Whether this message is a full orderbook snapshot (vs an incremental delta)
+    ///
+    /// Only an explicit `"price_change"` is a delta, so feeds that never send
+    /// `event_type` still seed the cache.
+    pub fn is_snapshot(&self) -> bool {
+        self.event_type.as_deref() != Some("price_change")
+    }
+
     /// Convert to internal OrderBook type
     pub fn to_order_book(&self) -> OrderBook {
         OrderBook {
@@ -91,6 +141,57 @@ impl OrderbookUpdate {
     }
 }
 
+/// Price-level change message
+///
+/// Sets the size of one level of a token's book; size 0 removes the level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceChangeUpdate {
+    /// Market identifier
+    #[serde(default)]
+    pub market_id: String,
+
+    /// Token identifier (YES/NO)
+    pub token_id: String,
+
+    /// Book side: `BUY` for bids, `SELL` for asks
+    pub side: OrderSide,
+
+    /// Price of the level
+    pub price: f64,
+
+    /// New size at the level (0 removes it)
+    pub size: f64,
+
+    /// Timestamp
+    #[serde(default)]
+    pub timestamp: i64,
+}
+
+impl PriceChangeUpdate {
+    /// Convert to a one-level delta book
+    ///
+    /// The level is kept even at size 0 so that applying the delta
+    /// (`PolymarketOrderbookUpdate::apply_to`) removes it.
+    pub fn to_order_book(&self) -> OrderBook {
+        let level = vec![OrderBookEntry {
+            price: self.price,
+            size: self.size,
+            timestamp: Some(self.timestamp),
+        }];
+        let (bids, asks) = match self.side {
+            OrderSide::BUY => (level, Vec::new()),
+            OrderSide::SELL => (Vec::new(), level),
+        };
+
+        OrderBook {
+            token_id: TokenId(self.token_id.clone()),
+            bids,
+            asks,
+            timestamp: self.timestamp,
+        }
+    }
+}
+
 /// Trade update message
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeUpdate {
@@ -127,12 +228,66 @@ pub struct PolymarketOrderbookUpdate {
     pub order_book: OrderBook,
     /// Update timestamp
     pub timestamp: i64,
+    /// Whether the update is a full snapshot (vs an incremental delta)
+    pub is_snapshot: bool,
 }
 
+impl PolymarketOrderbookUpdate {
+    /// Apply the update to the cached book of its token
+    ///
+    /// Snapshots replace the book. Deltas set each of their levels with
+    /// `OrderBook::apply_delta`, so zero-size levels are removed.
+    pub fn apply_to(&self, book: &mut OrderBook) {
+        if self.is_snapshot {
+            *book = self.order_book.clone();
+            return;
+        }
+
+        for bid in &self.order_book.bids {
+            book.apply_delta(OrderSide::BUY, bid.price, bid.size);
+        }
+        for ask in &self.order_book.asks {
+            book.apply_delta(OrderSide::SELL, ask.price, ask.size);
+        }
+        book.timestamp = book.timestamp.max(self.timestamp);
+    }
+}
+
+/// Callback invoked with each full orderbook snapshot
+pub type OrderbookSnapshotCallback = Arc<dyn Fn(MarketId, TokenId, OrderBook) + Send + Sync>;
+
+/// First delay between REST snapshot attempts for a token, doubled on each failure
+const SNAPSHOT_RETRY_INITIAL: Duration = Duration::from_millis(250);
+
+/// Longest delay between REST snapshot attempts
+const SNAPSHOT_RETRY_MAX: Duration = Duration::from_secs(30);
+
+/// Most deltas held per token while its REST snapshot is fetched (oldest dropped)
+const MAX_HELD_DELTAS: usize = 100;
+
+/// When each token's orderbook was last updated
+type LastUpdates = Arc<RwLock<HashMap<TokenId, Instant>>>;
+
+/// Fan-out of `message_tx` to several consumers, started with the client
+type PendingRelay =
+    Mutex<Option<(mpsc::Receiver<PolymarketMessage>, Subscribers<PolymarketMessage>)>>;
+
 /// Polymarket WebSocket client
 pub struct PolymarketWebSocket {
-    manager: WebSocketManager<PolymarketMessage>,
+    url: String,
+    message_tx: mpsc::Sender<PolymarketMessage>,
+    #[allow(dead_code)] // Not yet wired into the manager's connect loop
     subscriptions: Vec<(MarketId, TokenId)>,
+    /// Called for full snapshots only (see `OrderbookUpdate::is_snapshot`), not deltas
+    on_orderbook_snapshot: Option<OrderbookSnapshotCallback>,
+    /// REST fallback for deltas that arrive before any snapshot
+    snapshot_source: Option<ClobRestClient>,
+    /// Last orderbook update per token (see `market_data_age`)
+    last_updates: LastUpdates,
+    /// Receives `TradingEvent::MarketUpdate` for every orderbook message
+    event_bus: Option<EventBus<TradingEvent>>,
+    /// Consumers of a multiplexed client (see `new_multiplexed`)
+    relay: PendingRelay,
 }
 
 impl PolymarketWebSocket {
@@ -148,23 +303,96 @@ impl PolymarketWebSocket {
     ) -> (Self, mpsc::Receiver<PolymarketMessage>) {
         let (tx, rx) = mpsc::channel(1000);
 
-        let manager = WebSocketManager::new(url, tx);
-
         (
             Self {
-                manager,
+                url,
+                message_tx: tx,
                 subscriptions: markets,
+                on_orderbook_snapshot: None,
+                snapshot_source: None,
+                last_updates: Arc::new(RwLock::new(HashMap::new())),
+                event_bus: None,
+                relay: Mutex::new(None),
             },
             rx,
         )
     }
 
+    /// Create a client whose messages are shared by `num_consumers` receivers
+    ///
+    /// Every receiver gets a clone of each message over the one connection.
+    /// A receiver that falls behind misses messages (with a warning) instead
+    /// of slowing the others down; see [`Subscribers::broadcast`].
+    pub fn new_multiplexed(
+        url: String,
+        markets: Vec<(MarketId, TokenId)>,
+        num_consumers: usize,
+    ) -> (Self, Vec<mpsc::Receiver<PolymarketMessage>>) {
+        let (client, feed) = Self::new(url, markets);
+        let mut subscribers = Subscribers::new(DEFAULT_CONSUMER_CAPACITY);
+        let receivers = (0..num_consumers).map(|_| subscribers.subscribe()).collect();
+        *client.relay.lock() = Some((feed, subscribers));
+        (client, receivers)
+    }
+
+    /// Register a callback for full orderbook snapshots
+    ///
+    /// The callback runs before the snapshot is forwarded to the message channel.
+    /// Incremental deltas do not trigger it.
+    pub fn on_orderbook_snapshot(&mut self, callback: OrderbookSnapshotCallback) {
+        self.on_orderbook_snapshot = Some(callback);
+    }
+
+    /// Fetch a REST snapshot when a delta arrives for a token without one
+    ///
+    /// The fetch runs in the background, retrying with backoff, while the
+    /// token's deltas are held. The synthetic snapshot is then forwarded (and
+    /// passed to the snapshot callback) ahead of them, so consumers always
+    /// have a base book. Every (re)connect starts without snapshots.
+    pub fn resubscribe_on_snapshot_gap(&mut self, client: ClobRestClient) {
+        self.snapshot_source = Some(client);
+    }
+
+    /// Publish every orderbook snapshot and delta as `TradingEvent::MarketUpdate`
+    pub fn publish_events(&mut self, event_bus: EventBus<TradingEvent>) {
+        self.event_bus = Some(event_bus);
+    }
+
+    /// Time since the last orderbook update for `token_id`
+    ///
+    /// `None` if no update has been received. Cached books older than a few
+    /// seconds are stale and unsafe to trade on.
+    pub fn market_data_age(&self, token_id: &TokenId) -> Option<Duration> {
+        self.last_updates.read().get(token_id).map(Instant::elapsed)
+    }
+
     /// Start the WebSocket client
-    pub async fn start(self) -> Result<()> {
-        self.manager.start().await
+    ///
+    /// Runs until the connection is closed for good; share the client (e.g.,
+    /// in an `Arc`) to query `market_data_age` meanwhile.
+    pub async fn start(&self) -> Result<()> {
+        if let Some((feed, subscribers)) = self.relay.lock().take() {
+            tokio::spawn(relay(feed, subscribers));
+        }
+
+        // Intercept messages to track freshness, dispatch snapshots and fill gaps
+        let (tx, raw_rx) = mpsc::channel(1000);
+        let manager = WebSocketManager::builder(self.url.clone(), tx).build()?;
+        tokio::spawn(forward_messages(
+            raw_rx,
+            self.message_tx.clone(),
+            self.on_orderbook_snapshot.clone(),
+            self.snapshot_source.clone(),
+            self.last_updates.clone(),
+            self.event_bus.clone(),
+            manager.connections(),
+        ));
+
+        manager.start().await
     }
 
     /// Send subscription messages for configured markets
+    #[allow(dead_code)] // Not yet wired into the manager's connect loop
     async fn send_subscriptions(
         &self,
         stream: &mut WebSocketStream<MaybeTlsStream<TcpStream>>,
@@ -173,29 +401,238 @@ impl PolymarketWebSocket {
             let subscription = serde_json::json!({
                 "type": "subscribe",
                 "channel": "orderbook",
-                "market_id": market_id.0,
+                "market_id": market_id,
                 "token_id": token_id.0,
             });
 
             let msg = Message::Text(subscription.to_string());
             stream.send(msg).await?;
 
-            tracing::info!("Subscribed to {}/{}", market_id.0, token_id.0);
+            tracing::info!("Subscribed to {}/{}", market_id, token_id.0);
         }
 
         Ok(())
     }
 }
 
+/// REST snapshot fetched for a token, on the connection numbered `connection`
+struct FetchedSnapshot {
+    connection: u64,
+    market_id: String,
+    token_id: String,
+    book: OrderBook,
+}
+
+/// Forward raw messages, recording update times, dispatching snapshots and
+/// filling snapshot gaps
+///
+/// Deltas for a token without a snapshot are held while
+/// `fetch_snapshot` runs and forwarded after the snapshot it returns. Each
+/// change of `connections` clears which tokens have a snapshot.
+async fn forward_messages(
+    mut raw_rx: mpsc::Receiver<PolymarketMessage>,
+    message_tx: mpsc::Sender<PolymarketMessage>,
+    callback: Option<OrderbookSnapshotCallback>,
+    snapshot_source: Option<ClobRestClient>,
+    last_updates: LastUpdates,
+    event_bus: Option<EventBus<TradingEvent>>,
+    mut connections: watch::Receiver<u64>,
+) {
+    // Tokens that have received a full snapshot on this connection
+    let mut snapshotted: HashSet<String> = HashSet::new();
+    // Deltas held per token while its snapshot is fetched
+    let mut held: HashMap<String, Vec<PolymarketMessage>> = HashMap::new();
+    let (fetched_tx, mut fetched_rx) = mpsc::channel(100);
+
+    let forward = |msg: PolymarketMessage| {
+        if let Some(callback) = &callback {
+            dispatch_snapshot(callback, &msg);
+        }
+        if let Some(bus) = &event_bus {
+            publish_market_update(bus, &msg);
+        }
+        message_tx.send(msg)
+    };
+
+    loop {
+        tokio::select! {
+            // Checked first: a new connection's messages follow its count change
+            biased;
+
+            Ok(()) = connections.changed() => {
+                tracing::debug!("Reconnected; clearing {} snapshot(s)", snapshotted.len());
+                snapshotted.clear();
+                held.clear();
+            }
+
+            Some(fetched) = fetched_rx.recv() => {
+                let FetchedSnapshot { connection, market_id, token_id, book } = fetched;
+                // Superseded by a reconnect or a snapshot from the feed
+                if connection != *connections.borrow() || snapshotted.contains(&token_id) {
+                    continue;
+                }
+
+                tracing::info!("Filled snapshot gap for {} via REST", token_id);
+                snapshotted.insert(token_id.clone());
+                let snapshot =
+                    PolymarketMessage::Orderbook(OrderbookUpdate::snapshot(&market_id, &book));
+                if forward(snapshot).await.is_err() {
+                    break;
+                }
+                for delta in held.remove(&token_id).unwrap_or_default() {
+                    if forward(delta).await.is_err() {
+                        return;
+                    }
+                }
+            }
+
+            msg = raw_rx.recv() => {
+                let Some(msg) = msg else { break };
+                let book_update = book_update_key(&msg).map(|(market_id, token_id, is_snapshot)| {
+                    (market_id.to_string(), token_id.to_string(), is_snapshot)
+                });
+                if let Some((_, token_id, _)) = &book_update {
+                    last_updates.write().insert(TokenId(token_id.clone()), Instant::now());
+                }
+
+                if let (Some((market_id, token_id, is_snapshot)), Some(rest)) =
+                    (book_update, &snapshot_source)
+                {
+                    if is_snapshot {
+                        // Supersedes any held deltas and a snapshot still being fetched
+                        held.remove(&token_id);
+                        snapshotted.insert(token_id);
+                    } else if !snapshotted.contains(&token_id) {
+                        let deltas = held.entry(token_id.clone()).or_insert_with(|| {
+                            tokio::spawn(fetch_snapshot(
+                                rest.clone(),
+                                market_id,
+                                token_id,
+                                connections.clone(),
+                                fetched_tx.clone(),
+                            ));
+                            Vec::new()
+                        });
+                        if deltas.len() >= MAX_HELD_DELTAS {
+                            deltas.remove(0);
+                        }
+                        deltas.push(msg);
+                        continue;
+                    }
+                }
+
+                if forward(msg).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Fetch a token's REST snapshot, retrying with exponential backoff
+///
+/// Gives up once `connections` changes (the next connection fetches again if
+/// needed) or `fetched_tx` closes.
+async fn fetch_snapshot(
+    rest: ClobRestClient,
+    market_id: String,
+    token_id: String,
+    mut connections: watch::Receiver<u64>,
+    fetched_tx: mpsc::Sender<FetchedSnapshot>,
+) {
+    let connection = *connections.borrow_and_update();
+    let mut delay = SNAPSHOT_RETRY_INITIAL;
+
+    while !fetched_tx.is_closed() {
+        match rest.get_orderbook(&token_id).await {
+            Ok(book) => {
+                let fetched = FetchedSnapshot { connection, market_id, token_id, book };
+                let _ = fetched_tx.send(fetched).await;
+                return;
+            }
+            Err(e) => {
+                tracing::warn!("Snapshot gap for {}: {} (retrying in {:?})", token_id, e, delay);
+            }
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = connections.changed() => return,
+        }
+        delay = (delay * 2).min(SNAPSHOT_RETRY_MAX);
+    }
+}
+
+/// Market ID, token ID and snapshot flag of an orderbook message
+fn book_update_key(msg: &PolymarketMessage) -> Option<(&str, &str, bool)> {
+    match msg {
+        PolymarketMessage::Orderbook(update) => {
+            Some((&update.market_id, &update.token_id, update.is_snapshot()))
+        }
+        PolymarketMessage::PriceChange(change) => {
+            Some((&change.market_id, &change.token_id, false))
+        }
+        _ => None,
+    }
+}
+
+/// Publish an orderbook message as `TradingEvent::MarketUpdate`
+///
+/// The book is only built while the bus has subscribers.
+fn publish_market_update(bus: &EventBus<TradingEvent>, msg: &PolymarketMessage) {
+    if bus.subscriber_count() == 0 {
+        return;
+    }
+
+    let (market_id, order_book, is_snapshot) = match msg {
+        PolymarketMessage::Orderbook(update) => {
+            (&update.market_id, update.to_order_book(), update.is_snapshot())
+        }
+        PolymarketMessage::PriceChange(change) => {
+            (&change.market_id, change.to_order_book(), false)
+        }
+        _ => return,
+    };
+
+    bus.publish(TradingEvent::MarketUpdate {
+        market_id: MarketId::new(market_id.clone()),
+        order_book,
+        is_snapshot,
+    });
+}
+
+/// Invoke the snapshot callback if the message is a full orderbook snapshot
+fn dispatch_snapshot(callback: &OrderbookSnapshotCallback, msg: &PolymarketMessage) {
+    if let PolymarketMessage::Orderbook(update) = msg {
+        if update.is_snapshot() {
+            callback(
+                MarketId::new(update.market_id.clone()),
+                TokenId(update.token_id.clone()),
+                update.to_order_book(),
+            );
+        }
+    }
+}
+
 /// Process Polymarket messages and extract orderbook updates
 pub fn process_message(msg: PolymarketMessage) -> Option<PolymarketOrderbookUpdate> {
     match msg {
         PolymarketMessage::Orderbook(update) => {
             Some(PolymarketOrderbookUpdate {
-                market_id: MarketId(update.market_id.clone()),
+                market_id: MarketId::new(update.market_id.clone()),
                 token_id: TokenId(update.token_id.clone()),
                 order_book: update.to_order_book(),
                 timestamp: update.timestamp,
+                is_snapshot: update.is_snapshot(),
+            })
+        }
+        PolymarketMessage::PriceChange(change) => {
+            Some(PolymarketOrderbookUpdate {
+                market_id: MarketId::new(change.market_id.clone()),
+                token_id: TokenId(change.token_id.clone()),
+                order_book: change.to_order_book(),
+                timestamp: change.timestamp,
+                is_snapshot: false,
             })
         }
         PolymarketMessage::Subscribed(confirm) => {
@@ -226,6 +663,7 @@ mod tests {
             bids: vec![(0.75, 100.0), (0.74, 200.0)],
             asks: vec![(0.76, 150.0), (0.77, 250.0)],
             timestamp: 1000,
+            event_type: None,
         };
 
         let order_book = update.to_order_book();
@@ -246,17 +684,279 @@ mod tests {
             bids: vec![(0.75, 100.0)],
             asks: vec![(0.76, 150.0)],
             timestamp: 1000,
+            event_type: None,
         });
 
         let result = process_message(msg);
         assert!(result.is_some());
 
         let update = result.unwrap();
-        assert_eq!(update.market_id.0, "TRUMP-WIN");
+        assert_eq!(update.market_id.to_string(), "TRUMP-WIN");
         assert_eq!(update.token_id.0, "YES");
         assert_eq!(update.order_book.bids.len(), 1);
     }
 
+    #[test]
+    fn test_snapshot_callback_ignores_deltas() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let snapshots = Arc::new(AtomicUsize::new(0));
+        let counter = snapshots.clone();
+        let callback: OrderbookSnapshotCallback = Arc::new(move |market_id, token_id, book| {
+            assert_eq!(market_id.to_string(), "TRUMP-WIN");
+            assert_eq!(token_id.0, "YES");
+            assert_eq!(book.bids.len(), 1);
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let parse = |event_type: &str| -> PolymarketMessage {
+            serde_json::from_value(serde_json::json!({
+                "type": "orderbook",
+                "event_type": event_type,
+                "market_id": "TRUMP-WIN",
+                "token_id": "YES",
+                "bids": [[0.75, 100.0]],
+                "asks": [[0.76, 150.0]],
+            }))
+            .unwrap()
+        };
+
+        let snapshot = parse("book");
+        let delta = parse("price_change");
+
+        dispatch_snapshot(&callback, &snapshot);
+        dispatch_snapshot(&callback, &delta);
+        assert_eq!(snapshots.load(Ordering::SeqCst), 1);
+
+        assert!(process_message(snapshot).unwrap().is_snapshot);
+        assert!(!process_message(delta).unwrap().is_snapshot);
+    }
+
+    #[test]
+    fn test_orderbook_without_event_type_is_snapshot() {
+        // Shape of the module doc example: no `event_type`
+        let msg: PolymarketMessage = serde_json::from_value(serde_json::json!({
+            "type": "orderbook",
+            "market_id": "TRUMP-WIN",
+            "token_id": "YES",
+            "bids": [[0.75, 100.0]],
+            "asks": [[0.76, 150.0]],
+        }))
+        .unwrap();
+
+        match &msg {
+            PolymarketMessage::Orderbook(update) => assert!(update.is_snapshot()),
+            other => panic!("Expected orderbook, got {:?}", other),
+        }
+        assert!(process_message(msg).unwrap().is_snapshot);
+    }
+
+    #[test]
+    fn test_price_change_updates_cached_book() {
+        let snapshot: PolymarketMessage = serde_json::from_value(serde_json::json!({
+            "type": "orderbook",
+            "event_type": "book",
+            "market_id": "TRUMP-WIN",
+            "token_id": "YES",
+            "bids": [[0.75, 100.0], [0.74, 200.0]],
+            "asks": [[0.76, 150.0]],
+            "timestamp": 1000,
+        }))
+        .unwrap();
+        let price_change = |side: &str, price: f64, size: f64, timestamp: i64| {
+            let msg: PolymarketMessage = serde_json::from_value(serde_json::json!({
+                "type": "price_change",
+                "market_id": "TRUMP-WIN",
+                "token_id": "YES",
+                "side": side,
+                "price": price,
+                "size": size,
+                "timestamp": timestamp,
+            }))
+            .unwrap();
+            process_message(msg).unwrap()
+        };
+
+        let mut book = process_message(snapshot).unwrap().order_book;
+
+        // Best bid pulled, new best ask
+        let removal = price_change("BUY", 0.75, 0.0, 1001);
+        assert!(!removal.is_snapshot);
+        assert_eq!(removal.market_id.to_string(), "TRUMP-WIN");
+        removal.apply_to(&mut book);
+        price_change("SELL", 0.755, 50.0, 1002).apply_to(&mut book);
+
+        assert_eq!(book.best_bid().map(|e| (e.price, e.size)), Some((0.74, 200.0)));
+        assert_eq!(book.best_ask().map(|e| (e.price, e.size)), Some((0.755, 50.0)));
+        assert_eq!(book.ask_levels(), 2);
+        assert_eq!(book.timestamp, 1002);
+    }
+
+    #[tokio::test]
+    async fn test_resubscribe_on_snapshot_gap() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/book")
+            .match_query(mockito::Matcher::UrlEncoded("token_id".into(), "YES".into()))
+            .with_status(200)
+            .with_body(r#"{"bids": [["0.74", "80"]], "asks": [["0.77", "90"]], "timestamp": 900}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let (raw_tx, raw_rx) = mpsc::channel(10);
+        let (message_tx, mut message_rx) = mpsc::channel(10);
+        let rest = ClobRestClient::new(server.url());
+        let last_updates: LastUpdates = Arc::new(RwLock::new(HashMap::new()));
+        let bus = EventBus::default();
+        let mut events = bus.subscribe();
+        tokio::spawn(forward_messages(
+            raw_rx,
+            message_tx,
+            None,
+            Some(rest),
+            last_updates.clone(),
+            Some(bus),
+            watch::channel(1).1,
+        ));
+
+        let delta = |timestamp: i64| PolymarketMessage::Orderbook(OrderbookUpdate {
+            market_id: "TRUMP-WIN".to_string(),
+            token_id: "YES".to_string(),
+            bids: vec![(0.75, 100.0)],
+            asks: vec![(0.76, 150.0)],
+            timestamp,
+            event_type: Some("price_change".to_string()),
+        });
+
+        raw_tx.send(delta(1000)).await.unwrap();
+        raw_tx.send(delta(1001)).await.unwrap();
+
+        // Synthetic snapshot is injected ahead of the first delta only
+        let snapshot = process_message(message_rx.recv().await.unwrap()).unwrap();
+        assert!(snapshot.is_snapshot);
+        assert_eq!(snapshot.market_id.to_string(), "TRUMP-WIN");
+        assert_eq!(snapshot.order_book.bids[0].price, 0.74);
+        assert_eq!(snapshot.timestamp, 900);
+
+        assert_eq!(process_message(message_rx.recv().await.unwrap()).unwrap().timestamp, 1000);
+        assert_eq!(process_message(message_rx.recv().await.unwrap()).unwrap().timestamp, 1001);
+        mock.assert_async().await;
+
+        // Every forwarded book, synthetic snapshot included, is published
+        let mut published = Vec::new();
+        while let Ok(TradingEvent::MarketUpdate { order_book, is_snapshot, .. }) = events.try_recv()
+        {
+            published.push((order_book.timestamp, is_snapshot));
+        }
+        assert_eq!(published, vec![(900, true), (1000, false), (1001, false)]);
+
+        // Forwarded updates are recorded for freshness checks
+        let age = last_updates.read().get(&TokenId("YES".to_string())).map(Instant::elapsed);
+        assert!(age.unwrap() < Duration::from_secs(5));
+        assert!(!last_updates.read().contains_key(&TokenId("NO".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_gap_retries_and_resets_on_reconnect() {
+        let mut server = mockito::Server::new_async().await;
+        let by_token = || mockito::Matcher::UrlEncoded("token_id".into(), "YES".into());
+        let failure = server
+            .mock("GET", "/book")
+            .match_query(by_token())
+            .with_status(500)
+            .expect(1)
+            .create_async()
+            .await;
+        let book = server
+            .mock("GET", "/book")
+            .match_query(by_token())
+            .with_status(200)
+            .with_body(r#"{"bids": [["0.74", "80"]], "asks": [["0.77", "90"]], "timestamp": 900}"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let (raw_tx, raw_rx) = mpsc::channel(10);
+        let (message_tx, mut message_rx) = mpsc::channel(10);
+        let (connections_tx, connections) = watch::channel(1);
+        tokio::spawn(forward_messages(
+            raw_rx,
+            message_tx,
+            None,
+            Some(ClobRestClient::new(server.url())),
+            Arc::new(RwLock::new(HashMap::new())),
+            None,
+            connections,
+        ));
+
+        let delta = |timestamp: i64| PolymarketMessage::PriceChange(PriceChangeUpdate {
+            market_id: "TRUMP-WIN".to_string(),
+            token_id: "YES".to_string(),
+            side: OrderSide::BUY,
+            price: 0.75,
+            size: 100.0,
+            timestamp,
+        });
+        let next = |rx: &mut mpsc::Receiver<PolymarketMessage>| {
+            let update = process_message(rx.try_recv().unwrap()).unwrap();
+            (update.timestamp, update.is_snapshot)
+        };
+
+        // Deltas are held through the failed attempt and follow the snapshot
+        raw_tx.send(delta(1000)).await.unwrap();
+        raw_tx.send(delta(1001)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(message_rx.try_recv().is_err(), "Held until the snapshot arrives");
+        tokio::time::sleep(SNAPSHOT_RETRY_INITIAL + Duration::from_millis(200)).await;
+        assert_eq!(next(&mut message_rx), (900, true));
+        assert_eq!(next(&mut message_rx), (1000, false));
+        assert_eq!(next(&mut message_rx), (1001, false));
+
+        // After a reconnect the next delta needs a fresh snapshot
+        connections_tx.send(2).unwrap();
+        raw_tx.send(delta(1002)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(next(&mut message_rx), (900, true));
+        assert_eq!(next(&mut message_rx), (1002, false));
+
+        failure.assert_async().await;
+        book.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_new_multiplexed_shares_messages() {
+        let (ws, mut receivers) =
+            PolymarketWebSocket::new_multiplexed("wss://example.com/ws".to_string(), vec![], 3);
+        assert_eq!(receivers.len(), 3);
+
+        // What `start` does before connecting
+        let (feed, subscribers) = ws.relay.lock().take().unwrap();
+        tokio::spawn(relay(feed, subscribers));
+
+        let confirm = PolymarketMessage::Subscribed(SubscriptionConfirm {
+            channel: "orderbook".to_string(),
+            market_id: Some("TRUMP-WIN".to_string()),
+        });
+        ws.message_tx.send(confirm).await.unwrap();
+
+        for rx in &mut receivers {
+            assert!(matches!(rx.recv().await, Some(PolymarketMessage::Subscribed(_))));
+        }
+    }
+
+    #[test]
+    fn test_market_data_age() {
+        let (ws, _rx) = PolymarketWebSocket::new("wss://example.com/ws".to_string(), vec![]);
+        let token_id = TokenId("YES".to_string());
+        assert_eq!(ws.market_data_age(&token_id), None);
+
+        let updated_at = Instant::now() - Duration::from_millis(1500);
+        ws.last_updates.write().insert(token_id.clone(), updated_at);
+        let age = ws.market_data_age(&token_id).unwrap();
+        assert!(age >= Duration::from_millis(1500) && age < Duration::from_secs(5));
+    }
+
     #[test]
     fn test_process_subscription_confirm() {
         let msg = PolymarketMessage::Subscribed(SubscriptionConfirm {
@@ -7,7 +7,7 @@
 //! When prices are inefficient, we can arbitrage BOTH sides:
 //!
 //! ## BUY Arbitrage (YES + NO < $1.00)
-//! ```
+//! ```text
 //! YES ask: $0.45
 //! NO ask:  $0.48
 //! Sum:     $0.93  ← BUY BOTH!
@@ -18,7 +18,7 @@
 //! ```
 //!
 //! ## SELL Arbitrage (YES + NO > $1.00)
-//! ```
+//! ```text
 //! YES bid: $0.55
 //! NO bid:  $0.52
 //! Sum:     $1.07  ← SELL BOTH!
@@ -28,11 +28,36 @@
 //! Profit: $0.07 (7% return!)
 //! ```
 //!
+//! ## Redemption Fee
+//! Polymarket charges a redemption fee on winnings (2% = 200 bps):
+//! ```text
+//! Gross margin: $1.00 - $0.93 = $0.07
+//! Fee:          $1.00 × 2%    = $0.02
+//! Net margin:                   $0.05
+//! ```
+//!
 //! # Risk
 //! ZERO market risk - you either own both outcomes (buy) or owe $1 (sell)!
 //! Only execution risk (partial fill, fees, etc.)
 
-use crate::types::{OrderBook, MarketId, TokenId};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::clob::CreateOrderRequest;
+use crate::config::features::{FeatureStore, BINARY_ARB};
+use crate::types::{OrderBook, OrderBookEntry, MarketId, OrderSide, OrderType, TokenId};
+use crate::strategies::crypto_updown::CryptoUpDownMarket;
+use crate::strategies::simd_binary_arbitrage::SimdBinaryArbitrageDetector;
+use crate::utils::math::annualized_return;
+
+/// YES/NO market pair for batch detection: (market, YES token, NO token, YES book, NO book)
+pub type BinaryMarketPair = (MarketId, TokenId, TokenId, OrderBook, OrderBook);
+
+/// Markets per rayon task in `detect_parallel_batch` (a multiple of the SIMD width of 4)
+#[cfg(feature = "rayon")]
+const PARALLEL_CHUNK_SIZE: usize = 64;
 
 /// Arbitrage side (buy or sell)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -43,6 +68,77 @@ pub enum ArbitrageSide {
     Sell,
 }
 
+/// Why an opportunity's `max_size` is limited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintSide {
+    /// YES side has less liquidity (also reported on ties)
+    Yes,
+    /// NO side has less liquidity
+    No,
+    /// Total cost would exceed the configured `max_cost`
+    MaxCost,
+    /// Tradeable size is below the configured `min_size`
+    MinSize,
+}
+
+impl ConstraintSide {
+    /// Constraint from the liquidity available on each side
+    fn from_sizes(yes_size: f64, no_size: f64) -> Self {
+        if no_size < yes_size {
+            ConstraintSide::No
+        } else {
+            ConstraintSide::Yes
+        }
+    }
+}
+
+/// Estimated fees for executing an opportunity, in USDC
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeeEstimate {
+    /// Trading fees on both orders at the signed fee rate
+    pub trading_fee_usd: f64,
+
+    /// Redemption fee on the winning shares (already netted from `profit_margin`)
+    pub redemption_fee_usd: f64,
+}
+
+impl FeeEstimate {
+    /// Total estimated fees
+    pub fn total(&self) -> f64 {
+        self.trading_fee_usd + self.redemption_fee_usd
+    }
+}
+
+/// Orders and costs for executing a binary arbitrage opportunity
+#[derive(Debug, Clone)]
+pub struct ExecutionPlan {
+    /// Order for the YES token
+    pub yes_order: CreateOrderRequest,
+
+    /// Order for the NO token
+    pub no_order: CreateOrderRequest,
+
+    /// USDC notional of both orders at their limit prices
+    pub expected_execution_cost_usd: f64,
+
+    /// Estimated fees
+    pub fee_estimate: FeeEstimate,
+}
+
+impl ExecutionPlan {
+    /// Both orders in batch submission order (YES, NO)
+    pub fn orders(&self) -> [CreateOrderRequest; 2] {
+        [self.yes_order.clone(), self.no_order.clone()]
+    }
+
+    /// Submit both orders as `order_type` (e.g., `OrderType::FOK`)
+    pub fn with_order_type(mut self, order_type: OrderType) -> Self {
+        self.yes_order.order_type = order_type;
+        self.no_order.order_type = order_type;
+        self
+    }
+}
+
 /// Binary arbitrage opportunity
 #[derive(Debug, Clone)]
 pub struct BinaryArbitrageOpportunity {
@@ -67,13 +163,25 @@ pub struct BinaryArbitrageOpportunity {
     /// Sum of prices
     pub price_sum: f64,
 
-    /// Profit margin (abs(1.00 - sum))
+    /// Profit margin per share, net of redemption fee (abs(1.00 - sum) - fee)
     pub profit_margin: f64,
 
+    /// Redemption fee per share deducted from the margin
+    pub redemption_fee: f64,
+
     /// Maximum tradeable size (limited by smaller side)
     pub max_size: f64,
 
-    /// Expected profit in USDC
+    /// Size available at the YES price
+    pub yes_size: f64,
+
+    /// Size available at the NO price
+    pub no_size: f64,
+
+    /// What limited `max_size` (increase that side's liquidity for larger trades)
+    pub size_constrained_by: ConstraintSide,
+
+    /// Expected profit in USDC (net of redemption fee)
     pub expected_profit: f64,
 
     /// Market title (e.g., "BTC up or down 15min")
@@ -99,70 +207,178 @@ impl BinaryArbitrageOpportunity {
         expiry: Option<String>,
     ) -> Option<Self> {
         // Try BUY arbitrage first (YES ask + NO ask < $1.00)
-        if let Some(yes_ask) = yes_orderbook.best_ask() {
-            if let Some(no_ask) = no_orderbook.best_ask() {
-                let yes_price = yes_ask.price;
-                let no_price = no_ask.price;
-                let price_sum = yes_price + no_price;
-
-                // BUY arbitrage: sum < $1.00
-                if price_sum < 1.0 {
-                    let profit_margin = 1.0 - price_sum;
-                    let max_size = yes_ask.size.min(no_ask.size);
-                    let expected_profit = profit_margin * max_size;
-
-                    return Some(Self {
-                        market_id,
-                        yes_token_id,
-                        no_token_id,
-                        side: ArbitrageSide::Buy,
-                        yes_price,
-                        no_price,
-                        price_sum,
-                        profit_margin,
-                        max_size,
-                        expected_profit,
-                        title,
-                        expiry,
-                    });
-                }
+        if let (Some(yes_ask), Some(no_ask)) = (yes_orderbook.best_ask(), no_orderbook.best_ask()) {
+            if yes_ask.price + no_ask.price < 1.0 {
+                return Some(Self::from_levels(
+                    ArbitrageSide::Buy,
+                    market_id,
+                    yes_token_id,
+                    no_token_id,
+                    yes_ask,
+                    no_ask,
+                    title,
+                    expiry,
+                ));
             }
         }
 
         // Try SELL arbitrage (YES bid + NO bid > $1.00)
-        if let Some(yes_bid) = yes_orderbook.best_bid() {
-            if let Some(no_bid) = no_orderbook.best_bid() {
-                let yes_price = yes_bid.price;
-                let no_price = no_bid.price;
-                let price_sum = yes_price + no_price;
-
-                // SELL arbitrage: sum > $1.00
-                if price_sum > 1.0 {
-                    let profit_margin = price_sum - 1.0;
-                    let max_size = yes_bid.size.min(no_bid.size);
-                    let expected_profit = profit_margin * max_size;
-
-                    return Some(Self {
-                        market_id,
-                        yes_token_id,
-                        no_token_id,
-                        side: ArbitrageSide::Sell,
-                        yes_price,
-                        no_price,
-                        price_sum,
-                        profit_margin,
-                        max_size,
-                        expected_profit,
-                        title,
-                        expiry,
-                    });
-                }
+        if let (Some(yes_bid), Some(no_bid)) = (yes_orderbook.best_bid(), no_orderbook.best_bid()) {
+            if yes_bid.price + no_bid.price > 1.0 {
+                return Some(Self::from_levels(
+                    ArbitrageSide::Sell,
+                    market_id,
+                    yes_token_id,
+                    no_token_id,
+                    yes_bid,
+                    no_bid,
+                    title,
+                    expiry,
+                ));
             }
         }
 
         // No arbitrage opportunity
         None
     }
+
+    /// Build an opportunity from the YES/NO top-of-book levels of one side
+    ///
+    /// Does not check that the side is actually profitable.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn from_levels(
+        side: ArbitrageSide,
+        market_id: MarketId,
+        yes_token_id: TokenId,
+        no_token_id: TokenId,
+        yes_level: &OrderBookEntry,
+        no_level: &OrderBookEntry,
+        title: String,
+        expiry: Option<String>,
+    ) -> Self {
+        let yes_price = yes_level.price;
+        let no_price = no_level.price;
+        let price_sum = yes_price + no_price;
+        let profit_margin = match side {
+            ArbitrageSide::Buy => 1.0 - price_sum,
+            ArbitrageSide::Sell => price_sum - 1.0,
+        };
+        let max_size = yes_level.size.min(no_level.size);
+
+        Self {
+            market_id,
+            yes_token_id,
+            no_token_id,
+            side,
+            yes_price,
+            no_price,
+            price_sum,
+            profit_margin,
+            redemption_fee: 0.0,
+            max_size,
+            yes_size: yes_level.size,
+            no_size: no_level.size,
+            size_constrained_by: ConstraintSide::from_sizes(yes_level.size, no_level.size),
+            expected_profit: profit_margin * max_size,
+            title,
+            expiry,
+        }
+    }
+
+    /// Deduct the redemption fee from margin and expected profit
+    ///
+    /// Returns `None` if the fee turns the margin negative.
+    pub fn with_redemption_fee(mut self, redemption_fee_bps: u16) -> Option<Self> {
+        // Fee is charged on the $1.00 payout of the winning share
+        let fee = redemption_fee_bps as f64 / 10_000.0;
+        let gross_margin = self.profit_margin + self.redemption_fee;
+        let net_margin = gross_margin - fee;
+
+        if net_margin < 0.0 {
+            return None;
+        }
+
+        self.profit_margin = net_margin;
+        self.redemption_fee = fee;
+        self.expected_profit = net_margin * self.max_size;
+        Some(self)
+    }
+
+    /// Apply the config size limits to `size_constrained_by`
+    ///
+    /// `MinSize` takes precedence over `MaxCost`, which takes precedence
+    /// over the liquidity-side constraint.
+    pub fn with_size_limits(mut self, min_size: f64, max_cost: f64) -> Self {
+        if self.max_size < min_size {
+            self.size_constrained_by = ConstraintSide::MinSize;
+        } else if self.max_size * self.price_sum > max_cost {
+            self.size_constrained_by = ConstraintSide::MaxCost;
+        }
+        self
+    }
+
+    /// Profit margin before the redemption fee
+    pub fn gross_margin(&self) -> f64 {
+        self.profit_margin + self.redemption_fee
+    }
+
+    /// GTC orders for both sides at the opportunity's prices and `max_size`
+    ///
+    /// Both orders are signed with `fee_rate_bps` (0 = the client default).
+    pub fn execution_plan(&self, fee_rate_bps: u16) -> ExecutionPlan {
+        let side = match self.side {
+            ArbitrageSide::Buy => OrderSide::BUY,
+            ArbitrageSide::Sell => OrderSide::SELL,
+        };
+        let order = |token_id: &TokenId, price: f64| CreateOrderRequest {
+            token_id: token_id.0.clone(),
+            side,
+            price,
+            size: self.max_size,
+            order_type: OrderType::GTC,
+            expiration: None,
+            fee_rate_bps,
+            nonce: None,
+        };
+
+        let expected_execution_cost_usd = self.price_sum * self.max_size;
+        ExecutionPlan {
+            yes_order: order(&self.yes_token_id, self.yes_price),
+            no_order: order(&self.no_token_id, self.no_price),
+            expected_execution_cost_usd,
+            fee_estimate: FeeEstimate {
+                trading_fee_usd: expected_execution_cost_usd * fee_rate_bps as f64 / 10_000.0,
+                redemption_fee_usd: self.redemption_fee * self.max_size,
+            },
+        }
+    }
+
+    /// Annualized return if the trade is repeated every `time_to_expiry`
+    ///
+    /// `(1 + profit_margin / price_sum) ^ (periods per year) - 1`
+    ///
+    /// Returns `None` for a zero duration, or when compounding overflows `f64`.
+    ///
+    /// # Example
+    /// ```text
+    /// 7% margin on $0.93 = 7.53% per trade
+    ///
+    /// 30-day market:     1.0753^12.2   - 1 ≈ 142%
+    /// 7-day market:      1.0753^52.2   - 1 ≈ 4,300%
+    /// 15-minute market:  1.0753^35,064 - 1 ≈ 10^1105 (overflows → None)
+    /// ```
+    /// Small absolute profits on short expiries compound into enormous rates.
+    pub fn expected_annualized_return(&self, time_to_expiry: Duration) -> Option<f64> {
+        if time_to_expiry.is_zero() {
+            return None;
+        }
+
+        let per_trade_return = self.profit_margin / self.price_sum;
+        let holding_period_days = time_to_expiry.as_secs_f64() / 86_400.0;
+        let annualized = annualized_return(per_trade_return, holding_period_days);
+
+        annualized.is_finite().then_some(annualized)
+    }
 }
 
 /// Binary arbitrage detector configuration
@@ -176,6 +392,116 @@ pub struct BinaryArbitrageConfig {
 
     /// Maximum total cost per trade
     pub max_cost: f64,
+
+    /// Redemption fee on winnings in basis points (200 = 2%)
+    pub redemption_fee_bps: u16,
+
+    /// Skip markets expiring sooner than this (orders may not fill before settlement)
+    pub min_expiry_minutes: u64,
+
+    /// Skip markets with more time remaining than this (None = no upper bound)
+    pub max_time_remaining_secs: Option<u64>,
+
+    /// Skip markets whose orderbooks were last updated longer ago than this
+    pub max_market_data_age_ms: u64,
+
+    /// Require both YES and NO to have at least `min_size` without a large imbalance
+    pub require_both_sides_liquid: bool,
+
+    /// Largest allowed ratio of the larger side's size to the smaller side's
+    pub max_size_imbalance_ratio: f64,
+
+    /// Minimum price levels on each side of both the YES and NO books
+    /// (see `TradingConfig::order_book_depth_required`)
+    pub order_book_depth_required: usize,
+
+    /// Submit both legs as fill-or-kill orders (see `ClobClient::create_fok_order`)
+    ///
+    /// Neither leg can rest partially filled, but the two orders are
+    /// independent: one leg may still fill while the other is killed, leaving
+    /// a one-sided position the caller must handle as a partial fill.
+    pub require_atomic: bool,
+}
+
+impl BinaryArbitrageConfig {
+    /// Whether a market with `time_to_expiry` left is eligible for trading
+    ///
+    /// The eligible window is `[min_expiry_minutes, max_time_remaining_secs]`.
+    pub fn is_within_expiry_window(&self, time_to_expiry: Duration) -> bool {
+        if time_to_expiry < Duration::from_secs(self.min_expiry_minutes * 60) {
+            return false;
+        }
+        self.max_time_remaining_secs
+            .is_none_or(|max| time_to_expiry <= Duration::from_secs(max))
+    }
+
+    /// Whether orderbook data last updated `age` ago is fresh enough to trade on
+    ///
+    /// `None` (no update received yet) is never fresh.
+    pub fn is_market_data_fresh(&self, age: Option<Duration>) -> bool {
+        age.is_some_and(|age| age <= Duration::from_millis(self.max_market_data_age_ms))
+    }
+
+    /// Whether YES/NO sizes satisfy `require_both_sides_liquid`
+    ///
+    /// Both sides need `min_size`, and the larger side may be at most
+    /// `max_size_imbalance_ratio` times the smaller. Always true when disabled.
+    pub fn is_liquidity_balanced(&self, yes_size: f64, no_size: f64) -> bool {
+        if !self.require_both_sides_liquid {
+            return true;
+        }
+
+        let smaller = yes_size.min(no_size);
+        smaller > 0.0
+            && smaller >= self.min_size
+            && yes_size.max(no_size) / smaller <= self.max_size_imbalance_ratio
+    }
+
+    /// Whether both books meet `order_book_depth_required`
+    pub(crate) fn has_required_depth(&self, yes_book: &OrderBook, no_book: &OrderBook) -> bool {
+        let depth = self.order_book_depth_required;
+        yes_book.has_minimum_depth(depth) && no_book.has_minimum_depth(depth)
+    }
+
+    /// Apply redemption fee, minimum margin, liquidity and size limits
+    pub(crate) fn apply_filters(
+        &self,
+        opportunity: BinaryArbitrageOpportunity,
+    ) -> Option<BinaryArbitrageOpportunity> {
+        let opportunity = opportunity
+            .with_redemption_fee(self.redemption_fee_bps)?
+            .with_size_limits(self.min_size, self.max_cost);
+
+        // Check minimum profit margin (net of fees)
+        if opportunity.profit_margin < self.min_profit_margin {
+            return None;
+        }
+
+        // Check both sides have usable, comparable liquidity
+        if !self.is_liquidity_balanced(opportunity.yes_size, opportunity.no_size) {
+            tracing::debug!(
+                "Skipping {}: illiquid or imbalanced sides (YES {:.2}, NO {:.2})",
+                opportunity.title,
+                opportunity.yes_size,
+                opportunity.no_size
+            );
+            return None;
+        }
+
+        // Check minimum size and maximum cost
+        match opportunity.size_constrained_by {
+            ConstraintSide::MinSize | ConstraintSide::MaxCost => {
+                tracing::debug!(
+                    "Skipping {}: size {:.2} constrained by {:?}",
+                    opportunity.title,
+                    opportunity.max_size,
+                    opportunity.size_constrained_by
+                );
+                None
+            }
+            ConstraintSide::Yes | ConstraintSide::No => Some(opportunity),
+        }
+    }
 }
 
 impl Default for BinaryArbitrageConfig {
@@ -184,22 +510,63 @@ impl Default for BinaryArbitrageConfig {
             min_profit_margin: 0.02, // 2% minimum (to cover fees)
             min_size: 5.0,           // $5 minimum
             max_cost: 100.0,         // Max $100 total cost
+            redemption_fee_bps: 200, // Polymarket 2% redemption fee
+            min_expiry_minutes: 5,
+            max_time_remaining_secs: None,
+            max_market_data_age_ms: 10_000,
+            require_both_sides_liquid: true,
+            max_size_imbalance_ratio: 10.0, // At most 10:1
+            order_book_depth_required: 1,   // Top of book only
+            require_atomic: false,
         }
     }
 }
 
+/// Time from now until an RFC 3339 `expiry` (zero once it has passed)
+fn time_until(expiry: &str) -> Option<Duration> {
+    let expiry = DateTime::parse_from_rfc3339(expiry).ok()?;
+    let seconds = (expiry.with_timezone(&Utc) - Utc::now()).num_seconds();
+    Some(Duration::from_secs(seconds.max(0) as u64))
+}
+
 /// Binary arbitrage detector
 pub struct BinaryArbitrageDetector {
-    config: BinaryArbitrageConfig,
+    /// Batch-of-4 detector (owns the configuration)
+    simd: SimdBinaryArbitrageDetector,
+
+    /// Runtime feature flags (None = always enabled)
+    feature_store: Option<Arc<FeatureStore>>,
 }
 
 impl BinaryArbitrageDetector {
     /// Create new detector
     pub fn new(config: BinaryArbitrageConfig) -> Self {
-        Self { config }
+        Self {
+            simd: SimdBinaryArbitrageDetector::new(config),
+            feature_store: None,
+        }
+    }
+
+    /// Detector configuration
+    pub fn config(&self) -> &BinaryArbitrageConfig {
+        self.simd.config()
+    }
+
+    /// Check the `binary_arb` flag in `feature_store` on every `detect`
+    pub fn with_feature_store(mut self, feature_store: Arc<FeatureStore>) -> Self {
+        self.feature_store = Some(feature_store);
+        self
     }
 
     /// Detect arbitrage in binary market pair
+    ///
+    /// Same as [`detect_with_fees`](Self::detect_with_fees): margins are net of
+    /// the configured redemption fee. Returns `None` when the time left is
+    /// outside the configured expiry window, or while the `binary_arb` feature
+    /// flag is off. The time left is `time_to_expiry`, else it is computed
+    /// from an RFC 3339 `expiry` (past expiries count as zero); with neither,
+    /// the window is not checked.
+    #[allow(clippy::too_many_arguments)]
     pub fn detect(
         &self,
         market_id: &MarketId,
@@ -209,7 +576,72 @@ impl BinaryArbitrageDetector {
         no_orderbook: &OrderBook,
         title: String,
         expiry: Option<String>,
+        time_to_expiry: Option<Duration>,
+    ) -> Option<BinaryArbitrageOpportunity> {
+        if let Some(store) = &self.feature_store {
+            if !store.get(BINARY_ARB) {
+                return None;
+            }
+        }
+
+        if !self.in_expiry_window(&title, expiry.as_deref(), time_to_expiry) {
+            return None;
+        }
+
+        self.detect_with_fees(
+            market_id,
+            yes_token_id,
+            no_token_id,
+            yes_orderbook,
+            no_orderbook,
+            title,
+            expiry,
+        )
+    }
+
+    /// Whether the time left (`time_to_expiry`, else from `expiry`) is tradeable
+    ///
+    /// With neither, the window is not checked.
+    fn in_expiry_window(
+        &self,
+        title: &str,
+        expiry: Option<&str>,
+        time_to_expiry: Option<Duration>,
+    ) -> bool {
+        let Some(remaining) = time_to_expiry.or_else(|| expiry.and_then(time_until)) else {
+            return true;
+        };
+        let within = self.config().is_within_expiry_window(remaining);
+        if !within {
+            tracing::debug!(
+                "Skipping {}: {}s to expiry outside trading window",
+                title,
+                remaining.as_secs()
+            );
+        }
+        within
+    }
+
+    /// Detect arbitrage net of the redemption fee
+    ///
+    /// `profit_margin = (1.00 - price_sum) - redemption_fee_pct`. Returns `None`
+    /// when the fee makes the trade unprofitable, or when either book is
+    /// shallower than `order_book_depth_required`.
+    pub fn detect_with_fees(
+        &self,
+        market_id: &MarketId,
+        yes_token_id: &TokenId,
+        no_token_id: &TokenId,
+        yes_orderbook: &OrderBook,
+        no_orderbook: &OrderBook,
+        title: String,
+        expiry: Option<String>,
     ) -> Option<BinaryArbitrageOpportunity> {
+        if !self.config().has_required_depth(yes_orderbook, no_orderbook) {
+            tracing::debug!("Skipping {}: order book too thin", title);
+            return None;
+        }
+
         // Try to find opportunity
         let opportunity = BinaryArbitrageOpportunity::from_orderbooks(
             market_id.clone(),
@@ -221,23 +653,115 @@ impl BinaryArbitrageDetector {
             expiry,
         )?;
 
-        // Check minimum profit margin
-        if opportunity.profit_margin < self.config.min_profit_margin {
-            return None;
-        }
+        self.config().apply_filters(opportunity)
+    }
 
-        // Check minimum size
-        if opportunity.max_size < self.config.min_size {
-            return None;
+    /// Detect arbitrage across up/down markets, 4 at a time using SIMD
+    ///
+    /// Same results as [`detect`](Self::detect) per market: the expiry window
+    /// is checked first, then full chunks of 4 eligible markets go through
+    /// [`SimdBinaryArbitrageDetector::detect_batch`] and the remainder through
+    /// [`detect_with_fees`](Self::detect_with_fees). Each market's first two
+    /// token IDs are its YES and NO books in `cache`; markets without both
+    /// are skipped. Opportunities keep market order. Empty while the
+    /// `binary_arb` feature flag is off.
+    pub fn detect_batch(
+        &self,
+        markets: &[CryptoUpDownMarket],
+        cache: &HashMap<TokenId, OrderBook>,
+    ) -> Vec<BinaryArbitrageOpportunity> {
+        if let Some(store) = &self.feature_store {
+            if !store.get(BINARY_ARB) {
+                return Vec::new();
+            }
         }
 
-        // Check maximum cost
-        let total_cost = opportunity.price_sum * opportunity.max_size;
-        if total_cost > self.config.max_cost {
-            return None;
+        let (eligible, pairs): (Vec<&CryptoUpDownMarket>, Vec<BinaryMarketPair>) = markets
+            .iter()
+            .filter(|market| {
+                let expiry = market.end_date.as_deref();
+                self.in_expiry_window(&market.title, expiry, market.time_to_expiry())
+            })
+            .filter_map(|market| {
+                let [yes_token_id, no_token_id, ..] = market.token_ids.as_slice() else {
+                    return None;
+                };
+                let (yes_token_id, no_token_id) =
+                    (TokenId(yes_token_id.clone()), TokenId(no_token_id.clone()));
+                let yes_book = cache.get(&yes_token_id)?.clone();
+                let no_book = cache.get(&no_token_id)?.clone();
+                Some((market, (market.market_id(), yes_token_id, no_token_id, yes_book, no_book)))
+            })
+            .unzip();
+
+        let mut results = Vec::with_capacity(pairs.len());
+        let chunks = pairs.chunks_exact(4);
+        let remainder = chunks.remainder();
+        for chunk in chunks {
+            let batch: &[BinaryMarketPair; 4] = chunk.try_into().expect("chunk of 4");
+            results.extend(self.simd.detect_batch(batch));
+        }
+        for (market_id, yes_token_id, no_token_id, yes_book, no_book) in remainder {
+            results.push(self.detect_with_fees(
+                market_id,
+                yes_token_id,
+                no_token_id,
+                yes_book,
+                no_book,
+                String::new(),
+                None,
+            ));
         }
 
-        Some(opportunity)
+        // SIMD results carry no market metadata
+        eligible
+            .into_iter()
+            .zip(results)
+            .filter_map(|(market, opportunity)| {
+                Some(BinaryArbitrageOpportunity {
+                    title: market.title.clone(),
+                    expiry: market.end_date.clone(),
+                    ..opportunity?
+                })
+            })
+            .collect()
+    }
+
+    /// Detect arbitrage across many up/down markets, most profitable first
+    ///
+    /// [`detect_batch`](Self::detect_batch) over chunks of markets on rayon's
+    /// thread pool with the `rayon` feature, over all of them otherwise.
+    /// `cache` is only read, so markets need no locking. Sorted by
+    /// `expected_profit` descending.
+    pub fn detect_parallel_batch(
+        &self,
+        markets: &[CryptoUpDownMarket],
+        cache: &HashMap<TokenId, OrderBook>,
+    ) -> Vec<BinaryArbitrageOpportunity> {
+        #[cfg(feature = "rayon")]
+        let mut found: Vec<BinaryArbitrageOpportunity> = {
+            use rayon::prelude::*;
+            markets
+                .par_chunks(PARALLEL_CHUNK_SIZE)
+                .flat_map_iter(|chunk| self.detect_batch(chunk, cache))
+                .collect()
+        };
+        #[cfg(not(feature = "rayon"))]
+        let mut found = self.detect_batch(markets, cache);
+
+        found.sort_by(|a, b| b.expected_profit.total_cmp(&a.expected_profit));
+        found
+    }
+
+    /// Detect BOTH buy and sell arbitrage for 4 pairs at once using SIMD
+    ///
+    /// Returns `(buy, sell)` per pair; see
+    /// [`SimdBinaryArbitrageDetector::detect_both_sides`].
+    pub fn detect_simd_batch_both_sides(
+        &self,
+        pairs: &[BinaryMarketPair; 4],
+    ) -> [(Option<BinaryArbitrageOpportunity>, Option<BinaryArbitrageOpportunity>); 4] {
+        self.simd.detect_both_sides(pairs)
     }
 }
 
@@ -265,7 +789,7 @@ mod tests {
 
     #[test]
     fn test_buy_arbitrage_detection() {
-        let market_id = MarketId("btc-15min".to_string());
+        let market_id = MarketId::new("btc-15min");
         let yes_token = TokenId("yes-token".to_string());
         let no_token = TokenId("no-token".to_string());
 
@@ -295,9 +819,90 @@ mod tests {
         assert!((opp.expected_profit - 7.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_execution_plan() {
+        let detector = BinaryArbitrageDetector::new(BinaryArbitrageConfig::default());
+
+        // Sum: $0.93 (7% gross) - 2% redemption fee
+        let opp = detector
+            .detect_with_fees(
+                &MarketId::new("btc-15min"),
+                &TokenId("yes-token".to_string()),
+                &TokenId("no-token".to_string()),
+                &create_orderbook(0.45, 0.43, 100.0),
+                &create_orderbook(0.48, 0.46, 100.0),
+                "BTC Up/Down 15min".to_string(),
+                None,
+            )
+            .unwrap();
+
+        let plan = opp.execution_plan(100);
+        let [yes, no] = plan.orders();
+        assert_eq!((yes.token_id.as_str(), yes.price), ("yes-token", 0.45));
+        assert_eq!((no.token_id.as_str(), no.price), ("no-token", 0.48));
+        for order in [&yes, &no] {
+            assert_eq!(order.side, OrderSide::BUY);
+            assert_eq!((order.size, order.fee_rate_bps), (100.0, 100));
+            assert_eq!(order.order_type, OrderType::GTC);
+            assert!(order.validate().is_ok());
+        }
+
+        // $93 notional: $0.93 trading fees at 1%, $2 redemption fee
+        assert!((plan.expected_execution_cost_usd - 93.0).abs() < 1e-9);
+        assert!((plan.fee_estimate.trading_fee_usd - 0.93).abs() < 1e-9);
+        assert!((plan.fee_estimate.redemption_fee_usd - 2.0).abs() < 1e-9);
+
+        // Fill-or-kill legs are opt-in
+        assert!(!detector.config().require_atomic);
+        let [yes, no] = plan.clone().with_order_type(OrderType::FOK).orders();
+        assert_eq!((yes.order_type, no.order_type), (OrderType::FOK, OrderType::FOK));
+        assert!((plan.fee_estimate.total() - 2.93).abs() < 1e-9);
+
+        // Sell side and client-default fee rate
+        let sell = BinaryArbitrageOpportunity::from_orderbooks(
+            MarketId::new("eth-1h"),
+            TokenId("yes-token".to_string()),
+            TokenId("no-token".to_string()),
+            &create_orderbook(0.57, 0.55, 100.0),
+            &create_orderbook(0.54, 0.52, 100.0),
+            "ETH Up/Down 1h".to_string(),
+            None,
+        )
+        .unwrap()
+        .execution_plan(0);
+        assert_eq!(sell.yes_order.side, OrderSide::SELL);
+        assert_eq!((sell.yes_order.price, sell.no_order.price), (0.55, 0.52));
+        assert_eq!(sell.fee_estimate.trading_fee_usd, 0.0);
+    }
+
+    #[test]
+    fn test_expected_annualized_return() {
+        // Sum: $0.93, 7% margin
+        let opp = BinaryArbitrageOpportunity::from_orderbooks(
+            MarketId::new("btc-15min"),
+            TokenId("yes-token".to_string()),
+            TokenId("no-token".to_string()),
+            &create_orderbook(0.45, 0.43, 100.0),
+            &create_orderbook(0.48, 0.46, 100.0),
+            "BTC Up/Down 15min".to_string(),
+            None,
+        )
+        .unwrap();
+
+        let monthly = opp.expected_annualized_return(Duration::from_secs(30 * 86_400)).unwrap();
+        assert!((monthly - 1.4195).abs() < 0.001);
+
+        let weekly = opp.expected_annualized_return(Duration::from_secs(7 * 86_400)).unwrap();
+        assert!(weekly > 43.0 && weekly < 43.2);
+
+        // 15-minute compounding overflows f64
+        assert!(opp.expected_annualized_return(Duration::from_secs(15 * 60)).is_none());
+        assert!(opp.expected_annualized_return(Duration::ZERO).is_none());
+    }
+
     #[test]
     fn test_sell_arbitrage_detection() {
-        let market_id = MarketId("eth-1h".to_string());
+        let market_id = MarketId::new("eth-1h");
         let yes_token = TokenId("yes-token".to_string());
         let no_token = TokenId("no-token".to_string());
 
@@ -329,7 +934,7 @@ mod tests {
 
     #[test]
     fn test_no_arbitrage_when_sum_equals_one() {
-        let market_id = MarketId("btc-15min".to_string());
+        let market_id = MarketId::new("btc-15min");
         let yes_token = TokenId("yes-token".to_string());
         let no_token = TokenId("no-token".to_string());
 
@@ -352,7 +957,7 @@ mod tests {
 
     #[test]
     fn test_buy_arbitrage_prefers_buy_over_sell() {
-        let market_id = MarketId("sol-4h".to_string());
+        let market_id = MarketId::new("sol-4h");
         let yes_token = TokenId("yes-token".to_string());
         let no_token = TokenId("no-token".to_string());
 
@@ -381,16 +986,45 @@ mod tests {
         assert!((opp.profit_margin - 0.06).abs() < 0.001);
     }
 
+    #[test]
+    fn test_size_constrained_by() {
+        let opportunity = |yes_size: f64, no_size: f64| {
+            BinaryArbitrageOpportunity::from_orderbooks(
+                MarketId::new("btc-15min"),
+                TokenId("yes-token".to_string()),
+                TokenId("no-token".to_string()),
+                &create_orderbook(0.45, 0.43, yes_size),
+                &create_orderbook(0.48, 0.46, no_size),
+                "BTC Up/Down 15min".to_string(),
+                None,
+            )
+            .unwrap()
+        };
+
+        assert_eq!(opportunity(50.0, 100.0).size_constrained_by, ConstraintSide::Yes);
+        assert_eq!(opportunity(100.0, 50.0).size_constrained_by, ConstraintSide::No);
+
+        // 50 shares * $0.93 = $46.50
+        let constraint = |min_size, max_cost| {
+            opportunity(50.0, 100.0).with_size_limits(min_size, max_cost).size_constrained_by
+        };
+        assert_eq!(constraint(5.0, 40.0), ConstraintSide::MaxCost);
+        assert_eq!(constraint(60.0, 40.0), ConstraintSide::MinSize);
+        assert_eq!(constraint(5.0, 100.0), ConstraintSide::Yes);
+    }
+
     #[test]
     fn test_detector_filters_by_config() {
         let config = BinaryArbitrageConfig {
             min_profit_margin: 0.05, // 5% minimum
             min_size: 10.0,
             max_cost: 50.0,
+            redemption_fee_bps: 0,
+            ..BinaryArbitrageConfig::default()
         };
         let detector = BinaryArbitrageDetector::new(config);
 
-        let market_id = MarketId("btc-15min".to_string());
+        let market_id = MarketId::new("btc-15min");
         let yes_token = TokenId("yes-token".to_string());
         let no_token = TokenId("no-token".to_string());
 
@@ -406,8 +1040,392 @@ mod tests {
             &no_orderbook,
             "BTC Up/Down 15min".to_string(),
             None,
+            None,
         );
 
         assert!(result.is_none(), "Should filter out 3% profit when min is 5%");
     }
+
+    #[test]
+    fn test_detect_with_fees_nets_profit() {
+        let detector = BinaryArbitrageDetector::new(BinaryArbitrageConfig::default());
+
+        // Sum: $0.93 (7% gross) - 2% fee = 5% net
+        let yes_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let no_orderbook = create_orderbook(0.48, 0.46, 100.0);
+
+        let opp = detector
+            .detect_with_fees(
+                &MarketId::new("btc-15min"),
+                &TokenId("yes-token".to_string()),
+                &TokenId("no-token".to_string()),
+                &yes_orderbook,
+                &no_orderbook,
+                "BTC Up/Down 15min".to_string(),
+                None,
+            )
+            .expect("5% net profit should pass");
+
+        assert!((opp.gross_margin() - 0.07).abs() < 0.001);
+        assert!((opp.redemption_fee - 0.02).abs() < 1e-9);
+        assert!((opp.profit_margin - 0.05).abs() < 0.001);
+        assert!((opp.expected_profit - 5.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn test_detect_filters_by_expiry_window() {
+        let config = BinaryArbitrageConfig {
+            max_time_remaining_secs: Some(3600),
+            ..BinaryArbitrageConfig::default()
+        };
+        let detector = BinaryArbitrageDetector::new(config);
+
+        // Sum: $0.90 (10% gross)
+        let yes_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let no_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let detect = |time_to_expiry| {
+            detector.detect(
+                &MarketId::new("btc-15min"),
+                &TokenId("yes-token".to_string()),
+                &TokenId("no-token".to_string()),
+                &yes_orderbook,
+                &no_orderbook,
+                "BTC Up/Down 15min".to_string(),
+                None,
+                time_to_expiry,
+            )
+        };
+
+        assert!(detect(None).is_some());
+        assert!(detect(Some(Duration::from_secs(120))).is_none(), "Expires too soon");
+        assert!(detect(Some(Duration::from_secs(300))).is_some());
+        assert!(detect(Some(Duration::from_secs(3600))).is_some());
+        assert!(detect(Some(Duration::from_secs(3601))).is_none(), "Too far from expiry");
+    }
+
+    #[test]
+    fn test_detect_filters_by_expiry_timestamp() {
+        let config = BinaryArbitrageConfig {
+            max_time_remaining_secs: Some(3600),
+            ..BinaryArbitrageConfig::default()
+        };
+        let detector = BinaryArbitrageDetector::new(config);
+
+        // Sum: $0.90 (10% gross)
+        let yes_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let no_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let detect = |expiry: Option<String>| {
+            detector.detect(
+                &MarketId::new("btc-15min"),
+                &TokenId("yes-token".to_string()),
+                &TokenId("no-token".to_string()),
+                &yes_orderbook,
+                &no_orderbook,
+                "BTC Up/Down 15min".to_string(),
+                expiry,
+                None,
+            )
+        };
+        let expiring_in =
+            |secs: i64| Some((Utc::now() + chrono::Duration::seconds(secs)).to_rfc3339());
+
+        assert!(detect(expiring_in(-60)).is_none(), "Already expired");
+        assert!(detect(expiring_in(30)).is_none(), "Expires too soon");
+        assert!(detect(expiring_in(310)).is_some(), "Just above the 5 minute minimum");
+        assert!(detect(expiring_in(1800)).is_some());
+        assert!(detect(expiring_in(7200)).is_none(), "Too far from expiry");
+
+        // Unparseable expiry: the window is not checked
+        assert!(detect(Some("soon".to_string())).is_some());
+    }
+
+    #[test]
+    fn test_require_both_sides_liquid() {
+        let detector = BinaryArbitrageDetector::new(BinaryArbitrageConfig::default());
+        let detect = |yes_size, no_size| {
+            detector.detect_with_fees(
+                &MarketId::new("btc-15min"),
+                &TokenId("yes-token".to_string()),
+                &TokenId("no-token".to_string()),
+                &create_orderbook(0.45, 0.43, yes_size),
+                &create_orderbook(0.45, 0.43, no_size),
+                "BTC Up/Down 15min".to_string(),
+                None,
+            )
+        };
+
+        // Balanced and up to 10:1 imbalance
+        let opp = detect(100.0, 80.0).expect("Balanced book");
+        assert_eq!((opp.yes_size, opp.no_size, opp.max_size), (100.0, 80.0, 80.0));
+        assert!(detect(10.0, 100.0).is_some(), "Exactly 10:1");
+
+        // Heavily imbalanced, or one side dust
+        assert!(detect(5.0, 100.0).is_none(), "20:1 imbalance");
+        assert!(detect(0.001, 100.0).is_none(), "Negligible NO side");
+
+        let lenient = BinaryArbitrageConfig {
+            require_both_sides_liquid: false,
+            ..BinaryArbitrageConfig::default()
+        };
+        assert!(lenient.is_liquidity_balanced(5.0, 100.0));
+        assert!(!BinaryArbitrageConfig::default().is_liquidity_balanced(0.0, 0.0));
+
+        let strict = BinaryArbitrageConfig {
+            max_size_imbalance_ratio: 2.0,
+            ..BinaryArbitrageConfig::default()
+        };
+        assert!(strict.is_liquidity_balanced(50.0, 100.0));
+        assert!(!strict.is_liquidity_balanced(40.0, 100.0));
+    }
+
+    #[test]
+    fn test_order_book_depth_required() {
+        let config = BinaryArbitrageConfig {
+            order_book_depth_required: 2,
+            ..BinaryArbitrageConfig::default()
+        };
+        let detector = BinaryArbitrageDetector::new(config);
+        let detect = |yes_orderbook: &OrderBook, no_orderbook: &OrderBook| {
+            detector.detect(
+                &MarketId::new("btc-15min"),
+                &TokenId("yes-token".to_string()),
+                &TokenId("no-token".to_string()),
+                yes_orderbook,
+                no_orderbook,
+                "BTC Up/Down 15min".to_string(),
+                None,
+                None,
+            )
+        };
+
+        // Sum: $0.90 (10% gross), single-level books
+        let mut yes_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let mut no_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        assert!(detect(&yes_orderbook, &no_orderbook).is_none());
+
+        let deepen = |book: &mut OrderBook| {
+            book.bids.push(OrderBookEntry { price: 0.42, size: 100.0, timestamp: Some(1000) });
+            book.asks.push(OrderBookEntry { price: 0.46, size: 100.0, timestamp: Some(1000) });
+        };
+        deepen(&mut yes_orderbook);
+        assert!(detect(&yes_orderbook, &no_orderbook).is_none(), "NO book still too thin");
+        deepen(&mut no_orderbook);
+        assert!(detect(&yes_orderbook, &no_orderbook).is_some());
+    }
+
+    #[test]
+    fn test_is_market_data_fresh() {
+        let config = BinaryArbitrageConfig {
+            max_market_data_age_ms: 2_000,
+            ..BinaryArbitrageConfig::default()
+        };
+
+        assert!(!config.is_market_data_fresh(None), "No update received yet");
+        assert!(config.is_market_data_fresh(Some(Duration::from_millis(500))));
+        assert!(config.is_market_data_fresh(Some(Duration::from_millis(2_000))));
+        assert!(!config.is_market_data_fresh(Some(Duration::from_millis(2_001))));
+    }
+
+    #[test]
+    fn test_detect_respects_feature_flag() {
+        let store = Arc::new(FeatureStore::new());
+        let detector = BinaryArbitrageDetector::new(BinaryArbitrageConfig::default())
+            .with_feature_store(store.clone());
+
+        let yes_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let no_orderbook = create_orderbook(0.45, 0.43, 100.0);
+        let detect = || {
+            detector.detect(
+                &MarketId::new("btc-15min"),
+                &TokenId("yes-token".to_string()),
+                &TokenId("no-token".to_string()),
+                &yes_orderbook,
+                &no_orderbook,
+                "BTC Up/Down 15min".to_string(),
+                None,
+                None,
+            )
+        };
+
+        assert!(detect().is_none(), "Unset flag disables detection");
+        store.set(BINARY_ARB, true);
+        assert!(detect().is_some());
+        store.set(BINARY_ARB, false);
+        assert!(detect().is_none());
+    }
+
+    #[test]
+    fn test_detect_with_fees_rejects_fee_exceeding_profit() {
+        let config = BinaryArbitrageConfig {
+            min_profit_margin: 0.0,
+            ..BinaryArbitrageConfig::default()
+        };
+        let detector = BinaryArbitrageDetector::new(config);
+
+        // Sum: $0.985 (1.5% gross) - 2% fee = -0.5% net
+        let yes_orderbook = create_orderbook(0.485, 0.47, 100.0);
+        let no_orderbook = create_orderbook(0.50, 0.48, 100.0);
+
+        let result = detector.detect_with_fees(
+            &MarketId::new("btc-15min"),
+            &TokenId("yes-token".to_string()),
+            &TokenId("no-token".to_string()),
+            &yes_orderbook,
+            &no_orderbook,
+            "BTC Up/Down 15min".to_string(),
+            None,
+        );
+
+        assert!(result.is_none(), "1.5% gross profit should not survive 2% fee");
+    }
+
+    #[test]
+    fn test_detect_simd_batch_both_sides() {
+        let detector = BinaryArbitrageDetector::new(BinaryArbitrageConfig::default());
+        let pair = |id: &str, yes: OrderBook, no: OrderBook| {
+            (
+                MarketId::new(id),
+                TokenId(format!("{}-yes", id)),
+                TokenId(format!("{}-no", id)),
+                yes,
+                no,
+            )
+        };
+
+        let pairs = [
+            // Asks sum to $0.93: buy only
+            pair("buy", create_orderbook(0.45, 0.43, 50.0), create_orderbook(0.48, 0.46, 50.0)),
+            // Bids sum to $1.07: sell only
+            pair("sell", create_orderbook(0.57, 0.55, 50.0), create_orderbook(0.54, 0.52, 50.0)),
+            // Crossed books: asks sum to $0.93 and bids to $1.07
+            pair("both", create_orderbook(0.45, 0.55, 50.0), create_orderbook(0.48, 0.52, 50.0)),
+            // Fair market
+            pair("none", create_orderbook(0.51, 0.49, 50.0), create_orderbook(0.51, 0.49, 50.0)),
+        ];
+
+        let results = detector.detect_simd_batch_both_sides(&pairs);
+
+        let (buy, sell) = &results[0];
+        let buy = buy.as_ref().expect("Should detect buy");
+        assert_eq!(buy.side, ArbitrageSide::Buy);
+        assert_eq!(buy.no_token_id.0, "buy-no");
+        assert!((buy.profit_margin - 0.05).abs() < 1e-9); // 7% gross - 2% fee
+        assert!(sell.is_none());
+
+        let (buy, sell) = &results[1];
+        assert!(buy.is_none());
+        assert_eq!(sell.as_ref().expect("Should detect sell").side, ArbitrageSide::Sell);
+
+        let (buy, sell) = &results[2];
+        assert!(buy.is_some() && sell.is_some());
+        assert_eq!(sell.as_ref().unwrap().title, "both");
+
+        assert!(results[3].0.is_none() && results[3].1.is_none());
+
+        // Scalar detection agrees on the buy side
+        let (market_id, yes_token, no_token, yes_book, no_book) = &pairs[0];
+        let scalar = detector
+            .detect(market_id, yes_token, no_token, yes_book, no_book, "buy".into(), None, None)
+            .unwrap();
+        assert_eq!(scalar.expected_profit, results[0].0.as_ref().unwrap().expected_profit);
+    }
+
+    #[test]
+    fn test_detect_parallel_batch_sorted_by_profit() {
+        use crate::strategies::{CryptoAsset, Timeframe};
+
+        let detector = BinaryArbitrageDetector::new(BinaryArbitrageConfig::default());
+        let market = |id: &str, token_ids: &[&str]| CryptoUpDownMarket {
+            asset: CryptoAsset::Bitcoin,
+            timeframe: Timeframe::FifteenMin,
+            event_id: id.to_string(),
+            slug: format!("btc-updown-15m-{}", id),
+            title: id.to_string(),
+            token_ids: token_ids.iter().map(|t| t.to_string()).collect(),
+            condition_id: None,
+            end_date: None,
+        };
+        let markets = vec![
+            market("small", &["small-yes", "small-no"]),
+            market("fair", &["fair-yes", "fair-no"]),
+            market("big", &["big-yes", "big-no"]),
+            market("missing", &["big-yes", "missing-no"]),
+            market("single", &["big-yes"]),
+        ];
+
+        let mut cache = HashMap::new();
+        for (token, book) in [
+            ("small-yes", create_orderbook(0.45, 0.43, 20.0)),
+            ("small-no", create_orderbook(0.48, 0.46, 20.0)),
+            ("fair-yes", create_orderbook(0.51, 0.49, 50.0)),
+            ("fair-no", create_orderbook(0.51, 0.49, 50.0)),
+            ("big-yes", create_orderbook(0.45, 0.43, 50.0)),
+            ("big-no", create_orderbook(0.48, 0.46, 50.0)),
+        ] {
+            cache.insert(TokenId(token.to_string()), book);
+        }
+
+        let found = detector.detect_parallel_batch(&markets, &cache);
+        let titles: Vec<&str> = found.iter().map(|o| o.title.as_str()).collect();
+        assert_eq!(titles, ["big", "small"]);
+        assert_eq!(found[0].no_token_id.0, "big-no");
+        assert!(found[0].expected_profit > found[1].expected_profit);
+    }
+
+    #[test]
+    fn test_detect_batch_chunks_through_simd() {
+        use crate::strategies::{CryptoAsset, Timeframe};
+
+        let store = Arc::new(FeatureStore::new());
+        let detector = BinaryArbitrageDetector::new(BinaryArbitrageConfig::default())
+            .with_feature_store(store.clone());
+        let market = |id: &str, end_date: Option<&str>| CryptoUpDownMarket {
+            asset: CryptoAsset::Bitcoin,
+            timeframe: Timeframe::FifteenMin,
+            event_id: id.to_string(),
+            slug: format!("btc-updown-15m-{}", id),
+            title: format!("{} market", id),
+            token_ids: vec![format!("{}-yes", id), format!("{}-no", id)],
+            condition_id: None,
+            end_date: end_date.map(str::to_string),
+        };
+        let buy = || create_orderbook(0.45, 0.43, 50.0);
+        let fair = || create_orderbook(0.51, 0.49, 50.0);
+
+        // An expired market, then one SIMD chunk plus a scalar remainder
+        let end = (Utc::now() + chrono::Duration::minutes(10)).to_rfc3339();
+        let books = [
+            ("expired", buy(), buy()),
+            ("a", buy(), buy()),
+            ("b", fair(), fair()),
+            ("c", create_orderbook(0.57, 0.55, 50.0), create_orderbook(0.54, 0.52, 50.0)),
+            ("d", fair(), fair()),
+            ("e", buy(), create_orderbook(0.48, 0.46, 50.0)),
+        ];
+        let mut cache = HashMap::new();
+        let mut markets = Vec::new();
+        for (id, yes, no) in books {
+            cache.insert(TokenId(format!("{}-yes", id)), yes);
+            cache.insert(TokenId(format!("{}-no", id)), no);
+            let end_date = if id == "expired" { "2020-01-01T00:00:00Z" } else { end.as_str() };
+            markets.push(market(id, Some(end_date)));
+        }
+
+        assert!(detector.detect_batch(&markets, &cache).is_empty(), "Flag off");
+        store.set(BINARY_ARB, true);
+
+        let found = detector.detect_batch(&markets, &cache);
+        let found: Vec<_> = found.iter().map(|o| (o.title.as_str(), o.side)).collect();
+        assert_eq!(
+            found,
+            [
+                ("a market", ArbitrageSide::Buy),
+                ("c market", ArbitrageSide::Sell),
+                ("e market", ArbitrageSide::Buy)
+            ]
+        );
+        let remainder = detector.detect_parallel_batch(&markets[5..], &cache);
+        assert_eq!(remainder[0].expiry.as_deref(), Some(end.as_str()));
+        assert!(detector.detect_batch(&[], &cache).is_empty());
+    }
 }
@@ -16,9 +16,14 @@
 //! - Can lose 100% of position
 //! - Need to monitor actively
 
-use anyhow::{anyhow, Result};
-use serde::Deserialize;
+use anyhow::Result;
 use std::collections::HashMap;
+use std::time::Duration;
+
+pub use crate::services::polymarket::{GammaEvent, GammaResponse};
+
+use crate::services::polymarket::{ClobRestClient, GammaClient, MarketInfo};
+use crate::types::MarketId;
 
 /// Crypto asset for up/down markets
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -105,36 +110,6 @@ impl Timeframe {
     }
 }
 
-/// Gamma API event response
-#[derive(Debug, Clone, Deserialize)]
-pub struct GammaEvent {
-    /// Event ID
-    pub id: String,
-
-    /// Event slug (URL-friendly identifier)
-    pub slug: String,
-
-    /// Event title
-    pub title: String,
-
-    /// CLOB token IDs (YES/NO tokens)
-    #[serde(rename = "clobTokenIds")]
-    pub clob_token_ids: Vec<String>,
-
-    /// Whether event is active
-    pub active: bool,
-
-    /// Whether event is closed
-    pub closed: bool,
-
-    /// End date timestamp
-    #[serde(rename = "endDate")]
-    pub end_date: Option<String>,
-}
-
-/// Gamma API response (returns array directly)
-pub type GammaResponse = Vec<GammaEvent>;
-
 /// Crypto up/down market
 #[derive(Debug, Clone)]
 pub struct CryptoUpDownMarket {
@@ -156,6 +131,9 @@ pub struct CryptoUpDownMarket {
     /// Token IDs (YES/NO)
     pub token_ids: Vec<String>,
 
+    /// CLOB condition ID (if provided by Gamma)
+    pub condition_id: Option<String>,
+
     /// End date
     pub end_date: Option<String>,
 }
@@ -191,9 +169,93 @@ impl CryptoUpDownMarket {
             slug: event.slug,
             title: event.title,
             token_ids: event.clob_token_ids,
+            condition_id: event.condition_id,
             end_date: event.end_date,
         })
     }
+
+    /// Time left until `end_date` (zero once expired, None if unknown)
+    pub fn time_to_expiry(&self) -> Option<Duration> {
+        let end = chrono::DateTime::parse_from_rfc3339(self.end_date.as_deref()?).ok()?;
+        let remaining = end.with_timezone(&chrono::Utc) - chrono::Utc::now();
+        Some(remaining.to_std().unwrap_or(Duration::ZERO))
+    }
+
+    /// Target move in percent for threshold markets ("BTC up by 1%")
+    ///
+    /// Parsed from the title (`"1%"`, `"1.5%"`) or, failing that, the slug
+    /// (`"btc-up-1pct-15m"`, `"btc-up-1p5pct-1h"`). `None` for plain up/down
+    /// markets, which have no threshold.
+    pub fn target_move_pct(&self) -> Option<f64> {
+        let from_title = self.title.match_indices('%').find_map(|(end, _)| {
+            let number = self.title[..end]
+                .trim_end()
+                .rsplit(|c: char| !c.is_ascii_digit() && c != '.')
+                .next()?;
+            number.parse().ok()
+        });
+
+        let from_slug = || {
+            self.slug.split('-').find_map(|part| {
+                let number = part.strip_suffix("pct")?;
+                number.replace('p', ".").parse().ok()
+            })
+        };
+
+        from_title.or_else(from_slug).filter(|&pct: &f64| pct > 0.0)
+    }
+
+    /// Implied probability per percent of target move
+    ///
+    /// `current_price` is the YES price, i.e. the market's probability that
+    /// the target move happens. YES at $0.70 on a 1% target gives 0.7; a
+    /// ratio above 1.0 means the market prices in a larger move than the
+    /// stated threshold. `None` without a target move or for a price outside
+    /// (0, 1].
+    pub fn implied_move(&self, current_price: f64) -> Option<f64> {
+        if !(current_price > 0.0 && current_price <= 1.0) {
+            return None;
+        }
+        Some(current_price / self.target_move_pct()?)
+    }
+
+    /// Gamma event ID as a `MarketId` (an `EventSlug`-kind ID)
+    pub fn market_id(&self) -> MarketId {
+        MarketId::from_event_slug(&self.event_id)
+    }
+
+    /// Reconcile Gamma data with CLOB market info
+    ///
+    /// Returns `None` if the CLOB market is closed or inactive. Stale Gamma
+    /// token IDs are replaced by the CLOB's.
+    pub fn reconcile(mut self, info: &MarketInfo) -> Option<Self> {
+        if info.closed || !info.active {
+            tracing::warn!("Skipping {}: CLOB market is closed or inactive", self.slug);
+            return None;
+        }
+
+        let clob_token_ids = info.token_ids();
+        if clob_token_ids.len() < 2 {
+            tracing::warn!(
+                "Skipping {}: CLOB market has {} tokens",
+                self.slug,
+                clob_token_ids.len()
+            );
+            return None;
+        }
+
+        if clob_token_ids != self.token_ids {
+            tracing::warn!(
+                "Stale Gamma token IDs for {}: {:?} -> {:?}",
+                self.slug,
+                self.token_ids,
+                clob_token_ids
+            );
+            self.token_ids = clob_token_ids;
+        }
+
+        Some(self)
+    }
 }
 
 /// Configuration for crypto up/down market fetching
@@ -230,53 +292,39 @@ impl Default for CryptoUpDownConfig {
 
 /// Crypto up/down market fetcher
 pub struct CryptoUpDownFetcher {
-    /// HTTP client
-    client: reqwest::Client,
+    /// Gamma API client
+    gamma: GammaClient,
 
     /// Configuration
     config: CryptoUpDownConfig,
 
-    /// Gamma API base URL
-    gamma_api_url: String,
+    /// CLOB REST client for token ID validation (optional)
+    rest_client: Option<ClobRestClient>,
 }
 
 impl CryptoUpDownFetcher {
     /// Create new fetcher
-    pub fn new(config: CryptoUpDownConfig, gamma_api_url: String) -> Self {
+    pub fn new(config: CryptoUpDownConfig, gamma: GammaClient) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            gamma,
             config,
-            gamma_api_url,
+            rest_client: None,
         }
     }
 
+    /// Validate Gamma token IDs against the CLOB market info endpoint
+    pub fn with_clob_validation(mut self, rest_client: ClobRestClient) -> Self {
+        self.rest_client = Some(rest_client);
+        self
+    }
+
     /// Fetch active crypto up/down markets
     ///
     /// # Returns
     /// List of active markets matching configuration
     pub async fn fetch_markets(&self) -> Result<Vec<CryptoUpDownMarket>> {
-        // Build API URL
-        let url = format!(
-            "{}/events?closed=false&archived=false&limit={}&offset=0&order=id&ascending=false",
-            self.gamma_api_url,
-            self.config.max_markets
-        );
-
-        tracing::info!("Fetching crypto up/down markets from: {}", url);
-
         // Fetch events
-        let response = self.client.get(&url)
-            .send()
-            .await
-            .map_err(|e| anyhow!("Failed to fetch events: {}", e))?;
-
-        if !response.status().is_success() {
-            return Err(anyhow!("API returned error: {}", response.status()));
-        }
-
-        let gamma_response: GammaResponse = response.json()
-            .await
-            .map_err(|e| anyhow!("Failed to parse response: {}", e))?;
+        let gamma_response = self.gamma.get_events(self.config.max_markets).await?;
 
         let total_events = gamma_response.len();
         tracing::info!("Fetched {} events from API", total_events);
@@ -308,6 +356,11 @@ impl CryptoUpDownFetcher {
             })
             .collect();
 
+        let markets = match &self.rest_client {
+            Some(rest_client) => Self::validate_markets(rest_client, markets).await,
+            None => markets,
+        };
+
         tracing::info!(
             "Found {} crypto up/down markets (filtered from {} events)",
             markets.len(),
@@ -320,6 +373,33 @@ impl CryptoUpDownFetcher {
         Ok(markets)
     }
 
+    /// Cross-reference markets with CLOB market info
+    ///
+    /// Markets without a condition ID, or whose lookup fails, are kept as-is.
+    async fn validate_markets(
+        rest_client: &ClobRestClient,
+        markets: Vec<CryptoUpDownMarket>,
+    ) -> Vec<CryptoUpDownMarket> {
+        let mut validated = Vec::with_capacity(markets.len());
+
+        for market in markets {
+            let Some(condition_id) = market.condition_id.clone() else {
+                validated.push(market);
+                continue;
+            };
+
+            match rest_client.get_market_info(&condition_id).await {
+                Ok(info) => validated.extend(market.reconcile(&info)),
+                Err(e) => {
+                    tracing::warn!("Could not validate {} against CLOB: {}", market.slug, e);
+                    validated.push(market);
+                }
+            }
+        }
+
+        validated
+    }
+
     /// Get token IDs from markets
     pub fn get_token_ids(markets: &[CryptoUpDownMarket]) -> Vec<String> {
         markets.iter()
@@ -367,6 +447,104 @@ mod tests {
         assert_eq!(Timeframe::from_slug("btc-daily-updown"), Some(Timeframe::Daily));
     }
 
+    fn create_market_info(token_ids: &[&str], active: bool, closed: bool) -> MarketInfo {
+        serde_json::from_value(serde_json::json!({
+            "condition_id": "0xcond",
+            "question_id": "0xquestion",
+            "active": active,
+            "closed": closed,
+            "tokens": token_ids
+                .iter()
+                .map(|id| serde_json::json!({"token_id": id, "outcome": "Up"}))
+                .collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reconcile_with_clob_market_info() {
+        let event: GammaEvent = serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "slug": "btc-updown-15m-est",
+            "title": "BTC Up or Down",
+            "clobTokenIds": ["old-yes", "old-no"],
+            "conditionId": "0xcond",
+            "active": true,
+            "closed": false,
+            "endDate": null,
+        }))
+        .unwrap();
+        let market = CryptoUpDownMarket::from_gamma_event(event).unwrap();
+        assert_eq!(market.condition_id.as_deref(), Some("0xcond"));
+
+        // Matching IDs are kept
+        let info = create_market_info(&["old-yes", "old-no"], true, false);
+        assert_eq!(market.clone().reconcile(&info).unwrap().token_ids, vec!["old-yes", "old-no"]);
+
+        // Stale IDs are replaced
+        let info = create_market_info(&["yes", "no"], true, false);
+        assert_eq!(market.clone().reconcile(&info).unwrap().token_ids, vec!["yes", "no"]);
+
+        // Closed markets are dropped
+        let info = create_market_info(&["yes", "no"], true, true);
+        assert!(market.reconcile(&info).is_none());
+    }
+
+    #[test]
+    fn test_time_to_expiry() {
+        let mut market = CryptoUpDownMarket {
+            asset: CryptoAsset::Bitcoin,
+            timeframe: Timeframe::FifteenMin,
+            event_id: "1".to_string(),
+            slug: "btc-updown-15m-est".to_string(),
+            title: "BTC Up or Down".to_string(),
+            token_ids: vec!["yes".to_string(), "no".to_string()],
+            condition_id: None,
+            end_date: None,
+        };
+        assert_eq!(market.time_to_expiry(), None);
+
+        market.end_date = Some("2020-01-01T00:00:00Z".to_string());
+        assert_eq!(market.time_to_expiry(), Some(Duration::ZERO));
+
+        let end = chrono::Utc::now() + chrono::Duration::minutes(10);
+        market.end_date = Some(end.to_rfc3339());
+        let remaining = market.time_to_expiry().unwrap();
+        assert!(remaining > Duration::from_secs(590) && remaining <= Duration::from_secs(600));
+    }
+
+    #[test]
+    fn test_implied_move() {
+        let mut market = CryptoUpDownMarket {
+            asset: CryptoAsset::Bitcoin,
+            timeframe: Timeframe::FifteenMin,
+            event_id: "1".to_string(),
+            slug: "btc-updown-15m-est".to_string(),
+            title: "BTC Up or Down".to_string(),
+            token_ids: vec!["yes".to_string(), "no".to_string()],
+            condition_id: None,
+            end_date: None,
+        };
+        assert_eq!(market.target_move_pct(), None);
+        assert_eq!(market.implied_move(0.70), None);
+
+        market.title = "Will BTC be up by 1% in 15 minutes?".to_string();
+        assert_eq!(market.target_move_pct(), Some(1.0));
+        assert!((market.implied_move(0.70).unwrap() - 0.70).abs() < 1e-12);
+        assert_eq!(market.implied_move(0.0), None);
+        assert_eq!(market.implied_move(1.5), None);
+
+        market.title = "BTC up 0.5 %?".to_string();
+        assert!((market.implied_move(0.70).unwrap() - 1.4).abs() < 1e-12);
+
+        // Falls back to the slug
+        market.title = "BTC Up".to_string();
+        market.slug = "btc-up-1pct-15m".to_string();
+        assert_eq!(market.target_move_pct(), Some(1.0));
+        market.slug = "btc-up-1p5pct-1h".to_string();
+        assert_eq!(market.target_move_pct(), Some(1.5));
+    }
+
     #[test]
     fn test_asset_slug_patterns() {
         let btc = CryptoAsset::Bitcoin;
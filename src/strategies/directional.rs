@@ -0,0 +1,348 @@
+//! Directional Signals for Crypto Up/Down Markets
+//!
+//! Pluggable signals that decide whether to buy YES (up) or NO (down),
+//! plus Kelly criterion sizing for binary outcomes.
+//!
+//! # Kelly Sizing
+//! ```text
+//! Buy outcome at price p with estimated win probability q:
+//!   fraction = (q - p) / (1 - p)
+//!
+//! YES ask: $0.50, estimated P(up): 0.60
+//!   fraction = (0.60 - 0.50) / 0.50 = 20% of bankroll
+//! ```
+//!
+//! # Risk Warning
+//! Signals are estimates - directional positions can lose 100%!
+//! Use fractional Kelly (e.g., 0.25x) to reduce variance.
+
+use crate::types::{MarketId, OrderBook};
+use crate::utils::math;
+use std::collections::{HashMap, VecDeque};
+
+/// Direction to trade in an up/down market
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Price goes up - buy YES
+    Up,
+    /// Price goes down - buy NO
+    Down,
+}
+
+/// Output of a directional signal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DirectionalSignal {
+    /// Direction to trade
+    pub direction: Direction,
+
+    /// Estimated probability that the chosen outcome wins (0.0 - 1.0)
+    pub probability: f64,
+}
+
+/// Directional trading signal
+///
+/// Implement this trait to plug a custom signal into the up/down bot.
+pub trait Signal: Send {
+    /// Signal name for logging
+    fn name(&self) -> &str;
+
+    /// Evaluate the latest YES/NO orderbooks for a market
+    ///
+    /// Returns `None` when the signal has no opinion.
+    fn evaluate(
+        &mut self,
+        market_id: &MarketId,
+        yes_book: &OrderBook,
+        no_book: &OrderBook,
+    ) -> Option<DirectionalSignal>;
+}
+
+/// Built-in signal configuration
+#[derive(Debug, Clone, PartialEq)]
+pub enum SignalConfig {
+    /// Follow YES mid-price moves over a lookback window
+    Momentum {
+        /// Lookback window in milliseconds
+        lookback_ms: u64,
+        /// Minimum mid-price move to trigger a signal
+        min_move: f64,
+    },
+}
+
+impl SignalConfig {
+    /// Build the configured signal
+    pub fn build(&self) -> Box<dyn Signal> {
+        match self {
+            SignalConfig::Momentum { lookback_ms, min_move } => {
+                Box::new(MomentumSignal::new(*lookback_ms, *min_move))
+            }
+        }
+    }
+}
+
+/// Momentum signal on the YES mid-price
+///
+/// Compares the current YES mid-price with the mid-price `lookback_ms` ago.
+/// A move larger than `min_move` is extrapolated into a win probability.
+pub struct MomentumSignal {
+    /// Lookback window in milliseconds
+    lookback_ms: u64,
+
+    /// Minimum mid-price move to trigger a signal
+    min_move: f64,
+
+    /// (timestamp, YES mid-price) history per market
+    history: HashMap<MarketId, VecDeque<(i64, f64)>>,
+}
+
+impl MomentumSignal {
+    /// Create new momentum signal
+    pub fn new(lookback_ms: u64, min_move: f64) -> Self {
+        Self {
+            lookback_ms,
+            min_move,
+            history: HashMap::new(),
+        }
+    }
+}
+
+impl Signal for MomentumSignal {
+    fn name(&self) -> &str {
+        "momentum"
+    }
+
+    fn evaluate(
+        &mut self,
+        market_id: &MarketId,
+        yes_book: &OrderBook,
+        _no_book: &OrderBook,
+    ) -> Option<DirectionalSignal> {
+        let (bid, ask) = yes_book.bbo()?;
+        let mid = (bid.price + ask.price) / 2.0;
+        let now = yes_book.timestamp;
+
+        let history = self.history.entry(market_id.clone()).or_default();
+        if history.back().is_some_and(|&(ts, _)| ts >= now) {
+            // Stale or duplicate snapshot
+            return None;
+        }
+        history.push_back((now, mid));
+
+        // Keep the newest sample that is at least `lookback_ms` old as the reference
+        let cutoff = now - self.lookback_ms as i64;
+        while history.len() > 1 && history[1].0 <= cutoff {
+            history.pop_front();
+        }
+
+        let &(oldest_ts, oldest_mid) = history.front()?;
+        if oldest_ts > cutoff {
+            // Not enough history yet
+            return None;
+        }
+
+        let change = mid - oldest_mid;
+        if change.abs() < self.min_move {
+            return None;
+        }
+
+        // Extrapolate the move into an up probability
+        let up_probability = (mid + change).clamp(0.0, 1.0);
+
+        Some(if change > 0.0 {
+            DirectionalSignal { direction: Direction::Up, probability: up_probability }
+        } else {
+            DirectionalSignal { direction: Direction::Down, probability: 1.0 - up_probability }
+        })
+    }
+}
+
+/// Mock signal returning a fixed output (for testing)
+pub struct MockSignal {
+    /// Output returned on every evaluation
+    output: Option<DirectionalSignal>,
+
+    /// Number of evaluations
+    calls: usize,
+}
+
+impl MockSignal {
+    /// Create mock signal with a fixed output
+    pub fn new(output: Option<DirectionalSignal>) -> Self {
+        Self { output, calls: 0 }
+    }
+
+    /// Number of times `evaluate` was called
+    pub fn calls(&self) -> usize {
+        self.calls
+    }
+}
+
+impl Signal for MockSignal {
+    fn name(&self) -> &str {
+        "mock"
+    }
+
+    fn evaluate(
+        &mut self,
+        _market_id: &MarketId,
+        _yes_book: &OrderBook,
+        _no_book: &OrderBook,
+    ) -> Option<DirectionalSignal> {
+        self.calls += 1;
+        self.output
+    }
+}
+
+/// Kelly fraction for buying a binary outcome
+///
+/// Returns the fraction of bankroll to stake when buying at `price`
+/// with win `probability`. Zero when there is no edge.
+pub fn kelly_fraction(probability: f64, price: f64) -> f64 {
+    if !(price > 0.0 && price < 1.0) {
+        return 0.0;
+    }
+
+    // A share bought at `price` pays $1.00: wins (1 - p) / p, loses it all
+    math::kelly_fraction(probability, (1.0 - price) / price, 1.0)
+}
+
+/// Number of whole shares to buy using fractional Kelly, staking at most
+/// `fraction_cap` of bankroll
+///
+/// The Kelly fraction is scaled by `kelly_multiplier` (e.g. 0.25), then
+/// capped at `fraction_cap` (see `TradingConfig::kelly_fraction_cap`).
+pub fn capped_kelly_size(
+    bankroll: f64,
+    probability: f64,
+    price: f64,
+    kelly_multiplier: f64,
+    fraction_cap: f64,
+    max_stake: f64,
+) -> f64 {
+    let fraction = kelly_fraction(probability, price) * kelly_multiplier.clamp(0.0, 1.0);
+    let stake = bankroll * fraction.min(fraction_cap.clamp(0.0, 1.0));
+    if stake <= 0.0 {
+        return 0.0;
+    }
+
+    // Epsilon guards against float error flooring an exact share count down
+    (stake.min(max_stake).max(0.0) / price + 1e-9).floor()
+}
+
+/// Number of whole shares to buy using fractional Kelly
+///
+/// # Arguments
+/// * `bankroll` - Available capital in USDC
+/// * `probability` - Estimated win probability
+/// * `price` - Entry price per share
+/// * `kelly_multiplier` - Fraction of full Kelly (e.g., 0.25)
+/// * `max_stake` - Maximum USDC to stake
+pub fn kelly_size(
+    bankroll: f64,
+    probability: f64,
+    price: f64,
+    kelly_multiplier: f64,
+    max_stake: f64,
+) -> f64 {
+    let fraction = kelly_fraction(probability, price) * kelly_multiplier.clamp(0.0, 1.0);
+    if fraction <= 0.0 {
+        return 0.0;
+    }
+
+    let stake = (bankroll * fraction).min(max_stake).max(0.0);
+    // Epsilon guards against float error flooring an exact share count down
+    (stake / price + 1e-9).floor()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OrderBookEntry, TokenId};
+
+    fn create_orderbook(bid: f64, ask: f64, timestamp: i64) -> OrderBook {
+        OrderBook {
+            token_id: TokenId("yes".to_string()),
+            bids: vec![OrderBookEntry { price: bid, size: 100.0, timestamp: None }],
+            asks: vec![OrderBookEntry { price: ask, size: 100.0, timestamp: None }],
+            timestamp,
+        }
+    }
+
+    #[test]
+    fn test_kelly_fraction() {
+        assert!((kelly_fraction(0.60, 0.50) - 0.20).abs() < 1e-9);
+        assert_eq!(kelly_fraction(0.40, 0.50), 0.0); // No edge
+        assert_eq!(kelly_fraction(0.60, 1.0), 0.0); // Invalid price
+        assert_eq!(kelly_fraction(f64::NAN, 0.50), 0.0);
+    }
+
+    #[test]
+    fn test_capped_kelly_size() {
+        // Quarter of 20% Kelly under a 25% cap: $5 of $100 at $0.50
+        assert_eq!(capped_kelly_size(100.0, 0.60, 0.50, 0.25, 0.25, 50.0), 10.0);
+        // Full Kelly under a 10% cap, then the $5 stake limit
+        assert_eq!(capped_kelly_size(100.0, 0.60, 0.50, 1.0, 0.10, 50.0), 20.0);
+        assert_eq!(capped_kelly_size(100.0, 0.60, 0.50, 1.0, 0.25, 5.0), 10.0);
+        // Quarter of 80% Kelly reaches the 10% cap
+        assert_eq!(capped_kelly_size(100.0, 0.90, 0.50, 0.25, 0.10, 50.0), 20.0);
+        // No edge
+        assert_eq!(capped_kelly_size(100.0, 0.40, 0.50, 0.25, 0.25, 50.0), 0.0);
+    }
+
+    #[test]
+    fn test_kelly_size() {
+        // 20% Kelly * 0.5 multiplier = 10% of $100 = $10 at $0.50 = 20 shares
+        assert_eq!(kelly_size(100.0, 0.60, 0.50, 0.5, 50.0), 20.0);
+
+        // Capped by max stake: $5 at $0.50 = 10 shares
+        assert_eq!(kelly_size(100.0, 0.60, 0.50, 0.5, 5.0), 10.0);
+
+        assert_eq!(kelly_size(100.0, 0.40, 0.50, 0.5, 50.0), 0.0);
+    }
+
+    #[test]
+    fn test_momentum_signal() {
+        let market_id = MarketId::new("btc-15m");
+        let no_book = create_orderbook(0.49, 0.51, 0);
+        let mut signal = SignalConfig::Momentum { lookback_ms: 1_000, min_move: 0.02 }.build();
+
+        // Not enough history yet
+        assert!(signal.evaluate(&market_id, &create_orderbook(0.49, 0.51, 0), &no_book).is_none());
+        assert!(signal.evaluate(&market_id, &create_orderbook(0.50, 0.52, 500), &no_book).is_none());
+
+        // Mid moved 0.50 -> 0.55 over 1s
+        let up = signal.evaluate(&market_id, &create_orderbook(0.54, 0.56, 1_000), &no_book);
+        let up = up.expect("Should signal up");
+        assert_eq!(up.direction, Direction::Up);
+        assert!((up.probability - 0.60).abs() < 1e-9);
+
+        // Mid moved 0.51 -> 0.45 over 1s
+        let down = signal.evaluate(&market_id, &create_orderbook(0.44, 0.46, 1_500), &no_book);
+        let down = down.expect("Should signal down");
+        assert_eq!(down.direction, Direction::Down);
+        assert!((down.probability - 0.61).abs() < 1e-9);
+
+        // Duplicate snapshot is ignored
+        assert!(signal.evaluate(&market_id, &create_orderbook(0.44, 0.46, 1_500), &no_book).is_none());
+    }
+
+    #[test]
+    fn test_momentum_signal_below_threshold() {
+        let market_id = MarketId::new("btc-15m");
+        let no_book = create_orderbook(0.49, 0.51, 0);
+        let mut signal = MomentumSignal::new(1_000, 0.02);
+
+        signal.evaluate(&market_id, &create_orderbook(0.49, 0.51, 0), &no_book);
+        assert!(signal.evaluate(&market_id, &create_orderbook(0.50, 0.52, 1_000), &no_book).is_none());
+    }
+
+    #[test]
+    fn test_mock_signal() {
+        let output = DirectionalSignal { direction: Direction::Down, probability: 0.7 };
+        let mut signal = MockSignal::new(Some(output));
+        let book = create_orderbook(0.49, 0.51, 0);
+
+        assert_eq!(signal.evaluate(&MarketId::new("m"), &book, &book), Some(output));
+        assert_eq!(signal.calls(), 1);
+    }
+}
@@ -4,6 +4,8 @@
 
 pub mod crypto_updown;
 pub mod binary_arbitrage;
+pub mod simd_binary_arbitrage;
+pub mod directional;
 
 pub use crypto_updown::{
     CryptoAsset, CryptoUpDownConfig, CryptoUpDownFetcher, CryptoUpDownMarket, Timeframe,
@@ -11,4 +13,12 @@ pub use crypto_updown::{
 
 pub use binary_arbitrage::{
     ArbitrageSide, BinaryArbitrageConfig, BinaryArbitrageDetector, BinaryArbitrageOpportunity,
+    BinaryMarketPair, ConstraintSide, ExecutionPlan, FeeEstimate,
+};
+
+pub use simd_binary_arbitrage::SimdBinaryArbitrageDetector;
+
+pub use directional::{
+    capped_kelly_size, kelly_fraction, kelly_size, Direction, DirectionalSignal, MockSignal,
+    MomentumSignal, Signal, SignalConfig,
 };
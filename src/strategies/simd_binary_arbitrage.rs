@@ -0,0 +1,212 @@
+//! SIMD binary arbitrage detection for 4 YES/NO pairs at once
+//!
+//! Sums the YES+NO best asks and best bids of 4 pairs in two `f64x4`
+//! vectors and compares both against $1.00 in one pass: `ask_sum < 1.0` is a
+//! buy, `bid_sum > 1.0` a sell. Only the pairs whose mask bit is set are
+//! turned into opportunities, so the scalar work is skipped for fair markets.
+//! Opportunities go through the same `BinaryArbitrageConfig` filters as
+//! [`BinaryArbitrageDetector`](super::BinaryArbitrageDetector).
+
+use wide::{f64x4, CmpGt, CmpLt};
+
+use crate::strategies::binary_arbitrage::{
+    ArbitrageSide, BinaryArbitrageConfig, BinaryArbitrageOpportunity, BinaryMarketPair,
+};
+use crate::types::OrderBookEntry;
+
+/// Binary arbitrage detector for batches of 4 YES/NO pairs
+#[derive(Debug, Clone)]
+pub struct SimdBinaryArbitrageDetector {
+    config: BinaryArbitrageConfig,
+}
+
+impl SimdBinaryArbitrageDetector {
+    /// Create new detector
+    pub fn new(config: BinaryArbitrageConfig) -> Self {
+        Self { config }
+    }
+
+    /// Detector configuration
+    pub fn config(&self) -> &BinaryArbitrageConfig {
+        &self.config
+    }
+
+    /// Detect arbitrage in 4 pairs at once
+    ///
+    /// Matches scalar detection per pair: buy when the asks sum below $1.00,
+    /// otherwise sell when the bids sum above it, then the config filters
+    /// (redemption fee, `min_profit_margin`, `min_size`, `max_cost`, depth and
+    /// liquidity balance). Titles are the market IDs.
+    pub fn detect_batch(
+        &self,
+        pairs: &[BinaryMarketPair; 4],
+    ) -> [Option<BinaryArbitrageOpportunity>; 4] {
+        let (buy_mask, sell_mask) = Self::masks(pairs);
+
+        std::array::from_fn(|i| {
+            let pair @ (_, _, _, yes_book, no_book) = &pairs[i];
+            if buy_mask & (1 << i) != 0 {
+                self.build(pair, ArbitrageSide::Buy, yes_book.best_ask(), no_book.best_ask())
+            } else if sell_mask & (1 << i) != 0 {
+                self.build(pair, ArbitrageSide::Sell, yes_book.best_bid(), no_book.best_bid())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Detect BOTH buy and sell arbitrage in 4 pairs at once
+    ///
+    /// Returns `(buy, sell)` per pair, so crossed books yield both sides.
+    /// Filtered like [`detect_batch`](Self::detect_batch).
+    pub fn detect_both_sides(
+        &self,
+        pairs: &[BinaryMarketPair; 4],
+    ) -> [(Option<BinaryArbitrageOpportunity>, Option<BinaryArbitrageOpportunity>); 4] {
+        let (buy_mask, sell_mask) = Self::masks(pairs);
+
+        std::array::from_fn(|i| {
+            let pair @ (_, _, _, yes_book, no_book) = &pairs[i];
+            let buy = if buy_mask & (1 << i) != 0 {
+                self.build(pair, ArbitrageSide::Buy, yes_book.best_ask(), no_book.best_ask())
+            } else {
+                None
+            };
+            let sell = if sell_mask & (1 << i) != 0 {
+                self.build(pair, ArbitrageSide::Sell, yes_book.best_bid(), no_book.best_bid())
+            } else {
+                None
+            };
+
+            (buy, sell)
+        })
+    }
+
+    /// Buy (`ask_sum < 1.0`) and sell (`bid_sum > 1.0`) lane masks
+    fn masks(pairs: &[BinaryMarketPair; 4]) -> (i32, i32) {
+        // Missing asks count as $1.00 (never a buy), missing bids as $0.00 (never a sell)
+        let ask_sums = f64x4::new(std::array::from_fn(|i| {
+            let (_, _, _, yes, no) = &pairs[i];
+            yes.best_ask().map_or(1.0, |a| a.price) + no.best_ask().map_or(1.0, |a| a.price)
+        }));
+        let bid_sums = f64x4::new(std::array::from_fn(|i| {
+            let (_, _, _, yes, no) = &pairs[i];
+            yes.best_bid().map_or(0.0, |b| b.price) + no.best_bid().map_or(0.0, |b| b.price)
+        }));
+
+        let one = f64x4::splat(1.0);
+        (ask_sums.cmp_lt(one).move_mask(), bid_sums.cmp_gt(one).move_mask())
+    }
+
+    /// Filtered opportunity on `side` from a pair's top-of-book levels
+    fn build(
+        &self,
+        pair: &BinaryMarketPair,
+        side: ArbitrageSide,
+        yes_level: Option<&OrderBookEntry>,
+        no_level: Option<&OrderBookEntry>,
+    ) -> Option<BinaryArbitrageOpportunity> {
+        let (market_id, yes_token_id, no_token_id, yes_book, no_book) = pair;
+        if !self.config.has_required_depth(yes_book, no_book) {
+            return None;
+        }
+
+        let opportunity = BinaryArbitrageOpportunity::from_levels(
+            side,
+            market_id.clone(),
+            yes_token_id.clone(),
+            no_token_id.clone(),
+            yes_level?,
+            no_level?,
+            market_id.to_string(),
+            None,
+        );
+        self.config.apply_filters(opportunity)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategies::BinaryArbitrageDetector;
+    use crate::types::{MarketId, OrderBook, TokenId};
+
+    fn create_pair(id: &str, yes: (f64, f64, f64), no: (f64, f64, f64)) -> BinaryMarketPair {
+        // (ask, bid, size) per side
+        let book = |(ask, bid, size): (f64, f64, f64)| OrderBook {
+            token_id: TokenId(id.to_string()),
+            bids: vec![OrderBookEntry { price: bid, size, timestamp: None }],
+            asks: vec![OrderBookEntry { price: ask, size, timestamp: None }],
+            timestamp: 0,
+        };
+        (
+            MarketId::new(id),
+            TokenId(format!("{}-yes", id)),
+            TokenId(format!("{}-no", id)),
+            book(yes),
+            book(no),
+        )
+    }
+
+    #[test]
+    fn test_detect_batch_matches_scalar() {
+        let config = BinaryArbitrageConfig::default();
+        let simd = SimdBinaryArbitrageDetector::new(config.clone());
+        let scalar = BinaryArbitrageDetector::new(config);
+
+        let pairs = [
+            // Asks sum to $0.93: buy
+            create_pair("buy", (0.45, 0.43, 50.0), (0.48, 0.46, 50.0)),
+            // Bids sum to $1.07: sell
+            create_pair("sell", (0.57, 0.55, 50.0), (0.54, 0.52, 50.0)),
+            // $0.99: 1% gross is below the 2% fee
+            create_pair("thin", (0.50, 0.48, 50.0), (0.49, 0.47, 50.0)),
+            // Profitable but under min_size
+            create_pair("small", (0.45, 0.43, 2.0), (0.48, 0.46, 2.0)),
+        ];
+
+        let results = simd.detect_batch(&pairs);
+
+        let buy = results[0].as_ref().expect("Should detect buy");
+        assert_eq!(buy.side, ArbitrageSide::Buy);
+        assert_eq!(buy.no_token_id.0, "buy-no");
+        assert!((buy.profit_margin - 0.05).abs() < 1e-9); // 7% gross - 2% fee
+        assert_eq!(results[1].as_ref().expect("Should detect sell").side, ArbitrageSide::Sell);
+        assert!(results[2].is_none() && results[3].is_none());
+
+        for (pair, result) in pairs.iter().zip(&results) {
+            let (market_id, yes_token, no_token, yes_book, no_book) = pair;
+            let expected = scalar.detect_with_fees(
+                market_id,
+                yes_token,
+                no_token,
+                yes_book,
+                no_book,
+                market_id.to_string(),
+                None,
+            );
+            assert_eq!(
+                result.as_ref().map(|o| (o.side, o.expected_profit)),
+                expected.map(|o| (o.side, o.expected_profit)),
+                "{}",
+                market_id
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_batch_prefers_buy_on_crossed_book() {
+        let simd = SimdBinaryArbitrageDetector::new(BinaryArbitrageConfig::default());
+        let crossed = create_pair("both", (0.45, 0.55, 50.0), (0.48, 0.52, 50.0));
+        let fair = create_pair("fair", (0.51, 0.49, 50.0), (0.51, 0.49, 50.0));
+        let pairs = [crossed, fair.clone(), fair.clone(), fair];
+
+        let results = simd.detect_batch(&pairs);
+        assert_eq!(results[0].as_ref().unwrap().side, ArbitrageSide::Buy);
+        assert!(results[1..].iter().all(Option::is_none));
+
+        // Both sides are still available on request
+        let (buy, sell) = &simd.detect_both_sides(&pairs)[0];
+        assert!(buy.is_some() && sell.is_some());
+    }
+}
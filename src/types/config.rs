@@ -3,9 +3,76 @@
 //! Defines all configuration structures for the bot.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// Placeholder for secrets in logged configuration
+const REDACTED: &str = "[REDACTED]";
+
+/// Config keys whose values are never logged
+const SECRET_KEYS: &[&str] =
+    &["private_key", "api_key", "webhook_url", "integration_key", "headers"];
+
+/// CLOB API URL used for staging deployments
+pub const STAGING_CLOB_API_URL: &str = "https://clob-staging.polymarket.com";
+
+/// Chain IDs the bot can trade on (Polygon mainnet, Mumbai testnet)
+pub const SUPPORTED_CHAIN_IDS: [u64; 2] = [137, 80001];
+
+/// Environment variable that must confirm a production deployment
+pub const ENVIRONMENT_VAR: &str = "POLYMARKET_ENVIRONMENT";
+
+/// Deployment environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+    /// Local development (default)
+    #[default]
+    Development,
+    /// Staging (always uses the staging CLOB)
+    Staging,
+    /// Production (real funds)
+    Production,
+}
+
+impl Environment {
+    /// Environment name as used in config files and `POLYMARKET_ENVIRONMENT`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Environment::Development => "development",
+            Environment::Staging => "staging",
+            Environment::Production => "production",
+        }
+    }
+
+    /// Whether this is the production environment
+    pub fn is_production(&self) -> bool {
+        *self == Environment::Production
+    }
+}
+
+impl FromStr for Environment {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "development" => Ok(Environment::Development),
+            "staging" => Ok(Environment::Staging),
+            "production" => Ok(Environment::Production),
+            other => Err(format!("Unknown environment: {}", other)),
+        }
+    }
+}
+
+impl fmt::Display for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 /// Wallet configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct WalletConfig {
     /// Ethereum private key (0x-prefixed hex)
     pub private_key: String,
@@ -17,6 +84,60 @@ pub struct WalletConfig {
     pub chain_id: u64,
 }
 
+impl WalletConfig {
+    /// Every configuration error (empty when valid)
+    ///
+    /// An empty `address` is allowed: the CLOB client then uses the signer's.
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if !self.address.is_empty() && !self.address.starts_with("0x") {
+            errors.push("wallet.address must start with 0x".to_string());
+        }
+        errors.extend(check_chain_id("wallet.chain_id", self.chain_id));
+        errors
+    }
+}
+
+impl fmt::Debug for WalletConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WalletConfig")
+            .field("private_key", &REDACTED)
+            .field("address", &redact_address(&self.address))
+            .field("chain_id", &self.chain_id)
+            .finish()
+    }
+}
+
+/// Partially redact an address, keeping the last 4 hex chars
+fn redact_address(address: &str) -> String {
+    let hex = address.strip_prefix("0x").unwrap_or(address);
+    match hex.char_indices().rev().nth(3) {
+        Some((start, _)) if hex.len() > 4 => format!("0x...{}", &hex[start..]),
+        _ => REDACTED.to_string(),
+    }
+}
+
+/// Replace secret values in a serialized config (recursively)
+fn redact_value(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if SECRET_KEYS.contains(&key.as_str()) {
+                    *value = serde_json::Value::String(REDACTED.to_string());
+                } else if key == "address" {
+                    if let serde_json::Value::String(address) = value {
+                        *address = redact_address(address);
+                    }
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
 /// Trading configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradingConfig {
@@ -34,24 +155,53 @@ pub struct TradingConfig {
 
     /// Milliseconds between trades
     pub cooldown_ms: u64,
+
+    /// Minimum price levels on each side of a book before trading on it
+    #[serde(default = "default_order_book_depth_required")]
+    pub order_book_depth_required: usize,
+
+    /// Largest fraction of bankroll staked on one directional bet
+    /// (see `strategies::capped_kelly_size`)
+    #[serde(default = "default_kelly_fraction_cap")]
+    pub kelly_fraction_cap: f64,
+
+    /// Submit binary arbitrage legs as two fill-or-kill orders instead of a
+    /// GTC batch (see `BinaryArbitrageConfig::require_atomic`)
+    #[serde(default)]
+    pub require_atomic: bool,
+}
+
+fn default_order_book_depth_required() -> usize {
+    3
+}
+
+fn default_kelly_fraction_cap() -> f64 {
+    crate::utils::math::DEFAULT_KELLY_FRACTION_CAP
 }
 
 impl TradingConfig {
-    /// Validate configuration
-    pub fn validate(&self) -> Result<(), String> {
+    /// Every configuration error (empty when valid)
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
         if self.default_amount <= 0.0 {
-            return Err("default_amount must be positive".to_string());
+            errors.push("default_amount must be positive".to_string());
         }
         if self.default_amount > 10000.0 {
-            return Err("default_amount too large".to_string());
+            errors.push("default_amount too large".to_string());
         }
         if self.price_threshold < 0.0 || self.price_threshold > 1.0 {
-            return Err("price_threshold must be 0.0-1.0".to_string());
+            errors.push("price_threshold must be 0.0-1.0".to_string());
         }
         if self.take_profit_amount <= self.stop_loss_amount {
-            return Err("take_profit must be greater than stop_loss".to_string());
+            errors.push("take_profit must be greater than stop_loss".to_string());
         }
-        Ok(())
+        if self.order_book_depth_required == 0 {
+            errors.push("order_book_depth_required must be positive".to_string());
+        }
+        if !(self.kelly_fraction_cap > 0.0 && self.kelly_fraction_cap <= 1.0) {
+            errors.push("kelly_fraction_cap must be in (0, 1]".to_string());
+        }
+        errors
     }
 }
 
@@ -64,6 +214,10 @@ pub struct RiskConfig {
     /// Maximum size per position in USDC
     pub max_position_size: f64,
 
+    /// Maximum worst-case loss of a single trade in USDC
+    #[serde(default = "default_max_loss_per_trade")]
+    pub max_loss_per_trade: f64,
+
     /// Maximum concurrent open positions
     pub max_open_positions: usize,
 
@@ -75,24 +229,95 @@ pub struct RiskConfig {
 
     /// Maximum consecutive errors before circuit breaker trips
     pub max_consecutive_errors: usize,
+
+    /// Risk score (0-100) above which a warning is logged before tripping
+    #[serde(default = "default_risk_score_alert_threshold")]
+    pub risk_score_alert_threshold: f64,
+
+    /// Trading fee rate in basis points (per side, 100 = 1%)
+    #[serde(default = "default_fee_rate_bps")]
+    pub fee_rate_bps: u16,
+
+    /// Anomalies per market within 5 minutes above which the market is skipped
+    #[serde(default = "default_max_anomalies_before_skip")]
+    pub max_anomalies_before_skip: u32,
+
+    /// Maximum share of total exposure in a single market (0.0-1.0)
+    #[serde(default = "default_max_concentration")]
+    pub max_concentration: f64,
+
+    /// Maximum net loss in USDC within the rolling `loss_window_secs`
+    #[serde(default = "default_max_loss_in_window")]
+    pub max_loss_in_window: f64,
+
+    /// Rolling loss window in seconds
+    #[serde(default = "default_loss_window_secs")]
+    pub loss_window_secs: u64,
+}
+
+fn default_max_loss_per_trade() -> f64 {
+    50.0
+}
+
+fn default_risk_score_alert_threshold() -> f64 {
+    70.0
+}
+
+fn default_fee_rate_bps() -> u16 {
+    100
+}
+
+fn default_max_anomalies_before_skip() -> u32 {
+    3
+}
+
+fn default_max_concentration() -> f64 {
+    0.5
+}
+
+fn default_max_loss_in_window() -> f64 {
+    50.0
+}
+
+fn default_loss_window_secs() -> u64 {
+    3600
 }
 
 impl RiskConfig {
-    /// Validate configuration
-    pub fn validate(&self) -> Result<(), String> {
+    /// Every configuration error (empty when valid)
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
         if self.max_daily_loss <= 0.0 {
-            return Err("max_daily_loss must be positive".to_string());
+            errors.push("max_daily_loss must be positive".to_string());
         }
         if self.max_position_size <= 0.0 {
-            return Err("max_position_size must be positive".to_string());
+            errors.push("max_position_size must be positive".to_string());
+        }
+        if self.max_loss_per_trade <= 0.0 {
+            errors.push("max_loss_per_trade must be positive".to_string());
         }
         if self.max_open_positions == 0 {
-            return Err("max_open_positions must be positive".to_string());
+            errors.push("max_open_positions must be positive".to_string());
         }
         if self.max_open_positions > 100 {
-            return Err("max_open_positions too large".to_string());
+            errors.push("max_open_positions too large".to_string());
         }
-        Ok(())
+        if !(0.0..=100.0).contains(&self.risk_score_alert_threshold) {
+            errors.push("risk_score_alert_threshold must be 0-100".to_string());
+        }
+        if self.fee_rate_bps > 200 {
+            errors.push("fee_rate_bps must be <= 200".to_string());
+        }
+        if !(self.max_concentration > 0.0 && self.max_concentration <= 1.0) {
+            errors.push("max_concentration must be in (0, 1]".to_string());
+        }
+        if self.max_loss_in_window <= 0.0 {
+            errors.push("max_loss_in_window must be positive".to_string());
+        }
+        if self.loss_window_secs == 0 {
+            errors.push("loss_window_secs must be positive".to_string());
+        }
+        errors
     }
 }
 
@@ -105,6 +330,10 @@ pub struct PolymarketConfig {
     /// Gamma API URL
     pub gamma_api_url: String,
 
+    /// Market data WebSocket URL
+    #[serde(default = "default_websocket_url")]
+    pub websocket_url: String,
+
     /// Chain ID
     pub chain_id: u64,
 
@@ -112,6 +341,51 @@ pub struct PolymarketConfig {
     pub rpc_url: String,
 }
 
+fn default_websocket_url() -> String {
+    "wss://ws-subscriptions-clob.polymarket.com/ws/market".to_string()
+}
+
+impl PolymarketConfig {
+    /// Every configuration error (empty when valid)
+    pub fn validate(&self) -> Vec<String> {
+        [
+            check_url_scheme("websocket_url", &self.websocket_url, "wss").err(),
+            check_url_scheme("clob_api_url", &self.clob_api_url, "https").err(),
+            check_chain_id("polymarket.chain_id", self.chain_id),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Default for PolymarketConfig {
+    fn default() -> Self {
+        Self {
+            clob_api_url: "https://clob.polymarket.com".to_string(),
+            gamma_api_url: "https://gamma-api.polymarket.com".to_string(),
+            websocket_url: default_websocket_url(),
+            chain_id: 137,
+            rpc_url: "https://polygon-rpc.com".to_string(),
+        }
+    }
+}
+
+/// Ensure `value` parses as a URL with the given scheme
+fn check_url_scheme(name: &str, value: &str, scheme: &str) -> Result<(), String> {
+    let url = url::Url::parse(value).map_err(|e| format!("{} is not a valid URL: {}", name, e))?;
+    if url.scheme() != scheme {
+        return Err(format!("{} must use {}:// (got {})", name, scheme, value));
+    }
+    Ok(())
+}
+
+/// Error unless `chain_id` is one of `SUPPORTED_CHAIN_IDS`
+fn check_chain_id(name: &str, chain_id: u64) -> Option<String> {
+    (!SUPPORTED_CHAIN_IDS.contains(&chain_id))
+        .then(|| format!("{} must be 137 or 80001 (got {})", name, chain_id))
+}
+
 /// Logging configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
@@ -138,8 +412,109 @@ pub struct FeatureConfig {
     pub dry_run: bool,
 }
 
+/// Destination for operator alerts (sent with the `alerts` feature)
+///
+/// Tagged by `type`, e.g. `{ type = "slack", webhook_url = "https://..." }`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertChannel {
+    /// Slack incoming webhook
+    Slack {
+        /// Incoming webhook URL
+        webhook_url: String,
+    },
+    /// PagerDuty Events API v2
+    #[serde(rename = "pagerduty")]
+    PagerDuty {
+        /// Integration (routing) key
+        integration_key: String,
+    },
+    /// Generic JSON webhook
+    Webhook {
+        /// Endpoint receiving a POST per alert
+        url: String,
+        /// Extra request headers (e.g., authentication)
+        #[serde(default)]
+        headers: HashMap<String, String>,
+    },
+}
+
+impl AlertChannel {
+    /// Channel type name for logging
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AlertChannel::Slack { .. } => "slack",
+            AlertChannel::PagerDuty { .. } => "pagerduty",
+            AlertChannel::Webhook { .. } => "webhook",
+        }
+    }
+
+    /// Validate channel settings
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            AlertChannel::Slack { webhook_url } => {
+                check_url_scheme("Slack webhook_url", webhook_url, "https")
+            }
+            AlertChannel::PagerDuty { integration_key } if integration_key.is_empty() => {
+                Err("PagerDuty integration_key must not be empty".to_string())
+            }
+            AlertChannel::PagerDuty { .. } => Ok(()),
+            AlertChannel::Webhook { url, .. } => {
+                let parsed = url::Url::parse(url)
+                    .map_err(|e| format!("Alert webhook url is not a valid URL: {}", e))?;
+                match parsed.scheme() {
+                    "http" | "https" => Ok(()),
+                    _ => Err(format!("Alert webhook url must use http(s):// (got {})", url)),
+                }
+            }
+        }
+    }
+}
+
+/// Result of [`BotConfig::full_validate`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ConfigReport {
+    /// Settings the bot cannot start with
+    pub errors: Vec<String>,
+    /// Settings that are allowed but probably unintended
+    pub warnings: Vec<String>,
+}
+
+impl ConfigReport {
+    /// Whether there are no errors (warnings are allowed)
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Log every warning and error
+    pub fn log(&self) {
+        for warning in &self.warnings {
+            tracing::warn!(warning = %warning, "Configuration warning");
+        }
+        for error in &self.errors {
+            tracing::error!(error = %error, "Configuration error");
+        }
+    }
+
+    /// Log the report, then fail if there are errors
+    pub fn check(&self) -> Result<(), String> {
+        self.log();
+        if self.is_ok() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Invalid configuration ({} errors): {}",
+                self.errors.len(),
+                self.errors.join("; ")
+            ))
+        }
+    }
+}
+
 /// Complete bot configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `Debug` output is redacted (see [`BotConfig::to_redacted_string`]).
+#[derive(Clone, Serialize, Deserialize)]
 pub struct BotConfig {
     /// Wallet configuration
     pub wallet: WalletConfig,
@@ -153,14 +528,61 @@ pub struct BotConfig {
     pub logging: LoggingConfig,
     /// Feature flags
     pub features: FeatureConfig,
+    /// Deployment environment
+    #[serde(default)]
+    pub environment: Environment,
+    /// Derive CLOB API credentials from the wallet at startup when no API key
+    /// is configured (see `PolymarketRestClient::get_clob_auth`)
+    #[serde(default)]
+    pub auto_auth: bool,
+    /// Where critical events (e.g., a tripped circuit breaker) are sent
+    #[serde(default)]
+    pub alert_channels: Vec<AlertChannel>,
+    /// Database directory where open positions are persisted across restarts
+    #[serde(default = "default_position_db_path")]
+    pub position_db_path: String,
+}
+
+fn default_position_db_path() -> String {
+    "positions.db".to_string()
 }
 
 impl BotConfig {
-    /// Validate entire configuration
-    pub fn validate(&self) -> Result<(), String> {
-        self.trading.validate()?;
-        self.risk.validate()?;
-        Ok(())
+    /// Every configuration error across all sections (empty when valid)
+    pub fn validate(&self) -> Vec<String> {
+        self.full_validate().errors
+    }
+
+    /// Run every section's validation, collecting errors and warnings
+    ///
+    /// Warnings flag settings that are valid but probably unintended.
+    pub fn full_validate(&self) -> ConfigReport {
+        let mut errors = self.wallet.validate();
+        errors.extend(self.trading.validate());
+        errors.extend(self.risk.validate());
+        errors.extend(self.polymarket.validate());
+        errors.extend(self.alert_channels.iter().filter_map(|c| c.validate().err()));
+        if self.wallet.chain_id != self.polymarket.chain_id {
+            errors.push(format!(
+                "wallet.chain_id ({}) does not match polymarket.chain_id ({})",
+                self.wallet.chain_id, self.polymarket.chain_id
+            ));
+        }
+
+        let mut warnings = Vec::new();
+        if self.wallet.address.is_empty() {
+            warnings.push("wallet.address is not set; the signer's address is used".to_string());
+        }
+        if self.environment == Environment::Production {
+            if self.alert_channels.is_empty() {
+                warnings.push("No alert_channels configured for production".to_string());
+            }
+            if self.wallet.chain_id != 137 {
+                warnings.push(format!("Production on testnet chain {}", self.wallet.chain_id));
+            }
+        }
+
+        ConfigReport { errors, warnings }
     }
 
     /// Load configuration from environment and file
@@ -170,7 +592,74 @@ impl BotConfig {
             .add_source(config::Environment::with_prefix("BOT"))
             .build()?;
 
-        settings.try_deserialize()
+        let mut config: Self = settings.try_deserialize()?;
+        let declared = std::env::var(ENVIRONMENT_VAR).ok();
+        config
+            .apply_environment(declared.as_deref())
+            .map_err(config::ConfigError::Message)?;
+
+        Ok(config)
+    }
+
+    /// Whether this config targets production
+    pub fn is_production(&self) -> bool {
+        self.environment.is_production()
+    }
+
+    /// Enforce environment rules
+    ///
+    /// `declared` is the value of `POLYMARKET_ENVIRONMENT`, which must be
+    /// `production` for a production config. Staging configs are always
+    /// pointed at the staging CLOB.
+    pub fn apply_environment(&mut self, declared: Option<&str>) -> Result<(), String> {
+        match self.environment {
+            Environment::Production => {
+                let declared = declared.map(Environment::from_str).transpose()?;
+                if declared != Some(Environment::Production) {
+                    return Err(format!(
+                        "Production config requires {}=production to be set",
+                        ENVIRONMENT_VAR
+                    ));
+                }
+
+                if self.features.dry_run {
+                    tracing::warn!("Production environment running in dry-run mode");
+                }
+            }
+            Environment::Staging => {
+                if self.polymarket.clob_api_url != STAGING_CLOB_API_URL {
+                    tracing::warn!(
+                        "Staging environment: overriding CLOB URL {} with {}",
+                        self.polymarket.clob_api_url,
+                        STAGING_CLOB_API_URL
+                    );
+                    self.polymarket.clob_api_url = STAGING_CLOB_API_URL.to_string();
+                }
+            }
+            Environment::Development => {}
+        }
+
+        Ok(())
+    }
+
+    /// Serialize to JSON with secrets redacted, for safe logging
+    ///
+    /// `private_key` and `api_key` are replaced with `"[REDACTED]"`, and
+    /// addresses only show their last 4 hex chars.
+    pub fn to_redacted_string(&self) -> String {
+        match serde_json::to_value(self) {
+            Ok(mut value) => {
+                redact_value(&mut value);
+                value.to_string()
+            }
+            Err(e) => format!("BotConfig {{ <serialization failed: {}> }}", e),
+        }
+    }
+}
+
+impl fmt::Debug for BotConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_redacted_string())
     }
 }
 
@@ -188,21 +677,26 @@ impl Default for BotConfig {
                 take_profit_amount: 0.05,
                 stop_loss_amount: 0.03,
                 cooldown_ms: 1000,
+                order_book_depth_required: 3,
+                kelly_fraction_cap: default_kelly_fraction_cap(),
+                require_atomic: false,
             },
             risk: RiskConfig {
                 max_daily_loss: 100.0,
                 max_position_size: 50.0,
+                max_loss_per_trade: 50.0,
                 max_open_positions: 5,
                 min_usdc_balance: 10.0,
                 min_matic_balance: 0.1,
                 max_consecutive_errors: 3,
+                risk_score_alert_threshold: 70.0,
+                fee_rate_bps: 100,
+                max_anomalies_before_skip: 3,
+                max_concentration: 0.5,
+                max_loss_in_window: 50.0,
+                loss_window_secs: 3600,
             },
-            polymarket: PolymarketConfig {
-                clob_api_url: "https://clob.polymarket.com".to_string(),
-                gamma_api_url: "https://gamma-api.polymarket.com".to_string(),
-                chain_id: 137,
-                rpc_url: "https://polygon-rpc.com".to_string(),
-            },
+            polymarket: PolymarketConfig::default(),
             logging: LoggingConfig {
                 level: "info".to_string(),
                 to_file: true,
@@ -213,6 +707,10 @@ impl Default for BotConfig {
                 copy_trading_enabled: false,
                 dry_run: true,
             },
+            environment: Environment::Development,
+            auto_auth: false,
+            alert_channels: Vec::new(),
+            position_db_path: default_position_db_path(),
         }
     }
 }
@@ -229,20 +727,27 @@ mod tests {
             take_profit_amount: 0.05,
             stop_loss_amount: 0.03,
             cooldown_ms: 1000,
+            order_book_depth_required: 3,
+            kelly_fraction_cap: 0.25,
+            require_atomic: false,
         };
 
-        assert!(config.validate().is_ok());
+        assert!(config.validate().is_empty());
+
+        config.kelly_fraction_cap = 0.0;
+        assert!(!config.validate().is_empty());
+        config.kelly_fraction_cap = 0.25;
 
         // Test invalid amount
         config.default_amount = -1.0;
-        assert!(config.validate().is_err());
+        assert!(!config.validate().is_empty());
 
         config.default_amount = 10.0;
 
         // Test invalid take profit
         config.take_profit_amount = 0.01;
         config.stop_loss_amount = 0.03;
-        assert!(config.validate().is_err());
+        assert!(!config.validate().is_empty());
     }
 
     #[test]
@@ -250,19 +755,159 @@ mod tests {
         let mut config = RiskConfig {
             max_daily_loss: 100.0,
             max_position_size: 50.0,
+            max_loss_per_trade: 50.0,
             max_open_positions: 5,
             min_usdc_balance: 10.0,
             min_matic_balance: 0.1,
             max_consecutive_errors: 3,
+            risk_score_alert_threshold: 70.0,
+            fee_rate_bps: 100,
+            max_anomalies_before_skip: 3,
+            max_concentration: 0.5,
+            max_loss_in_window: 50.0,
+            loss_window_secs: 3600,
         };
 
-        assert!(config.validate().is_ok());
+        assert!(config.validate().is_empty());
 
         // Test invalid max positions
         config.max_open_positions = 0;
-        assert!(config.validate().is_err());
+        assert!(!config.validate().is_empty());
 
         config.max_open_positions = 150;
-        assert!(config.validate().is_err());
+        assert!(!config.validate().is_empty());
+    }
+
+    #[test]
+    fn test_polymarket_config_validation() {
+        let mut config = PolymarketConfig::default();
+        assert!(config.validate().is_empty());
+        assert!(config.websocket_url.starts_with("wss://"));
+
+        config.websocket_url = "ws://ws-subscriptions-clob.polymarket.com/ws/market".to_string();
+        assert!(!config.validate().is_empty());
+
+        config.websocket_url = "not a url".to_string();
+        assert!(!config.validate().is_empty());
+
+        config.websocket_url = default_websocket_url();
+        config.clob_api_url = "http://clob.polymarket.com".to_string();
+        assert!(!config.validate().is_empty());
+        assert!(BotConfig::default().validate().is_empty());
+    }
+
+    #[test]
+    fn test_full_validate_reports_every_error() {
+        let report = BotConfig::default().full_validate();
+        assert!(report.is_ok());
+        assert_eq!(report.warnings.len(), 1, "Unset wallet address: {:?}", report.warnings);
+
+        let mut config = BotConfig::default();
+        config.wallet.address = "742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string();
+        config.wallet.chain_id = 1;
+        config.polymarket.clob_api_url = "http://clob.polymarket.com".to_string();
+        config.risk.max_open_positions = 0;
+        config.environment = Environment::Production;
+
+        let report = config.full_validate();
+        assert_eq!(report.errors.len(), 5, "{:?}", report.errors);
+        assert!(report.errors.iter().any(|e| e.starts_with("wallet.address")));
+        assert!(report.errors.iter().any(|e| e.starts_with("wallet.chain_id must be")));
+        assert!(report.errors.iter().any(|e| e.contains("does not match")));
+        assert!(report.errors.iter().any(|e| e.starts_with("clob_api_url")));
+        assert!(report.errors.iter().any(|e| e.starts_with("max_open_positions")));
+        assert_eq!(report.warnings.len(), 2, "{:?}", report.warnings);
+        assert!(report.check().unwrap_err().contains("5 errors"));
+        assert_eq!(config.validate(), report.errors);
+
+        // Mumbai is supported on both sides
+        config = BotConfig::default();
+        config.wallet.chain_id = 80001;
+        config.polymarket.chain_id = 80001;
+        assert!(config.full_validate().check().is_ok());
+    }
+
+    #[test]
+    fn test_redacted_config_hides_secrets() {
+        let private_key = "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+        let mut config = BotConfig::default();
+        config.wallet.private_key = private_key.to_string();
+        config.wallet.address = "0x742d35Cc6634C0532925a3b844Bc454e4438f44e".to_string();
+
+        let redacted = config.to_redacted_string();
+        assert!(redacted.contains(r#""private_key":"[REDACTED]""#));
+        assert!(redacted.contains(r#""address":"0x...f44e""#));
+        assert!(redacted.contains(r#""chain_id":137"#));
+
+        for output in [redacted, format!("{:?}", config), format!("{:#?}", config.wallet)] {
+            assert!(!output.contains(private_key), "Private key leaked: {}", output);
+            assert!(!output.contains("742d35Cc"), "Address leaked: {}", output);
+        }
+    }
+
+    #[test]
+    fn test_apply_environment() {
+        let mut config = BotConfig::default();
+        assert!(config.apply_environment(None).is_ok());
+        assert!(!config.is_production());
+
+        // Production must be confirmed explicitly
+        config.environment = Environment::Production;
+        assert!(config.apply_environment(None).is_err());
+        assert!(config.apply_environment(Some("staging")).is_err());
+        assert!(config.apply_environment(Some("Production")).is_ok());
+        assert!(config.is_production());
+
+        // Staging always targets the staging CLOB
+        config.environment = Environment::Staging;
+        assert!(config.apply_environment(None).is_ok());
+        assert_eq!(config.polymarket.clob_api_url, STAGING_CLOB_API_URL);
+
+        assert_eq!("staging".parse::<Environment>(), Ok(Environment::Staging));
+        assert!("prod".parse::<Environment>().is_err());
+    }
+
+    #[test]
+    fn test_redact_value_handles_nested_api_key() {
+        let mut value = serde_json::json!({
+            "clob": { "api_key": "secret", "urls": [{ "api_key": "secret" }] },
+            "address": "0x12",
+        });
+
+        redact_value(&mut value);
+
+        assert!(!value.to_string().contains("secret"));
+        assert_eq!(value["address"], REDACTED);
+    }
+
+    #[test]
+    fn test_alert_channels() {
+        let channels: Vec<AlertChannel> = serde_json::from_value(serde_json::json!([
+            { "type": "slack", "webhook_url": "https://hooks.slack.com/services/T/B/X" },
+            { "type": "pagerduty", "integration_key": "pd-key" },
+            { "type": "webhook", "url": "http://alerts.internal/hook" },
+        ]))
+        .unwrap();
+        assert_eq!(channels[1], AlertChannel::PagerDuty { integration_key: "pd-key".to_string() });
+        assert_eq!(channels[2].kind(), "webhook");
+
+        let mut config = BotConfig { alert_channels: channels, ..BotConfig::default() };
+        assert!(config.validate().is_empty());
+
+        // Alert secrets are never logged
+        let redacted = config.to_redacted_string();
+        assert!(!redacted.contains("hooks.slack.com") && !redacted.contains("pd-key"));
+
+        config.alert_channels =
+            vec![AlertChannel::Slack { webhook_url: "http://hooks.slack.com/x".to_string() }];
+        assert!(!config.validate().is_empty());
+        config.alert_channels =
+            vec![AlertChannel::PagerDuty { integration_key: String::new() }];
+        assert!(!config.validate().is_empty());
+        config.alert_channels = vec![AlertChannel::Webhook {
+            url: "ftp://alerts.internal".to_string(),
+            headers: HashMap::new(),
+        }];
+        assert!(!config.validate().is_empty());
     }
 }
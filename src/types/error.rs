@@ -0,0 +1,70 @@
+//! Typed errors for order execution and risk checks
+//!
+//! The CLOB client, order signer, executor and circuit breaker return
+//! [`HftError`] so callers can react to the kind of failure (back off on a
+//! rate limit, skip a trade over a risk limit) instead of matching on
+//! message text. It converts into `anyhow::Error` with `?` where the kind
+//! does not matter.
+
+use thiserror::Error;
+
+/// Error from order execution, signing or risk checks
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum HftError {
+    /// Request failed to send or the API returned an error status
+    #[error("Network error: {0}")]
+    Network(String),
+
+    /// Invalid key or signing failure
+    #[error("Signing error: {0}")]
+    Signing(String),
+
+    /// Circuit breaker tripped or a position/concentration limit was hit
+    #[error("Risk limit exceeded: {0}")]
+    RiskLimitExceeded(String),
+
+    /// Malformed response, address or persisted state
+    #[error("Parse error: {0}")]
+    ParseError(String),
+
+    /// API returned 429
+    #[error("Rate limit exceeded (429)")]
+    RateLimitExceeded,
+
+    /// Local nonce (first) does not match the chain or API nonce (second)
+    #[error("Nonce mismatch: local {0}, expected {1}")]
+    NonceMismatch(u64, u64),
+
+    /// Exchange contract rejected the order
+    #[error("Contract error: {0}")]
+    ContractError(String),
+
+    /// Order or batch fails Polymarket's constraints before submission
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+
+    /// Reading or writing persisted state failed
+    #[error("I/O error: {0}")]
+    Io(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_and_anyhow_conversion() {
+        assert_eq!(HftError::RateLimitExceeded.to_string(), "Rate limit exceeded (429)");
+        assert_eq!(
+            HftError::NonceMismatch(12, 10).to_string(),
+            "Nonce mismatch: local 12, expected 10"
+        );
+
+        // Kind survives the trip through anyhow
+        let error: anyhow::Error = HftError::RiskLimitExceeded("max positions".to_string()).into();
+        assert!(matches!(
+            error.downcast_ref::<HftError>(),
+            Some(HftError::RiskLimitExceeded(_))
+        ));
+    }
+}
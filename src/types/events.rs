@@ -0,0 +1,164 @@
+//! Crate-wide trading events
+//!
+//! Components that observe something worth reacting to (a book update, a
+//! detected opportunity, an order, a fill, a position change, the breaker
+//! tripping) publish a [`TradingEvent`] on a shared [`EventBus`]. Consumers
+//! such as loggers, metrics or the journal subscribe without the publishers
+//! knowing about them.
+//!
+//! # Example
+//! ```
+//! use polymarket_hft_bot::types::{EventBus, TradingEvent};
+//!
+//! let bus = EventBus::default();
+//! let mut events = bus.subscribe();
+//!
+//! bus.publish(TradingEvent::CircuitBreakerStateChanged { tripped: true });
+//! assert!(matches!(
+//!     events.try_recv(),
+//!     Ok(TradingEvent::CircuitBreakerStateChanged { tripped: true })
+//! ));
+//! ```
+
+use tokio::sync::broadcast;
+
+use super::{ArbitrageOpportunity, Fill, MarketId, OrderBook, OrderSide, Position, TokenId};
+
+/// Default number of events buffered per subscriber
+///
+/// Subscribers that fall further behind skip the oldest events
+/// (`RecvError::Lagged`).
+pub const DEFAULT_EVENT_BUS_CAPACITY: usize = 1024;
+
+/// Observable event anywhere in the trading pipeline
+#[derive(Debug, Clone)]
+pub enum TradingEvent {
+    /// Orderbook snapshot or delta received from the market data feed
+    MarketUpdate {
+        /// Market of the book
+        market_id: MarketId,
+        /// Book contents (levels of a delta only, for `is_snapshot == false`)
+        order_book: OrderBook,
+        /// Whether this is a full snapshot
+        is_snapshot: bool,
+    },
+
+    /// Arbitrage opportunity found by a detector
+    OpportunityDetected(ArbitrageOpportunity),
+
+    /// Order accepted by the CLOB
+    OrderSubmitted {
+        /// Order hash returned by the CLOB
+        order_hash: String,
+        /// Token ordered
+        token_id: TokenId,
+        /// Buy or sell
+        side: OrderSide,
+        /// Limit price
+        price: f64,
+        /// Size in shares
+        size: f64,
+    },
+
+    /// Fill of a submitted order
+    FillReceived(Fill),
+
+    /// New position tracked
+    PositionOpened(Position),
+
+    /// Position removed from tracking
+    PositionClosed(Position),
+
+    /// Circuit breaker tripped (`true`) or reset (`false`)
+    CircuitBreakerStateChanged {
+        /// Whether trading is halted
+        tripped: bool,
+    },
+}
+
+/// Multi-producer, multi-consumer event channel
+///
+/// Cheap to clone; all clones publish to the same subscribers. Publishing
+/// never blocks and is a no-op without subscribers.
+#[derive(Debug, Clone)]
+pub struct EventBus<T> {
+    tx: broadcast::Sender<T>,
+}
+
+impl<T: Clone> EventBus<T> {
+    /// Create bus buffering up to `capacity` events per subscriber
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            tx: broadcast::channel(capacity).0,
+        }
+    }
+
+    /// Publish an event to all current subscribers
+    ///
+    /// Returns the number of subscribers that will receive it.
+    pub fn publish(&self, event: T) -> usize {
+        self.tx.send(event).unwrap_or(0)
+    }
+
+    /// Receive every event published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<T> {
+        self.tx.subscribe()
+    }
+
+    /// Number of active subscribers
+    pub fn subscriber_count(&self) -> usize {
+        self.tx.receiver_count()
+    }
+}
+
+impl<T: Clone> Default for EventBus<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_BUS_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publish_to_all_subscribers() {
+        let bus: EventBus<TradingEvent> = EventBus::new(8);
+        assert_eq!(bus.publish(TradingEvent::CircuitBreakerStateChanged { tripped: true }), 0);
+
+        let mut first = bus.subscribe();
+        let mut second = bus.clone().subscribe();
+        assert_eq!(bus.subscriber_count(), 2);
+
+        let fill = Fill {
+            id: "f1".to_string(),
+            order_hash: "0xabc".to_string(),
+            price: 0.45,
+            size: 10.0,
+            timestamp: 1,
+        };
+        assert_eq!(bus.publish(TradingEvent::FillReceived(fill.clone())), 2);
+
+        for rx in [&mut first, &mut second] {
+            match rx.try_recv() {
+                Ok(TradingEvent::FillReceived(received)) => assert_eq!(received, fill),
+                other => panic!("Unexpected event: {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_slow_subscriber_lags() {
+        let bus = EventBus::new(2);
+        let mut rx = bus.subscribe();
+        for tripped in [true, false, true] {
+            bus.publish(TradingEvent::CircuitBreakerStateChanged { tripped });
+        }
+
+        assert!(matches!(rx.try_recv(), Err(broadcast::error::TryRecvError::Lagged(1))));
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(TradingEvent::CircuitBreakerStateChanged { tripped: false })
+        ));
+    }
+}
@@ -2,19 +2,133 @@
 //!
 //! Defines all market-related data structures including markets, order books, and outcomes.
 
+use anyhow::Result;
+use async_trait::async_trait;
+use ethers::types::H256;
+use fxhash::FxHasher;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::utils::fixed_point::{deserialize_decimal_f64, FixedPrice};
+use super::OrderSide;
+
+/// Tolerance when comparing consumed depth to a target size
+const SIZE_EPSILON: f64 = 1e-9;
 
 /// Unique identifier for a market
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub struct MarketId(pub String);
+///
+/// Normalized on construction (see [`MarketId::new`]), so the same market
+/// always compares and hashes equal. Serialized as its string form.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct MarketId(MarketIdKind);
 
 impl fmt::Display for MarketId {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        match &self.0 {
+            MarketIdKind::EventSlug(slug) => f.write_str(slug),
+            MarketIdKind::ConditionId(hash) => write!(f, "0x{}", hex::encode(hash.as_bytes())),
+        }
+    }
+}
+
+/// Kind of Polymarket market identifier
+///
+/// Gamma identifies markets by event ID/slug, the CLOB by hex condition ID.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MarketIdKind {
+    /// Gamma event ID or slug (e.g., "btc-updown-15m-1700000000")
+    EventSlug(String),
+    /// CLOB condition ID (32 bytes)
+    ConditionId(H256),
+}
+
+/// Looks up the other kind of a market ID (e.g., `PolymarketRestClient`)
+#[async_trait]
+pub trait MarketIdResolver: Send + Sync {
+    /// Condition ID for an event slug, or the event slug for a condition ID
+    async fn resolve_market_id(&self, id: &MarketId) -> Result<MarketId>;
+}
+
+impl MarketId {
+    /// Market ID from any Polymarket identifier
+    ///
+    /// `0x`-prefixed 32-byte hex IDs (either case) become condition IDs and
+    /// display in lowercase; anything else is an event slug. Surrounding
+    /// whitespace is trimmed.
+    pub fn new(id: impl Into<String>) -> Self {
+        let id = id.into();
+        let trimmed = id.trim();
+        match parse_condition_id(trimmed) {
+            Some(hash) => Self(MarketIdKind::ConditionId(hash)),
+            None if trimmed.len() == id.len() => Self(MarketIdKind::EventSlug(id)),
+            None => Self(MarketIdKind::EventSlug(trimmed.to_string())),
+        }
+    }
+
+    /// Market ID from a CLOB condition ID (see [`MarketId::new`])
+    pub fn from_condition_id(condition_id: &str) -> Self {
+        Self::new(condition_id)
+    }
+
+    /// Market ID from a Gamma event ID or slug (see [`MarketId::new`])
+    pub fn from_event_slug(slug: &str) -> Self {
+        Self::new(slug)
+    }
+
+    /// Which kind of identifier this is
+    pub fn kind(&self) -> &MarketIdKind {
+        &self.0
+    }
+
+    /// Condition ID, if this is one
+    pub fn to_condition_id(&self) -> Option<H256> {
+        match self.0 {
+            MarketIdKind::ConditionId(hash) => Some(hash),
+            MarketIdKind::EventSlug(_) => None,
+        }
+    }
+
+    /// Condition ID form of this market, looking event slugs up with `resolver`
+    pub async fn normalize<R: MarketIdResolver + ?Sized>(&self, resolver: &R) -> Result<MarketId> {
+        match self.0 {
+            MarketIdKind::ConditionId(_) => Ok(self.clone()),
+            MarketIdKind::EventSlug(_) => resolver.resolve_market_id(self).await,
+        }
+    }
+}
+
+impl From<String> for MarketId {
+    fn from(id: String) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<&str> for MarketId {
+    fn from(id: &str) -> Self {
+        Self::new(id)
+    }
+}
+
+impl From<MarketId> for String {
+    fn from(id: MarketId) -> Self {
+        id.to_string()
     }
 }
 
+/// Parse a `0x`-prefixed 32-byte hex condition ID
+fn parse_condition_id(id: &str) -> Option<H256> {
+    let hex_part = id.strip_prefix("0x").or_else(|| id.strip_prefix("0X"))?;
+    if hex_part.len() != 64 {
+        return None;
+    }
+
+    let bytes = hex::decode(hex_part).ok()?;
+    Some(H256::from_slice(&bytes))
+}
+
 /// Unique identifier for a token (used in CLOB trading)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TokenId(pub String);
@@ -81,12 +195,17 @@ pub struct Market {
 }
 
 /// Order book entry (bid or ask)
+///
+/// Price and size deserialize from Polymarket's decimal strings
+/// (`"0.750000"`) as well as JSON numbers.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct OrderBookEntry {
     /// Price (0.0-1.0)
+    #[serde(deserialize_with = "deserialize_decimal_f64")]
     pub price: f64,
 
     /// Size in shares
+    #[serde(deserialize_with = "deserialize_decimal_f64")]
     pub size: f64,
 
     /// Timestamp of entry (Unix timestamp in milliseconds)
@@ -121,16 +240,305 @@ impl OrderBook {
         self.asks.first()
     }
 
+    /// Best bid and best ask from the same snapshot (None if either side is empty)
+    pub fn bbo(&self) -> Option<(OrderBookEntry, OrderBookEntry)> {
+        Some((*self.bids.first()?, *self.asks.first()?))
+    }
+
     /// Check if order book has sufficient depth
     pub fn has_depth(&self) -> bool {
         !self.bids.is_empty() && !self.asks.is_empty()
     }
+
+    /// Whether both sides have no entries
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    /// Number of bid price levels
+    pub fn bid_levels(&self) -> usize {
+        self.bids.len()
+    }
+
+    /// Number of ask price levels
+    pub fn ask_levels(&self) -> usize {
+        self.asks.len()
+    }
+
+    /// Number of price levels on both sides
+    pub fn price_levels(&self) -> usize {
+        self.bid_levels() + self.ask_levels()
+    }
+
+    /// Total size across all bid levels
+    pub fn bid_volume(&self) -> f64 {
+        self.bids.iter().fold(0.0, |volume, entry| volume + entry.size)
+    }
+
+    /// Total size across all ask levels
+    pub fn ask_volume(&self) -> f64 {
+        self.asks.iter().fold(0.0, |volume, entry| volume + entry.size)
+    }
+
+    /// Total size on both sides
+    pub fn total_volume(&self) -> f64 {
+        self.bid_volume() + self.ask_volume()
+    }
+
+    /// Milliseconds since the last update, as of `now` (Unix milliseconds)
+    ///
+    /// Zero for timestamps after `now` (clock skew).
+    pub fn age_ms(&self, now: u64) -> u64 {
+        now.saturating_sub(self.timestamp.max(0) as u64)
+    }
+
+    /// Check that both sides have at least `depth` price levels
+    pub fn has_minimum_depth(&self, depth: usize) -> bool {
+        self.bids.len() >= depth && self.asks.len() >= depth
+    }
+
+    /// Fast fingerprint of the top of book for change detection
+    ///
+    /// Hashes best bid/ask price and size (fixed-point) plus the timestamp.
+    /// Equal hashes mean detection would see the same inputs, so callers
+    /// scanning faster than the book updates can skip redundant work.
+    pub fn snapshot_hash(&self) -> u64 {
+        let raw = |entry: Option<&OrderBookEntry>| {
+            entry.map(|e| (FixedPrice::from_f64(e.price).raw(), FixedPrice::from_f64(e.size).raw()))
+        };
+
+        let mut hasher = FxHasher::default();
+        raw(self.best_bid()).hash(&mut hasher);
+        raw(self.best_ask()).hash(&mut hasher);
+        self.timestamp.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Merge two books for the same token (e.g. snapshots from two connections)
+    ///
+    /// Price levels present in both books take the size from whichever entry
+    /// is more recent (entries without a timestamp inherit their book's).
+    /// Levels present in only one book are included as-is. The result is
+    /// re-sorted and stamped with the newer of the two book timestamps.
+    pub fn merge(&self, other: &OrderBook) -> OrderBook {
+        let (a_ts, b_ts) = (self.timestamp, other.timestamp);
+
+        OrderBook {
+            token_id: self.token_id.clone(),
+            bids: Self::merge_levels(&self.bids, a_ts, &other.bids, b_ts, true),
+            asks: Self::merge_levels(&self.asks, a_ts, &other.asks, b_ts, false),
+            timestamp: a_ts.max(b_ts),
+        }
+    }
+
+    /// Volume-weighted average price of filling `target_size` against the book
+    ///
+    /// Buys walk the asks and sells the bids, best level first. Returns
+    /// `(avg_price, slippage_fraction)`, the slippage being the distance of
+    /// the average from the best price relative to it. `None` if the side is
+    /// shallower than `target_size` (or `target_size` isn't positive).
+    pub fn vwap(&self, side: OrderSide, target_size: f64) -> Option<(f64, f64)> {
+        let best = self.levels_taken(side).first()?.price;
+        let (filled, cost) = self.walk(side, target_size);
+        if target_size <= 0.0 || filled < target_size - SIZE_EPSILON {
+            return None;
+        }
+
+        let avg_price = cost / filled;
+        Some((avg_price, (avg_price - best).abs() / best))
+    }
+
+    /// Average price and total cost of filling `size` against the book
+    ///
+    /// Covers only the available depth if the side is shallower than `size`;
+    /// `(0.0, 0.0)` for an empty side.
+    pub fn compute_market_impact(&self, side: OrderSide, size: f64) -> (f64, f64) {
+        let (filled, cost) = self.walk(side, size);
+        if filled > 0.0 {
+            (cost / filled, cost)
+        } else {
+            (0.0, 0.0)
+        }
+    }
+
+    /// Largest size whose VWAP stays within `max_slippage` of the best price
+    ///
+    /// 0.0 slippage allows the best level only; 0.0 for an empty side.
+    pub fn max_size_within_slippage(&self, side: OrderSide, max_slippage: f64) -> f64 {
+        let levels = self.levels_taken(side);
+        let Some(best) = levels.first().map(|level| level.price) else {
+            return 0.0;
+        };
+
+        // Worst average price allowed
+        let limit = match side {
+            OrderSide::BUY => best * (1.0 + max_slippage),
+            OrderSide::SELL => best * (1.0 - max_slippage),
+        };
+
+        let (mut filled, mut cost) = (0.0, 0.0);
+        for level in levels {
+            let within = match side {
+                OrderSide::BUY => level.price <= limit,
+                OrderSide::SELL => level.price >= limit,
+            };
+            if !within {
+                // Take just enough of this level to bring the average to the limit
+                let partial = (limit * filled - cost) / (level.price - limit);
+                return filled + partial.clamp(0.0, level.size);
+            }
+
+            filled += level.size;
+            cost += level.price * level.size;
+        }
+
+        filled
+    }
+
+    /// Limit price needed to fill `size`: the worst level it reaches
+    ///
+    /// `None` if the side is shallower than `size`.
+    pub fn limit_price(&self, side: OrderSide, size: f64) -> Option<f64> {
+        let mut filled = 0.0;
+        self.levels_taken(side)
+            .iter()
+            .find(|level| {
+                filled += level.size;
+                filled >= size - SIZE_EPSILON
+            })
+            .map(|level| level.price)
+    }
+
+    /// Levels an order on `side` takes: asks for buys, bids for sells
+    fn levels_taken(&self, side: OrderSide) -> &[OrderBookEntry] {
+        match side {
+            OrderSide::BUY => &self.asks,
+            OrderSide::SELL => &self.bids,
+        }
+    }
+
+    /// Size filled and its cost when taking up to `size` from the book
+    fn walk(&self, side: OrderSide, size: f64) -> (f64, f64) {
+        let (mut filled, mut cost) = (0.0, 0.0);
+        for level in self.levels_taken(side) {
+            let take = level.size.min(size - filled);
+            if take <= 0.0 {
+                break;
+            }
+            filled += take;
+            cost += level.price * take;
+        }
+        (filled, cost)
+    }
+
+    /// Apply an incremental price-level change (e.g. a `price_change` event)
+    ///
+    /// `BUY` updates bids, `SELL` asks. A positive size sets the level at
+    /// `price` (inserting it if new); zero removes it. The side is re-sorted.
+    pub fn apply_delta(&mut self, side: OrderSide, price: f64, size: f64) {
+        let levels = match side {
+            OrderSide::BUY => &mut self.bids,
+            OrderSide::SELL => &mut self.asks,
+        };
+
+        // Match by fixed-point price to avoid f64 equality issues
+        let key = FixedPrice::from_f64(price).raw();
+        levels.retain(|entry| FixedPrice::from_f64(entry.price).raw() != key);
+
+        if size > 0.0 {
+            levels.push(OrderBookEntry { price, size, timestamp: None });
+            match side {
+                OrderSide::BUY => levels.sort_by(|x, y| y.price.total_cmp(&x.price)),
+                OrderSide::SELL => levels.sort_by(|x, y| x.price.total_cmp(&y.price)),
+            }
+        }
+    }
+
+    /// Check that every entry timestamp is within `max_age_ms` of the book timestamp
+    ///
+    /// Entries without a timestamp are considered coherent.
+    pub fn timestamp_coherent(&self, max_age_ms: u64) -> bool {
+        self.bids
+            .iter()
+            .chain(self.asks.iter())
+            .filter_map(|entry| entry.timestamp)
+            .all(|ts| ts.abs_diff(self.timestamp) <= max_age_ms)
+    }
+
+    /// Merge one side of two books, keyed by fixed-point price
+    fn merge_levels(
+        a: &[OrderBookEntry],
+        a_timestamp: i64,
+        b: &[OrderBookEntry],
+        b_timestamp: i64,
+        descending: bool,
+    ) -> Vec<OrderBookEntry> {
+        // Key by fixed-point price to avoid f64 equality issues
+        let mut levels: HashMap<u64, (OrderBookEntry, i64)> =
+            HashMap::with_capacity(a.len() + b.len());
+
+        let sides = a
+            .iter()
+            .map(|entry| (entry, a_timestamp))
+            .chain(b.iter().map(|entry| (entry, b_timestamp)));
+
+        for (entry, book_timestamp) in sides {
+            let key = FixedPrice::from_f64(entry.price).raw();
+            let entry_timestamp = entry.timestamp.unwrap_or(book_timestamp);
+
+            levels
+                .entry(key)
+                .and_modify(|(existing, existing_timestamp)| {
+                    if entry_timestamp > *existing_timestamp {
+                        *existing = *entry;
+                        *existing_timestamp = entry_timestamp;
+                    }
+                })
+                .or_insert((*entry, entry_timestamp));
+        }
+
+        let mut merged: Vec<OrderBookEntry> =
+            levels.into_values().map(|(entry, _)| entry).collect();
+
+        if descending {
+            merged.sort_by(|x, y| y.price.total_cmp(&x.price));
+        } else {
+            merged.sort_by(|x, y| x.price.total_cmp(&y.price));
+        }
+
+        merged
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_market_id_kinds() {
+        let hex = "ABCDEF0000000000000000000000000000000000000000000000000000000001";
+        let condition = MarketId::from_condition_id(&format!("0x{}", hex));
+
+        assert_eq!(condition.to_string(), format!("0x{}", hex.to_lowercase()));
+        assert!(matches!(condition.kind(), MarketIdKind::ConditionId(_)));
+        assert_eq!(condition.to_condition_id().unwrap().as_bytes()[31], 1);
+
+        // Every constructor normalizes, so case and whitespace do not matter
+        assert_eq!(MarketId::new(format!(" 0X{} ", hex.to_lowercase())), condition);
+        let json = serde_json::to_string(&condition).unwrap();
+        assert_eq!(json, format!("\"0x{}\"", hex.to_lowercase()));
+        assert_eq!(serde_json::from_str::<MarketId>(&json.to_uppercase()).unwrap(), condition);
+
+        let slug = MarketId::from_event_slug("btc-updown-15m-1700000000");
+        assert_eq!(slug.kind(), &MarketIdKind::EventSlug("btc-updown-15m-1700000000".to_string()));
+        assert_eq!(MarketId::from("btc-updown-15m-1700000000\n"), slug);
+        assert!(slug.to_condition_id().is_none());
+
+        // Wrong length or missing prefix is not a condition ID
+        assert!(MarketId::from_condition_id("0xabc").to_condition_id().is_none());
+        assert!(MarketId::new(hex).to_condition_id().is_none());
+    }
+
     #[test]
     fn test_order_book_best_bid() {
         let order_book = OrderBook {
@@ -146,6 +554,32 @@ mod tests {
         assert_eq!(order_book.best_bid().unwrap().price, 0.75);
     }
 
+    #[test]
+    fn test_order_book_entry_decimal_strings() {
+        let entry: OrderBookEntry =
+            serde_json::from_str(r#"{"price":"0.750000","size":"12.5"}"#).unwrap();
+        assert_eq!((entry.price, entry.size, entry.timestamp), (0.75, 12.5, None));
+
+        let entry: OrderBookEntry =
+            serde_json::from_str(r#"{"price":0.75,"size":100,"timestamp":1}"#).unwrap();
+        assert_eq!((entry.price, entry.size), (0.75, 100.0));
+
+        assert!(serde_json::from_str::<OrderBookEntry>(r#"{"price":"x","size":1}"#).is_err());
+    }
+
+    #[test]
+    fn test_order_book_age_ms() {
+        let order_book = OrderBook {
+            token_id: TokenId("test".to_string()),
+            bids: vec![],
+            asks: vec![],
+            timestamp: 1_700_000_000_000,
+        };
+
+        assert_eq!(order_book.age_ms(1_700_000_000_750), 750);
+        assert_eq!(order_book.age_ms(1_699_999_999_000), 0, "Clock skew");
+    }
+
     #[test]
     fn test_order_book_best_ask() {
         let order_book = OrderBook {
@@ -161,6 +595,26 @@ mod tests {
         assert_eq!(order_book.best_ask().unwrap().price, 0.70);
     }
 
+    #[test]
+    fn test_order_book_bbo() {
+        let mut order_book = OrderBook {
+            token_id: TokenId("test".to_string()),
+            bids: vec![
+                OrderBookEntry { price: 0.48, size: 100.0, timestamp: None },
+                OrderBookEntry { price: 0.47, size: 50.0, timestamp: None },
+            ],
+            asks: vec![OrderBookEntry { price: 0.52, size: 20.0, timestamp: None }],
+            timestamp: 0,
+        };
+
+        let (bid, ask) = order_book.bbo().unwrap();
+        assert_eq!((bid.price, bid.size), (0.48, 100.0));
+        assert_eq!((ask.price, ask.size), (0.52, 20.0));
+
+        order_book.asks.clear();
+        assert!(order_book.bbo().is_none());
+    }
+
     #[test]
     fn test_order_book_has_depth() {
         let order_book = OrderBook {
@@ -171,6 +625,8 @@ mod tests {
         };
 
         assert!(order_book.has_depth());
+        assert!(order_book.has_minimum_depth(1));
+        assert!(!order_book.has_minimum_depth(2), "Single-level book");
     }
 
     #[test]
@@ -183,5 +639,169 @@ mod tests {
         };
 
         assert!(!order_book.has_depth());
+        assert!(!order_book.is_empty(), "Asks remain");
+    }
+
+    #[test]
+    fn test_order_book_aggregates() {
+        let entry = |price, size| OrderBookEntry { price, size, timestamp: None };
+        let mut order_book = OrderBook {
+            token_id: TokenId("test".to_string()),
+            bids: vec![entry(0.70, 100.0), entry(0.69, 50.0), entry(0.68, 25.5)],
+            asks: vec![entry(0.72, 80.0), entry(0.73, 20.0)],
+            timestamp: 0,
+        };
+
+        assert_eq!((order_book.bid_levels(), order_book.ask_levels()), (3, 2));
+        assert_eq!(order_book.price_levels(), 5);
+        assert_eq!(order_book.bid_volume(), 175.5);
+        assert_eq!(order_book.ask_volume(), 100.0);
+        assert_eq!(order_book.total_volume(), 275.5);
+        assert!(!order_book.is_empty());
+
+        order_book.bids.clear();
+        order_book.asks.clear();
+        assert!(order_book.is_empty());
+        assert_eq!((order_book.price_levels(), order_book.total_volume()), (0, 0.0));
+    }
+
+    #[test]
+    fn test_order_book_merge_prefers_recent_levels() {
+        let older = OrderBook {
+            token_id: TokenId("test".to_string()),
+            bids: vec![
+                OrderBookEntry { price: 0.75, size: 100.0, timestamp: Some(1000) },
+                OrderBookEntry { price: 0.70, size: 50.0, timestamp: Some(1000) },
+            ],
+            asks: vec![OrderBookEntry { price: 0.80, size: 60.0, timestamp: Some(1000) }],
+            timestamp: 1000,
+        };
+        let newer = OrderBook {
+            token_id: TokenId("test".to_string()),
+            bids: vec![
+                OrderBookEntry { price: 0.75, size: 25.0, timestamp: Some(2000) },
+                OrderBookEntry { price: 0.72, size: 10.0, timestamp: Some(2000) },
+            ],
+            asks: vec![OrderBookEntry { price: 0.78, size: 40.0, timestamp: None }],
+            timestamp: 2000,
+        };
+
+        // Merge is symmetric w.r.t. which level wins
+        for merged in [older.merge(&newer), newer.merge(&older)] {
+            let bid_prices: Vec<f64> = merged.bids.iter().map(|e| e.price).collect();
+            assert_eq!(bid_prices, vec![0.75, 0.72, 0.70]);
+            assert_eq!(merged.bids[0].size, 25.0, "More recent size should win");
+
+            let ask_prices: Vec<f64> = merged.asks.iter().map(|e| e.price).collect();
+            assert_eq!(ask_prices, vec![0.78, 0.80]);
+            assert_eq!(merged.timestamp, 2000);
+        }
+    }
+
+    #[test]
+    fn test_order_book_apply_delta() {
+        let entry = |price, size| OrderBookEntry { price, size, timestamp: Some(1000) };
+        let mut book = OrderBook {
+            token_id: TokenId("t".to_string()),
+            bids: vec![entry(0.50, 10.0), entry(0.48, 20.0)],
+            asks: vec![entry(0.52, 30.0)],
+            timestamp: 1000,
+        };
+
+        // Insert a new best bid, resize an ask, add a worse ask
+        book.apply_delta(OrderSide::BUY, 0.51, 5.0);
+        book.apply_delta(OrderSide::SELL, 0.52, 15.0);
+        book.apply_delta(OrderSide::SELL, 0.55, 40.0);
+        let levels = |side: &[OrderBookEntry]| -> Vec<(f64, f64)> {
+            side.iter().map(|e| (e.price, e.size)).collect()
+        };
+        assert_eq!(levels(&book.bids), vec![(0.51, 5.0), (0.50, 10.0), (0.48, 20.0)]);
+        assert_eq!(levels(&book.asks), vec![(0.52, 15.0), (0.55, 40.0)]);
+
+        // Zero size removes the level; unknown levels are ignored
+        book.apply_delta(OrderSide::BUY, 0.5, 0.0);
+        book.apply_delta(OrderSide::SELL, 0.60, 0.0);
+        assert_eq!(levels(&book.bids), vec![(0.51, 5.0), (0.48, 20.0)]);
+        assert_eq!(book.ask_levels(), 2);
+    }
+
+    #[test]
+    fn test_order_book_vwap() {
+        let entry = |price, size| OrderBookEntry { price, size, timestamp: None };
+        let book = OrderBook {
+            token_id: TokenId("t".to_string()),
+            bids: vec![entry(0.50, 100.0), entry(0.45, 100.0)],
+            asks: vec![entry(0.40, 50.0), entry(0.44, 150.0)],
+            timestamp: 1000,
+        };
+
+        // Within the best level: no slippage
+        assert_eq!(book.vwap(OrderSide::SELL, 80.0), Some((0.50, 0.0)));
+
+        // 50 @ 0.40 + 50 @ 0.44 = 0.42 average, 5% above the best ask
+        let (avg, slippage) = book.vwap(OrderSide::BUY, 100.0).unwrap();
+        assert!((avg - 0.42).abs() < 1e-12);
+        assert!((slippage - 0.05).abs() < 1e-12);
+        assert!(book.vwap(OrderSide::BUY, 201.0).is_none());
+        assert!(book.vwap(OrderSide::BUY, 0.0).is_none());
+
+        // Impact over the available depth only
+        let (avg, cost) = book.compute_market_impact(OrderSide::SELL, 500.0);
+        assert!((avg - 0.475).abs() < 1e-12);
+        assert!((cost - 95.0).abs() < 1e-12);
+        assert_eq!(book.limit_price(OrderSide::SELL, 150.0), Some(0.45));
+        assert_eq!(book.limit_price(OrderSide::SELL, 100.0), Some(0.50));
+        assert_eq!(book.limit_price(OrderSide::SELL, 201.0), None);
+
+        // Largest size keeping the average within the cap
+        assert_eq!(book.max_size_within_slippage(OrderSide::BUY, 0.0), 50.0);
+        let size = book.max_size_within_slippage(OrderSide::BUY, 0.05);
+        assert!((size - 100.0).abs() < 1e-9);
+        assert!((book.vwap(OrderSide::BUY, size).unwrap().1 - 0.05).abs() < 1e-9);
+        assert_eq!(book.max_size_within_slippage(OrderSide::SELL, 0.5), 200.0);
+    }
+
+    #[test]
+    fn test_order_book_snapshot_hash() {
+        let order_book = OrderBook {
+            token_id: TokenId("test".to_string()),
+            bids: vec![OrderBookEntry { price: 0.75, size: 100.0, timestamp: None }],
+            asks: vec![OrderBookEntry { price: 0.80, size: 100.0, timestamp: None }],
+            timestamp: 1000,
+        };
+        let hash = order_book.snapshot_hash();
+
+        // Deeper levels don't affect the hash
+        let mut deeper = order_book.clone();
+        deeper.bids.push(OrderBookEntry { price: 0.70, size: 50.0, timestamp: None });
+        assert_eq!(deeper.snapshot_hash(), hash);
+
+        let mut resized = order_book.clone();
+        resized.asks[0].size = 90.0;
+        assert_ne!(resized.snapshot_hash(), hash);
+
+        let mut newer = order_book.clone();
+        newer.timestamp = 2000;
+        assert_ne!(newer.snapshot_hash(), hash);
+
+        let mut empty = order_book;
+        empty.bids.clear();
+        assert_ne!(empty.snapshot_hash(), hash);
+    }
+
+    #[test]
+    fn test_order_book_timestamp_coherent() {
+        let mut order_book = OrderBook {
+            token_id: TokenId("test".to_string()),
+            bids: vec![OrderBookEntry { price: 0.75, size: 100.0, timestamp: Some(1900) }],
+            asks: vec![OrderBookEntry { price: 0.80, size: 100.0, timestamp: None }],
+            timestamp: 2000,
+        };
+
+        assert!(order_book.timestamp_coherent(100));
+        assert!(!order_book.timestamp_coherent(99));
+
+        order_book.asks[0].timestamp = Some(5000);
+        assert!(!order_book.timestamp_coherent(1000));
     }
 }
@@ -7,8 +7,12 @@ pub mod market;
 pub mod order;
 pub mod trade;
 pub mod config;
+pub mod events;
+pub mod error;
 
 pub use market::*;
 pub use order::*;
 pub use trade::*;
 pub use config::*;
+pub use events::*;
+pub use error::*;
@@ -2,11 +2,17 @@
 //!
 //! Defines all order-related data structures for trading.
 
-use serde::{Deserialize, Serialize};
-use super::TokenId;
+use anyhow::{anyhow, Result};
+use ethers::types::{H160, U256};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::str::FromStr;
+use super::{HftError, TokenId};
 
 /// Order side (buy or sell)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Serializes as `"BUY"`/`"SELL"`; also deserializes from the compact
+/// `0`/`1` used by `SignedOrder::side`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum OrderSide {
     /// Buy order
@@ -15,6 +21,59 @@ pub enum OrderSide {
     SELL,
 }
 
+impl OrderSide {
+    /// Compact representation (0 = BUY, 1 = SELL)
+    pub fn to_u8(self) -> u8 {
+        match self {
+            OrderSide::BUY => 0,
+            OrderSide::SELL => 1,
+        }
+    }
+
+    /// Parse the compact representation (0 = BUY, 1 = SELL)
+    pub fn from_u8(v: u8) -> Result<Self> {
+        match v {
+            0 => Ok(OrderSide::BUY),
+            1 => Ok(OrderSide::SELL),
+            _ => Err(anyhow!("Invalid order side: {}", v)),
+        }
+    }
+}
+
+impl From<OrderSide> for u8 {
+    fn from(side: OrderSide) -> Self {
+        side.to_u8()
+    }
+}
+
+impl TryFrom<u8> for OrderSide {
+    type Error = anyhow::Error;
+
+    fn try_from(v: u8) -> Result<Self> {
+        Self::from_u8(v)
+    }
+}
+
+impl<'de> Deserialize<'de> for OrderSide {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Name(String),
+            Code(u8),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Name(name) => match name.as_str() {
+                "BUY" => Ok(OrderSide::BUY),
+                "SELL" => Ok(OrderSide::SELL),
+                _ => Err(de::Error::unknown_variant(&name, &["BUY", "SELL"])),
+            },
+            Repr::Code(code) => Self::from_u8(code).map_err(de::Error::custom),
+        }
+    }
+}
+
 /// Order type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -31,6 +90,20 @@ pub enum OrderType {
     IOC,
 }
 
+impl OrderType {
+    /// `orderType` value expected by the CLOB API
+    ///
+    /// The CLOB has no IOC type; its fill-and-kill (FAK) is the same thing.
+    pub fn as_wire_str(self) -> &'static str {
+        match self {
+            OrderType::GTC => "GTC",
+            OrderType::GTD => "GTD",
+            OrderType::FOK => "FOK",
+            OrderType::FAK | OrderType::IOC => "FAK",
+        }
+    }
+}
+
 /// Order status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -208,6 +281,49 @@ pub struct SignedOrder {
     pub signature: String,
 }
 
+impl SignedOrder {
+    /// Check every field parses to its EIP-712 type
+    ///
+    /// Addresses must be `H160`s, amounts/IDs `U256`s, `side` 0 or 1 and
+    /// `signature_type` a [`SignatureType`]. Catches a bad configured
+    /// address locally instead of as an opaque 400 from the API.
+    pub fn validate(&self) -> std::result::Result<(), HftError> {
+        let addresses = [("maker", &self.maker), ("signer", &self.signer), ("taker", &self.taker)];
+        for (field, value) in addresses {
+            H160::from_str(value).map_err(|e| {
+                HftError::ParseError(format!("Invalid {} address '{}': {}", field, value, e))
+            })?;
+        }
+
+        let numbers = [
+            ("salt", &self.salt),
+            ("token_id", &self.token_id),
+            ("maker_amount", &self.maker_amount),
+            ("taker_amount", &self.taker_amount),
+            ("expiration", &self.expiration),
+            ("nonce", &self.nonce),
+            ("fee_rate_bps", &self.fee_rate_bps),
+        ];
+        for (field, value) in numbers {
+            U256::from_str(value).map_err(|e| {
+                HftError::ParseError(format!("Invalid {} '{}': {}", field, value, e))
+            })?;
+        }
+
+        if OrderSide::from_u8(self.side).is_err() {
+            return Err(HftError::InvalidOrder(format!("Invalid side {}", self.side)));
+        }
+        if self.signature_type > SignatureType::PolyProxy as u8 {
+            return Err(HftError::InvalidOrder(format!(
+                "Invalid signature type {}",
+                self.signature_type
+            )));
+        }
+
+        Ok(())
+    }
+}
+
 /// Post order wrapper for batch requests
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostOrder {
@@ -222,6 +338,28 @@ pub struct PostOrder {
     pub owner: String,
 }
 
+/// Order fill (trade) from `GET /fills`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fill {
+    /// Fill ID
+    #[serde(default)]
+    pub id: String,
+
+    /// Hash of the filled order
+    #[serde(alias = "orderHash")]
+    pub order_hash: String,
+
+    /// Execution price
+    pub price: f64,
+
+    /// Filled size in shares
+    pub size: f64,
+
+    /// Fill timestamp (Unix seconds)
+    #[serde(default)]
+    pub timestamp: i64,
+}
+
 /// Batch order response from Polymarket CLOB
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -233,8 +371,8 @@ pub struct BatchOrderResponse {
     #[serde(default)]
     pub error_msg: String,
 
-    /// Order ID (if single order)
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Order ID (if single order, sent as `orderID` by `POST /order`)
+    #[serde(alias = "orderID", skip_serializing_if = "Option::is_none")]
     pub order_id: Option<String>,
 
     /// Order hashes for successful orders in batch
@@ -249,17 +387,49 @@ pub struct BatchOrderResponse {
 impl BatchOrderResponse {
     /// Check if both orders in arbitrage pair succeeded
     pub fn both_succeeded(&self) -> bool {
-        self.success && self.order_hashes.len() >= 2
+        self.success && self.failed_count(2) == 0
     }
 
     /// Check if partial fill occurred (only one order succeeded)
     pub fn is_partial_fill(&self) -> bool {
-        self.success && self.order_hashes.len() == 1
+        self.success && self.succeeded_count() == 1
+    }
+
+    /// Number of orders that were accepted
+    ///
+    /// Hashes are returned in submission order; an empty hash marks a
+    /// rejected order.
+    pub fn succeeded_count(&self) -> usize {
+        self.order_hashes.iter().filter(|hash| !hash.is_empty()).count()
+    }
+
+    /// Number of the `submitted` orders that were rejected
+    pub fn failed_count(&self, submitted: usize) -> usize {
+        submitted.saturating_sub(self.succeeded_count())
+    }
+
+    /// Submission indices of accepted orders
+    pub fn succeeded_indices(&self) -> Vec<usize> {
+        self.order_hashes
+            .iter()
+            .enumerate()
+            .filter(|(_, hash)| !hash.is_empty())
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Submission indices (of `submitted` orders) that were rejected
+    ///
+    /// Indices past the end of `order_hashes` are missing and count as failed.
+    pub fn failed_indices(&self, submitted: usize) -> Vec<usize> {
+        (0..submitted)
+            .filter(|&index| self.order_hashes.get(index).is_none_or(|hash| hash.is_empty()))
+            .collect()
     }
 
     /// Get buy order hash (first order)
     pub fn buy_hash(&self) -> Option<&String> {
-        self.order_hashes.get(0)
+        self.order_hashes.first()
     }
 
     /// Get sell order hash (second order)
@@ -272,6 +442,23 @@ impl BatchOrderResponse {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_order_side_compact_form() {
+        for side in [OrderSide::BUY, OrderSide::SELL] {
+            assert_eq!(OrderSide::from_u8(side.to_u8()).unwrap(), side);
+            assert_eq!(OrderSide::try_from(u8::from(side)).unwrap(), side);
+        }
+        assert_eq!(OrderSide::SELL.to_u8(), 1);
+        assert!(OrderSide::from_u8(2).is_err());
+
+        // JSON stays "BUY"/"SELL"; 0/1 is accepted as an alternative
+        assert_eq!(serde_json::to_string(&OrderSide::BUY).unwrap(), r#""BUY""#);
+        let sides: Vec<OrderSide> = serde_json::from_str(r#"["SELL", 0, 1]"#).unwrap();
+        assert_eq!(sides, vec![OrderSide::SELL, OrderSide::BUY, OrderSide::SELL]);
+        assert!(serde_json::from_str::<OrderSide>("2").is_err());
+        assert!(serde_json::from_str::<OrderSide>(r#""buy""#).is_err());
+    }
+
     #[test]
     fn test_order_is_filled() {
         let order = OrderResponse {
@@ -309,4 +496,79 @@ mod tests {
         assert_eq!(order.fill_percentage(), 0.75);
         assert!(order.is_active());
     }
+
+    #[test]
+    fn test_batch_response_counts() {
+        let response = |hashes: &[&str]| BatchOrderResponse {
+            success: true,
+            error_msg: String::new(),
+            order_id: None,
+            order_hashes: hashes.iter().map(|h| h.to_string()).collect(),
+            status: None,
+        };
+
+        let both = response(&["0xbuy", "0xsell"]);
+        assert!(both.both_succeeded());
+        assert_eq!(both.succeeded_count(), 2);
+        assert_eq!(both.failed_count(2), 0);
+        assert!(both.failed_indices(2).is_empty());
+
+        // Second order missing
+        let first_only = response(&["0xbuy"]);
+        assert!(first_only.is_partial_fill());
+        assert_eq!(first_only.succeeded_indices(), vec![0]);
+        assert_eq!(first_only.failed_indices(2), vec![1]);
+
+        // First order rejected (empty hash)
+        let second_only = response(&["", "0xsell"]);
+        assert!(!second_only.both_succeeded());
+        assert!(second_only.is_partial_fill());
+        assert_eq!(second_only.succeeded_indices(), vec![1]);
+        assert_eq!(second_only.failed_count(2), 1);
+        assert_eq!(second_only.failed_indices(2), vec![0]);
+
+        // Single-order batch
+        let single = response(&["0xorder"]);
+        assert_eq!(single.failed_count(1), 0);
+        assert!(single.failed_indices(1).is_empty());
+    }
+
+    #[test]
+    fn test_signed_order_validate() {
+        let order = SignedOrder {
+            salt: "12345".to_string(),
+            maker: "0x0000000000000000000000000000000000000002".to_string(),
+            signer: "0x0000000000000000000000000000000000000003".to_string(),
+            taker: "0x0000000000000000000000000000000000000000".to_string(),
+            token_id: "123456".to_string(),
+            maker_amount: "100000000".to_string(),
+            taker_amount: "70000000".to_string(),
+            expiration: "0".to_string(),
+            nonce: "7".to_string(),
+            fee_rate_bps: "100".to_string(),
+            side: 0,
+            signature_type: 2,
+            signature: String::new(),
+        };
+        assert!(order.validate().is_ok());
+
+        let invalid = [
+            SignedOrder { maker: String::new(), ..order.clone() },
+            SignedOrder { taker: "0x123".to_string(), ..order.clone() },
+            SignedOrder { nonce: "-1".to_string(), ..order.clone() },
+            SignedOrder { maker_amount: "1.5".to_string(), ..order.clone() },
+        ];
+        for order in invalid {
+            assert!(matches!(order.validate(), Err(HftError::ParseError(_))), "{:?}", order);
+        }
+
+        assert!(matches!(
+            SignedOrder { side: 2, ..order.clone() }.validate(),
+            Err(HftError::InvalidOrder(_))
+        ));
+        assert!(matches!(
+            SignedOrder { signature_type: 3, ..order }.validate(),
+            Err(HftError::InvalidOrder(_))
+        ));
+    }
 }
@@ -3,7 +3,9 @@
 //! Defines structures for tracking trades and positions.
 
 use serde::{Deserialize, Serialize};
-use super::{MarketId, TokenId, OrderSide};
+use super::{MarketId, OrderBook, TokenId, OrderSide};
+use crate::utils::fixed_point::SignedFixedPrice;
+use crate::utils::math::liquidity_score;
 
 /// Trade execution data
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -69,12 +71,23 @@ pub struct Position {
 
     /// Position last updated timestamp
     pub updated_at: i64,
+
+    /// Orders that opened the position (cancelled on shutdown if still resting)
+    #[serde(default)]
+    pub order_hashes: Vec<String>,
 }
 
 impl Position {
     /// Calculate current unrealized P&L
     pub fn calculate_unrealized_pnl(&self, current_price: f64) -> f64 {
-        (current_price - self.entry_price) * self.size
+        self.unrealized_pnl_fixed(current_price).to_f64()
+    }
+
+    /// Calculate current unrealized P&L in fixed point (negative for losses)
+    pub fn unrealized_pnl_fixed(&self, current_price: f64) -> SignedFixedPrice {
+        let (current, entry) = (current_price, self.entry_price);
+        let change = SignedFixedPrice::from_f64(current) - SignedFixedPrice::from_f64(entry);
+        change.mul_price(SignedFixedPrice::from_f64(self.size))
     }
 
     /// Check if position is long
@@ -114,11 +127,16 @@ pub struct ArbitrageOpportunity {
     /// Maximum tradeable size
     pub max_size: f64,
 
-    /// Expected profit in USDC
+    /// Expected profit in USDC (net of fees once `with_fee_rate` is applied)
     pub expected_profit: f64,
 
     /// Detection timestamp
     pub detected_at: i64,
+
+    /// How cleanly `max_size` fills (0.0-1.0, see `utils::math::liquidity_score`)
+    ///
+    /// 1.0 until scored with `with_liquidity`.
+    pub liquidity_score: f64,
 }
 
 impl ArbitrageOpportunity {
@@ -150,9 +168,37 @@ impl ArbitrageOpportunity {
             max_size,
             expected_profit,
             detected_at: chrono::Utc::now().timestamp_millis(),
+            liquidity_score: 1.0,
         })
     }
 
+    /// Score how cleanly `max_size` fills against `order_book`
+    pub fn with_liquidity(mut self, order_book: &OrderBook) -> Self {
+        self.liquidity_score = liquidity_score(order_book, self.max_size);
+        self
+    }
+
+    /// Execution priority: `liquidity_score * profit_margin` (higher first)
+    pub fn priority(&self) -> f64 {
+        self.liquidity_score * self.profit_margin
+    }
+
+    /// Price `expected_profit` at the VWAPs of filling `max_size` on each side
+    ///
+    /// See `OrderBook::vwap`; the best prices overstate profit once the size
+    /// reaches past the best levels.
+    pub fn with_vwap(mut self, bid_vwap: f64, ask_vwap: f64) -> Self {
+        self.expected_profit = (bid_vwap - ask_vwap) * self.max_size;
+        self
+    }
+
+    /// Deduct fees at `fee_rate_bps` on both legs from `expected_profit`
+    pub fn with_fee_rate(mut self, fee_rate_bps: u16) -> Self {
+        let fee_rate = fee_rate_bps as f64 / 10_000.0;
+        self.expected_profit -= (self.bid_price + self.ask_price) * self.max_size * fee_rate;
+        self
+    }
+
     /// Check if opportunity meets minimum profit threshold
     pub fn meets_threshold(&self, min_profit_margin: f64) -> bool {
         self.profit_margin >= min_profit_margin
@@ -218,7 +264,7 @@ mod tests {
     #[test]
     fn test_position_unrealized_pnl() {
         let position = Position {
-            market_id: MarketId("market1".to_string()),
+            market_id: MarketId::new("market1"),
             token_id: TokenId("token1".to_string()),
             size: 100.0,
             entry_price: 0.70,
@@ -227,6 +273,7 @@ mod tests {
             realized_pnl: 0.0,
             opened_at: 0,
             updated_at: 0,
+            order_hashes: Vec::new(),
         };
 
         let pnl = position.calculate_unrealized_pnl(0.75);
@@ -236,7 +283,7 @@ mod tests {
     #[test]
     fn test_arbitrage_opportunity_creation() {
         let opportunity = ArbitrageOpportunity::new(
-            MarketId("market1".to_string()),
+            MarketId::new("market1"),
             TokenId("token1".to_string()),
             0.75,
             0.70,
@@ -252,7 +299,7 @@ mod tests {
     #[test]
     fn test_arbitrage_opportunity_no_profit() {
         let opportunity = ArbitrageOpportunity::new(
-            MarketId("market1".to_string()),
+            MarketId::new("market1"),
             TokenId("token1".to_string()),
             0.70,
             0.75,
@@ -265,7 +312,7 @@ mod tests {
     #[test]
     fn test_arbitrage_meets_threshold() {
         let opportunity = ArbitrageOpportunity::new(
-            MarketId("market1".to_string()),
+            MarketId::new("market1"),
             TokenId("token1".to_string()),
             0.75,
             0.70,
@@ -275,4 +322,22 @@ mod tests {
         assert!(opportunity.meets_threshold(0.02)); // 2% threshold
         assert!(!opportunity.meets_threshold(0.10)); // 10% threshold
     }
+
+    #[test]
+    fn test_arbitrage_opportunity_with_fee_rate() {
+        let opportunity = ArbitrageOpportunity::new(
+            MarketId::new("market1"),
+            TokenId("token1".to_string()),
+            0.75,
+            0.70,
+            100.0,
+        ).unwrap();
+
+        // $5 spread - 1% of $75 + $70 notional
+        let net = opportunity.clone().with_fee_rate(100);
+        assert!((net.expected_profit - 3.55).abs() < 1e-9);
+        assert_eq!(net.profit_margin, opportunity.profit_margin);
+        let expected_profit = opportunity.expected_profit;
+        assert_eq!(opportunity.with_fee_rate(0).expected_profit, expected_profit);
+    }
 }
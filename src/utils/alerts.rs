@@ -0,0 +1,255 @@
+//! Operator alerts for critical events (requires the `alerts` feature)
+//!
+//! Sends events such as a tripped circuit breaker to every configured
+//! [`AlertChannel`]: Slack incoming webhooks, PagerDuty Events API v2, or a
+//! generic JSON webhook. Delivery failures are logged and never propagate.
+//!
+//! # Example
+//! ```rust,ignore
+//! let sender = Arc::new(AlertSender::new(config.alert_channels.clone()));
+//! let circuit_breaker = CircuitBreaker::new(config.risk.clone())
+//!     .with_alert_sender(sender.clone());
+//!
+//! sender.send(Severity::Warning, "Balance low", json!({ "usdc": 9.5 })).await;
+//! ```
+
+use futures_util::future::join_all;
+use serde_json::{json, Value};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing;
+
+use crate::types::config::AlertChannel;
+
+/// PagerDuty Events API v2 endpoint
+const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+
+/// Source reported to PagerDuty and webhooks
+const ALERT_SOURCE: &str = "polymarket-hft-bot";
+
+/// Per-request timeout for alert delivery
+const ALERT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Alert severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// Informational
+    Info,
+    /// Needs attention soon
+    Warning,
+    /// Needs immediate attention (pages on-call)
+    Critical,
+}
+
+impl Severity {
+    /// Lowercase name (also the PagerDuty severity)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Sends alerts to all configured channels
+///
+/// `Debug` lists channel kinds only: webhook URLs, headers and integration
+/// keys are secrets.
+#[derive(Clone)]
+pub struct AlertSender {
+    /// Destinations for every alert
+    channels: Vec<AlertChannel>,
+
+    /// HTTP client shared across channels
+    http: reqwest::Client,
+}
+
+impl fmt::Debug for AlertSender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kinds: Vec<&str> = self.channels.iter().map(AlertChannel::kind).collect();
+        f.debug_struct("AlertSender").field("channels", &kinds).finish_non_exhaustive()
+    }
+}
+
+impl AlertSender {
+    /// Create sender for `channels`
+    pub fn new(channels: Vec<AlertChannel>) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(ALERT_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+
+        Self { channels, http }
+    }
+
+    /// Configured channels
+    pub fn channels(&self) -> &[AlertChannel] {
+        &self.channels
+    }
+
+    /// Send an alert to every channel concurrently
+    ///
+    /// Failures are logged per channel; other channels are unaffected.
+    pub async fn send(&self, severity: Severity, message: &str, context: Value) {
+        let deliveries = self.channels.iter().map(|channel| {
+            let (url, body) = request(channel, severity, message, &context);
+            let mut req = self.http.post(url).json(&body);
+            if let AlertChannel::Webhook { headers, .. } = channel {
+                for (name, value) in headers {
+                    req = req.header(name, value);
+                }
+            }
+
+            async move {
+                match req.send().await.and_then(|resp| resp.error_for_status()) {
+                    Ok(_) => tracing::debug!("Alert delivered via {}", channel.kind()),
+                    Err(e) => tracing::warn!("Failed to send {} alert: {}", channel.kind(), e),
+                }
+            }
+        });
+
+        join_all(deliveries).await;
+    }
+
+    /// Send an alert from synchronous code without waiting for delivery
+    ///
+    /// Spawns onto the current Tokio runtime; outside a runtime the alert is
+    /// dropped with a warning.
+    pub fn spawn_send(self: &Arc<Self>, severity: Severity, message: &str, context: Value) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            tracing::warn!("No Tokio runtime, dropping {} alert: {}", severity, message);
+            return;
+        };
+
+        let sender = Arc::clone(self);
+        let message = message.to_string();
+        runtime.spawn(async move { sender.send(severity, &message, context).await });
+    }
+}
+
+/// Destination URL and JSON body for one channel
+fn request<'a>(
+    channel: &'a AlertChannel,
+    severity: Severity,
+    message: &str,
+    context: &Value,
+) -> (&'a str, Value) {
+    match channel {
+        AlertChannel::Slack { webhook_url } => (
+            webhook_url,
+            json!({
+                "text": format!(
+                    "[{}] {}\n```{}```",
+                    severity.as_str().to_uppercase(),
+                    message,
+                    context
+                ),
+            }),
+        ),
+        AlertChannel::PagerDuty { integration_key } => (
+            PAGERDUTY_EVENTS_URL,
+            json!({
+                "routing_key": integration_key,
+                "event_action": "trigger",
+                "payload": {
+                    "summary": message,
+                    "severity": severity.as_str(),
+                    "source": ALERT_SOURCE,
+                    "custom_details": context,
+                },
+            }),
+        ),
+        AlertChannel::Webhook { url, .. } => (
+            url,
+            json!({
+                "severity": severity.as_str(),
+                "message": message,
+                "context": context,
+                "source": ALERT_SOURCE,
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+            }),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_debug_redacts_secrets() {
+        let sender = AlertSender::new(vec![
+            AlertChannel::Slack { webhook_url: "https://hooks.slack.test/secret".to_string() },
+            AlertChannel::PagerDuty { integration_key: "key-123".to_string() },
+        ]);
+        let debug = format!("{:?}", sender);
+        assert!(debug.contains("slack") && debug.contains("pagerduty"), "{}", debug);
+        assert!(!debug.contains("secret") && !debug.contains("key-123"), "{}", debug);
+    }
+
+    #[test]
+    fn test_request_bodies() {
+        let context = json!({ "daily_loss": 42.5 });
+
+        let slack = AlertChannel::Slack { webhook_url: "https://hooks.slack.test/x".to_string() };
+        let (url, body) = request(&slack, Severity::Critical, "Circuit breaker tripped", &context);
+        assert_eq!(url, "https://hooks.slack.test/x");
+        let text = body["text"].as_str().unwrap();
+        assert!(text.starts_with("[CRITICAL] Circuit breaker tripped"));
+        assert!(text.contains("42.5"));
+
+        let pagerduty = AlertChannel::PagerDuty { integration_key: "key-123".to_string() };
+        let (url, body) = request(&pagerduty, Severity::Warning, "Rollback failed", &context);
+        assert_eq!(url, PAGERDUTY_EVENTS_URL);
+        assert_eq!(body["routing_key"], "key-123");
+        assert_eq!(body["event_action"], "trigger");
+        assert_eq!(body["payload"]["severity"], "warning");
+        assert_eq!(body["payload"]["custom_details"], context);
+    }
+
+    #[tokio::test]
+    async fn test_send_to_webhooks() {
+        let mut server = mockito::Server::new_async().await;
+        let webhook = server
+            .mock("POST", "/alerts")
+            .match_header("x-api-key", "secret")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "severity": "critical",
+                "message": "Circuit breaker tripped",
+                "context": { "open_positions": 3 },
+            })))
+            .with_status(200)
+            .create_async()
+            .await;
+        let slack = server
+            .mock("POST", "/slack")
+            .match_body(mockito::Matcher::Regex(r"\[CRITICAL\] Circuit breaker".to_string()))
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let sender = AlertSender::new(vec![
+            AlertChannel::Webhook {
+                url: format!("{}/alerts", server.url()),
+                headers: HashMap::from([("x-api-key".to_string(), "secret".to_string())]),
+            },
+            AlertChannel::Slack { webhook_url: format!("{}/slack", server.url()) },
+        ]);
+
+        // A failing channel does not prevent delivery to the others
+        sender
+            .send(Severity::Critical, "Circuit breaker tripped", json!({ "open_positions": 3 }))
+            .await;
+        webhook.assert_async().await;
+        slack.assert_async().await;
+    }
+}
@@ -10,8 +10,10 @@
 //! - $0.750000 → 750000 (u64)
 //! - Range: $0.000001 to $18,446,744.073709 (u64::MAX / 1_000_000)
 
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
 use std::fmt;
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Add, Sub, Mul, Div, Neg};
 
 /// Fixed-point price with 6 decimal precision
 ///
@@ -26,6 +28,7 @@ use std::ops::{Add, Sub, Mul, Div};
 /// assert_eq!(doubled.to_f64(), 1.5);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
 pub struct FixedPrice(u64);
 
 impl FixedPrice {
@@ -41,12 +44,47 @@ impl FixedPrice {
     /// Maximum representable value (~$18.4M)
     pub const MAX: Self = Self(u64::MAX);
 
+    /// Polymarket price increment ($0.001)
+    pub const POLYMARKET_TICK: Self = Self::from_raw(1_000);
+
     /// Create from f64 (rounds to nearest micro-dollar)
     #[inline]
     pub fn from_f64(value: f64) -> Self {
         Self((value * Self::SCALE as f64).round() as u64)
     }
 
+    /// Parse a decimal string exactly (e.g., `"0.515"` from the REST API)
+    ///
+    /// Avoids the f64 round-trip. Digits beyond 6 decimal places are
+    /// rounded half-up. Returns `None` for malformed or negative input.
+    pub fn from_decimal_str(value: &str) -> Option<Self> {
+        let value = value.trim();
+        let (int_part, frac_part) = value.split_once('.').unwrap_or((value, ""));
+
+        let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        if (int_part.is_empty() && frac_part.is_empty())
+            || !all_digits(int_part)
+            || !all_digits(frac_part)
+        {
+            return None;
+        }
+
+        let int_value: u64 = if int_part.is_empty() { 0 } else { int_part.parse().ok()? };
+
+        // First 6 fractional digits, right-padded with zeros
+        let mut frac_value = 0u64;
+        let mut digits = frac_part.bytes();
+        for _ in 0..6 {
+            frac_value = frac_value * 10 + digits.next().map_or(0, |b| (b - b'0') as u64);
+        }
+        let round_up = digits.next().is_some_and(|b| b >= b'5');
+
+        int_value
+            .checked_mul(Self::SCALE)?
+            .checked_add(frac_value + round_up as u64)
+            .map(Self)
+    }
+
     /// Convert to f64
     #[inline]
     pub fn to_f64(self) -> f64 {
@@ -65,6 +103,71 @@ impl FixedPrice {
         self.0
     }
 
+    /// Get the amount in USDC base units (6 decimals on Polygon)
+    ///
+    /// Same as [`raw`](Self::raw): `SCALE` happens to equal USDC's
+    /// 1,000,000 units per dollar. Only valid for 6-decimal USDC - other
+    /// collateral tokens need their own conversion.
+    #[inline]
+    pub const fn to_usdc_cents(self) -> u64 {
+        self.0
+    }
+
+    /// Create from USDC base units (6 decimals on Polygon)
+    ///
+    /// Same as [`from_raw`](Self::from_raw); see [`to_usdc_cents`](Self::to_usdc_cents).
+    #[inline]
+    pub const fn from_usdc_cents(cents: u64) -> Self {
+        Self(cents)
+    }
+
+    /// Convert a slice of f64 values (rounds like [`from_f64`](Self::from_f64))
+    ///
+    /// Uses AVX2 (4 values per instruction) when the CPU supports it.
+    pub fn batch_from_f64(values: &[f64]) -> Vec<Self> {
+        let mut out = vec![Self::ZERO; values.len()];
+        Self::batch_from_f64_into(values, &mut out);
+        out
+    }
+
+    /// Non-allocating [`batch_from_f64`](Self::batch_from_f64)
+    ///
+    /// # Panics
+    /// If `out` is shorter than `values`.
+    pub fn batch_from_f64_into(values: &[f64], out: &mut [Self]) {
+        assert!(out.len() >= values.len(), "output slice too short");
+
+        #[cfg(target_arch = "x86_64")]
+        if std::arch::is_x86_feature_detected!("avx2") {
+            // SAFETY: AVX2 support was just verified
+            unsafe { avx2::from_f64(values, out) };
+            return;
+        }
+
+        for (value, fixed) in values.iter().zip(out.iter_mut()) {
+            *fixed = Self::from_f64(*value);
+        }
+    }
+
+    /// Convert a slice of prices to f64 (same as [`to_f64`](Self::to_f64))
+    ///
+    /// Uses AVX2 (4 values per instruction) when the CPU supports it.
+    pub fn batch_to_f64(prices: &[Self]) -> Vec<f64> {
+        let mut out = vec![0.0; prices.len()];
+
+        #[cfg(target_arch = "x86_64")]
+        if std::arch::is_x86_feature_detected!("avx2") {
+            // SAFETY: AVX2 support was just verified
+            unsafe { avx2::to_f64(prices, &mut out) };
+            return out;
+        }
+
+        for (price, value) in prices.iter().zip(out.iter_mut()) {
+            *value = price.to_f64();
+        }
+        out
+    }
+
     /// Multiply two prices (result scaled correctly)
     ///
     /// # Performance
@@ -123,6 +226,24 @@ impl FixedPrice {
     pub const fn is_zero(self) -> bool {
         self.0 == 0
     }
+
+    /// Round down to a multiple of `tick_size`
+    ///
+    /// # Panics
+    /// If `tick_size` is zero.
+    #[inline]
+    pub const fn round_to_tick(self, tick_size: Self) -> Self {
+        Self((self.0 / tick_size.0) * tick_size.0)
+    }
+
+    /// Round up to a multiple of `tick_size` (saturates at `MAX`)
+    ///
+    /// # Panics
+    /// If `tick_size` is zero.
+    #[inline]
+    pub const fn round_to_tick_up(self, tick_size: Self) -> Self {
+        Self(self.0.div_ceil(tick_size.0).saturating_mul(tick_size.0))
+    }
 }
 
 // Arithmetic operators
@@ -170,10 +291,340 @@ impl fmt::Display for FixedPrice {
     }
 }
 
+impl FixedPrice {
+    /// Exact decimal string with 6 places, as Polymarket sends prices
+    ///
+    /// ```
+    /// use polymarket_hft_bot::utils::fixed_point::FixedPrice;
+    ///
+    /// assert_eq!(FixedPrice::from_f64(0.75).to_decimal_string(), "0.750000");
+    /// ```
+    pub fn to_decimal_string(self) -> String {
+        format!("{}.{:06}", self.0 / Self::SCALE, self.0 % Self::SCALE)
+    }
+}
+
+/// Serialized as a 6-decimal string (`"0.750000"`)
+impl Serialize for FixedPrice {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_decimal_string())
+    }
+}
+
+/// Deserialized from a decimal string (exact) or a non-negative JSON number
+impl<'de> Deserialize<'de> for FixedPrice {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedPriceVisitor;
+
+        impl Visitor<'_> for FixedPriceVisitor {
+            type Value = FixedPrice;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a non-negative decimal string or number")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<FixedPrice, E> {
+                FixedPrice::from_decimal_str(value)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<FixedPrice, E> {
+                value
+                    .checked_mul(FixedPrice::SCALE)
+                    .map(FixedPrice)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<FixedPrice, E> {
+                u64::try_from(value)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+                    .and_then(|value| self.visit_u64(value))
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<FixedPrice, E> {
+                if value.is_finite() && value >= 0.0 {
+                    Ok(FixedPrice::from_f64(value))
+                } else {
+                    Err(E::invalid_value(de::Unexpected::Float(value), &self))
+                }
+            }
+        }
+
+        deserializer.deserialize_any(FixedPriceVisitor)
+    }
+}
+
+/// `#[serde(deserialize_with)]` for `f64` fields sent as decimal strings or numbers
+///
+/// Strings are parsed exactly (see `FixedPrice::from_decimal_str`) before
+/// the single conversion to `f64`.
+pub fn deserialize_decimal_f64<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    FixedPrice::deserialize(deserializer).map(FixedPrice::to_f64)
+}
+
+/// Signed fixed-point amount with 6 decimal precision (e.g., P&L)
+///
+/// `FixedPrice` is unsigned, so a loss such as `current - entry` on a losing
+/// long would underflow. Same scale as `FixedPrice`.
+///
+/// # Examples
+/// ```
+/// use polymarket_hft_bot::utils::fixed_point::{FixedPrice, SignedFixedPrice};
+///
+/// let entry = SignedFixedPrice::from_fixed(FixedPrice::from_f64(0.75));
+/// let loss = SignedFixedPrice::from_f64(0.70) - entry;
+/// assert_eq!((loss * 100).to_f64(), -5.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct SignedFixedPrice(i64);
+
+impl SignedFixedPrice {
+    /// Scaling factor: 1,000,000 (6 decimal places)
+    pub const SCALE: i64 = FixedPrice::SCALE as i64;
+
+    /// Zero value
+    pub const ZERO: Self = Self(0);
+
+    /// Create from f64 (rounds to nearest micro-dollar)
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self((value * Self::SCALE as f64).round() as i64)
+    }
+
+    /// Convert to f64
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        self.0 as f64 / Self::SCALE as f64
+    }
+
+    /// Convert from an unsigned price (saturates above `i64::MAX` micro-dollars)
+    #[inline]
+    pub fn from_fixed(price: FixedPrice) -> Self {
+        Self(i64::try_from(price.raw()).unwrap_or(i64::MAX))
+    }
+
+    /// Create from raw micro-dollars
+    #[inline]
+    pub const fn from_raw(value: i64) -> Self {
+        Self(value)
+    }
+
+    /// Raw micro-dollars
+    #[inline]
+    pub const fn raw(self) -> i64 {
+        self.0
+    }
+
+    /// Whole cents, truncated toward zero
+    #[inline]
+    pub const fn to_cents(self) -> i64 {
+        self.0 / (Self::SCALE / 100)
+    }
+
+    /// Whether the amount is below zero
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.0 < 0
+    }
+
+    /// Multiply two fixed-point values (e.g., price change by fractional size)
+    #[inline]
+    pub fn mul_price(self, other: Self) -> Self {
+        // (a * b) / SCALE
+        let result = (self.0 as i128 * other.0 as i128) / Self::SCALE as i128;
+        Self(result as i64)
+    }
+}
+
+impl Add for SignedFixedPrice {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub for SignedFixedPrice {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Neg for SignedFixedPrice {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Mul<i64> for SignedFixedPrice {
+    type Output = Self;
+
+    /// Multiply amount by integer quantity
+    #[inline]
+    fn mul(self, quantity: i64) -> Self {
+        Self(self.0 * quantity)
+    }
+}
+
+impl From<f64> for SignedFixedPrice {
+    fn from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl fmt::Display for SignedFixedPrice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.is_negative() { "-" } else { "" };
+        write!(f, "{}${:.6}", sign, self.to_f64().abs())
+    }
+}
+
+/// AVX2 batch conversions
+///
+/// AVX2 has no f64 <-> u64 conversion, so integers below 2^52 are converted
+/// by adding/subtracting 2^52 and reinterpreting the bits (exact in that
+/// range). Chunks with values outside it fall back to scalar.
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use super::FixedPrice;
+    use std::arch::x86_64::*;
+
+    /// 2^52: doubles at or above this have no fractional bits
+    const MAGIC: f64 = 4_503_599_627_370_496.0;
+
+    /// # Safety
+    /// CPU must support AVX2; `out.len() >= values.len()`.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn from_f64(values: &[f64], out: &mut [FixedPrice]) {
+        let scale = _mm256_set1_pd(FixedPrice::SCALE as f64);
+        let half = _mm256_set1_pd(0.5);
+        let one = _mm256_set1_pd(1.0);
+        let zero = _mm256_setzero_pd();
+        let magic = _mm256_set1_pd(MAGIC);
+
+        let chunks = values.len() / 4;
+        for i in 0..chunks {
+            let scaled = _mm256_mul_pd(_mm256_loadu_pd(values.as_ptr().add(i * 4)), scale);
+
+            // Vector path only for 0 <= x < 2^52 (NaN fails both comparisons)
+            let in_range = _mm256_and_pd(
+                _mm256_cmp_pd::<_CMP_GE_OQ>(scaled, zero),
+                _mm256_cmp_pd::<_CMP_LT_OQ>(scaled, magic),
+            );
+            if _mm256_movemask_pd(in_range) != 0b1111 {
+                for j in i * 4..i * 4 + 4 {
+                    out[j] = FixedPrice::from_f64(values[j]);
+                }
+                continue;
+            }
+
+            // Round half away from zero, like f64::round: floor + (frac >= 0.5)
+            let floor = _mm256_floor_pd(scaled);
+            let round_up = _mm256_and_pd(
+                _mm256_cmp_pd::<_CMP_GE_OQ>(_mm256_sub_pd(scaled, floor), half),
+                one,
+            );
+            let rounded = _mm256_add_pd(floor, round_up);
+
+            let raw = _mm256_sub_epi64(
+                _mm256_castpd_si256(_mm256_add_pd(rounded, magic)),
+                _mm256_castpd_si256(magic),
+            );
+            // FixedPrice is a transparent u64 newtype
+            _mm256_storeu_si256(out.as_mut_ptr().add(i * 4) as *mut __m256i, raw);
+        }
+
+        for j in chunks * 4..values.len() {
+            out[j] = FixedPrice::from_f64(values[j]);
+        }
+    }
+
+    /// # Safety
+    /// CPU must support AVX2; `out.len() >= prices.len()`.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn to_f64(prices: &[FixedPrice], out: &mut [f64]) {
+        let scale = _mm256_set1_pd(FixedPrice::SCALE as f64);
+        let magic = _mm256_set1_pd(MAGIC);
+        let magic_bits = _mm256_castpd_si256(magic);
+        let limit = _mm256_set1_epi64x((1i64 << 52) - 1);
+
+        let chunks = prices.len() / 4;
+        for i in 0..chunks {
+            let raw = _mm256_loadu_si256(prices.as_ptr().add(i * 4) as *const __m256i);
+
+            // Raw values must be < 2^52 (signed compare also rejects >= 2^63)
+            let too_large = _mm256_or_si256(
+                _mm256_cmpgt_epi64(raw, limit),
+                _mm256_cmpgt_epi64(_mm256_setzero_si256(), raw),
+            );
+            if _mm256_movemask_pd(_mm256_castsi256_pd(too_large)) != 0 {
+                for j in i * 4..i * 4 + 4 {
+                    out[j] = prices[j].to_f64();
+                }
+                continue;
+            }
+
+            let with_magic = _mm256_castsi256_pd(_mm256_or_si256(raw, magic_bits));
+            let as_f64 = _mm256_sub_pd(with_magic, magic);
+            _mm256_storeu_pd(out.as_mut_ptr().add(i * 4), _mm256_div_pd(as_f64, scale));
+        }
+
+        for j in chunks * 4..prices.len() {
+            out[j] = prices[j].to_f64();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_batch_conversions_match_scalar() {
+        let values = [
+            0.75, 0.0000015, 0.0000005, 1.0, 0.0, 123.456789, -1.0, f64::NAN,
+            1e13, 0.51, 0.49, 0.333333333,
+            // Remainder (not a multiple of 4)
+            0.25, 2.5,
+        ];
+
+        let batch = FixedPrice::batch_from_f64(&values);
+        let scalar: Vec<FixedPrice> = values.iter().map(|v| FixedPrice::from_f64(*v)).collect();
+        assert_eq!(batch, scalar);
+
+        let prices = [
+            FixedPrice::from_raw(750_000),
+            FixedPrice::from_raw(1),
+            FixedPrice::MAX,
+            FixedPrice::ZERO,
+            FixedPrice::from_raw((1 << 52) - 1),
+        ];
+        let batch = FixedPrice::batch_to_f64(&prices);
+        let scalar: Vec<f64> = prices.iter().map(|p| p.to_f64()).collect();
+        assert_eq!(batch, scalar);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn batch_from_f64_matches_scalar(
+            values in proptest::collection::vec(0.0f64..1_000.0, 0..64),
+        ) {
+            let scalar: Vec<FixedPrice> = values.iter().map(|v| FixedPrice::from_f64(*v)).collect();
+            proptest::prop_assert_eq!(FixedPrice::batch_from_f64(&values), scalar);
+        }
+    }
+
     #[test]
     fn test_from_f64() {
         assert_eq!(FixedPrice::from_f64(0.75).raw(), 750_000);
@@ -187,6 +638,55 @@ mod tests {
         assert!((FixedPrice::from_raw(1_000_000).to_f64() - 1.0).abs() < 0.000001);
     }
 
+    #[test]
+    fn test_from_decimal_str() {
+        assert_eq!(FixedPrice::from_decimal_str("0.515"), Some(FixedPrice::from_raw(515_000)));
+        assert_eq!(FixedPrice::from_decimal_str("12"), Some(FixedPrice::from_raw(12_000_000)));
+        assert_eq!(FixedPrice::from_decimal_str(".5"), Some(FixedPrice::from_raw(500_000)));
+        assert_eq!(FixedPrice::from_decimal_str("0.0000015"), Some(FixedPrice::from_raw(2)));
+        assert_eq!(FixedPrice::from_decimal_str("0.0000014"), Some(FixedPrice::from_raw(1)));
+
+        for invalid in ["", ".", "-0.5", "abc", "0.5.1", "1e-3"] {
+            assert_eq!(FixedPrice::from_decimal_str(invalid), None, "{:?}", invalid);
+        }
+        assert_eq!(FixedPrice::from_decimal_str("99999999999999999999"), None);
+    }
+
+    #[test]
+    fn test_usdc_cents() {
+        // 1 USDC = 1,000,000 base units
+        assert_eq!(FixedPrice::ONE.to_usdc_cents(), 1_000_000);
+        assert_eq!(FixedPrice::from_usdc_cents(750_000), FixedPrice::from_f64(0.75));
+
+        // Rounds instead of truncating float error (57.0 * 1e6 = 56999999.99...)
+        assert_eq!(FixedPrice::from_f64(100.0 * 0.57).to_usdc_cents(), 57_000_000);
+    }
+
+    #[test]
+    fn test_round_to_tick() {
+        let tick = FixedPrice::POLYMARKET_TICK;
+        assert_eq!(tick, FixedPrice::from_f64(0.001));
+
+        // Between ticks
+        let vwap = FixedPrice::from_raw(752_300);
+        assert_eq!(vwap.round_to_tick(tick), FixedPrice::from_raw(752_000));
+        assert_eq!(vwap.round_to_tick_up(tick), FixedPrice::from_raw(753_000));
+
+        // One micro-dollar either side of a tick
+        assert_eq!(FixedPrice::from_raw(752_999).round_to_tick(tick).raw(), 752_000);
+        assert_eq!(FixedPrice::from_raw(752_001).round_to_tick_up(tick).raw(), 753_000);
+
+        // Exactly on a tick is unchanged
+        for price in [FixedPrice::ZERO, FixedPrice::from_raw(752_000), FixedPrice::ONE] {
+            assert_eq!(price.round_to_tick(tick), price);
+            assert_eq!(price.round_to_tick_up(tick), price);
+        }
+
+        // Below one tick
+        assert_eq!(FixedPrice::from_raw(999).round_to_tick(tick), FixedPrice::ZERO);
+        assert_eq!(FixedPrice::from_raw(1).round_to_tick_up(tick), tick);
+    }
+
     #[test]
     fn test_addition() {
         let a = FixedPrice::from_f64(0.5);
@@ -282,4 +782,52 @@ mod tests {
         let price = FixedPrice::from_f64(0.750000);
         assert_eq!(format!("{}", price), "$0.750000");
     }
+
+    #[test]
+    fn test_signed_arithmetic() {
+        let entry = SignedFixedPrice::from_fixed(FixedPrice::from_f64(0.75));
+        let current = SignedFixedPrice::from_f64(0.70);
+
+        // Losing long goes negative instead of underflowing
+        let change = current - entry;
+        assert_eq!(change.raw(), -50_000);
+        assert!(change.is_negative());
+        assert_eq!((change * 100).raw(), -5_000_000);
+        assert_eq!(-change, entry - current);
+        assert_eq!(change + entry, current);
+
+        // Fractional size
+        let pnl = change.mul_price(SignedFixedPrice::from_f64(10.5));
+        assert_eq!(pnl, SignedFixedPrice::from_f64(-0.525));
+        assert_eq!(format!("{}", pnl), "-$0.525000");
+    }
+
+    #[test]
+    fn test_signed_conversions() {
+        assert_eq!(SignedFixedPrice::from(-42.5).to_cents(), -4250);
+        assert_eq!(SignedFixedPrice::from_f64(0.019).to_cents(), 1, "Truncates toward zero");
+        assert_eq!(SignedFixedPrice::from_f64(-0.019).to_cents(), -1);
+        assert!((SignedFixedPrice::from_raw(-750_000).to_f64() + 0.75).abs() < 0.000001);
+        assert_eq!(SignedFixedPrice::from_fixed(FixedPrice::MAX).raw(), i64::MAX);
+    }
+
+    #[test]
+    fn test_serde_wire_format() {
+        let price = FixedPrice::from_raw(750_000);
+        assert_eq!(serde_json::to_string(&price).unwrap(), r#""0.750000""#);
+        let large = FixedPrice::from_raw(12_000_001);
+        assert_eq!(serde_json::to_string(&large).unwrap(), r#""12.000001""#);
+
+        let parse = |json: &str| serde_json::from_str::<FixedPrice>(json);
+        assert_eq!(parse(r#""0.750000""#).unwrap(), price);
+        assert_eq!(parse(r#""0.75""#).unwrap(), price);
+        assert_eq!(parse("0.75").unwrap(), price);
+        assert_eq!(parse("1").unwrap(), FixedPrice::ONE);
+        assert!(parse(r#""-0.5""#).is_err());
+        assert!(parse("-1").is_err());
+        assert!(parse(r#""abc""#).is_err());
+
+        let round_trip = parse(&serde_json::to_string(&price).unwrap()).unwrap();
+        assert_eq!(round_trip, price);
+    }
 }
@@ -0,0 +1,173 @@
+//! Exponential moving averages for smoothed price signals
+//!
+//! ```text
+//! ema_0 = value_0
+//! ema_t = alpha * value_t + (1 - alpha) * ema_{t-1}
+//! ```
+//!
+//! # Example: 5/20 period crossover
+//! ```
+//! use polymarket_hft_bot::utils::math::{ema_crossover, CrossoverSignal};
+//!
+//! // Mid-price falls for 30 ticks, then rallies for 30 ticks
+//! let prices = (0..30)
+//!     .map(|i| 0.60 - i as f64 * 0.005)
+//!     .chain((0..30).map(|i| 0.45 + i as f64 * 0.005));
+//!
+//! let signals: Vec<_> = ema_crossover(5, 20, prices).collect();
+//! assert_eq!(signals, vec![CrossoverSignal::Golden]);
+//! ```
+
+/// Moving average crossover signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossoverSignal {
+    /// Short EMA crossed above the long EMA (bullish)
+    Golden,
+    /// Short EMA crossed below the long EMA (bearish)
+    Death,
+}
+
+/// Smoothing factor for an N-period EMA: `2 / (period + 1)`
+///
+/// A period of 0 is treated as 1 (no smoothing).
+pub fn ema_period(period: usize) -> f64 {
+    2.0 / (period.max(1) as f64 + 1.0)
+}
+
+/// Exponential moving average of a stream of values
+///
+/// Yields one smoothed value per input value. The first value seeds the EMA.
+///
+/// # Arguments
+/// * `values` - Input values (e.g., mid-prices)
+/// * `alpha` - Smoothing factor (0 < alpha <= 1); higher reacts faster
+pub fn ema<I>(values: I, alpha: f64) -> Ema<I::IntoIter>
+where
+    I: IntoIterator<Item = f64>,
+{
+    debug_assert!(alpha > 0.0 && alpha <= 1.0, "alpha must be in (0, 1]");
+
+    Ema {
+        values: values.into_iter(),
+        alpha,
+        current: None,
+    }
+}
+
+/// Iterator returned by [`ema`]
+#[derive(Debug, Clone)]
+pub struct Ema<I> {
+    /// Input values
+    values: I,
+
+    /// Smoothing factor
+    alpha: f64,
+
+    /// Latest EMA (None before the first value)
+    current: Option<f64>,
+}
+
+impl<I: Iterator<Item = f64>> Iterator for Ema<I> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        let next = step(self.alpha, self.current, self.values.next()?);
+        self.current = Some(next);
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+/// Single EMA update (the first value seeds the average)
+#[inline]
+fn step(alpha: f64, prev: Option<f64>, value: f64) -> f64 {
+    match prev {
+        Some(prev) => alpha * value + (1.0 - alpha) * prev,
+        None => value,
+    }
+}
+
+/// Golden/death cross signals between a short and a long period EMA
+///
+/// Emits a signal only when the short EMA moves to the other side of the
+/// long EMA; touching without crossing does not count.
+pub fn ema_crossover<I>(
+    short_period: usize,
+    long_period: usize,
+    values: I,
+) -> impl Iterator<Item = CrossoverSignal>
+where
+    I: IntoIterator<Item = f64>,
+{
+    let short_alpha = ema_period(short_period);
+    let long_alpha = ema_period(long_period);
+
+    // Side of the short EMA relative to the long EMA (true = above)
+    let mut above: Option<bool> = None;
+
+    let mut short = None;
+    let mut long = None;
+
+    values.into_iter().filter_map(move |value| {
+        let s = step(short_alpha, short, value);
+        let l = step(long_alpha, long, value);
+        short = Some(s);
+        long = Some(l);
+
+        if s == l {
+            return None;
+        }
+
+        let is_above = s > l;
+        let signal = match above {
+            Some(false) if is_above => Some(CrossoverSignal::Golden),
+            Some(true) if !is_above => Some(CrossoverSignal::Death),
+            _ => None,
+        };
+        above = Some(is_above);
+        signal
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ema_values() {
+        let smoothed: Vec<f64> = ema([1.0, 2.0, 3.0], 0.5).collect();
+        assert_eq!(smoothed, vec![1.0, 1.5, 2.25]);
+
+        // alpha = 1 tracks the input exactly
+        let raw: Vec<f64> = ema([0.4, 0.6], 1.0).collect();
+        assert_eq!(raw, vec![0.4, 0.6]);
+
+        assert_eq!(ema(std::iter::empty(), 0.5).next(), None);
+    }
+
+    #[test]
+    fn test_ema_period() {
+        assert_eq!(ema_period(1), 1.0);
+        assert_eq!(ema_period(3), 0.5);
+        assert!((ema_period(20) - 2.0 / 21.0).abs() < 1e-12);
+        assert_eq!(ema_period(0), 1.0);
+    }
+
+    #[test]
+    fn test_ema_crossover() {
+        // Rise, fall, rise
+        let prices = (0..30)
+            .map(|i| 0.40 + i as f64 * 0.005)
+            .chain((0..30).map(|i| 0.55 - i as f64 * 0.005))
+            .chain((0..30).map(|i| 0.40 + i as f64 * 0.005));
+
+        let signals: Vec<_> = ema_crossover(5, 20, prices).collect();
+        assert_eq!(signals, vec![CrossoverSignal::Death, CrossoverSignal::Golden]);
+
+        // Flat prices never cross
+        assert_eq!(ema_crossover(5, 20, [0.5; 50]).count(), 0);
+    }
+}
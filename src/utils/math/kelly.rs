@@ -0,0 +1,104 @@
+//! Kelly criterion bet sizing
+//!
+//! A bet that wins `win_payout` per unit staked with probability `p` and
+//! otherwise loses `lose_fraction` of the stake grows a bankroll fastest when
+//! staking `f* = p / lose_fraction - (1 - p) / win_payout` of it. Full Kelly is
+//! volatile and assumes `p` is exact, so stakes are capped well below it.
+//!
+//! # Example: binary outcome bought at $0.50 with P(win) = 0.60
+//! ```
+//! use polymarket_hft_bot::utils::math::{kelly_fraction, kelly_usdc};
+//!
+//! // A $0.50 share pays $1.00: +100% on a win, -100% on a loss
+//! assert!((kelly_fraction(0.60, 1.0, 1.0) - 0.20).abs() < 1e-9);
+//!
+//! // Capped at 10% of a $1,000 bankroll
+//! assert!((kelly_usdc(0.60, 1.0, 1.0, 1_000.0, 0.10) - 100.0).abs() < 1e-9);
+//! ```
+
+/// Default cap on the staked fraction of bankroll (quarter Kelly territory)
+pub const DEFAULT_KELLY_FRACTION_CAP: f64 = 0.25;
+
+/// Fraction of bankroll to stake, in `[0.0, 1.0]`
+///
+/// `win_payout` is the profit per unit staked on a win and `lose_fraction`
+/// the share of the stake lost otherwise (1.0 for binary outcomes). With
+/// `lose_fraction = 1.0` this is `(p * b - (1 - p)) / b`. Returns 0.0 (do not
+/// bet) without an edge and for invalid inputs; stakes are never leveraged.
+pub fn kelly_fraction(win_prob: f64, win_payout: f64, lose_fraction: f64) -> f64 {
+    let valid = (0.0..=1.0).contains(&win_prob)
+        && win_payout > 0.0
+        && win_payout.is_finite()
+        && lose_fraction > 0.0
+        && lose_fraction <= 1.0;
+    if !valid {
+        return 0.0;
+    }
+
+    let fraction = win_prob / lose_fraction - (1.0 - win_prob) / win_payout;
+    fraction.clamp(0.0, 1.0)
+}
+
+/// USDC to stake: the Kelly fraction capped at `max_fraction`, times `bankroll_usdc`
+///
+/// `max_fraction` is clamped to `[0.0, 1.0]` (see `DEFAULT_KELLY_FRACTION_CAP`).
+pub fn kelly_usdc(
+    win_prob: f64,
+    win_payout: f64,
+    lose_fraction: f64,
+    bankroll_usdc: f64,
+    max_fraction: f64,
+) -> f64 {
+    let fraction = kelly_fraction(win_prob, win_payout, lose_fraction)
+        .min(max_fraction.clamp(0.0, 1.0));
+    if fraction <= 0.0 || bankroll_usdc.is_nan() || bankroll_usdc <= 0.0 {
+        return 0.0;
+    }
+
+    bankroll_usdc * fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kelly_fraction_edge_cases() {
+        // Even odds at 60%: 0.6 - 0.4 = 20%
+        assert!((kelly_fraction(0.60, 1.0, 1.0) - 0.20).abs() < 1e-9);
+
+        // Never wins: do not bet
+        assert_eq!(kelly_fraction(0.0, 1.0, 1.0), 0.0);
+
+        // Always wins: the whole bankroll, but never leveraged
+        assert_eq!(kelly_fraction(1.0, 0.5, 1.0), 1.0);
+        assert_eq!(kelly_fraction(1.0, 0.5, 0.5), 1.0);
+
+        // Negative edge: 0.4 - 0.6 / 1.0 < 0, do not bet
+        assert_eq!(kelly_fraction(0.40, 1.0, 1.0), 0.0);
+        assert_eq!(kelly_fraction(0.50, 0.5, 1.0), 0.0);
+
+        // Partial loss: 0.5 / 0.5 - 0.5 / 1.0 = 50%
+        assert!((kelly_fraction(0.50, 1.0, 0.5) - 0.50).abs() < 1e-9);
+
+        // Invalid inputs
+        assert_eq!(kelly_fraction(f64::NAN, 1.0, 1.0), 0.0);
+        assert_eq!(kelly_fraction(1.5, 1.0, 1.0), 0.0);
+        assert_eq!(kelly_fraction(0.60, 0.0, 1.0), 0.0);
+        assert_eq!(kelly_fraction(0.60, 1.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_kelly_usdc_caps_fraction() {
+        // 20% Kelly under the default 25% cap
+        let stake = kelly_usdc(0.60, 1.0, 1.0, 500.0, DEFAULT_KELLY_FRACTION_CAP);
+        assert!((stake - 100.0).abs() < 1e-9);
+
+        // Certain win capped at 25%
+        assert_eq!(kelly_usdc(1.0, 1.0, 1.0, 500.0, DEFAULT_KELLY_FRACTION_CAP), 125.0);
+
+        assert_eq!(kelly_usdc(0.40, 1.0, 1.0, 500.0, 0.25), 0.0, "No edge");
+        assert_eq!(kelly_usdc(0.60, 1.0, 1.0, 500.0, 0.0), 0.0, "Zero cap");
+        assert_eq!(kelly_usdc(0.60, 1.0, 1.0, -5.0, 0.25), 0.0, "No bankroll");
+    }
+}
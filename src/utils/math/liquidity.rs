@@ -0,0 +1,94 @@
+//! Order book liquidity scoring
+//!
+//! Ranks simultaneous opportunities by how cleanly `target_size` fills: a
+//! score of 1.0 fills entirely at the best price on both sides, 0.0 not at
+//! all. Size beyond the book's depth counts as 100% slippage, so shallow
+//! books score low even when their best levels look attractive.
+
+use crate::types::{OrderBook, OrderSide};
+
+/// `1.0 - slippage_fraction` of filling `target_size`, on the worse side
+///
+/// Walks the asks (buy) and bids (sell). Each side's slippage fraction is
+/// the VWAP's distance from the best price relative to it, weighted by the
+/// filled share, plus the unfilled share. 0.0 for an empty side or a
+/// non-positive `target_size`.
+pub fn liquidity_score(ob: &OrderBook, target_size: f64) -> f64 {
+    if target_size.is_nan() || target_size <= 0.0 {
+        return 0.0;
+    }
+
+    [OrderSide::BUY, OrderSide::SELL]
+        .into_iter()
+        .map(|side| side_score(ob, side, target_size))
+        .fold(1.0, f64::min)
+}
+
+/// Liquidity score of taking `target_size` on one side
+fn side_score(ob: &OrderBook, side: OrderSide, target_size: f64) -> f64 {
+    let best = match side {
+        OrderSide::BUY => ob.best_ask(),
+        OrderSide::SELL => ob.best_bid(),
+    };
+    let Some(best) = best.map(|level| level.price).filter(|&price| price > 0.0) else {
+        return 0.0;
+    };
+
+    let (avg_price, cost) = ob.compute_market_impact(side, target_size);
+    if cost <= 0.0 {
+        return 0.0;
+    }
+
+    let fill_fraction = (cost / avg_price / target_size).min(1.0);
+    let slippage = (avg_price - best).abs() / best;
+    let slippage_fraction = fill_fraction * slippage + (1.0 - fill_fraction);
+    (1.0 - slippage_fraction).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{OrderBookEntry, TokenId};
+
+    fn book(bids: &[(f64, f64)], asks: &[(f64, f64)]) -> OrderBook {
+        let levels = |levels: &[(f64, f64)]| {
+            levels
+                .iter()
+                .map(|&(price, size)| OrderBookEntry { price, size, timestamp: None })
+                .collect()
+        };
+        OrderBook {
+            token_id: TokenId("token".to_string()),
+            bids: levels(bids),
+            asks: levels(asks),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_empty_book_scores_zero() {
+        assert_eq!(liquidity_score(&book(&[], &[]), 10.0), 0.0);
+        assert_eq!(liquidity_score(&book(&[(0.48, 100.0)], &[]), 10.0), 0.0);
+        assert_eq!(liquidity_score(&book(&[(0.48, 100.0)], &[(0.50, 100.0)]), 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_deep_book_scores_one() {
+        let deep = book(&[(0.48, 500.0), (0.47, 500.0)], &[(0.50, 500.0), (0.51, 500.0)]);
+        assert_eq!(liquidity_score(&deep, 100.0), 1.0);
+    }
+
+    #[test]
+    fn test_partial_depth_and_slippage() {
+        // Only half of 20 shares on the asks: unfilled half counts as full slippage
+        let shallow = book(&[(0.48, 100.0)], &[(0.50, 10.0)]);
+        assert!((liquidity_score(&shallow, 20.0) - 0.5).abs() < 1e-9);
+
+        // Walking a second ask level: VWAP 0.525 is 5% above the best ask
+        let layered = book(&[(0.48, 100.0)], &[(0.50, 10.0), (0.55, 10.0)]);
+        assert!((liquidity_score(&layered, 20.0) - 0.95).abs() < 1e-9);
+
+        // More depth scores higher at the same size
+        assert!(liquidity_score(&layered, 20.0) > liquidity_score(&shallow, 20.0));
+    }
+}
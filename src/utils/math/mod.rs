@@ -1,2 +1,23 @@
-// Math utilities
-// TODO: Implement precision math helpers
+//! Math utilities
+
+/// Exponential moving averages and crossovers
+pub mod ema;
+/// Kelly criterion bet sizing
+pub mod kelly;
+/// Order book liquidity scoring for opportunity prioritization
+pub mod liquidity;
+/// Percentiles and fixed-bucket histograms for latency reporting
+pub mod percentile;
+/// Geometric mean and annualized returns
+pub mod returns;
+/// Online mean/variance for P&L tracking
+pub mod running_statistics;
+
+pub use ema::{ema, ema_crossover, ema_period, CrossoverSignal, Ema};
+pub use kelly::{kelly_fraction, kelly_usdc, DEFAULT_KELLY_FRACTION_CAP};
+pub use liquidity::liquidity_score;
+pub use percentile::{
+    percentile, percentile_of_sorted, percentiles, HistogramU64, LATENCY_BUCKETS_MS,
+};
+pub use returns::{annualized_return, geometric_mean};
+pub use running_statistics::RunningStatistics;
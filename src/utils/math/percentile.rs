@@ -0,0 +1,209 @@
+//! Percentiles for latency reporting
+//!
+//! Exact percentiles over raw samples use linear interpolation between
+//! adjacent ranks (rank = p / 100 * (n - 1)). [`HistogramU64`] trades
+//! precision for constant memory by counting samples into fixed buckets.
+//!
+//! # Example
+//! ```
+//! use polymarket_hft_bot::utils::math::{percentile, percentiles};
+//!
+//! let mut latencies_ms = vec![40.0, 10.0, 60.0, 30.0, 20.0, 50.0];
+//! assert_eq!(percentile(&mut latencies_ms, 0.0), 10.0);
+//! assert_eq!(percentiles(&mut latencies_ms, &[50.0, 90.0]), vec![35.0, 55.0]);
+//! ```
+
+/// Prometheus-style latency buckets in milliseconds
+pub const LATENCY_BUCKETS_MS: &[u64] = &[5, 10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000];
+
+/// `p`-th percentile (0-100) of `data`, sorting it in place
+///
+/// Returns 0.0 if `data` is empty. `p` is clamped to [0, 100]. NaN samples
+/// sort by [`f64::total_cmp`] (NaN after `+inf`, `-NaN` before `-inf`), so
+/// percentiles interpolating next to them are NaN.
+pub fn percentile(data: &mut [f64], p: f64) -> f64 {
+    data.sort_unstable_by(f64::total_cmp);
+    percentile_of_sorted(data, p)
+}
+
+/// `p`-th percentile (0-100) of `data` ascending by [`f64::total_cmp`]
+///
+/// Returns 0.0 if `data` is empty. `p` is clamped to [0, 100].
+pub fn percentile_of_sorted(data: &[f64], p: f64) -> f64 {
+    debug_assert!(data.windows(2).all(|w| w[0].total_cmp(&w[1]).is_le()), "data must be sorted");
+
+    let Some(&last) = data.last() else {
+        return 0.0;
+    };
+
+    let rank = p.clamp(0.0, 100.0) / 100.0 * (data.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    if lower + 1 >= data.len() {
+        return last;
+    }
+
+    let fraction = rank - lower as f64;
+    data[lower] + (data[lower + 1] - data[lower]) * fraction
+}
+
+/// Percentiles (0-100) of `data` in the order of `ps`, with a single sort
+pub fn percentiles(data: &mut [f64], ps: &[f64]) -> Vec<f64> {
+    data.sort_unstable_by(f64::total_cmp);
+    ps.iter().map(|&p| percentile_of_sorted(data, p)).collect()
+}
+
+/// Histogram over fixed `u64` buckets (e.g., latency in milliseconds)
+///
+/// `buckets` are ascending inclusive upper bounds, like Prometheus `le`;
+/// values above the last bound land in an overflow (`+Inf`) bucket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramU64 {
+    /// Bucket upper bounds (ascending)
+    pub buckets: &'static [u64],
+
+    /// Count per bucket, plus the overflow bucket at the end
+    counts: Vec<u64>,
+
+    /// Sum of recorded values
+    sum: u64,
+}
+
+impl HistogramU64 {
+    /// Create empty histogram with ascending bucket upper bounds
+    pub fn new(buckets: &'static [u64]) -> Self {
+        debug_assert!(buckets.windows(2).all(|w| w[0] < w[1]), "buckets must be ascending");
+
+        Self {
+            buckets,
+            counts: vec![0; buckets.len() + 1],
+            sum: 0,
+        }
+    }
+
+    /// Record a value
+    #[inline]
+    pub fn record(&mut self, value: u64) {
+        let bucket = self.buckets.partition_point(|&bound| bound < value);
+        self.counts[bucket] += 1;
+        self.sum = self.sum.saturating_add(value);
+    }
+
+    /// Number of recorded values
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Sum of recorded values
+    pub fn sum(&self) -> u64 {
+        self.sum
+    }
+
+    /// Cumulative count per bucket upper bound, for Prometheus export
+    ///
+    /// The `+Inf` bucket is not included; its cumulative count is `count()`.
+    pub fn cumulative_counts(&self) -> Vec<(u64, u64)> {
+        self.buckets
+            .iter()
+            .zip(&self.counts)
+            .scan(0, |cumulative, (&bound, &count)| {
+                *cumulative += count;
+                Some((bound, *cumulative))
+            })
+            .collect()
+    }
+
+    /// Upper bound of the bucket containing the `p`-th percentile (0-100)
+    ///
+    /// Returns 0.0 if empty, and `f64::INFINITY` if the percentile falls in
+    /// the overflow bucket.
+    pub fn percentile(&self, p: f64) -> f64 {
+        let total = self.count();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let target = ((p.clamp(0.0, 100.0) / 100.0 * total as f64).ceil() as u64).max(1);
+        self.cumulative_counts()
+            .into_iter()
+            .find(|&(_, cumulative)| cumulative >= target)
+            .map_or(f64::INFINITY, |(bound, _)| bound as f64)
+    }
+
+    /// Clear all counts, keeping the buckets
+    pub fn reset(&mut self) {
+        self.counts.iter_mut().for_each(|count| *count = 0);
+        self.sum = 0;
+    }
+}
+
+impl Default for HistogramU64 {
+    fn default() -> Self {
+        Self::new(LATENCY_BUCKETS_MS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_interpolates() {
+        let mut data = vec![4.0, 1.0, 3.0, 2.0];
+
+        assert_eq!(percentile(&mut data, 0.0), 1.0);
+        assert_eq!(data, vec![1.0, 2.0, 3.0, 4.0], "Sorted in place");
+        assert_eq!(percentile_of_sorted(&data, 100.0), 4.0);
+        assert_eq!(percentile_of_sorted(&data, 50.0), 2.5);
+        assert!((percentile_of_sorted(&data, 90.0) - 3.7).abs() < 1e-12);
+
+        // Out of range p is clamped
+        assert_eq!(percentile_of_sorted(&data, -5.0), 1.0);
+        assert_eq!(percentile_of_sorted(&data, 150.0), 4.0);
+    }
+
+    #[test]
+    fn test_percentile_edge_cases() {
+        assert_eq!(percentile(&mut [], 99.0), 0.0);
+        assert_eq!(percentile(&mut [7.0], 99.0), 7.0);
+        assert!(percentiles(&mut [], &[50.0, 99.0]).iter().all(|&v| v == 0.0));
+
+        // NaN sorts last instead of tripping the sortedness check
+        let mut data = [3.0, f64::NAN, 1.0, 2.0];
+        assert_eq!(percentiles(&mut data, &[0.0, 50.0]), vec![1.0, 2.5]);
+        assert!(percentile(&mut data, 100.0).is_nan());
+    }
+
+    #[test]
+    fn test_percentiles_match_single_calls() {
+        let mut data: Vec<f64> = (1..=100).rev().map(f64::from).collect();
+        let ps = [50.0, 95.0, 99.0];
+
+        let results = percentiles(&mut data, &ps);
+        let expected: Vec<f64> = ps.iter().map(|&p| percentile_of_sorted(&data, p)).collect();
+        assert_eq!(results, expected);
+        assert!((results[0] - 50.5).abs() < 1e-12);
+        assert!((results[2] - 99.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_histogram_buckets() {
+        let mut histogram = HistogramU64::new(&[10, 100, 1_000]);
+        for value in [5, 10, 11, 100, 500, 5_000] {
+            histogram.record(value);
+        }
+
+        assert_eq!(histogram.count(), 6);
+        assert_eq!(histogram.sum(), 5_626);
+        assert_eq!(histogram.cumulative_counts(), vec![(10, 2), (100, 4), (1_000, 5)]);
+
+        assert_eq!(histogram.percentile(0.0), 10.0);
+        assert_eq!(histogram.percentile(50.0), 100.0);
+        assert_eq!(histogram.percentile(80.0), 1_000.0);
+        assert_eq!(histogram.percentile(99.0), f64::INFINITY, "Overflow bucket");
+
+        histogram.reset();
+        assert_eq!(histogram.count(), 0);
+        assert_eq!(histogram.percentile(99.0), 0.0);
+        assert_eq!(HistogramU64::default().buckets, LATENCY_BUCKETS_MS);
+    }
+}
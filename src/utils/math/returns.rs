@@ -0,0 +1,84 @@
+//! Compound returns over multiple periods
+//!
+//! The arithmetic mean of per-period returns overestimates compound growth:
+//! +50% then -50% averages 0% but loses 25%. Take the geometric mean of
+//! growth factors (`1 + return`) instead.
+//!
+//! # Example: 7% on a 15-minute market held to expiry
+//! ```
+//! use polymarket_hft_bot::utils::math::{annualized_return, geometric_mean};
+//!
+//! // 15 minutes = 15 / 1440 days, so 35,064 periods per year:
+//! // 1.07^35064 - 1 ≈ 10^1030, which overflows f64
+//! let fifteen_minutes = 15.0 / 1440.0;
+//! assert_eq!(annualized_return(0.07, fifteen_minutes), f64::INFINITY);
+//!
+//! // The same 7% over 30 days: 1.07^12.175 - 1 ≈ 128%
+//! let monthly = annualized_return(0.07, 30.0);
+//! assert!((monthly - 1.2790).abs() < 0.001);
+//!
+//! // +50% then -50% compounds to -13.4% per period, not 0%
+//! let growth = geometric_mean(&[1.5, 0.5]).unwrap();
+//! assert!((growth - 0.8660).abs() < 0.001);
+//! ```
+
+/// Days per year used for annualization
+const DAYS_PER_YEAR: f64 = 365.25;
+
+/// Geometric mean: `exp(sum(ln(v)) / n)`
+///
+/// Pass growth factors (`1 + return`), not raw returns. Returns `None` for an
+/// empty slice or if any value is not positive.
+pub fn geometric_mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() || values.iter().any(|&v| v.is_nan() || v <= 0.0) {
+        return None;
+    }
+
+    let log_sum: f64 = values.iter().map(|v| v.ln()).sum();
+    Some((log_sum / values.len() as f64).exp())
+}
+
+/// Annualize a return earned over `holding_period_days`
+///
+/// `(1 + total_return) ^ (365.25 / holding_period_days) - 1`. Short holding
+/// periods compound into enormous rates and may overflow to infinity.
+pub fn annualized_return(total_return: f64, holding_period_days: f64) -> f64 {
+    (1.0 + total_return).powf(DAYS_PER_YEAR / holding_period_days) - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometric_mean() {
+        assert_eq!(geometric_mean(&[4.0]), Some(4.0));
+        assert!((geometric_mean(&[2.0, 8.0]).unwrap() - 4.0).abs() < 1e-12);
+        assert!((geometric_mean(&[1.1, 1.1, 1.1]).unwrap() - 1.1).abs() < 1e-12);
+
+        // Never above the arithmetic mean
+        let growth = [1.5, 0.5, 1.2];
+        let arithmetic = growth.iter().sum::<f64>() / growth.len() as f64;
+        assert!(geometric_mean(&growth).unwrap() < arithmetic);
+    }
+
+    #[test]
+    fn test_geometric_mean_rejects_invalid_input() {
+        assert_eq!(geometric_mean(&[]), None);
+        assert_eq!(geometric_mean(&[1.2, 0.0]), None);
+        assert_eq!(geometric_mean(&[1.2, -0.5]), None);
+        assert_eq!(geometric_mean(&[f64::NAN]), None);
+    }
+
+    #[test]
+    fn test_annualized_return() {
+        // A year-long holding is already annual
+        assert!((annualized_return(0.10, DAYS_PER_YEAR) - 0.10).abs() < 1e-12);
+
+        // Two years at 21% total is 10% per year
+        assert!((annualized_return(0.21, 2.0 * DAYS_PER_YEAR) - 0.10).abs() < 1e-12);
+
+        assert_eq!(annualized_return(0.0, 1.0), 0.0);
+        assert!(annualized_return(-0.05, 30.0) < -0.05);
+    }
+}
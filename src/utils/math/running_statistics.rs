@@ -0,0 +1,219 @@
+//! Running statistics using Welford's online algorithm
+//!
+//! Tracks mean and variance of a stream (e.g., per-trade P&L) in O(1) per
+//! update without storing values. Welford's update is numerically stable,
+//! unlike the naive sum-of-squares approach.
+//!
+//! # Example
+//! ```
+//! use polymarket_hft_bot::utils::math::RunningStatistics;
+//!
+//! let mut stats = RunningStatistics::new(0.0);
+//! for pnl in [1.0, 2.0, 3.0] {
+//!     stats.update(pnl);
+//! }
+//!
+//! assert_eq!(stats.mean(), 2.0);
+//! assert_eq!(stats.variance(), 1.0);
+//! ```
+
+/// Online mean and variance (Welford's algorithm)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunningStatistics {
+    /// Number of values seen
+    count: u64,
+
+    /// Running mean
+    mean: f64,
+
+    /// Sum of squared deviations from the mean
+    m2: f64,
+
+    /// Risk-free rate per observation (used by `sharpe_ratio`)
+    risk_free_rate: f64,
+}
+
+impl RunningStatistics {
+    /// Create empty statistics
+    ///
+    /// # Arguments
+    /// * `risk_free_rate` - Risk-free return per observation, in the same units as values
+    pub fn new(risk_free_rate: f64) -> Self {
+        Self {
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+            risk_free_rate,
+        }
+    }
+
+    /// Add a value
+    #[inline]
+    pub fn update(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Number of values seen
+    #[inline]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Mean of values (0.0 if empty)
+    #[inline]
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Sample variance (0.0 with fewer than 2 values)
+    #[inline]
+    pub fn variance(&self) -> f64 {
+        if self.count < 2 {
+            0.0
+        } else {
+            self.m2 / (self.count - 1) as f64
+        }
+    }
+
+    /// Sample standard deviation
+    #[inline]
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// Risk-free rate per observation
+    pub fn risk_free_rate(&self) -> f64 {
+        self.risk_free_rate
+    }
+
+    /// Sharpe ratio: `(mean - risk_free_rate) / std_dev`
+    ///
+    /// Per-observation (not annualized). Returns `None` with fewer than
+    /// 2 values or zero variance.
+    pub fn sharpe_ratio(&self) -> Option<f64> {
+        let std_dev = self.std_dev();
+        if self.count < 2 || std_dev == 0.0 {
+            return None;
+        }
+
+        Some((self.mean - self.risk_free_rate) / std_dev)
+    }
+
+    /// Clear all values (e.g., daily reset), keeping the risk-free rate
+    pub fn reset(&mut self) {
+        self.count = 0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+    }
+}
+
+impl Default for RunningStatistics {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Two-pass reference mean and sample variance
+    fn two_pass(values: &[f64]) -> (f64, f64) {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        (mean, variance)
+    }
+
+    #[test]
+    fn test_empty_and_single_value() {
+        let mut stats = RunningStatistics::new(0.0);
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), 0.0);
+        assert_eq!(stats.variance(), 0.0);
+        assert!(stats.sharpe_ratio().is_none());
+
+        stats.update(5.0);
+        assert_eq!(stats.count(), 1);
+        assert_eq!(stats.mean(), 5.0);
+        assert_eq!(stats.variance(), 0.0);
+        assert!(stats.sharpe_ratio().is_none());
+    }
+
+    #[test]
+    fn test_mean_variance_and_sharpe() {
+        let mut stats = RunningStatistics::new(1.0);
+        for value in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.update(value);
+        }
+
+        assert_eq!(stats.count(), 8);
+        assert!((stats.mean() - 5.0).abs() < 1e-12);
+        assert!((stats.variance() - 32.0 / 7.0).abs() < 1e-12);
+
+        // (5.0 - 1.0) / sqrt(32/7)
+        let expected = 4.0 / (32.0f64 / 7.0).sqrt();
+        assert!((stats.sharpe_ratio().unwrap() - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_constant_values_have_no_sharpe() {
+        let mut stats = RunningStatistics::default();
+        for _ in 0..10 {
+            stats.update(3.0);
+        }
+
+        assert_eq!(stats.variance(), 0.0);
+        assert!(stats.sharpe_ratio().is_none());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stats = RunningStatistics::new(0.5);
+        stats.update(1.0);
+        stats.update(2.0);
+
+        stats.reset();
+
+        assert_eq!(stats, RunningStatistics::new(0.5));
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+
+        #[test]
+        fn matches_two_pass_for_10k_updates(
+            values in prop::collection::vec(-1_000.0f64..1_000.0, 10_000),
+        ) {
+            let mut stats = RunningStatistics::default();
+            for &value in &values {
+                stats.update(value);
+            }
+
+            let (mean, variance) = two_pass(&values);
+            prop_assert_eq!(stats.count(), 10_000);
+            prop_assert!((stats.mean() - mean).abs() < 1e-9);
+            prop_assert!((stats.variance() - variance).abs() <= variance * 1e-9);
+        }
+
+        #[test]
+        fn stable_with_large_offset(
+            offset in 1e6f64..1e9,
+            noise in prop::collection::vec(-1.0f64..1.0, 10_000),
+        ) {
+            // Naive sum-of-squares loses all precision here
+            let mut stats = RunningStatistics::default();
+            for &n in &noise {
+                stats.update(offset + n);
+            }
+
+            let (_, variance) = two_pass(&noise);
+            prop_assert!(stats.variance() >= 0.0);
+            prop_assert!((stats.variance() - variance).abs() < variance * 1e-3);
+        }
+    }
+}
@@ -0,0 +1,475 @@
+//! Prometheus metrics for operational monitoring
+//!
+//! [`HftMetrics`] owns its own registry. `ArbitrageExecutor` and
+//! `CircuitBreaker` update it after each operation once attached with
+//! `with_metrics`, and [`HftMetrics::serve`] exposes it as a plain-text
+//! scrape endpoint (plus a JSON `/health` with [`HftMetrics::serve_with_health`]):
+//!
+//! - `hft_arbitrage_opportunities_found_total`
+//! - `hft_trades_executed_total{result="success|partial|failed"}`
+//! - `hft_circuit_breaker_tripped_total`
+//! - `hft_daily_loss_usdc`
+//! - `hft_open_positions`
+//! - `hft_execution_latency_ms` (buckets: `math::LATENCY_BUCKETS_MS`)
+//! - `cb_risk_score`, registered by `CircuitBreaker::with_metrics`
+//!
+//! [`LatencyTracker`] complements the latency histogram with exact
+//! p50/p95/p99 over the most recent executions.
+
+use anyhow::{anyhow, Result};
+use parking_lot::Mutex;
+use prometheus::{
+    Encoder, Gauge, Histogram, HistogramOpts, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use crate::utils::math::{percentile_of_sorted, RunningStatistics, LATENCY_BUCKETS_MS};
+
+/// Default scrape endpoint address
+pub const DEFAULT_METRICS_ADDR: &str = "0.0.0.0:9090";
+
+/// Latency samples kept by a `LatencyTracker`
+pub const LATENCY_SAMPLES: usize = 4096;
+
+/// Body of the `GET /health` endpoint (see [`HftMetrics::serve_with_health`])
+pub trait HealthReport: Send + Sync {
+    /// Current state as JSON
+    fn health(&self) -> serde_json::Value;
+}
+
+/// Bot-wide Prometheus metrics
+#[derive(Debug)]
+pub struct HftMetrics {
+    /// Registry holding every metric below
+    registry: Registry,
+
+    /// Opportunities handed to the executor
+    opportunities_found: IntCounter,
+
+    /// Executions by result
+    trades_executed: IntCounterVec,
+
+    /// Circuit breaker trips
+    circuit_breaker_tripped: IntCounter,
+
+    /// Current daily loss
+    daily_loss_usdc: Gauge,
+
+    /// Currently open positions
+    open_positions: IntGauge,
+
+    /// Execution latency
+    execution_latency_ms: Histogram,
+}
+
+impl HftMetrics {
+    /// Create and register all metrics in a fresh registry
+    pub fn new() -> Self {
+        let metrics = Self {
+            registry: Registry::new(),
+            opportunities_found: IntCounter::new(
+                "hft_arbitrage_opportunities_found_total",
+                "Arbitrage opportunities handed to the executor",
+            )
+            .expect("valid counter definition"),
+            trades_executed: IntCounterVec::new(
+                Opts::new("hft_trades_executed_total", "Arbitrage executions by result"),
+                &["result"],
+            )
+            .expect("valid counter definition"),
+            circuit_breaker_tripped: IntCounter::new(
+                "hft_circuit_breaker_tripped_total",
+                "Circuit breaker trips",
+            )
+            .expect("valid counter definition"),
+            daily_loss_usdc: Gauge::new("hft_daily_loss_usdc", "Daily loss in USDC")
+                .expect("valid gauge definition"),
+            open_positions: IntGauge::new("hft_open_positions", "Open positions")
+                .expect("valid gauge definition"),
+            execution_latency_ms: Histogram::with_opts(
+                HistogramOpts::new("hft_execution_latency_ms", "Execution latency in ms")
+                    .buckets(LATENCY_BUCKETS_MS.iter().map(|&bound| bound as f64).collect()),
+            )
+            .expect("valid histogram definition"),
+        };
+
+        let collectors: [Box<dyn prometheus::core::Collector>; 6] = [
+            Box::new(metrics.opportunities_found.clone()),
+            Box::new(metrics.trades_executed.clone()),
+            Box::new(metrics.circuit_breaker_tripped.clone()),
+            Box::new(metrics.daily_loss_usdc.clone()),
+            Box::new(metrics.open_positions.clone()),
+            Box::new(metrics.execution_latency_ms.clone()),
+        ];
+        for collector in collectors {
+            metrics.registry.register(collector).expect("unique metric names");
+        }
+
+        metrics
+    }
+
+    /// Registry with every metric (e.g., to serve alongside other collectors)
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Count an opportunity handed to the executor
+    pub fn record_opportunity(&self) {
+        self.opportunities_found.inc();
+    }
+
+    /// Count an execution by `result` ("success", "partial" or "failed")
+    pub fn record_execution(&self, result: &str) {
+        self.trades_executed.with_label_values(&[result]).inc();
+    }
+
+    /// Observe an execution latency
+    pub fn observe_latency(&self, latency_ms: f64) {
+        self.execution_latency_ms.observe(latency_ms);
+    }
+
+    /// Count a circuit breaker trip
+    pub fn record_trip(&self) {
+        self.circuit_breaker_tripped.inc();
+    }
+
+    /// Set the daily loss gauge
+    pub fn set_daily_loss(&self, daily_loss_usdc: f64) {
+        self.daily_loss_usdc.set(daily_loss_usdc);
+    }
+
+    /// Set the open positions gauge
+    pub fn set_open_positions(&self, open_positions: u32) {
+        self.open_positions.set(open_positions as i64);
+    }
+
+    /// Executions recorded with `result` ("success", "partial" or "failed")
+    pub fn trades_executed(&self, result: &str) -> u64 {
+        self.trades_executed.with_label_values(&[result]).get()
+    }
+
+    /// All metrics in the Prometheus text format
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        if let Err(e) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            tracing::error!("Failed to encode metrics: {}", e);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+
+    /// Serve `GET /metrics` on `addr` (e.g., [`DEFAULT_METRICS_ADDR`])
+    ///
+    /// Binds before returning, so a taken port is reported here; connections
+    /// are then handled by the returned task. Must be called from within a
+    /// Tokio runtime.
+    pub async fn serve(self: Arc<Self>, addr: &str) -> Result<JoinHandle<()>> {
+        self.listen(addr, None).await
+    }
+
+    /// Like [`serve`](Self::serve), plus `GET /health` answered by `health`
+    pub async fn serve_with_health(
+        self: Arc<Self>,
+        addr: &str,
+        health: Arc<dyn HealthReport>,
+    ) -> Result<JoinHandle<()>> {
+        self.listen(addr, Some(health)).await
+    }
+
+    async fn listen(
+        self: Arc<Self>,
+        addr: &str,
+        health: Option<Arc<dyn HealthReport>>,
+    ) -> Result<JoinHandle<()>> {
+        let listener = TcpListener::bind(addr)
+            .await
+            .map_err(|e| anyhow!("Failed to bind metrics endpoint {}: {}", addr, e))?;
+        tracing::info!("📈 Metrics endpoint listening on http://{}/metrics", addr);
+
+        Ok(tokio::spawn(async move {
+            loop {
+                match listener.accept().await {
+                    Ok((stream, _)) => {
+                        let metrics = Arc::clone(&self);
+                        let health = health.clone();
+                        tokio::spawn(async move {
+                            if let Err(e) = metrics.respond(stream, health.as_deref()).await {
+                                tracing::debug!("Metrics request failed: {}", e);
+                            }
+                        });
+                    }
+                    Err(e) => tracing::warn!("Metrics endpoint accept failed: {}", e),
+                }
+            }
+        }))
+    }
+
+    /// Answer one HTTP request: `GET /metrics` or `/health`, 404 otherwise
+    async fn respond(
+        &self,
+        mut stream: TcpStream,
+        health: Option<&dyn HealthReport>,
+    ) -> std::io::Result<()> {
+        // The request line fits in the first read; headers and body are ignored
+        let mut request = [0u8; 1024];
+        let read = stream.read(&mut request).await?;
+        let request_line = String::from_utf8_lossy(&request[..read]);
+
+        let (status, content_type, body) = match health {
+            _ if request_line.starts_with("GET /metrics ") => {
+                ("200 OK", "text/plain; version=0.0.4", self.render())
+            }
+            Some(health) if request_line.starts_with("GET /health ") => {
+                ("200 OK", "application/json", health.health().to_string())
+            }
+            _ => ("404 Not Found", "text/plain", String::new()),
+        };
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            content_type,
+            body.len(),
+            body
+        );
+
+        stream.write_all(response.as_bytes()).await?;
+        stream.shutdown().await
+    }
+}
+
+impl Default for HftMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Exact latency percentiles over the last [`LATENCY_SAMPLES`] samples
+///
+/// Keeps a ring buffer plus a sorted copy of it: `record` is O(n) (one
+/// removal and one insertion into the sorted copy), percentiles are O(1).
+/// The mean covers every sample since creation or `reset`.
+#[derive(Debug, Default)]
+pub struct LatencyTracker {
+    window: Mutex<LatencyWindow>,
+}
+
+/// Ring buffer of samples and the same samples sorted
+#[derive(Debug, Default)]
+struct LatencyWindow {
+    /// Samples in arrival order (overwritten from `next` once full)
+    ring: Vec<u64>,
+
+    /// Index of the oldest sample once the ring is full
+    next: usize,
+
+    /// `ring` as `f64` (exact below 2^53 ns), ascending
+    sorted: Vec<f64>,
+
+    /// Every sample, including evicted ones
+    stats: RunningStatistics,
+}
+
+/// Serializable latency percentiles (e.g., for a health endpoint)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LatencySummary {
+    /// Samples in the window
+    pub count: usize,
+    /// Median latency in nanoseconds
+    pub p50_ns: u64,
+    /// 95th percentile latency in nanoseconds
+    pub p95_ns: u64,
+    /// 99th percentile latency in nanoseconds
+    pub p99_ns: u64,
+}
+
+impl LatencyTracker {
+    /// Empty tracker
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a latency sample, evicting the oldest once full
+    pub fn record(&self, ns: u64) {
+        let mut window = self.window.lock();
+        let window = &mut *window;
+
+        if window.ring.len() < LATENCY_SAMPLES {
+            window.ring.push(ns);
+        } else {
+            let evicted = std::mem::replace(&mut window.ring[window.next], ns) as f64;
+            window.next = (window.next + 1) % LATENCY_SAMPLES;
+            let index = window.sorted.partition_point(|&sample| sample < evicted);
+            if window.sorted.get(index) == Some(&evicted) {
+                window.sorted.remove(index);
+            }
+        }
+
+        let index = window.sorted.partition_point(|&sample| sample <= ns as f64);
+        window.sorted.insert(index, ns as f64);
+        window.stats.update(ns as f64);
+    }
+
+    /// Mean latency in nanoseconds (0.0 if empty)
+    pub fn mean(&self) -> f64 {
+        self.window.lock().stats.mean()
+    }
+
+    /// Median latency in nanoseconds (0 if empty)
+    pub fn p50(&self) -> u64 {
+        self.percentile(50.0)
+    }
+
+    /// 95th percentile latency in nanoseconds (0 if empty)
+    pub fn p95(&self) -> u64 {
+        self.percentile(95.0)
+    }
+
+    /// 99th percentile latency in nanoseconds (0 if empty)
+    pub fn p99(&self) -> u64 {
+        self.percentile(99.0)
+    }
+
+    /// Samples in the window
+    pub fn len(&self) -> usize {
+        self.window.lock().sorted.len()
+    }
+
+    /// Whether no sample has been recorded since creation or `reset`
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all samples
+    pub fn reset(&self) {
+        *self.window.lock() = LatencyWindow::default();
+    }
+
+    /// Sample count and percentiles from one consistent snapshot
+    pub fn to_summary(&self) -> LatencySummary {
+        let window = self.window.lock();
+        let percentile = |p| percentile_of_sorted(&window.sorted, p).round() as u64;
+        LatencySummary {
+            count: window.sorted.len(),
+            p50_ns: percentile(50.0),
+            p95_ns: percentile(95.0),
+            p99_ns: percentile(99.0),
+        }
+    }
+
+    /// See `math::percentile_of_sorted`, rounded to whole nanoseconds
+    fn percentile(&self, p: f64) -> u64 {
+        percentile_of_sorted(&self.window.lock().sorted, p).round() as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_tracker_percentiles() {
+        let tracker = LatencyTracker::new();
+        assert_eq!((tracker.p50(), tracker.p99()), (0, 0));
+
+        // 1..=1000 µs, recorded out of order
+        for i in (1..=1000u64).rev() {
+            tracker.record(i * 1_000);
+        }
+        // Interpolated between adjacent ranks, like `math::percentile`
+        assert_eq!(tracker.p50(), 500_500);
+        assert_eq!(tracker.p95(), 950_050);
+        assert_eq!(tracker.p99(), 990_010);
+
+        let summary = tracker.to_summary();
+        assert_eq!(
+            summary,
+            LatencySummary { count: 1000, p50_ns: 500_500, p95_ns: 950_050, p99_ns: 990_010 }
+        );
+        assert!(serde_json::to_string(&summary).unwrap().contains("\"p99_ns\":990010"));
+
+        tracker.reset();
+        assert!(tracker.is_empty());
+        assert_eq!(tracker.p95(), 0);
+    }
+
+    #[test]
+    fn test_latency_tracker_evicts_oldest() {
+        let tracker = LatencyTracker::new();
+        for _ in 0..LATENCY_SAMPLES {
+            tracker.record(1_000_000);
+        }
+        assert_eq!(tracker.p99(), 1_000_000);
+
+        // Replace 98% of the window with faster samples
+        for _ in 0..(LATENCY_SAMPLES * 98 / 100) {
+            tracker.record(100);
+        }
+        assert_eq!(tracker.len(), LATENCY_SAMPLES);
+        assert_eq!(tracker.p50(), 100);
+        assert_eq!(tracker.p95(), 100);
+        assert_eq!(tracker.p99(), 1_000_000);
+    }
+
+    struct StaticHealth;
+
+    impl HealthReport for StaticHealth {
+        fn health(&self) -> serde_json::Value {
+            serde_json::json!({ "status": "ok" })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_and_serves_metrics() {
+        let metrics = Arc::new(HftMetrics::new());
+        metrics.record_opportunity();
+        metrics.record_execution("partial");
+        metrics.observe_latency(120.0);
+        metrics.record_trip();
+        metrics.set_daily_loss(12.5);
+        metrics.set_open_positions(2);
+        assert_eq!(metrics.trades_executed("partial"), 1);
+        assert_eq!(metrics.trades_executed("success"), 0);
+
+        let rendered = metrics.render();
+        for line in [
+            "hft_arbitrage_opportunities_found_total 1",
+            "hft_trades_executed_total{result=\"partial\"} 1",
+            "hft_circuit_breaker_tripped_total 1",
+            "hft_daily_loss_usdc 12.5",
+            "hft_open_positions 2",
+            "hft_execution_latency_ms_bucket{le=\"100\"} 0",
+            "hft_execution_latency_ms_bucket{le=\"250\"} 1",
+        ] {
+            assert!(rendered.contains(line), "Missing {:?} in:\n{}", line, rendered);
+        }
+
+        // Scrape over TCP: bind an ephemeral port first to learn its address
+        let addr = TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+        let server = Arc::clone(&metrics)
+            .serve_with_health(&addr.to_string(), Arc::new(StaticHealth))
+            .await
+            .unwrap();
+        let scrape = |path: &'static str| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path);
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        let response = scrape("/metrics").await;
+        assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+        assert!(response.ends_with(&metrics.render()));
+        let response = scrape("/health").await;
+        assert!(response.contains("Content-Type: application/json"), "{}", response);
+        assert!(response.ends_with(r#"{"status":"ok"}"#), "{}", response);
+        assert!(scrape("/").await.starts_with("HTTP/1.1 404 Not Found"));
+        server.abort();
+    }
+}
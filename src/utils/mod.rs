@@ -1,8 +1,15 @@
 //! Utility modules
 
+/// Operator alerts (Slack, PagerDuty, webhooks)
+#[cfg(feature = "alerts")]
+pub mod alerts;
 /// Logging utilities
 pub mod logger;
 /// Math utilities
 pub mod math;
+/// Prometheus metrics and scrape endpoint
+pub mod metrics;
 /// Fixed-point math for ultra-low latency
 pub mod fixed_point;
+/// Graceful shutdown on SIGTERM
+pub mod shutdown;
@@ -0,0 +1,184 @@
+//! Graceful shutdown on SIGTERM
+//!
+//! Killing the bot used to leave its resting GTC orders on the exchange,
+//! where the next run could trade against the same opportunity twice.
+//! [`ShutdownCoordinator`] turns SIGTERM (or Ctrl+C) into a broadcast that
+//! every task selects on; `ArbitrageExecutor` stops taking new executions,
+//! and [`ShutdownCoordinator::cancel_all_open_orders`] then cancels the
+//! orders behind every tracked position before the process exits.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+
+use crate::clob::ClobClient;
+use crate::core::risk::PositionTracker;
+
+/// Broadcasts a single shutdown signal to all bot tasks
+#[derive(Debug)]
+pub struct ShutdownCoordinator {
+    /// Shutdown broadcast (tasks hold receivers from `subscribe`)
+    sender: broadcast::Sender<()>,
+
+    /// Set once the shutdown has been broadcast
+    triggered: AtomicBool,
+}
+
+impl ShutdownCoordinator {
+    /// Create coordinator (no signal handler yet, see `install_signal_handler`)
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(1);
+        Self {
+            sender,
+            triggered: AtomicBool::new(false),
+        }
+    }
+
+    /// Receiver that gets `()` once shutdown starts
+    ///
+    /// Subscribe before the signal can arrive: a receiver created afterwards
+    /// misses the broadcast (check `is_shutting_down` instead).
+    pub fn subscribe(&self) -> broadcast::Receiver<()> {
+        self.sender.subscribe()
+    }
+
+    /// Whether shutdown has started
+    pub fn is_shutting_down(&self) -> bool {
+        self.triggered.load(Ordering::Acquire)
+    }
+
+    /// Start shutdown (idempotent)
+    pub fn trigger(&self) {
+        if !self.triggered.swap(true, Ordering::AcqRel) {
+            tracing::warn!("🛑 Shutdown started");
+            // No receivers just means nothing is waiting yet
+            let _ = self.sender.send(());
+        }
+    }
+
+    /// Trigger shutdown on SIGTERM or Ctrl+C
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn install_signal_handler(self: &Arc<Self>) -> JoinHandle<()> {
+        let coordinator = Arc::clone(self);
+        tokio::spawn(async move {
+            wait_for_signal().await;
+            coordinator.trigger();
+        })
+    }
+
+    /// Cancel the orders behind every tracked position
+    ///
+    /// Each order hash is cancelled individually so one failure does not stop
+    /// the rest. Returns the number successfully cancelled.
+    pub async fn cancel_all_open_orders(client: &ClobClient, tracker: &PositionTracker) -> usize {
+        let order_hashes: Vec<String> = tracker
+            .get_all_positions()
+            .into_iter()
+            .flat_map(|(_, position)| position.order_hashes)
+            .collect();
+
+        let mut cancelled = 0;
+        for order_hash in &order_hashes {
+            match client.cancel_order(order_hash).await {
+                Ok(()) => cancelled += 1,
+                Err(e) => tracing::warn!("Failed to cancel {} on shutdown: {}", order_hash, e),
+            }
+        }
+
+        tracing::info!("Cancelled {}/{} open orders on shutdown", cancelled, order_hashes.len());
+        cancelled
+    }
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wait for SIGTERM or Ctrl+C (Ctrl+C only off Unix)
+async fn wait_for_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = sigterm.recv() => {}
+                    _ = tokio::signal::ctrl_c() => {}
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to install SIGTERM handler: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clob::ClobConfig;
+    use crate::types::{MarketId, Position, TokenId};
+
+    #[tokio::test]
+    async fn test_trigger_broadcasts_once() {
+        let coordinator = ShutdownCoordinator::new();
+        let mut receiver = coordinator.subscribe();
+        assert!(!coordinator.is_shutting_down());
+
+        coordinator.trigger();
+        coordinator.trigger();
+        assert!(coordinator.is_shutting_down());
+        assert!(receiver.recv().await.is_ok());
+        assert!(receiver.try_recv().is_err(), "Broadcast only once");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_open_orders() {
+        let mut server = mockito::Server::new_async().await;
+        let cancelled =
+            server.mock("DELETE", "/orders/0xyes").with_status(200).create_async().await;
+        let failed = server.mock("DELETE", "/orders/0xno").with_status(404).create_async().await;
+
+        let client = ClobClient::new(ClobConfig {
+            base_url: server.url(),
+            private_key: "0x0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+                .to_string(),
+            retry_attempts: 1,
+            ..ClobConfig::default()
+        })
+        .unwrap();
+
+        let tracker = PositionTracker::new();
+        for (token, hash) in [("yes", "0xyes"), ("no", "0xno")] {
+            let position = Position {
+                market_id: MarketId::new("m1"),
+                token_id: TokenId(token.to_string()),
+                size: 10.0,
+                entry_price: 0.5,
+                current_price: 0.5,
+                unrealized_pnl: 0.0,
+                realized_pnl: 0.0,
+                opened_at: 0,
+                updated_at: 0,
+                order_hashes: vec![hash.to_string()],
+            };
+            let key = (position.market_id.clone(), position.token_id.clone());
+            tracker.update_position(key.0, key.1, position);
+        }
+
+        assert_eq!(ShutdownCoordinator::cancel_all_open_orders(&client, &tracker).await, 1);
+        cancelled.assert_async().await;
+        failed.assert_async().await;
+    }
+}
@@ -1,6 +1,6 @@
-///! Integration tests for configuration loading
-///!
-///! Tests that configuration can be loaded from environment variables
+//! Integration tests for configuration loading
+//!
+//! Tests that configuration can be loaded from environment variables
 
 use polymarket_hft_bot::types::config::BotConfig;
 
@@ -26,21 +26,21 @@ fn test_config_validation() {
     let mut config = BotConfig::default();
 
     // Valid config should pass
-    assert!(config.validate().is_ok());
+    assert!(config.validate().is_empty());
 
     // Invalid trading config (take profit < stop loss)
     config.trading.take_profit_amount = 0.01;
     config.trading.stop_loss_amount = 0.05;
-    assert!(config.validate().is_err());
+    assert!(!config.validate().is_empty());
 
     // Fix it
     config.trading.take_profit_amount = 0.05;
     config.trading.stop_loss_amount = 0.03;
-    assert!(config.validate().is_ok());
+    assert!(config.validate().is_empty());
 
     // Invalid risk config (0 positions)
     config.risk.max_open_positions = 0;
-    assert!(config.validate().is_err());
+    assert!(!config.validate().is_empty());
 }
 
 #[test]
@@ -1,14 +1,14 @@
-///! Phase 1 Validation Tests
-///!
-///! Comprehensive tests to validate Phase 1 completion before moving to Phase 2
+//! Phase 1 Validation Tests
+//!
+//! Comprehensive tests to validate Phase 1 completion before moving to Phase 2
 
 use polymarket_hft_bot::types::*;
 
 #[test]
 fn test_market_types_complete() {
     // Test MarketId
-    let market_id = MarketId("test-market-123".to_string());
-    assert!(!market_id.0.is_empty());
+    let market_id = MarketId::new("test-market-123");
+    assert!(!market_id.to_string().is_empty());
 
     // Test TokenId
     let token_id = TokenId("0x123456".to_string());
@@ -135,7 +135,7 @@ fn test_order_response_logic() {
 fn test_arbitrage_opportunity_detection() {
     // Valid arbitrage (bid > ask)
     let opportunity = ArbitrageOpportunity::new(
-        MarketId("market-1".to_string()),
+        MarketId::new("market-1"),
         TokenId("token-1".to_string()),
         0.75, // bid
         0.70, // ask
@@ -152,7 +152,7 @@ fn test_arbitrage_opportunity_detection() {
 
     // No arbitrage (bid < ask)
     let no_opportunity = ArbitrageOpportunity::new(
-        MarketId("market-2".to_string()),
+        MarketId::new("market-2"),
         TokenId("token-2".to_string()),
         0.70, // bid
         0.75, // ask
@@ -166,7 +166,7 @@ fn test_arbitrage_opportunity_detection() {
 
     // Edge case (bid == ask)
     let edge_case = ArbitrageOpportunity::new(
-        MarketId("market-3".to_string()),
+        MarketId::new("market-3"),
         TokenId("token-3".to_string()),
         0.75,
         0.75,
@@ -182,7 +182,7 @@ fn test_arbitrage_opportunity_detection() {
 #[test]
 fn test_arbitrage_threshold_logic() {
     let opportunity = ArbitrageOpportunity::new(
-        MarketId("market-1".to_string()),
+        MarketId::new("market-1"),
         TokenId("token-1".to_string()),
         0.75,
         0.70,
@@ -206,7 +206,7 @@ fn test_arbitrage_threshold_logic() {
 #[test]
 fn test_position_tracking() {
     let position = Position {
-        market_id: MarketId("market-1".to_string()),
+        market_id: MarketId::new("market-1"),
         token_id: TokenId("token-1".to_string()),
         size: 100.0,
         entry_price: 0.70,
@@ -215,6 +215,7 @@ fn test_position_tracking() {
         realized_pnl: 0.0,
         opened_at: 1000,
         updated_at: 1100,
+        order_hashes: Vec::new(),
     };
 
     // Test position direction
@@ -276,9 +277,10 @@ fn test_execution_result_types() {
 
 /// Test that all critical types are defined and working
 #[test]
+#[allow(clippy::assertions_on_constants)]
 fn test_phase1_type_system_complete() {
     // Market types ✅
-    let _ = MarketId("test".to_string());
+    let _ = MarketId::new("test");
     let _ = TokenId("0x123".to_string());
     let _ = Outcome::YES;
     let _ = MarketStatus::Active;
@@ -290,7 +292,7 @@ fn test_phase1_type_system_complete() {
 
     // Trade types ✅
     let _ = ArbitrageOpportunity::new(
-        MarketId("m1".to_string()),
+        MarketId::new("m1"),
         TokenId("t1".to_string()),
         0.75,
         0.70,